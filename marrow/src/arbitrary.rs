@@ -0,0 +1,761 @@
+//! Generate random data types, fields and arrays with [`arbitrary`]
+//!
+//! The generated data types pass [`DataType::validate`] and the generated arrays conform to their
+//! data type: offsets are monotonic and in bounds, dictionary keys and union offsets reference
+//! existing values, run ends are strictly increasing and byte views reference their buffers. This
+//! makes them suitable to fuzz code that consumes arrays, e.g., conversions into other Arrow
+//! implementations.
+//!
+//! Fields that are not nullable never contain missing values. Floats are always finite to keep
+//! arrays comparable with `==`.
+//!
+//! ```rust
+//! # use marrow::arbitrary::{arbitrary_array, arbitrary_field, Options};
+//! let data = [7_u8; 256];
+//! let mut u = arbitrary::Unstructured::new(&data);
+//!
+//! let options = Options::default();
+//! let field = arbitrary_field(&mut u, &options).unwrap();
+//! let array = arbitrary_array(&mut u, &field.data_type, field.nullable, &options).unwrap();
+//!
+//! assert!(field.validate().is_ok());
+//! assert_eq!(array.data_type(), field.data_type);
+//! ```
+use std::collections::HashMap;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use half::f16;
+
+use crate::{
+    array::{
+        Array, BooleanArray, BytesArray, BytesViewArray, DecimalArray, DictionaryArray,
+        FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray,
+        RunEndEncodedArray, StructArray, TimeArray, TimestampArray, UnionArray,
+    },
+    bits::Bitmap,
+    datatypes::{
+        meta_from_field, DataType, DictionaryMeta, Field, FieldMeta, IntervalUnit, MapMeta,
+        RunEndEncodedMeta, TimeUnit, UnionMode,
+    },
+    types::{DayTimeInterval, MonthDayNanoInterval},
+};
+
+/// Options to control the generated data types and arrays
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    /// The maximum nesting depth of data types, a depth of 0 only generates non-nested types
+    pub max_depth: usize,
+    /// The maximum number of fields of structs and unions
+    pub max_fields: usize,
+    /// The maximum number of elements of top-level arrays
+    pub max_len: usize,
+    /// Whether to generate `Utf8View` and `BinaryView` data types
+    pub views: bool,
+    /// Whether to generate union data types
+    pub unions: bool,
+    /// Whether to generate run-end encoded data types
+    pub run_end_encoded: bool,
+}
+
+impl std::default::Default for Options {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_fields: 4,
+            max_len: 16,
+            views: true,
+            unions: true,
+            run_end_encoded: true,
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for DataType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_data_type(u, &Options::default())
+    }
+}
+
+impl<'a> Arbitrary<'a> for Field {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_field(u, &Options::default())
+    }
+}
+
+impl<'a> Arbitrary<'a> for Array {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let options = Options::default();
+        let field = arbitrary_field(u, &options)?;
+        arbitrary_array(u, &field.data_type, field.nullable, &options)
+    }
+}
+
+/// Generate a random data type
+pub fn arbitrary_data_type(u: &mut Unstructured<'_>, options: &Options) -> Result<DataType> {
+    data_type(u, options, options.max_depth)
+}
+
+/// Generate a random field
+///
+/// Fields of type `Null` are always nullable.
+pub fn arbitrary_field(u: &mut Unstructured<'_>, options: &Options) -> Result<Field> {
+    field(u, options, options.max_depth, "field")
+}
+
+/// Generate a random array of the given data type with up to `options.max_len` elements
+///
+/// If `nullable` is `false`, the array does not contain missing values.
+pub fn arbitrary_array(
+    u: &mut Unstructured<'_>,
+    data_type: &DataType,
+    nullable: bool,
+    options: &Options,
+) -> Result<Array> {
+    let len = u.int_in_range(0..=options.max_len)?;
+    array(u, data_type, nullable, len)
+}
+
+fn field(u: &mut Unstructured<'_>, options: &Options, depth: usize, name: &str) -> Result<Field> {
+    let data_type = data_type(u, options, depth)?;
+    let nullable = matches!(data_type, DataType::Null) || u.arbitrary()?;
+    let dictionary = if matches!(data_type, DataType::Dictionary(_, _)) {
+        DictionaryMeta {
+            ordered: u.arbitrary()?,
+            // ids of `0` are not distinguishable from missing ids in `arrow`
+            id: if u.arbitrary()? {
+                Some(u.int_in_range(1..=1000)?)
+            } else {
+                None
+            },
+        }
+    } else {
+        DictionaryMeta::default()
+    };
+    Ok(Field {
+        name: name.to_owned(),
+        data_type,
+        nullable,
+        metadata: HashMap::new(),
+        dictionary,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Leaf(usize),
+    Struct,
+    List,
+    LargeList,
+    FixedSizeList,
+    Map,
+    Dictionary,
+    RunEndEncoded,
+    Union(UnionMode),
+}
+
+fn data_type(u: &mut Unstructured<'_>, options: &Options, depth: usize) -> Result<DataType> {
+    use DataType as T;
+
+    let mut kinds = (0..19).map(Kind::Leaf).collect::<Vec<_>>();
+    if options.views {
+        kinds.extend([Kind::Leaf(19), Kind::Leaf(20)]);
+    }
+    if depth > 0 {
+        kinds.extend([
+            Kind::Struct,
+            Kind::List,
+            Kind::LargeList,
+            Kind::FixedSizeList,
+            Kind::Map,
+            Kind::Dictionary,
+        ]);
+        if options.run_end_encoded {
+            kinds.push(Kind::RunEndEncoded);
+        }
+        if options.unions {
+            kinds.extend([
+                Kind::Union(UnionMode::Dense),
+                Kind::Union(UnionMode::Sparse),
+            ]);
+        }
+    }
+
+    let data_type = match *u.choose(&kinds)? {
+        Kind::Leaf(0) => T::Null,
+        Kind::Leaf(1) => T::Boolean,
+        Kind::Leaf(2) => u.choose(&INTEGER_TYPES)?.clone(),
+        Kind::Leaf(3) => u.choose(&[T::Float16, T::Float32, T::Float64])?.clone(),
+        Kind::Leaf(4) => T::Utf8,
+        Kind::Leaf(5) => T::LargeUtf8,
+        Kind::Leaf(6) => T::Binary,
+        Kind::Leaf(7) => T::LargeBinary,
+        Kind::Leaf(8) => T::FixedSizeBinary(u.int_in_range(1..=8)?),
+        Kind::Leaf(9) => T::Date32,
+        Kind::Leaf(10) => T::Date64,
+        Kind::Leaf(11) => T::Time32(*u.choose(&[TimeUnit::Second, TimeUnit::Millisecond])?),
+        Kind::Leaf(12) => T::Time64(*u.choose(&[TimeUnit::Microsecond, TimeUnit::Nanosecond])?),
+        Kind::Leaf(13) => T::Duration(time_unit(u)?),
+        Kind::Leaf(14) => {
+            let timezone = match u.choose_index(3)? {
+                0 => None,
+                1 => Some(String::from("UTC")),
+                _ => Some(String::from("+01:00")),
+            };
+            T::Timestamp(time_unit(u)?, timezone)
+        }
+        Kind::Leaf(15) => T::Interval(IntervalUnit::YearMonth),
+        Kind::Leaf(16) => T::Interval(IntervalUnit::DayTime),
+        Kind::Leaf(17) => T::Interval(IntervalUnit::MonthDayNano),
+        Kind::Leaf(18) => {
+            let precision = u.int_in_range(1..=38)?;
+            let scale = u.int_in_range(0..=precision.min(10))?;
+            T::Decimal128(precision, scale as i8)
+        }
+        Kind::Leaf(19) => T::Utf8View,
+        Kind::Leaf(_) => T::BinaryView,
+        Kind::Struct => {
+            let len = u.int_in_range(0..=options.max_fields)?;
+            let mut fields = Vec::with_capacity(len);
+            for idx in 0..len {
+                fields.push(field(u, options, depth - 1, &format!("f{idx}"))?);
+            }
+            T::Struct(fields)
+        }
+        Kind::List => T::List(Box::new(field(u, options, depth - 1, "item")?)),
+        Kind::LargeList => T::LargeList(Box::new(field(u, options, depth - 1, "item")?)),
+        Kind::FixedSizeList => T::FixedSizeList(
+            Box::new(field(u, options, depth - 1, "item")?),
+            u.int_in_range(1..=3)?,
+        ),
+        Kind::Map => {
+            let keys = Field {
+                name: String::from("key"),
+                data_type: u
+                    .choose(&[T::Int32, T::Int64, T::Utf8, T::LargeUtf8])?
+                    .clone(),
+                ..Field::default()
+            };
+            let values = field(u, options, depth - 1, "value")?;
+            let entries = Field {
+                name: String::from("entries"),
+                data_type: T::Struct(vec![keys, values]),
+                ..Field::default()
+            };
+            T::Map(Box::new(entries), false)
+        }
+        Kind::Dictionary => T::Dictionary(
+            Box::new(u.choose(&INTEGER_TYPES)?.clone()),
+            Box::new(
+                u.choose(&[T::Utf8, T::LargeUtf8, T::Binary, T::Int32])?
+                    .clone(),
+            ),
+        ),
+        Kind::RunEndEncoded => {
+            let run_ends = Field {
+                name: String::from("run_ends"),
+                data_type: u.choose(&[T::Int16, T::Int32, T::Int64])?.clone(),
+                ..Field::default()
+            };
+            let mut values = field(u, options, 0, "values")?;
+            values.nullable = true;
+            T::RunEndEncoded(Box::new(run_ends), Box::new(values))
+        }
+        Kind::Union(mode) => {
+            let len = u.int_in_range(1..=options.max_fields.max(1))?;
+            let mut type_ids = (0..=127).collect::<Vec<i8>>();
+            let mut fields = Vec::with_capacity(len);
+            for idx in 0..len {
+                let type_id = type_ids.remove(u.choose_index(type_ids.len())?);
+                fields.push((type_id, field(u, options, depth - 1, &format!("u{idx}"))?));
+            }
+            T::Union(fields, mode)
+        }
+    };
+    Ok(data_type)
+}
+
+const INTEGER_TYPES: [DataType; 8] = [
+    DataType::Int8,
+    DataType::Int16,
+    DataType::Int32,
+    DataType::Int64,
+    DataType::UInt8,
+    DataType::UInt16,
+    DataType::UInt32,
+    DataType::UInt64,
+];
+
+fn time_unit(u: &mut Unstructured<'_>) -> Result<TimeUnit> {
+    Ok(*u.choose(&[
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Nanosecond,
+    ])?)
+}
+
+fn array(
+    u: &mut Unstructured<'_>,
+    data_type: &DataType,
+    nullable: bool,
+    len: usize,
+) -> Result<Array> {
+    use {Array as A, DataType as T};
+
+    macro_rules! primitive {
+        ($variant:ident, $value:expr) => {{
+            let validity = validity(u, nullable, len)?;
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push($value(&mut *u)?);
+            }
+            A::$variant(PrimitiveArray { validity, values })
+        }};
+    }
+    macro_rules! time {
+        ($variant:ident, $unit:expr, $value:expr) => {{
+            let validity = validity(u, nullable, len)?;
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push($value(&mut *u)?);
+            }
+            A::$variant(TimeArray {
+                unit: $unit,
+                validity,
+                values,
+            })
+        }};
+    }
+
+    let array = match data_type {
+        T::Null => A::Null(NullArray { len }),
+        T::Boolean => A::Boolean(BooleanArray {
+            len,
+            validity: validity(u, nullable, len)?,
+            values: bits(u, len)?.into_vec(),
+        }),
+        T::Int8 => primitive!(Int8, Unstructured::arbitrary),
+        T::Int16 => primitive!(Int16, Unstructured::arbitrary),
+        T::Int32 => primitive!(Int32, Unstructured::arbitrary),
+        T::Int64 => primitive!(Int64, Unstructured::arbitrary),
+        T::UInt8 => primitive!(UInt8, Unstructured::arbitrary),
+        T::UInt16 => primitive!(UInt16, Unstructured::arbitrary),
+        T::UInt32 => primitive!(UInt32, Unstructured::arbitrary),
+        T::UInt64 => primitive!(UInt64, Unstructured::arbitrary),
+        T::Float16 => primitive!(Float16, |u: &mut Unstructured<'_>| {
+            Ok(f16::from_f32(float(u)? as f32))
+        }),
+        T::Float32 => primitive!(Float32, |u: &mut Unstructured<'_>| Ok(float(u)? as f32)),
+        T::Float64 => primitive!(Float64, float),
+        T::Date32 => primitive!(Date32, |u: &mut Unstructured<'_>| u
+            .int_in_range(-1_000_000..=1_000_000)),
+        T::Date64 => primitive!(Date64, |u: &mut Unstructured<'_>| Ok(u
+            .int_in_range(-1_000_000..=1_000_000_i64)?
+            * 86_400_000)),
+        T::Time32(unit) => time!(Time32, *unit, |u: &mut Unstructured<'_>| {
+            u.int_in_range(0..=(86_400 * seconds_per_unit(*unit) as i32 - 1))
+        }),
+        T::Time64(unit) => time!(Time64, *unit, |u: &mut Unstructured<'_>| {
+            u.int_in_range(0..=(86_400 * seconds_per_unit(*unit) - 1))
+        }),
+        T::Duration(unit) => time!(Duration, *unit, Unstructured::arbitrary),
+        T::Timestamp(unit, timezone) => {
+            let validity = validity(u, nullable, len)?;
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push(u.arbitrary()?);
+            }
+            A::Timestamp(TimestampArray {
+                unit: *unit,
+                timezone: timezone.clone(),
+                validity,
+                values,
+            })
+        }
+        T::Interval(IntervalUnit::YearMonth) => {
+            primitive!(YearMonthInterval, Unstructured::arbitrary)
+        }
+        T::Interval(IntervalUnit::DayTime) => {
+            primitive!(DayTimeInterval, |u: &mut Unstructured<'_>| {
+                Ok(DayTimeInterval {
+                    days: u.arbitrary()?,
+                    milliseconds: u.arbitrary()?,
+                })
+            })
+        }
+        T::Interval(IntervalUnit::MonthDayNano) => {
+            primitive!(MonthDayNanoInterval, |u: &mut Unstructured<'_>| {
+                Ok(MonthDayNanoInterval {
+                    months: u.arbitrary()?,
+                    days: u.arbitrary()?,
+                    nanoseconds: u.arbitrary()?,
+                })
+            })
+        }
+        T::Decimal128(precision, scale) => {
+            let bound = 10_i128.pow(u32::from(*precision));
+            let validity = validity(u, nullable, len)?;
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push(u.arbitrary::<i128>()? % bound);
+            }
+            A::Decimal128(DecimalArray {
+                precision: *precision,
+                scale: *scale,
+                validity,
+                values,
+            })
+        }
+        T::Utf8 => A::Utf8(bytes_array(u, nullable, len, string)?),
+        T::LargeUtf8 => A::LargeUtf8(bytes_array(u, nullable, len, string)?),
+        T::Binary => A::Binary(bytes_array(u, nullable, len, binary)?),
+        T::LargeBinary => A::LargeBinary(bytes_array(u, nullable, len, binary)?),
+        T::Utf8View => A::Utf8View(bytes_view_array(u, nullable, len, string)?),
+        T::BinaryView => A::BinaryView(bytes_view_array(u, nullable, len, binary)?),
+        T::FixedSizeBinary(n) => {
+            let validity = validity(u, nullable, len)?;
+            let mut data = vec![0; len * usize::try_from(*n).unwrap_or_default()];
+            u.fill_buffer(&mut data)?;
+            A::FixedSizeBinary(FixedSizeBinaryArray {
+                n: *n,
+                validity,
+                data,
+            })
+        }
+        T::Struct(fields) => {
+            let validity = validity(u, nullable, len)?;
+            let mut children = Vec::with_capacity(fields.len());
+            for field in fields {
+                children.push(child(u, field, len)?);
+            }
+            A::Struct(StructArray {
+                len,
+                validity,
+                fields: children,
+            })
+        }
+        T::List(item) => {
+            let validity = validity(u, nullable, len)?;
+            let (offsets, child_len) = offsets(u, len)?;
+            let (meta, elements) = child(u, item, child_len)?;
+            A::List(ListArray {
+                validity,
+                offsets: convert_offsets(offsets),
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::LargeList(item) => {
+            let validity = validity(u, nullable, len)?;
+            let (offsets, child_len) = offsets(u, len)?;
+            let (meta, elements) = child(u, item, child_len)?;
+            A::LargeList(ListArray {
+                validity,
+                offsets: offsets.into_iter().map(|offset| offset as i64).collect(),
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::FixedSizeList(item, n) => {
+            let validity = validity(u, nullable, len)?;
+            let child_len = len * usize::try_from(*n).unwrap_or_default();
+            let (meta, elements) = child(u, item, child_len)?;
+            A::FixedSizeList(FixedSizeListArray {
+                len,
+                n: *n,
+                validity,
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::Map(entries, sorted) => {
+            let validity = validity(u, nullable, len)?;
+            let (offsets, child_len) = offsets(u, len)?;
+            let T::Struct(entry_fields) = &entries.data_type else {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            };
+            let [keys, values] = entry_fields.as_slice() else {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            };
+            let (keys_meta, keys) = child(u, keys, child_len)?;
+            let (values_meta, values) = child(u, values, child_len)?;
+            A::Map(MapArray {
+                validity,
+                offsets: convert_offsets(offsets),
+                meta: MapMeta {
+                    entries_name: entries.name.clone(),
+                    sorted: *sorted,
+                    keys: keys_meta,
+                    values: values_meta,
+                },
+                keys: Box::new(keys),
+                values: Box::new(values),
+            })
+        }
+        T::Dictionary(key_type, value_type) => {
+            let values_len = u.int_in_range(1..=4)?;
+            let values = array(u, value_type, false, values_len)?;
+            let keys = array(u, key_type, nullable, len)?;
+            A::Dictionary(DictionaryArray {
+                keys: Box::new(map_keys(keys, values_len)),
+                values: Box::new(values),
+            })
+        }
+        T::RunEndEncoded(run_ends_field, values_field) => {
+            let mut run_ends = Vec::new();
+            let mut end = 0;
+            while end < len {
+                end = (end + u.int_in_range(1..=3)?).min(len);
+                run_ends.push(end as i64);
+            }
+            let values_len = run_ends.len();
+            let run_ends = match &run_ends_field.data_type {
+                T::Int16 => A::Int16(PrimitiveArray {
+                    validity: None,
+                    values: run_ends.iter().map(|end| *end as i16).collect(),
+                }),
+                T::Int32 => A::Int32(PrimitiveArray {
+                    validity: None,
+                    values: run_ends.iter().map(|end| *end as i32).collect(),
+                }),
+                _ => A::Int64(PrimitiveArray {
+                    validity: None,
+                    values: run_ends,
+                }),
+            };
+            let (values_meta, values) = child(u, values_field, values_len)?;
+            A::RunEndEncoded(RunEndEncodedArray {
+                meta: RunEndEncodedMeta {
+                    run_ends_name: run_ends_field.name.clone(),
+                    values: values_meta,
+                },
+                run_ends: Box::new(run_ends),
+                values: Box::new(values),
+            })
+        }
+        T::Union(fields, mode) => {
+            if fields.is_empty() && len > 0 {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            }
+            let mut child_types = Vec::with_capacity(len);
+            for _ in 0..len {
+                child_types.push(u.choose_index(fields.len())?);
+            }
+            let types = child_types.iter().map(|idx| fields[*idx].0).collect();
+
+            let mut counts = vec![0; fields.len()];
+            let offsets = match mode {
+                UnionMode::Dense => {
+                    let mut offsets = Vec::with_capacity(len);
+                    for idx in &child_types {
+                        offsets.push(counts[*idx]);
+                        counts[*idx] += 1;
+                    }
+                    Some(offsets)
+                }
+                UnionMode::Sparse => None,
+            };
+
+            let mut children = Vec::with_capacity(fields.len());
+            for (idx, (type_id, field)) in fields.iter().enumerate() {
+                let child_len = match mode {
+                    UnionMode::Dense => counts[idx] as usize,
+                    UnionMode::Sparse => len,
+                };
+                let (meta, array) = child(u, field, child_len)?;
+                children.push((*type_id, meta, array));
+            }
+            A::Union(UnionArray {
+                types,
+                offsets,
+                fields: children,
+            })
+        }
+    };
+    Ok(array)
+}
+
+fn child(u: &mut Unstructured<'_>, field: &Field, len: usize) -> Result<(FieldMeta, Array)> {
+    let array = array(u, &field.data_type, field.nullable, len)?;
+    Ok((meta_from_field(field.clone()), array))
+}
+
+fn validity(u: &mut Unstructured<'_>, nullable: bool, len: usize) -> Result<Option<Vec<u8>>> {
+    if !nullable {
+        return Ok(None);
+    }
+    Ok(Some(bits(u, len)?.into_vec()))
+}
+
+fn bits(u: &mut Unstructured<'_>, len: usize) -> Result<Bitmap> {
+    let mut bits = Bitmap::new();
+    for _ in 0..len {
+        bits.push(u.arbitrary()?);
+    }
+    Ok(bits)
+}
+
+/// Generate offsets with up to 3 elements per item, the first offset is not necessarily zero
+fn offsets(u: &mut Unstructured<'_>, len: usize) -> Result<(Vec<usize>, usize)> {
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(u.int_in_range(0..=2)?);
+    for idx in 0..len {
+        offsets.push(offsets[idx] + u.int_in_range(0..=3)?);
+    }
+    let child_len = offsets[len] + u.int_in_range(0..=1)?;
+    Ok((offsets, child_len))
+}
+
+fn convert_offsets(offsets: Vec<usize>) -> Vec<i32> {
+    offsets.into_iter().map(|offset| offset as i32).collect()
+}
+
+/// Map the keys of a dictionary into the range of the values
+fn map_keys(keys: Array, values_len: usize) -> Array {
+    macro_rules! map {
+        ($variant:ident, $array:expr, $ty:ty) => {
+            Array::$variant(PrimitiveArray {
+                validity: $array.validity,
+                values: $array
+                    .values
+                    .into_iter()
+                    .map(|key| (key as i128).rem_euclid(values_len as i128) as $ty)
+                    .collect(),
+            })
+        };
+    }
+    match keys {
+        Array::Int8(keys) => map!(Int8, keys, i8),
+        Array::Int16(keys) => map!(Int16, keys, i16),
+        Array::Int32(keys) => map!(Int32, keys, i32),
+        Array::Int64(keys) => map!(Int64, keys, i64),
+        Array::UInt8(keys) => map!(UInt8, keys, u8),
+        Array::UInt16(keys) => map!(UInt16, keys, u16),
+        Array::UInt32(keys) => map!(UInt32, keys, u32),
+        Array::UInt64(keys) => map!(UInt64, keys, u64),
+        keys => keys,
+    }
+}
+
+fn bytes_array<O: TryFrom<usize> + Default>(
+    u: &mut Unstructured<'_>,
+    nullable: bool,
+    len: usize,
+    value: fn(&mut Unstructured<'_>) -> Result<Vec<u8>>,
+) -> Result<BytesArray<O>> {
+    let validity = validity(u, nullable, len)?;
+    let mut offsets = vec![O::default()];
+    let mut data = Vec::new();
+    for _ in 0..len {
+        data.extend(value(u)?);
+        offsets.push(O::try_from(data.len()).map_err(|_| ::arbitrary::Error::IncorrectFormat)?);
+    }
+    Ok(BytesArray {
+        validity,
+        offsets,
+        data,
+    })
+}
+
+/// Generate byte views, values with more than 12 bytes are stored in one of up to two buffers
+fn bytes_view_array(
+    u: &mut Unstructured<'_>,
+    nullable: bool,
+    len: usize,
+    value: fn(&mut Unstructured<'_>) -> Result<Vec<u8>>,
+) -> Result<BytesViewArray> {
+    let validity = validity(u, nullable, len)?;
+    let mut buffers = vec![Vec::new(); u.int_in_range(1..=2)?];
+    let mut data = Vec::with_capacity(len);
+    for _ in 0..len {
+        let value = value(u)?;
+        let mut view = [0_u8; 16];
+        view[0..4].copy_from_slice(&(value.len() as u32).to_le_bytes());
+        if value.len() <= 12 {
+            view[4..4 + value.len()].copy_from_slice(&value);
+        } else {
+            let buffer_index = u.choose_index(buffers.len())?;
+            let buffer = &mut buffers[buffer_index];
+            view[4..8].copy_from_slice(&value[..4]);
+            view[8..12].copy_from_slice(&(buffer_index as u32).to_le_bytes());
+            view[12..16].copy_from_slice(&(buffer.len() as u32).to_le_bytes());
+            buffer.extend(value);
+        }
+        data.push(u128::from_le_bytes(view));
+    }
+    buffers.retain(|buffer| !buffer.is_empty());
+    if buffers.len() == 1 {
+        // all buffered values were stored in the second buffer: renumber them
+        for view in &mut data {
+            if (*view as u32) > 12 {
+                *view &= !(u128::from(u32::MAX) << 64);
+            }
+        }
+    }
+    Ok(BytesViewArray {
+        validity,
+        data,
+        buffers,
+    })
+}
+
+fn string(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let len = u.int_in_range(0..=16)?;
+    let mut res = String::new();
+    for _ in 0..len {
+        res.push(u.arbitrary()?);
+    }
+    Ok(res.into_bytes())
+}
+
+fn binary(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let mut res = vec![0; u.int_in_range(0..=24)?];
+    u.fill_buffer(&mut res)?;
+    Ok(res)
+}
+
+fn float(u: &mut Unstructured<'_>) -> Result<f64> {
+    Ok(f64::from(u.arbitrary::<i16>()?) / 8.0)
+}
+
+fn seconds_per_unit(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+#[test]
+fn generated_arrays_conform_to_their_data_type() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..200 {
+        let data = (0..2048)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        let options = Options::default();
+        let field = arbitrary_field(&mut u, &options).unwrap();
+        field.validate().unwrap();
+
+        let array = arbitrary_array(&mut u, &field.data_type, field.nullable, &options).unwrap();
+        assert_eq!(array.data_type(), field.data_type);
+        crate::array::view_len(&array.as_view()).unwrap();
+    }
+
+    let options = Options {
+        max_depth: 0,
+        views: false,
+        ..Options::default()
+    };
+    let mut u = Unstructured::new(&[]);
+    let data_type = arbitrary_data_type(&mut u, &options).unwrap();
+    assert_eq!(data_type, DataType::Null);
+}
@@ -9,7 +9,7 @@ use crate::{
         TimeUnit, UnionMode,
     },
     error::{fail, ErrorKind, Result},
-    types::{DayTimeInterval, MonthDayNanoInterval},
+    types::{DayTimeInterval, MarrowPrimitive, MonthDayNanoInterval},
     view::{
         BitsWithOffset, BooleanView, BytesView, BytesViewView, DecimalView, DictionaryView,
         FixedSizeBinaryView, FixedSizeListView, ListView, MapView, NullView, PrimitiveView,
@@ -17,6 +17,14 @@ use crate::{
     },
 };
 
+mod from_iter;
+mod from_view;
+mod memory;
+
+#[cfg(any(feature = "serde", feature = "arrow2-0-17", feature = "arrow2-0-16"))]
+pub(crate) use from_view::copy_bits;
+pub(crate) use from_view::view_len;
+
 // assert that the `Array` implements the expected traits
 #[allow(unused)]
 const _: () = {
@@ -28,6 +36,7 @@ const _: () = {
 ///
 /// The corresponding view is [`View`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Array {
     /// An array without data
@@ -69,16 +78,10 @@ pub enum Array {
     /// An `i64` array of durations
     Duration(TimeArray<i64>),
     /// Interval with `YearMonth` unit
-    ///
-    /// Interval arrays are not supported for `arrow2`.
     YearMonthInterval(PrimitiveArray<i32>),
     /// Interval with `DayTime` unit
-    ///
-    /// Interval arrays are not supported for `arrow2`.
     DayTimeInterval(PrimitiveArray<DayTimeInterval>),
     /// Interval with `MonthDayNano` unit
-    ///
-    /// Interval arrays are not supported for `arrow2`.
     MonthDayNanoInterval(PrimitiveArray<MonthDayNanoInterval>),
     /// A `[u8]` array with `i32` offsets interpreted as strings
     Utf8(BytesArray<i32>),
@@ -201,6 +204,7 @@ impl Array {
                     data_type: arr.run_ends.data_type(),
                     nullable: false,
                     metadata: Default::default(),
+                    dictionary: Default::default(),
                 }),
                 Box::new(field_from_meta(
                     arr.values.data_type(),
@@ -259,10 +263,124 @@ impl Array {
     }
 }
 
+/// Define the typed accessors of [`Array`] and [`View`], e.g., `as_struct`
+macro_rules! define_accessors {
+    (
+        @accessor $(#[$meta:meta])* $name:ident, [$($mut_:tt)?], $ty:ty, $kind:literal,
+        $desc:literal, [$($variant:ident),*]
+    ) => {
+        $(#[$meta])*
+        pub fn $name(& $($mut_)? self) -> Result<& $($mut_)? $ty> {
+            match self {
+                $(Self::$variant(inner))|* => Ok(inner),
+                _ => fail!(
+                    ErrorKind::Unsupported,
+                    concat!("Cannot access ", $kind, " of type {dt} as ", $desc),
+                    dt = self.data_type(),
+                ),
+            }
+        }
+    };
+    (Array, [$(
+        $(#[$meta:meta])*
+        ($name:ident, $name_mut:ident, $ty:ty, $desc:literal, [$($variant:ident),*]),
+    )*]) => {
+        impl Array {
+            $(
+                define_accessors!(
+                    @accessor $(#[$meta])* $name, [], $ty, "an array", $desc, [$($variant),*]
+                );
+                define_accessors!(
+                    @accessor $(#[$meta])* $name_mut, [mut], $ty, "an array", $desc, [$($variant),*]
+                );
+            )*
+        }
+    };
+    (View, [$(
+        $(#[$meta:meta])*
+        ($name:ident, $ty:ty, $desc:literal, [$($variant:ident),*]),
+    )*]) => {
+        impl<'a> View<'a> {
+            $(
+                define_accessors!(
+                    @accessor $(#[$meta])* $name, [], $ty, "a view", $desc, [$($variant),*]
+                );
+            )*
+        }
+    };
+}
+
+pub(crate) use define_accessors;
+
+define_accessors!(Array, [
+    /// Access the array as a struct array
+    (as_struct, as_struct_mut, StructArray, "a struct array", [Struct]),
+    /// Access the array as a list array with `i32` offsets
+    (as_list, as_list_mut, ListArray<i32>, "a list array", [List]),
+    /// Access the array as a list array with `i64` offsets
+    (as_large_list, as_large_list_mut, ListArray<i64>, "a large list array", [LargeList]),
+    /// Access `Utf8` or `Binary` arrays as a bytes array with `i32` offsets
+    (as_bytes, as_bytes_mut, BytesArray<i32>, "a bytes array", [Utf8, Binary]),
+    /// Access `LargeUtf8` or `LargeBinary` arrays as a bytes array with `i64` offsets
+    (as_large_bytes, as_large_bytes_mut, BytesArray<i64>, "a large bytes array", [LargeUtf8, LargeBinary]),
+]);
+
+impl Array {
+    /// Access the array as a primitive array with elements of type `T`
+    ///
+    /// All arrays stored as [`PrimitiveArray<T>`] are accepted, e.g., `Date32` and
+    /// `YearMonthInterval` arrays for `i32`. Arrays with additional metadata, e.g., `Time32`,
+    /// `Timestamp` or `Decimal128` arrays, are not accepted. Their values are accessible via the
+    /// `values` field of the corresponding array struct. [`View::as_primitive`] accepts the same
+    /// views.
+    ///
+    /// ```rust
+    /// # use marrow::array::{Array, PrimitiveArray};
+    /// # fn main() -> marrow::error::Result<()> {
+    /// let mut array = Array::Date32(PrimitiveArray {
+    ///     validity: None,
+    ///     values: vec![1, 2, 3],
+    /// });
+    /// array.as_primitive_mut::<i32>()?.values[0] = 4;
+    ///
+    /// assert_eq!(array.as_primitive::<i32>()?.values, vec![4, 2, 3]);
+    /// assert!(array.as_primitive::<i64>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_primitive<T: MarrowPrimitive>(&self) -> Result<&PrimitiveArray<T>> {
+        match T::from_array(self) {
+            Some(array) => Ok(array),
+            None => fail!(
+                ErrorKind::Unsupported,
+                "Cannot access an array of type {dt} as a primitive array of {ty}",
+                dt = self.data_type(),
+                ty = std::any::type_name::<T>(),
+            ),
+        }
+    }
+
+    /// Access the array as a mutable primitive array with elements of type `T`
+    ///
+    /// See [`as_primitive`][Array::as_primitive] for the accepted arrays.
+    pub fn as_primitive_mut<T: MarrowPrimitive>(&mut self) -> Result<&mut PrimitiveArray<T>> {
+        let dt = self.data_type();
+        match T::from_array_mut(self) {
+            Some(array) => Ok(array),
+            None => fail!(
+                ErrorKind::Unsupported,
+                "Cannot access an array of type {dt} as a primitive array of {ty}",
+                ty = std::any::type_name::<T>(),
+            ),
+        }
+    }
+}
+
 /// An array without data
 ///
 /// The corresponding view is [`NullView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NullArray {
     /// The len of the array
     pub len: usize,
@@ -279,6 +397,7 @@ impl NullArray {
 ///
 /// The corresponding view is [`BooleanView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BooleanArray {
     // Note: len is required to know how many bits of values are used
     /// The len of the array
@@ -310,6 +429,7 @@ impl BooleanArray {
 ///
 /// The corresponding view is [`PrimitiveView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrimitiveArray<T> {
     /// The validity of the elements as a bitmap
     pub validity: Option<Vec<u8>>,
@@ -334,6 +454,7 @@ impl<T> PrimitiveArray<T> {
 ///
 /// The corresponding view is [`TimeView`].
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeArray<T> {
     /// The time unit of the values
     pub unit: TimeUnit,
@@ -361,7 +482,7 @@ impl<T> TimeArray<T> {
 ///
 /// The corresponding view is [`TimestampView`].
 #[derive(Debug, Clone, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimestampArray {
     /// The time unit of the values
     pub unit: TimeUnit,
@@ -392,6 +513,7 @@ impl TimestampArray {
 ///
 /// The corresponding view is [`StructView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructArray {
     /// The number of elements in the array
     pub len: usize,
@@ -423,6 +545,7 @@ impl StructArray {
 ///
 /// The corresponding view is [`MapView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MapArray {
     /// The validity of the elements as a bitmap
     pub validity: Option<Vec<u8>>,
@@ -478,6 +601,61 @@ impl MapArray {
             self.offsets,
         ))
     }
+
+    #[cfg(any(
+        feature = "serde",
+        // arrow-version:insert:         feature = "arrow-{version}",
+        feature = "arrow-58",
+        feature = "arrow-57",
+        feature = "arrow-56",
+        feature = "arrow-55",
+        feature = "arrow-54",
+        feature = "arrow-53",
+        feature = "arrow-52",
+        feature = "arrow-51",
+        feature = "arrow-50",
+        feature = "arrow-49",
+        feature = "arrow-48",
+        feature = "arrow-47",
+        feature = "arrow-46",
+        feature = "arrow-45",
+        feature = "arrow-44",
+        feature = "arrow-43",
+        feature = "arrow-42",
+        feature = "arrow-41",
+        feature = "arrow-40",
+        feature = "arrow-39",
+        feature = "arrow-38",
+        feature = "arrow-37",
+    ))]
+    pub(crate) fn from_logical_array(
+        entries: Array,
+        entries_name: String,
+        sorted: bool,
+        validity: Option<Vec<u8>>,
+        offsets: Vec<i32>,
+    ) -> Result<Self> {
+        let Array::Struct(entries) = entries else {
+            fail!(ErrorKind::Unsupported, "Expected struct array");
+        };
+        let Ok(entries_fields) = <[(FieldMeta, Array); 2]>::try_from(entries.fields) else {
+            fail!(ErrorKind::Unsupported, "Expected two entries");
+        };
+        let [(keys_meta, keys), (values_meta, values)] = entries_fields;
+
+        Ok(MapArray {
+            validity,
+            offsets,
+            meta: MapMeta {
+                entries_name,
+                sorted,
+                keys: keys_meta,
+                values: values_meta,
+            },
+            keys: Box::new(keys),
+            values: Box::new(values),
+        })
+    }
 }
 
 /// An array of lists
@@ -486,6 +664,7 @@ impl MapArray {
 ///
 /// The corresponding view is [`ListView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListArray<O> {
     /// The validity of the elements as a bitmap
     pub validity: Option<Vec<u8>>,
@@ -518,6 +697,7 @@ impl<O> ListArray<O> {
 ///
 /// The corresponding view is [`FixedSizeListView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedSizeListArray {
     /// The number of elements in this array, each a list with `n` children
     pub len: usize,
@@ -553,6 +733,7 @@ impl FixedSizeListArray {
 ///
 /// The corresponding view is [`BytesView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BytesArray<O> {
     /// The validity of the elements as a bitmap
     pub validity: Option<Vec<u8>>,
@@ -578,6 +759,7 @@ impl<O> BytesArray<O> {
 
 /// An array of (possibly inlined) byte strings
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BytesViewArray {
     /// The validity of the elements as a bitmap
     pub validity: Option<Vec<u8>>,
@@ -605,6 +787,7 @@ impl BytesViewArray {
 ///
 /// The corresponding view is [`FixedSizeBinaryView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedSizeBinaryArray {
     /// The number of bytes per element
     pub n: i32,
@@ -634,6 +817,7 @@ impl FixedSizeBinaryArray {
 ///
 /// The corresponding view is [`DecimalView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecimalArray<T> {
     /// The precision, i.e., the number of digits
     pub precision: u8,
@@ -666,6 +850,7 @@ impl<T> DecimalArray<T> {
 ///
 /// The corresponding view is [`DictionaryView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DictionaryArray {
     /// The indices into the values array for each element
     pub keys: Box<Array>,
@@ -701,6 +886,7 @@ impl DictionaryArray {
 ///
 /// The corresponding view is [`UnionView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnionArray {
     /// The type of each element
     pub types: Vec<i8>,
@@ -732,6 +918,7 @@ impl UnionArray {
 ///
 /// The corresponding view is [`RunEndEncodedView`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunEndEncodedArray {
     /// The metadata for the arrays
     pub meta: RunEndEncodedMeta,
@@ -0,0 +1,394 @@
+//! Construction of arrays from vectors and iterators
+//!
+//! Missing elements are given as `None`. If all elements are present, the validity is `None`.
+use crate::{
+    array::{
+        Array, BooleanArray, BytesArray, BytesViewArray, FixedSizeBinaryArray, ListArray,
+        PrimitiveArray,
+    },
+    bits::Bitmap,
+    datatypes::FieldMeta,
+    types::MarrowPrimitive,
+};
+
+/// Collect the validity of the elements, while passing through their values
+struct ValidityBuilder {
+    validity: Bitmap,
+    all_valid: bool,
+}
+
+impl ValidityBuilder {
+    fn new() -> Self {
+        Self {
+            validity: Bitmap::new(),
+            all_valid: true,
+        }
+    }
+
+    fn push<T>(&mut self, item: Option<T>) -> Option<T> {
+        self.all_valid &= item.is_some();
+        self.validity.push(item.is_some());
+        item
+    }
+
+    fn finish(self) -> Option<Vec<u8>> {
+        if self.all_valid {
+            None
+        } else {
+            Some(self.validity.into_vec())
+        }
+    }
+}
+
+impl Array {
+    /// Build an array from an iterator of optional primitive values
+    ///
+    /// The variant is determined by [`MarrowPrimitive::DATA_TYPE`].
+    ///
+    /// ```rust
+    /// # use marrow::{array::{Array, PrimitiveArray}, datatypes::DataType};
+    /// let array = Array::from_iter_typed([Some(1_u16), None, Some(3)]);
+    ///
+    /// assert_eq!(array.data_type(), DataType::UInt16);
+    /// assert_eq!(
+    ///     array,
+    ///     Array::UInt16(PrimitiveArray {
+    ///         validity: Some(marrow::bit_vec![true, false, true]),
+    ///         values: vec![1, 0, 3],
+    ///     }),
+    /// );
+    /// ```
+    pub fn from_iter_typed<T: MarrowPrimitive>(iter: impl IntoIterator<Item = Option<T>>) -> Self {
+        T::into_array(iter.into_iter().collect())
+    }
+}
+
+impl<T: MarrowPrimitive> From<PrimitiveArray<T>> for Array {
+    fn from(value: PrimitiveArray<T>) -> Self {
+        T::into_array(value)
+    }
+}
+
+impl<T> From<Vec<T>> for PrimitiveArray<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self {
+            validity: None,
+            values,
+        }
+    }
+}
+
+impl<T: Default> From<Vec<Option<T>>> for PrimitiveArray<T> {
+    fn from(values: Vec<Option<T>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Default> FromIterator<Option<T>> for PrimitiveArray<T> {
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        let mut validity = ValidityBuilder::new();
+        let values = iter
+            .into_iter()
+            .map(|item| validity.push(item).unwrap_or_default())
+            .collect();
+        Self {
+            validity: validity.finish(),
+            values,
+        }
+    }
+}
+
+impl From<Vec<bool>> for BooleanArray {
+    fn from(values: Vec<bool>) -> Self {
+        let values = Bitmap::from(values);
+        Self {
+            len: values.len(),
+            validity: None,
+            values: values.into_vec(),
+        }
+    }
+}
+
+impl From<Vec<Option<bool>>> for BooleanArray {
+    fn from(values: Vec<Option<bool>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl FromIterator<Option<bool>> for BooleanArray {
+    fn from_iter<I: IntoIterator<Item = Option<bool>>>(iter: I) -> Self {
+        let mut validity = ValidityBuilder::new();
+        let values = iter
+            .into_iter()
+            .map(|item| validity.push(item).unwrap_or_default())
+            .collect::<Bitmap>();
+        Self {
+            len: values.len(),
+            validity: validity.finish(),
+            values: values.into_vec(),
+        }
+    }
+}
+
+macro_rules! impl_bytes_array_from {
+    ($offset:ty, $(<$($lt:lifetime)?> $item:ty),*) => {
+        $(
+            impl<$($lt)?> From<Vec<$item>> for BytesArray<$offset> {
+                fn from(values: Vec<$item>) -> Self {
+                    values.into_iter().map(Some).collect()
+                }
+            }
+
+            impl<$($lt)?> From<Vec<Option<$item>>> for BytesArray<$offset> {
+                fn from(values: Vec<Option<$item>>) -> Self {
+                    values.into_iter().collect()
+                }
+            }
+
+            impl<$($lt)?> FromIterator<Option<$item>> for BytesArray<$offset> {
+                /// # Panics
+                ///
+                /// If the total number of bytes exceeds the range of the offsets.
+                fn from_iter<I: IntoIterator<Item = Option<$item>>>(iter: I) -> Self {
+                    let mut validity = ValidityBuilder::new();
+                    let mut offsets = vec![0];
+                    let mut data = Vec::new();
+                    for item in iter {
+                        if let Some(item) = validity.push(item) {
+                            data.extend_from_slice(AsRef::<[u8]>::as_ref(&item));
+                        }
+                        let Ok(offset) = <$offset>::try_from(data.len()) else {
+                            panic!("Offset overflow: the data exceeds the range of {}", stringify!($offset));
+                        };
+                        offsets.push(offset);
+                    }
+                    Self {
+                        validity: validity.finish(),
+                        offsets,
+                        data,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes_array_from!(i32, <'a> &'a str, <> String, <'a> &'a [u8], <> Vec<u8>);
+impl_bytes_array_from!(i64, <'a> &'a str, <> String, <'a> &'a [u8], <> Vec<u8>);
+
+macro_rules! impl_bytes_view_array_from {
+    ($(<$($lt:lifetime)?> $item:ty),*) => {
+        $(
+            impl<$($lt)?> From<Vec<$item>> for BytesViewArray {
+                fn from(values: Vec<$item>) -> Self {
+                    values.into_iter().map(Some).collect()
+                }
+            }
+
+            impl<$($lt)?> From<Vec<Option<$item>>> for BytesViewArray {
+                fn from(values: Vec<Option<$item>>) -> Self {
+                    values.into_iter().collect()
+                }
+            }
+
+            impl<$($lt)?> FromIterator<Option<$item>> for BytesViewArray {
+                fn from_iter<I: IntoIterator<Item = Option<$item>>>(iter: I) -> Self {
+                    let mut builder = BytesViewBuilder::default();
+                    for item in iter {
+                        let item = builder.validity.push(item);
+                        builder.push(item.as_ref().map_or(&[], AsRef::<[u8]>::as_ref));
+                    }
+                    builder.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes_view_array_from!(<'a> &'a str, <> String, <'a> &'a [u8], <> Vec<u8>);
+
+struct BytesViewBuilder {
+    validity: ValidityBuilder,
+    data: Vec<u128>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl Default for BytesViewBuilder {
+    fn default() -> Self {
+        Self {
+            validity: ValidityBuilder::new(),
+            data: Vec::new(),
+            buffers: Vec::new(),
+        }
+    }
+}
+
+impl BytesViewBuilder {
+    fn push(&mut self, item: &[u8]) {
+        let len = u32::try_from(item.len()).expect("byte strings must be shorter than 4 GiB");
+        if item.len() <= 12 {
+            let mut bytes = [0; 16];
+            bytes[..4].copy_from_slice(&len.to_le_bytes());
+            bytes[4..4 + item.len()].copy_from_slice(item);
+            self.data.push(u128::from_le_bytes(bytes));
+            return;
+        }
+
+        // start a new buffer, if the offset would not fit into an u32
+        let needs_new_buffer = match self.buffers.last() {
+            Some(buffer) => u32::try_from(buffer.len() + item.len()).is_err(),
+            None => true,
+        };
+        if needs_new_buffer {
+            self.buffers.push(Vec::new());
+        }
+        let buffer_index = self.buffers.len() - 1;
+        let buffer = &mut self.buffers[buffer_index];
+        let offset = buffer.len();
+        buffer.extend_from_slice(item);
+
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&len.to_le_bytes());
+        bytes[4..8].copy_from_slice(&item[..4]);
+        bytes[8..12].copy_from_slice(&(buffer_index as u32).to_le_bytes());
+        bytes[12..].copy_from_slice(&(offset as u32).to_le_bytes());
+        self.data.push(u128::from_le_bytes(bytes));
+    }
+
+    fn finish(self) -> BytesViewArray {
+        BytesViewArray {
+            validity: self.validity.finish(),
+            data: self.data,
+            buffers: self.buffers,
+        }
+    }
+}
+
+impl<const N: usize> From<Vec<[u8; N]>> for FixedSizeBinaryArray {
+    fn from(values: Vec<[u8; N]>) -> Self {
+        values.into_iter().map(Some).collect()
+    }
+}
+
+impl<const N: usize> From<Vec<Option<[u8; N]>>> for FixedSizeBinaryArray {
+    fn from(values: Vec<Option<[u8; N]>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<const N: usize> FromIterator<Option<[u8; N]>> for FixedSizeBinaryArray {
+    /// # Panics
+    ///
+    /// If `N` exceeds `i32::MAX`.
+    fn from_iter<I: IntoIterator<Item = Option<[u8; N]>>>(iter: I) -> Self {
+        let mut validity = ValidityBuilder::new();
+        let mut data = Vec::new();
+        for item in iter {
+            data.extend_from_slice(&validity.push(item).unwrap_or([0; N]));
+        }
+        Self {
+            n: i32::try_from(N).expect("the size must not exceed i32::MAX"),
+            validity: validity.finish(),
+            data,
+        }
+    }
+}
+
+macro_rules! impl_list_array_from {
+    ($($offset:ty),*) => {
+        $(
+            impl<T: MarrowPrimitive> From<Vec<Option<Vec<Option<T>>>>> for ListArray<$offset> {
+                fn from(values: Vec<Option<Vec<Option<T>>>>) -> Self {
+                    values.into_iter().collect()
+                }
+            }
+
+            /// Build a list array of primitive elements
+            ///
+            /// The elements are stored in an array of type [`MarrowPrimitive::DATA_TYPE`] in a
+            /// nullable field called `"item"`.
+            ///
+            /// # Panics
+            ///
+            /// If the total number of elements exceeds the range of the offsets.
+            impl<T, L> FromIterator<Option<L>> for ListArray<$offset>
+            where
+                T: MarrowPrimitive,
+                L: IntoIterator<Item = Option<T>>,
+            {
+                fn from_iter<I: IntoIterator<Item = Option<L>>>(iter: I) -> Self {
+                    let mut validity = ValidityBuilder::new();
+                    let mut offsets = vec![0];
+                    let mut elements = Vec::new();
+                    for item in iter {
+                        if let Some(item) = validity.push(item) {
+                            elements.extend(item);
+                        }
+                        let Ok(offset) = <$offset>::try_from(elements.len()) else {
+                            panic!("Offset overflow: the elements exceed the range of {}", stringify!($offset));
+                        };
+                        offsets.push(offset);
+                    }
+                    Self {
+                        validity: validity.finish(),
+                        offsets,
+                        meta: FieldMeta {
+                            name: String::from("item"),
+                            nullable: true,
+                            ..FieldMeta::default()
+                        },
+                        elements: Box::new(Array::from_iter_typed(elements)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_list_array_from!(i32, i64);
+
+#[test]
+fn bytes_arrays() {
+    let array = BytesArray::<i32>::from(vec![Some("foo"), None, Some("")]);
+    assert_eq!(array.validity, Some(vec![0b_101]));
+    assert_eq!(array.offsets, vec![0, 3, 3, 3]);
+    assert_eq!(array.data, b"foo");
+
+    let array = BytesArray::<i64>::from(vec![b"ab".as_slice(), b"c"]);
+    assert_eq!(array.validity, None);
+    assert_eq!(array.offsets, vec![0, 2, 3]);
+
+    let items = [Some("short"), None, Some("a string longer than 12 bytes")];
+    let array = BytesViewArray::from(items.to_vec());
+    let view = array.as_view();
+    assert_eq!(
+        view.iter_str()
+            .collect::<crate::error::Result<Vec<_>>>()
+            .unwrap(),
+        items
+    );
+}
+
+#[test]
+fn list_arrays() {
+    let array = ListArray::<i32>::from(vec![Some(vec![Some(1_i64), None]), None, Some(vec![])]);
+    assert_eq!(array.validity, Some(vec![0b_101]));
+    assert_eq!(array.offsets, vec![0, 2, 2, 2]);
+    assert_eq!(
+        *array.elements,
+        Array::Int64(PrimitiveArray {
+            validity: Some(vec![0b_01]),
+            values: vec![1, 0],
+        })
+    );
+
+    let array = FixedSizeBinaryArray::from(vec![Some([1_u8, 2]), None]);
+    assert_eq!(array.n, 2);
+    assert_eq!(array.data, vec![1, 2, 0, 0]);
+    assert_eq!(array.validity, Some(vec![0b_01]));
+
+    let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+    assert_eq!(array.len, 3);
+    assert_eq!(array.values, vec![0b_001]);
+    assert_eq!(array.validity, Some(vec![0b_101]));
+}
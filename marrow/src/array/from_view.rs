@@ -0,0 +1,691 @@
+//! Deep copies of views into owned arrays
+use crate::{
+    array::{
+        Array, BooleanArray, BytesArray, BytesViewArray, DecimalArray, DictionaryArray,
+        FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray,
+        RunEndEncodedArray, StructArray, TimeArray, TimestampArray, UnionArray,
+    },
+    bits,
+    error::{fail, ErrorKind, MarrowError, Result},
+    view::{BitsWithOffset, BytesViewView, RunEndEncodedView, View},
+};
+
+/// Copy the data referenced by a view into an owned array
+///
+/// Only the referenced ranges of the underlying buffers are copied. Bitmaps with a non-zero
+/// [`offset`][BitsWithOffset::offset] are realigned to start at bit `0` and the offsets of
+/// lists, maps, byte arrays and dense unions are rebased to start at `0`. The only exception are
+/// the values of dictionaries, which are copied in full as the keys index into them.
+///
+/// ```rust
+/// # use marrow::{array::{Array, PrimitiveArray}, view::{BitsWithOffset, PrimitiveView, View}};
+/// # fn main() -> marrow::error::Result<()> {
+/// let view = View::Int32(PrimitiveView {
+///     validity: Some(BitsWithOffset { offset: 2, data: &[0b_1110_1000] }),
+///     values: &[1, 2, 3, 4],
+/// });
+///
+/// assert_eq!(
+///     Array::try_from(view)?,
+///     Array::Int32(PrimitiveArray {
+///         validity: Some(vec![0b_1010]),
+///         values: vec![1, 2, 3, 4],
+///     }),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<View<'_>> for Array {
+    type Error = MarrowError;
+
+    fn try_from(value: View<'_>) -> Result<Self> {
+        copy_view(&value, 0, view_len(&value)?)
+    }
+}
+
+pub(crate) fn view_len(view: &View<'_>) -> Result<usize> {
+    match view {
+        View::Null(view) => Ok(view.len),
+        View::Boolean(view) => Ok(view.len),
+        View::Int8(view) => Ok(view.values.len()),
+        View::Int16(view) => Ok(view.values.len()),
+        View::Int32(view) => Ok(view.values.len()),
+        View::Int64(view) => Ok(view.values.len()),
+        View::UInt8(view) => Ok(view.values.len()),
+        View::UInt16(view) => Ok(view.values.len()),
+        View::UInt32(view) => Ok(view.values.len()),
+        View::UInt64(view) => Ok(view.values.len()),
+        View::Float16(view) => Ok(view.values.len()),
+        View::Float32(view) => Ok(view.values.len()),
+        View::Float64(view) => Ok(view.values.len()),
+        View::Date32(view) => Ok(view.values.len()),
+        View::Date64(view) => Ok(view.values.len()),
+        View::Time32(view) => Ok(view.values.len()),
+        View::Time64(view) => Ok(view.values.len()),
+        View::Timestamp(view) => Ok(view.values.len()),
+        View::Duration(view) => Ok(view.values.len()),
+        View::YearMonthInterval(view) => Ok(view.values.len()),
+        View::DayTimeInterval(view) => Ok(view.values.len()),
+        View::MonthDayNanoInterval(view) => Ok(view.values.len()),
+        View::Decimal128(view) => Ok(view.values.len()),
+        View::Utf8(view) | View::Binary(view) => Ok(view.offsets.len().saturating_sub(1)),
+        View::LargeUtf8(view) | View::LargeBinary(view) => Ok(view.offsets.len().saturating_sub(1)),
+        View::Utf8View(view) | View::BinaryView(view) => Ok(view.data.len()),
+        View::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            if n == 0 {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Cannot determine the length of FixedSizeBinary views with n = 0"
+                );
+            }
+            Ok(view.data.len() / n)
+        }
+        View::Struct(view) => Ok(view.len),
+        View::List(view) => Ok(view.offsets.len().saturating_sub(1)),
+        View::LargeList(view) => Ok(view.offsets.len().saturating_sub(1)),
+        View::FixedSizeList(view) => Ok(view.len),
+        View::Map(view) => Ok(view.offsets.len().saturating_sub(1)),
+        View::Dictionary(view) => view_len(&view.keys),
+        View::RunEndEncoded(view) => match view.run_ends.as_ref() {
+            View::Int16(run_ends) => last_run_end(run_ends.values),
+            View::Int32(run_ends) => last_run_end(run_ends.values),
+            View::Int64(run_ends) => last_run_end(run_ends.values),
+            _ => fail!(
+                ErrorKind::Unsupported,
+                "unsupported run ends in RunEndEncoded"
+            ),
+        },
+        View::Union(view) => Ok(view.types.len()),
+    }
+}
+
+fn last_run_end<T>(run_ends: &[T]) -> Result<usize>
+where
+    T: Copy,
+    usize: TryFrom<T, Error = std::num::TryFromIntError>,
+{
+    match run_ends.last() {
+        Some(&run_end) => Ok(usize::try_from(run_end)?),
+        None => Ok(0),
+    }
+}
+
+/// Copy the elements `start..start + len` of the view into an owned array
+fn copy_view(view: &View<'_>, start: usize, len: usize) -> Result<Array> {
+    macro_rules! copy_primitive {
+        ($variant:ident, $view:expr) => {
+            Array::$variant(PrimitiveArray {
+                validity: copy_validity($view.validity, start, len)?,
+                values: copy_slice($view.values, start, len)?,
+            })
+        };
+    }
+    macro_rules! copy_time {
+        ($variant:ident, $view:expr) => {
+            Array::$variant(TimeArray {
+                unit: $view.unit,
+                validity: copy_validity($view.validity, start, len)?,
+                values: copy_slice($view.values, start, len)?,
+            })
+        };
+    }
+    macro_rules! copy_bytes {
+        ($variant:ident, $view:expr) => {{
+            let (offsets, data_start, data_len) = copy_offsets($view.offsets, start, len)?;
+            Array::$variant(BytesArray {
+                validity: copy_validity($view.validity, start, len)?,
+                offsets,
+                data: copy_slice($view.data, data_start, data_len)?,
+            })
+        }};
+    }
+    macro_rules! copy_list {
+        ($variant:ident, $view:expr) => {{
+            let (offsets, elements_start, elements_len) = copy_offsets($view.offsets, start, len)?;
+            Array::$variant(ListArray {
+                validity: copy_validity($view.validity, start, len)?,
+                offsets,
+                meta: $view.meta.clone(),
+                elements: Box::new(copy_view(&$view.elements, elements_start, elements_len)?),
+            })
+        }};
+    }
+
+    let array = match view {
+        View::Null(view) => {
+            check_range(start, len, view.len)?;
+            Array::Null(NullArray { len })
+        }
+        View::Boolean(view) => {
+            check_range(start, len, view.len)?;
+            Array::Boolean(BooleanArray {
+                len,
+                validity: copy_validity(view.validity, start, len)?,
+                values: copy_bits(view.values, start, len)?,
+            })
+        }
+        View::Int8(view) => copy_primitive!(Int8, view),
+        View::Int16(view) => copy_primitive!(Int16, view),
+        View::Int32(view) => copy_primitive!(Int32, view),
+        View::Int64(view) => copy_primitive!(Int64, view),
+        View::UInt8(view) => copy_primitive!(UInt8, view),
+        View::UInt16(view) => copy_primitive!(UInt16, view),
+        View::UInt32(view) => copy_primitive!(UInt32, view),
+        View::UInt64(view) => copy_primitive!(UInt64, view),
+        View::Float16(view) => copy_primitive!(Float16, view),
+        View::Float32(view) => copy_primitive!(Float32, view),
+        View::Float64(view) => copy_primitive!(Float64, view),
+        View::Date32(view) => copy_primitive!(Date32, view),
+        View::Date64(view) => copy_primitive!(Date64, view),
+        View::YearMonthInterval(view) => copy_primitive!(YearMonthInterval, view),
+        View::DayTimeInterval(view) => copy_primitive!(DayTimeInterval, view),
+        View::MonthDayNanoInterval(view) => copy_primitive!(MonthDayNanoInterval, view),
+        View::Time32(view) => copy_time!(Time32, view),
+        View::Time64(view) => copy_time!(Time64, view),
+        View::Duration(view) => copy_time!(Duration, view),
+        View::Timestamp(view) => Array::Timestamp(TimestampArray {
+            unit: view.unit,
+            timezone: view.timezone.clone(),
+            validity: copy_validity(view.validity, start, len)?,
+            values: copy_slice(view.values, start, len)?,
+        }),
+        View::Decimal128(view) => Array::Decimal128(DecimalArray {
+            precision: view.precision,
+            scale: view.scale,
+            validity: copy_validity(view.validity, start, len)?,
+            values: copy_slice(view.values, start, len)?,
+        }),
+        View::Utf8(view) => copy_bytes!(Utf8, view),
+        View::LargeUtf8(view) => copy_bytes!(LargeUtf8, view),
+        View::Binary(view) => copy_bytes!(Binary, view),
+        View::LargeBinary(view) => copy_bytes!(LargeBinary, view),
+        View::Utf8View(view) => Array::Utf8View(copy_bytes_view(view, start, len)?),
+        View::BinaryView(view) => Array::BinaryView(copy_bytes_view(view, start, len)?),
+        View::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            Array::FixedSizeBinary(FixedSizeBinaryArray {
+                n: view.n,
+                validity: copy_validity(view.validity, start, len)?,
+                data: copy_slice(view.data, start * n, len * n)?,
+            })
+        }
+        View::Struct(view) => {
+            check_range(start, len, view.len)?;
+            let mut fields = Vec::new();
+            for (meta, field) in &view.fields {
+                fields.push((meta.clone(), copy_view(field, start, len)?));
+            }
+            Array::Struct(StructArray {
+                len,
+                validity: copy_validity(view.validity, start, len)?,
+                fields,
+            })
+        }
+        View::List(view) => copy_list!(List, view),
+        View::LargeList(view) => copy_list!(LargeList, view),
+        View::FixedSizeList(view) => {
+            check_range(start, len, view.len)?;
+            let n = usize::try_from(view.n)?;
+            Array::FixedSizeList(FixedSizeListArray {
+                len,
+                n: view.n,
+                validity: copy_validity(view.validity, start, len)?,
+                meta: view.meta.clone(),
+                elements: Box::new(copy_view(&view.elements, start * n, len * n)?),
+            })
+        }
+        View::Map(view) => {
+            let (offsets, entries_start, entries_len) = copy_offsets(view.offsets, start, len)?;
+            Array::Map(MapArray {
+                validity: copy_validity(view.validity, start, len)?,
+                offsets,
+                meta: view.meta.clone(),
+                keys: Box::new(copy_view(&view.keys, entries_start, entries_len)?),
+                values: Box::new(copy_view(&view.values, entries_start, entries_len)?),
+            })
+        }
+        View::Dictionary(view) => Array::Dictionary(DictionaryArray {
+            keys: Box::new(copy_view(&view.keys, start, len)?),
+            values: Box::new(copy_view(&view.values, 0, view_len(&view.values)?)?),
+        }),
+        View::RunEndEncoded(view) => Array::RunEndEncoded(copy_run_end_encoded(view, start, len)?),
+        View::Union(view) => {
+            let types = copy_slice(view.types, start, len)?;
+            let mut offsets = match view.offsets {
+                Some(offsets) => Some(copy_slice(offsets, start, len)?),
+                None => None,
+            };
+            let mut fields = Vec::new();
+            for (type_id, meta, field) in &view.fields {
+                // dense unions reference their children via offsets, sparse unions are aligned
+                let field = match offsets.as_mut() {
+                    Some(offsets) => copy_dense_union_child(*type_id, &types, offsets, field)?,
+                    None => copy_view(field, start, len)?,
+                };
+                fields.push((*type_id, meta.clone(), field));
+            }
+            Array::Union(UnionArray {
+                types,
+                offsets,
+                fields,
+            })
+        }
+    };
+    Ok(array)
+}
+
+/// Copy the range of a dense union child referenced by the slice and rebase the offsets into it
+fn copy_dense_union_child(
+    type_id: i8,
+    types: &[i8],
+    offsets: &mut [i32],
+    child: &View<'_>,
+) -> Result<Array> {
+    let mut range: Option<(i32, i32)> = None;
+    for (&ty, &offset) in types.iter().zip(offsets.iter()) {
+        if ty == type_id {
+            range = Some(match range {
+                Some((min, max)) => (min.min(offset), max.max(offset)),
+                None => (offset, offset),
+            });
+        }
+    }
+    let Some((min, max)) = range else {
+        return copy_view(child, 0, 0);
+    };
+
+    for (&ty, offset) in types.iter().zip(offsets.iter_mut()) {
+        if ty == type_id {
+            *offset -= min;
+        }
+    }
+    copy_view(
+        child,
+        usize::try_from(min)?,
+        usize::try_from(max - min)? + 1,
+    )
+}
+
+fn check_range(start: usize, len: usize, total: usize) -> Result<()> {
+    if start.checked_add(len).map_or(true, |end| end > total) {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot copy {len} elements starting at {start} from an array with {total} elements"
+        );
+    }
+    Ok(())
+}
+
+fn copy_slice<T: Clone>(data: &[T], start: usize, len: usize) -> Result<Vec<T>> {
+    check_range(start, len, data.len())?;
+    Ok(data[start..start + len].to_vec())
+}
+
+fn copy_validity(
+    validity: Option<BitsWithOffset<'_>>,
+    start: usize,
+    len: usize,
+) -> Result<Option<Vec<u8>>> {
+    match validity {
+        Some(validity) => Ok(Some(copy_bits(validity, start, len)?)),
+        None => Ok(None),
+    }
+}
+
+/// Copy `len` bits starting at `start` into a new bitmap starting at bit `0`
+pub(crate) fn copy_bits(bits: BitsWithOffset<'_>, start: usize, len: usize) -> Result<Vec<u8>> {
+    let offset = bits.offset + start;
+    check_range(offset, len, 8 * bits.data.len())?;
+
+    let num_bytes = len / 8 + usize::from(len % 8 != 0);
+    if offset % 8 == 0 {
+        return Ok(bits.data[offset / 8..offset / 8 + num_bytes].to_vec());
+    }
+
+    let mut res = vec![0; num_bytes];
+    for idx in 0..len {
+        bits::set(&mut res, idx, bits::get(bits.data, offset + idx));
+    }
+    Ok(res)
+}
+
+/// Copy `len + 1` offsets starting at `start` and rebase them to start at `0`
+///
+/// Returns the rebased offsets and the range of the child data they reference.
+fn copy_offsets<O>(offsets: &[O], start: usize, len: usize) -> Result<(Vec<O>, usize, usize)>
+where
+    O: Copy + Default + std::ops::Sub<Output = O>,
+    usize: TryFrom<O, Error = std::num::TryFromIntError>,
+{
+    if offsets.is_empty() && start == 0 && len == 0 {
+        return Ok((vec![O::default()], 0, 0));
+    }
+    check_range(start, len + 1, offsets.len())?;
+    let offsets = &offsets[start..start + len + 1];
+
+    let first = offsets[0];
+    let child_start = usize::try_from(first)?;
+    let child_end = usize::try_from(offsets[len])?;
+    let Some(child_len) = child_end.checked_sub(child_start) else {
+        fail!(ErrorKind::Unsupported, "Offsets must be non-decreasing");
+    };
+
+    Ok((
+        offsets.iter().map(|&offset| offset - first).collect(),
+        child_start,
+        child_len,
+    ))
+}
+
+/// Copy the byte views and compact the referenced external data into a single buffer
+fn copy_bytes_view(view: &BytesViewView<'_>, start: usize, len: usize) -> Result<BytesViewArray> {
+    check_range(start, len, view.data.len())?;
+
+    let mut data = Vec::with_capacity(len);
+    let mut buffer = Vec::new();
+    for &item in &view.data[start..start + len] {
+        let item_len = item as u32 as usize;
+        if item_len <= 12 {
+            data.push(item);
+            continue;
+        }
+
+        let buffer_index = (item >> 64) as u32 as usize;
+        let offset = (item >> 96) as u32 as usize;
+        let Some(bytes) = view
+            .buffers
+            .get(buffer_index)
+            .and_then(|bytes| bytes.get(offset..offset + item_len))
+        else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Invalid byte view: buffer {buffer_index} does not contain {offset}..{}",
+                offset + item_len
+            );
+        };
+
+        let new_offset = u32::try_from(buffer.len())?;
+        buffer.extend_from_slice(bytes);
+
+        // keep length and prefix, reference the single new buffer with the new offset
+        data.push((item & u128::from(u64::MAX)) | (u128::from(new_offset) << 96));
+    }
+
+    Ok(BytesViewArray {
+        validity: copy_validity(view.validity, start, len)?,
+        data,
+        buffers: if buffer.is_empty() {
+            vec![]
+        } else {
+            vec![buffer]
+        },
+    })
+}
+
+fn copy_run_end_encoded(
+    view: &RunEndEncodedView<'_>,
+    start: usize,
+    len: usize,
+) -> Result<RunEndEncodedArray> {
+    let (run_ends, values_start, values_len) = match view.run_ends.as_ref() {
+        View::Int16(run_ends) => {
+            let (run_ends, values_start, values_len) = copy_run_ends(run_ends.values, start, len)?;
+            (
+                Array::Int16(PrimitiveArray {
+                    validity: None,
+                    values: run_ends,
+                }),
+                values_start,
+                values_len,
+            )
+        }
+        View::Int32(run_ends) => {
+            let (run_ends, values_start, values_len) = copy_run_ends(run_ends.values, start, len)?;
+            (
+                Array::Int32(PrimitiveArray {
+                    validity: None,
+                    values: run_ends,
+                }),
+                values_start,
+                values_len,
+            )
+        }
+        View::Int64(run_ends) => {
+            let (run_ends, values_start, values_len) = copy_run_ends(run_ends.values, start, len)?;
+            (
+                Array::Int64(PrimitiveArray {
+                    validity: None,
+                    values: run_ends,
+                }),
+                values_start,
+                values_len,
+            )
+        }
+        _ => fail!(
+            ErrorKind::Unsupported,
+            "unsupported run ends in RunEndEncoded"
+        ),
+    };
+
+    Ok(RunEndEncodedArray {
+        meta: view.meta.clone(),
+        run_ends: Box::new(run_ends),
+        values: Box::new(copy_view(&view.values, values_start, values_len)?),
+    })
+}
+
+/// Select the runs overlapping `start..start + len` and shift them to start at `0`
+///
+/// Returns the new run ends and the range of values they reference.
+fn copy_run_ends<T>(run_ends: &[T], start: usize, len: usize) -> Result<(Vec<T>, usize, usize)>
+where
+    T: Copy + TryFrom<usize, Error = std::num::TryFromIntError>,
+    usize: TryFrom<T, Error = std::num::TryFromIntError>,
+{
+    let end = start + len;
+    let mut res = Vec::new();
+    let mut first_run = 0;
+
+    if len != 0 {
+        for (idx, &run_end) in run_ends.iter().enumerate() {
+            let run_end = usize::try_from(run_end)?;
+            if run_end <= start {
+                continue;
+            }
+            if res.is_empty() {
+                first_run = idx;
+            }
+            res.push(T::try_from(run_end.min(end) - start)?);
+            if run_end >= end {
+                break;
+            }
+        }
+    }
+
+    let covered = match res.last() {
+        Some(&last) => usize::try_from(last)?,
+        None => 0,
+    };
+    if covered != len {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot copy {len} elements starting at {start} from a run end encoded array with too few elements"
+        );
+    }
+
+    let num_runs = res.len();
+    Ok((res, first_run, num_runs))
+}
+
+#[test]
+fn copy_sliced_validity() {
+    let view = View::Boolean(crate::view::BooleanView {
+        len: 5,
+        validity: Some(BitsWithOffset {
+            offset: 3,
+            data: &[0b_1110_1000, 0b_0000_0001],
+        }),
+        values: BitsWithOffset {
+            offset: 7,
+            data: &[0b_1000_0000, 0b_0000_1010],
+        },
+    });
+
+    assert_eq!(
+        Array::try_from(view).unwrap(),
+        Array::Boolean(BooleanArray {
+            len: 5,
+            validity: Some(vec![0b_11101]),
+            values: vec![0b_10101],
+        }),
+    );
+}
+
+#[test]
+fn copy_sliced_lists() {
+    use crate::{
+        datatypes::FieldMeta,
+        view::{BytesView, ListView, PrimitiveView},
+    };
+
+    let view = View::List(ListView {
+        validity: None,
+        offsets: &[2, 3, 5],
+        meta: FieldMeta::default(),
+        elements: Box::new(View::Utf8(BytesView {
+            validity: Some(BitsWithOffset {
+                offset: 1,
+                data: &[0b_0010_1110],
+            }),
+            offsets: &[0, 1, 3, 6, 8, 9],
+            data: b"abbcccdde",
+        })),
+    });
+
+    assert_eq!(
+        Array::try_from(view).unwrap(),
+        Array::List(ListArray {
+            validity: None,
+            offsets: vec![0, 1, 3],
+            meta: FieldMeta::default(),
+            elements: Box::new(Array::Utf8(BytesArray {
+                validity: Some(vec![0b_101]),
+                offsets: vec![0, 3, 5, 6],
+                data: b"cccdde".to_vec(),
+            })),
+        }),
+    );
+
+    let view = View::FixedSizeList(crate::view::FixedSizeListView {
+        len: 2,
+        n: 2,
+        validity: None,
+        meta: FieldMeta::default(),
+        elements: Box::new(View::Int8(PrimitiveView {
+            validity: None,
+            values: &[1, 2, 3, 4],
+        })),
+    });
+    assert_eq!(
+        copy_view(&view, 1, 1).unwrap(),
+        Array::FixedSizeList(FixedSizeListArray {
+            len: 1,
+            n: 2,
+            validity: None,
+            meta: FieldMeta::default(),
+            elements: Box::new(Array::Int8(PrimitiveArray {
+                validity: None,
+                values: vec![3, 4],
+            })),
+        }),
+    );
+}
+
+#[test]
+fn copy_sliced_run_ends() {
+    assert_eq!(
+        copy_run_ends::<i32>(&[2, 5, 6], 0, 6).unwrap(),
+        (vec![2, 5, 6], 0, 3)
+    );
+    assert_eq!(
+        copy_run_ends::<i32>(&[2, 5, 6], 3, 2).unwrap(),
+        (vec![2], 1, 1)
+    );
+    assert_eq!(
+        copy_run_ends::<i32>(&[2, 5, 6], 1, 5).unwrap(),
+        (vec![1, 4, 5], 0, 3)
+    );
+    assert_eq!(
+        copy_run_ends::<i32>(&[2, 5, 6], 2, 0).unwrap(),
+        (vec![], 0, 0)
+    );
+    assert!(copy_run_ends::<i32>(&[2, 5, 6], 4, 3).is_err());
+}
+
+#[test]
+fn copy_sliced_bytes_views() {
+    let long = b"hello world, this is long";
+    let item = (long.len() as u128)
+        | (u128::from(u32::from_le_bytes([b'h', b'e', b'l', b'l'])) << 32)
+        | (1 << 64)
+        | (3 << 96);
+    let mut buffer = b"...".to_vec();
+    buffer.extend_from_slice(long);
+
+    let view = BytesViewView {
+        validity: None,
+        data: &[0, item],
+        buffers: vec![b"unused", &buffer],
+    };
+
+    let array = copy_bytes_view(&view, 1, 1).unwrap();
+    assert_eq!(
+        array.data,
+        vec![(long.len() as u128) | (u128::from(u32::from_le_bytes(*b"hell")) << 32)]
+    );
+    assert_eq!(array.buffers, vec![long.to_vec()]);
+}
+
+#[test]
+fn copy_sliced_dense_unions() {
+    use crate::{
+        datatypes::FieldMeta,
+        view::{NullView, PrimitiveView, UnionView},
+    };
+
+    let view = View::Union(UnionView {
+        types: &[0, 1, 0, 1, 0],
+        offsets: Some(&[0, 0, 1, 1, 2]),
+        fields: vec![
+            (
+                0,
+                FieldMeta::default(),
+                View::Int8(PrimitiveView {
+                    validity: None,
+                    values: &[1, 2, 3],
+                }),
+            ),
+            (1, FieldMeta::default(), View::Null(NullView { len: 2 })),
+        ],
+    });
+
+    assert_eq!(
+        copy_view(&view, 1, 2).unwrap(),
+        Array::Union(UnionArray {
+            types: vec![1, 0],
+            offsets: Some(vec![0, 0]),
+            fields: vec![
+                (
+                    0,
+                    FieldMeta::default(),
+                    Array::Int8(PrimitiveArray {
+                        validity: None,
+                        values: vec![2],
+                    }),
+                ),
+                (1, FieldMeta::default(), Array::Null(NullArray { len: 1 })),
+            ],
+        }),
+    );
+}
@@ -0,0 +1,192 @@
+//! The memory allocated by arrays
+use crate::array::Array;
+
+impl Array {
+    /// The number of bytes allocated by the buffers of this array and its children
+    ///
+    /// The allocated capacity of the buffers is counted, even if it exceeds their length. Metadata,
+    /// e.g., field names and timezones, and the size of the array structs themselves are not
+    /// included. Use [`View::buffer_sizes`][crate::view::View::buffer_sizes] to determine the
+    /// bytes that are referenced by a view.
+    ///
+    /// ```rust
+    /// # use marrow::array::{Array, PrimitiveArray};
+    /// let mut values = Vec::<i64>::with_capacity(8);
+    /// values.extend([1, 2, 3]);
+    /// let array = Array::Int64(PrimitiveArray { validity: Some(vec![0b_101]), values });
+    /// assert_eq!(array.memory_size(), 1 + 8 * 8);
+    /// ```
+    pub fn memory_size(&self) -> usize {
+        use Array as A;
+
+        fn bytes<T>(values: &Vec<T>) -> usize {
+            values.capacity() * std::mem::size_of::<T>()
+        }
+
+        fn validity(validity: &Option<Vec<u8>>) -> usize {
+            validity.as_ref().map(bytes).unwrap_or_default()
+        }
+
+        match self {
+            A::Null(_) => 0,
+            A::Boolean(array) => validity(&array.validity) + bytes(&array.values),
+            A::Int8(array) => validity(&array.validity) + bytes(&array.values),
+            A::Int16(array) => validity(&array.validity) + bytes(&array.values),
+            A::Int32(array) => validity(&array.validity) + bytes(&array.values),
+            A::Int64(array) => validity(&array.validity) + bytes(&array.values),
+            A::UInt8(array) => validity(&array.validity) + bytes(&array.values),
+            A::UInt16(array) => validity(&array.validity) + bytes(&array.values),
+            A::UInt32(array) => validity(&array.validity) + bytes(&array.values),
+            A::UInt64(array) => validity(&array.validity) + bytes(&array.values),
+            A::Float16(array) => validity(&array.validity) + bytes(&array.values),
+            A::Float32(array) => validity(&array.validity) + bytes(&array.values),
+            A::Float64(array) => validity(&array.validity) + bytes(&array.values),
+            A::Date32(array) => validity(&array.validity) + bytes(&array.values),
+            A::Date64(array) => validity(&array.validity) + bytes(&array.values),
+            A::Time32(array) => validity(&array.validity) + bytes(&array.values),
+            A::Time64(array) => validity(&array.validity) + bytes(&array.values),
+            A::Timestamp(array) => validity(&array.validity) + bytes(&array.values),
+            A::Duration(array) => validity(&array.validity) + bytes(&array.values),
+            A::YearMonthInterval(array) => validity(&array.validity) + bytes(&array.values),
+            A::DayTimeInterval(array) => validity(&array.validity) + bytes(&array.values),
+            A::MonthDayNanoInterval(array) => validity(&array.validity) + bytes(&array.values),
+            A::Decimal128(array) => validity(&array.validity) + bytes(&array.values),
+            A::Utf8(array) | A::Binary(array) => {
+                validity(&array.validity) + bytes(&array.offsets) + bytes(&array.data)
+            }
+            A::LargeUtf8(array) | A::LargeBinary(array) => {
+                validity(&array.validity) + bytes(&array.offsets) + bytes(&array.data)
+            }
+            A::Utf8View(array) | A::BinaryView(array) => {
+                validity(&array.validity)
+                    + bytes(&array.data)
+                    + array.buffers.iter().map(bytes).sum::<usize>()
+            }
+            A::FixedSizeBinary(array) => validity(&array.validity) + bytes(&array.data),
+            A::Struct(array) => {
+                validity(&array.validity)
+                    + array
+                        .fields
+                        .iter()
+                        .map(|(_, child)| child.memory_size())
+                        .sum::<usize>()
+            }
+            A::List(array) => {
+                validity(&array.validity) + bytes(&array.offsets) + array.elements.memory_size()
+            }
+            A::LargeList(array) => {
+                validity(&array.validity) + bytes(&array.offsets) + array.elements.memory_size()
+            }
+            A::FixedSizeList(array) => validity(&array.validity) + array.elements.memory_size(),
+            A::Map(array) => {
+                validity(&array.validity)
+                    + bytes(&array.offsets)
+                    + array.keys.memory_size()
+                    + array.values.memory_size()
+            }
+            A::Dictionary(array) => array.keys.memory_size() + array.values.memory_size(),
+            A::RunEndEncoded(array) => array.run_ends.memory_size() + array.values.memory_size(),
+            A::Union(array) => {
+                bytes(&array.types)
+                    + array.offsets.as_ref().map(bytes).unwrap_or_default()
+                    + array
+                        .fields
+                        .iter()
+                        .map(|(_, _, child)| child.memory_size())
+                        .sum::<usize>()
+            }
+        }
+    }
+}
+
+#[test]
+fn memory_sizes() {
+    use crate::{
+        array::{BytesViewArray, ListArray, PrimitiveArray, RunEndEncodedArray},
+        datatypes::{BufferKind, FieldMeta, RunEndEncodedMeta},
+        view::{slice_view, BufferSize},
+    };
+
+    let mut offsets = Vec::with_capacity(10);
+    offsets.extend([0_i32, 2, 3, 5]);
+    let array = Array::List(ListArray {
+        validity: None,
+        offsets,
+        meta: FieldMeta {
+            name: String::from("item"),
+            ..Default::default()
+        },
+        elements: Box::new(Array::Int16(PrimitiveArray {
+            validity: Some(vec![0b_0001_1111]),
+            values: vec![1, 2, 3, 4, 5],
+        })),
+    });
+    assert_eq!(array.memory_size(), 10 * 4 + 1 + 5 * 2);
+
+    let view = slice_view(&array.as_view(), 1, 2).unwrap();
+    let size = |path: &str, kind, bytes| BufferSize {
+        path: String::from(path),
+        kind,
+        bytes,
+    };
+    assert_eq!(
+        view.buffer_sizes().unwrap(),
+        vec![
+            size("", BufferKind::Offsets(4), 3 * 4),
+            size("item", BufferKind::Validity, 1),
+            size("item", BufferKind::FixedWidth(2), 3 * 2),
+        ],
+    );
+    assert_eq!(view.referenced_bytes().unwrap(), 12 + 1 + 6);
+
+    let long = |buffer: u128, offset: u128| 20 | (buffer << 64) | (offset << 96);
+    let array = Array::Utf8View(BytesViewArray {
+        validity: None,
+        data: vec![3, long(1, 0), long(1, 20), long(0, 0)],
+        buffers: vec![vec![0; 30], vec![0; 40], vec![0; 50]],
+    });
+    assert_eq!(array.memory_size(), 4 * 16 + 30 + 40 + 50);
+    assert_eq!(
+        array.as_view().referenced_bytes().unwrap(),
+        4 * 16 + 30 + 40
+    );
+
+    let view = slice_view(&array.as_view(), 0, 3).unwrap();
+    assert_eq!(
+        view.buffer_sizes().unwrap(),
+        vec![
+            size("", BufferKind::Views, 3 * 16),
+            size("", BufferKind::VariadicData, 40),
+        ],
+    );
+
+    // the run ends and values of run end encoded children are counted in full
+    let array = Array::List(ListArray {
+        validity: None,
+        offsets: vec![0_i32, 2, 3, 5],
+        meta: FieldMeta {
+            name: String::from("item"),
+            ..Default::default()
+        },
+        elements: Box::new(Array::RunEndEncoded(RunEndEncodedArray {
+            meta: RunEndEncodedMeta::default(),
+            run_ends: Box::new(Array::Int32(PrimitiveArray {
+                validity: None,
+                values: vec![2, 5],
+            })),
+            values: Box::new(Array::Int64(PrimitiveArray {
+                validity: None,
+                values: vec![1, 2],
+            })),
+        })),
+    });
+    let view = slice_view(&array.as_view(), 1, 2).unwrap();
+    assert_eq!(
+        view.buffer_sizes().unwrap(),
+        vec![
+            size("", BufferKind::Offsets(4), 3 * 4),
+            size("item.run_ends", BufferKind::FixedWidth(4), 2 * 4),
+            size("item.values", BufferKind::FixedWidth(8), 2 * 8),
+        ],
+    );
+}
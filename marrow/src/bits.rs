@@ -5,6 +5,13 @@
 //!
 //! To construct bit vectors as arrays or vectors see [`marrow::bit_array!`][crate::bit_array] and
 //! [`marrow::bit_vec!`][crate::bit_vec].
+//!
+//! For higher level operations, see the owned [`Bitmap`] and the methods of [`BitsWithOffset`].
+
+use crate::{
+    error::{fail, ErrorKind, Result},
+    view::BitsWithOffset,
+};
 
 /// Build a fixed-size bit array from a sequence of booleans
 ///
@@ -248,3 +255,527 @@ pub fn push(bit_vec: &mut Vec<u8>, len: &mut usize, value: bool) {
     // NOTE: needs to be last
     *len += 1;
 }
+
+/// An owned bit vector with a length
+///
+/// In contrast to bit vectors stored as `Vec<u8>`, the bitmap keeps track of the number of bits.
+/// Bits beyond the length are always unset.
+///
+/// ```rust
+/// # use marrow::bits::Bitmap;
+/// let mut bitmap = Bitmap::from(vec![true, false, true]);
+/// bitmap.push(true);
+///
+/// assert_eq!(bitmap.len(), 4);
+/// assert_eq!(bitmap.get(1), Some(false));
+/// assert_eq!(bitmap.count_set_bits(), 3);
+/// assert_eq!(bitmap.set_indices().collect::<Vec<_>>(), vec![0, 2, 3]);
+/// assert_eq!(bitmap.into_vec(), vec![0b_1101]);
+/// ```
+///
+/// Bitmaps can be combined via [`and`][Bitmap::and], [`or`][Bitmap::or] and
+/// [`not`][Bitmap::not]. Use [`as_bits`][Bitmap::as_bits] to get a [`BitsWithOffset`], e.g., to
+/// build views.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl Bitmap {
+    /// Construct an empty bitmap
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a bitmap with `len` bits set to `value`
+    pub fn from_value(value: bool, len: usize) -> Self {
+        let mut res = Self {
+            data: vec![if value { u8::MAX } else { 0 }; num_bytes(len)],
+            len,
+        };
+        res.clear_padding();
+        res
+    }
+
+    /// Construct a bitmap from a bit vector with `len` bits
+    ///
+    /// Additional bytes are removed and bits beyond `len` are unset. Fails if the bit vector
+    /// contains less than `len` bits.
+    ///
+    /// ```rust
+    /// # use marrow::bits::Bitmap;
+    /// # fn main() -> marrow::error::Result<()> {
+    /// let bitmap = Bitmap::from_vec(vec![0b_1111_0101, 0b_1111], 6)?;
+    /// assert_eq!(bitmap.into_vec(), vec![0b_11_0101]);
+    ///
+    /// assert!(Bitmap::from_vec(vec![0b_1], 9).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_vec(mut data: Vec<u8>, len: usize) -> Result<Self> {
+        if data.len() < num_bytes(len) {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot construct a bitmap with {len} bits from {bytes} bytes",
+                bytes = data.len(),
+            );
+        }
+        data.truncate(num_bytes(len));
+        let mut res = Self { data, len };
+        res.clear_padding();
+        Ok(res)
+    }
+
+    /// The number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the bitmap does not contain any bits
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The underlying bit vector
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the underlying bit vector
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Get the bits as a [`BitsWithOffset`] with zero offset
+    pub fn as_bits(&self) -> BitsWithOffset<'_> {
+        BitsWithOffset {
+            offset: 0,
+            data: &self.data,
+        }
+    }
+
+    /// Get the bit at position `idx` or `None` if the index is out of bounds
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        if idx < self.len {
+            Some(get(&self.data, idx))
+        } else {
+            None
+        }
+    }
+
+    /// Set the bit at position `idx`
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn set(&mut self, idx: usize, value: bool) {
+        assert!(
+            idx < self.len,
+            "index {idx} out of bounds for a bitmap of length {len}",
+            len = self.len
+        );
+        set(&mut self.data, idx, value);
+    }
+
+    /// Append a bit
+    pub fn push(&mut self, value: bool) {
+        push(&mut self.data, &mut self.len, value);
+    }
+
+    /// Iterate over the bits
+    pub fn iter(&self) -> BitIter<'_> {
+        self.as_bits().iter(self.len)
+    }
+
+    /// Count the number of set bits
+    pub fn count_set_bits(&self) -> usize {
+        self.as_bits().count_set_bits(self.len)
+    }
+
+    /// Iterate over the indices of the set bits
+    pub fn set_indices(&self) -> SetIndices<'_> {
+        self.as_bits().set_indices(self.len)
+    }
+
+    /// Copy the bits `start..start + len` into a new bitmap
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds.
+    pub fn slice(&self, start: usize, len: usize) -> Self {
+        assert!(
+            start.checked_add(len).is_some_and(|end| end <= self.len),
+            "{len} bits starting at {start} out of bounds for a bitmap of length {total}",
+            total = self.len,
+        );
+        self.as_bits().to_bitmap_at(start, len)
+    }
+
+    /// Compute the bitwise and of two bitmaps
+    ///
+    /// # Panics
+    ///
+    /// If the bitmaps differ in length.
+    pub fn and(&self, other: &Bitmap) -> Self {
+        self.assert_same_len(other);
+        self.as_bits().and(other.as_bits(), self.len)
+    }
+
+    /// Compute the bitwise or of two bitmaps
+    ///
+    /// # Panics
+    ///
+    /// If the bitmaps differ in length.
+    pub fn or(&self, other: &Bitmap) -> Self {
+        self.assert_same_len(other);
+        self.as_bits().or(other.as_bits(), self.len)
+    }
+
+    /// Compute the bitwise negation of this bitmap
+    pub fn not(&self) -> Self {
+        self.as_bits().not(self.len)
+    }
+
+    fn assert_same_len(&self, other: &Bitmap) {
+        assert_eq!(
+            self.len, other.len,
+            "cannot combine bitmaps of different lengths"
+        );
+    }
+
+    fn clear_padding(&mut self) {
+        if self.len % 8 != 0 {
+            if let Some(last) = self.data.last_mut() {
+                *last &= (1 << (self.len % 8)) - 1;
+            }
+        }
+    }
+}
+
+impl From<Vec<bool>> for Bitmap {
+    fn from(value: Vec<bool>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<&[bool]> for Bitmap {
+    fn from(value: &[bool]) -> Self {
+        value.iter().copied().collect()
+    }
+}
+
+impl From<Bitmap> for Vec<bool> {
+    fn from(value: Bitmap) -> Self {
+        value.iter().collect()
+    }
+}
+
+impl FromIterator<bool> for Bitmap {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut res = Bitmap::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl Extend<bool> for Bitmap {
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Bitmap {
+    type Item = bool;
+    type IntoIter = BitIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Bitmap operations
+///
+/// As `BitsWithOffset` does not store the number of bits, most methods take it as an argument.
+/// Bulk operations process up to 64 bits at once, independent of the offset.
+///
+/// ```rust
+/// # use marrow::view::BitsWithOffset;
+/// let bits = BitsWithOffset { offset: 2, data: &[0b_0110_1100, 0b_1] };
+///
+/// assert_eq!(bits.get(0), true);
+/// assert_eq!(bits.count_set_bits(7), 5);
+/// assert_eq!(bits.set_indices(7).collect::<Vec<_>>(), vec![0, 1, 3, 4, 6]);
+/// assert_eq!(bits.to_vec_bool(7), vec![true, true, false, true, true, false, true]);
+/// assert_eq!(bits.not(7).into_vec(), vec![0b_010_0100]);
+/// ```
+impl<'a> BitsWithOffset<'a> {
+    /// Get the bit at position `idx` (relative to the offset)
+    ///
+    /// # Panics
+    ///
+    /// If the bit is outside of the data.
+    pub fn get(self, idx: usize) -> bool {
+        get(self.data, self.offset + idx)
+    }
+
+    /// Get the bits `start..start + len` without copying
+    ///
+    /// Bytes outside of the range are removed, the offset of the result is less than `8`.
+    ///
+    /// # Panics
+    ///
+    /// If the range is outside of the data.
+    pub fn slice(self, start: usize, len: usize) -> Self {
+        let offset = self.offset + start;
+        let first_byte = offset / 8;
+        let end_byte = num_bytes(offset + len);
+        Self {
+            offset: offset % 8,
+            data: &self.data[first_byte..end_byte],
+        }
+    }
+
+    /// Iterate over the first `len` bits
+    pub fn iter(self, len: usize) -> BitIter<'a> {
+        BitIter {
+            bits: self,
+            idx: 0,
+            len,
+        }
+    }
+
+    /// Count the number of set bits among the first `len` bits
+    pub fn count_set_bits(self, len: usize) -> usize {
+        let mut count = 0;
+        for_each_word(len, |start, n| {
+            count += self.read_word(start, n).count_ones() as usize;
+        });
+        count
+    }
+
+    /// Iterate over the indices of the set bits among the first `len` bits
+    pub fn set_indices(self, len: usize) -> SetIndices<'a> {
+        SetIndices {
+            bits: self,
+            len,
+            next_start: 0,
+            word_start: 0,
+            word: 0,
+        }
+    }
+
+    /// Copy the first `len` bits into a bitmap without offset
+    pub fn to_bitmap(self, len: usize) -> Bitmap {
+        self.to_bitmap_at(0, len)
+    }
+
+    /// Collect the first `len` bits into a vector of booleans
+    pub fn to_vec_bool(self, len: usize) -> Vec<bool> {
+        self.iter(len).collect()
+    }
+
+    /// Compute the bitwise and of the first `len` bits
+    pub fn and(self, other: BitsWithOffset<'_>, len: usize) -> Bitmap {
+        build_bitmap(len, |start, n| {
+            self.read_word(start, n) & other.read_word(start, n)
+        })
+    }
+
+    /// Compute the bitwise or of the first `len` bits
+    pub fn or(self, other: BitsWithOffset<'_>, len: usize) -> Bitmap {
+        build_bitmap(len, |start, n| {
+            self.read_word(start, n) | other.read_word(start, n)
+        })
+    }
+
+    /// Compute the bitwise negation of the first `len` bits
+    pub fn not(self, len: usize) -> Bitmap {
+        build_bitmap(len, |start, n| !self.read_word(start, n) & word_mask(n))
+    }
+
+    fn to_bitmap_at(self, offset: usize, len: usize) -> Bitmap {
+        build_bitmap(len, |start, n| self.read_word(offset + start, n))
+    }
+
+    /// Read `n <= 64` bits starting at bit `start` into the lowest bits of a word
+    fn read_word(self, start: usize, n: usize) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        let offset = self.offset + start;
+        let shift = offset % 8;
+
+        // at most 9 bytes are required to cover 64 bits at an arbitrary offset
+        let mut word: u128 = 0;
+        for (idx, &byte) in self.data[offset / 8..num_bytes(offset + n)]
+            .iter()
+            .enumerate()
+        {
+            word |= u128::from(byte) << (8 * idx);
+        }
+        ((word >> shift) as u64) & word_mask(n)
+    }
+}
+
+/// An iterator over the bits of a bitmap
+///
+/// See [`Bitmap::iter`] and [`BitsWithOffset::iter`].
+#[derive(Debug, Clone)]
+pub struct BitIter<'a> {
+    bits: BitsWithOffset<'a>,
+    idx: usize,
+    len: usize,
+}
+
+impl Iterator for BitIter<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let res = self.bits.get(self.idx);
+        self.idx += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitIter<'_> {}
+
+/// An iterator over the indices of the set bits of a bitmap
+///
+/// See [`Bitmap::set_indices`] and [`BitsWithOffset::set_indices`].
+#[derive(Debug, Clone)]
+pub struct SetIndices<'a> {
+    bits: BitsWithOffset<'a>,
+    len: usize,
+    next_start: usize,
+    word_start: usize,
+    word: u64,
+}
+
+impl Iterator for SetIndices<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            if self.next_start >= self.len {
+                return None;
+            }
+            let n = usize::min(64, self.len - self.next_start);
+            self.word = self.bits.read_word(self.next_start, n);
+            self.word_start = self.next_start;
+            self.next_start += n;
+        }
+        let idx = self.word_start + self.word.trailing_zeros() as usize;
+        // clear the lowest set bit
+        self.word &= self.word - 1;
+        Some(idx)
+    }
+}
+
+fn num_bytes(num_bits: usize) -> usize {
+    num_bits / 8 + if num_bits % 8 != 0 { 1 } else { 0 }
+}
+
+fn word_mask(n: usize) -> u64 {
+    if n >= 64 {
+        u64::MAX
+    } else {
+        (1 << n) - 1
+    }
+}
+
+/// Call `func(start, n)` for consecutive chunks of at most 64 bits
+fn for_each_word(len: usize, mut func: impl FnMut(usize, usize)) {
+    let mut start = 0;
+    while start < len {
+        let n = usize::min(64, len - start);
+        func(start, n);
+        start += n;
+    }
+}
+
+/// Build a bitmap from words of at most 64 bits, the bits beyond `n` must be unset
+fn build_bitmap(len: usize, mut word: impl FnMut(usize, usize) -> u64) -> Bitmap {
+    let mut data = Vec::with_capacity(num_bytes(len) + 8);
+    for_each_word(len, |start, n| {
+        data.extend_from_slice(&word(start, n).to_le_bytes());
+    });
+    data.truncate(num_bytes(len));
+    Bitmap { data, len }
+}
+
+#[test]
+fn bitmap_operations_with_offsets() {
+    let lhs: Vec<bool> = (0..150).map(|idx| idx % 3 == 0 || idx % 7 == 0).collect();
+    let rhs: Vec<bool> = (0..150).map(|idx| idx % 2 == 0).collect();
+
+    for offset in [0, 3, 8, 13] {
+        let mut padded = vec![true; offset];
+        padded.extend(&lhs);
+        let padded = Bitmap::from(padded);
+        let lhs_bits = BitsWithOffset {
+            offset,
+            data: padded.as_slice(),
+        };
+        let rhs = Bitmap::from(rhs.clone());
+
+        for len in [0, 1, 7, 64, 65, 130, 150] {
+            let lhs = &lhs[..len];
+            let rhs_bits = rhs.as_bits();
+            let rhs = &rhs.iter().collect::<Vec<_>>()[..len];
+
+            assert_eq!(lhs_bits.to_vec_bool(len), lhs);
+            assert_eq!(
+                lhs_bits.count_set_bits(len),
+                lhs.iter().filter(|v| **v).count()
+            );
+            assert_eq!(
+                lhs_bits.set_indices(len).collect::<Vec<_>>(),
+                (0..len).filter(|idx| lhs[*idx]).collect::<Vec<_>>(),
+            );
+            assert_eq!(
+                lhs_bits.and(rhs_bits, len),
+                Bitmap::from_iter(std::iter::zip(lhs, rhs).map(|(a, b)| *a && *b)),
+            );
+            assert_eq!(
+                lhs_bits.or(rhs_bits, len),
+                Bitmap::from_iter(std::iter::zip(lhs, rhs).map(|(a, b)| *a || *b)),
+            );
+            assert_eq!(
+                lhs_bits.not(len),
+                Bitmap::from_iter(lhs.iter().map(|a| !*a)),
+            );
+        }
+    }
+}
+
+#[test]
+fn bitmap_slices() {
+    let values: Vec<bool> = (0..100).map(|idx| idx % 5 == 1).collect();
+    let bitmap = Bitmap::from(values.clone());
+
+    for (start, len) in [(0, 100), (3, 10), (8, 64), (17, 83), (99, 1), (50, 0)] {
+        let expected = &values[start..start + len];
+        assert_eq!(Vec::<bool>::from(bitmap.slice(start, len)), expected);
+        assert_eq!(
+            bitmap.as_bits().slice(start, len).to_vec_bool(len),
+            expected
+        );
+    }
+
+    let mut bitmap = Bitmap::from_value(true, 10);
+    bitmap.set(9, false);
+    assert_eq!(bitmap.count_set_bits(), 9);
+    assert_eq!(bitmap.get(10), None);
+    assert_eq!(bitmap.into_vec(), vec![0xff, 0b_01]);
+}
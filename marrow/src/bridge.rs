@@ -0,0 +1,373 @@
+//! Direct conversions between different arrow implementations
+//!
+//! For each enabled `arrow` or `arrow2` feature, this module contains a submodule with functions to
+//! convert arrays, fields and schemas into any other enabled implementation. For example, with the
+//! `arrow-53` and `arrow-58` features enabled, an `arrow=53` array can be converted via
+//! `marrow::bridge::arrow_53::to_arrow_58(&array)`.
+//!
+//! Arrays are converted by viewing the source array as a [`View`][crate::view::View] and handing
+//! the viewed buffers to the target implementation without copying. The target buffers keep the
+//! source array alive. `arrow2` targets receive the buffers via the C data interface. Only data
+//! whose layout differs between the implementations is copied: for `arrow` targets the values of
+//! interval arrays, whose alignment differs between versions, and for `arrow2` targets validity
+//! bitmaps whose bit offset differs from the offset of the values.
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! # #[cfg(all(feature = "arrow-58", feature = "arrow2-0-17"))] {
+//! # use arrow_array_58 as arrow_array;
+//! # use arrow2_0_17 as arrow2;
+//! use arrow_array::Array as _;
+//!
+//! let array = arrow2::array::Int32Array::from(vec![Some(1), None, Some(3)]);
+//! let array = marrow::bridge::arrow2_0_17::to_arrow_58(&array)?;
+//!
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.null_count(), 1);
+//! # }
+//! # Ok(())
+//! # }
+//! ```
+macro_rules! define_bridges {
+    ($({ $($source:tt)* })*) => {
+        define_bridges!(@sources [$({ $($source)* })*] [$({ $($source)* })*]);
+    };
+    (@sources [$(
+        {
+            feature: $feature:literal,
+            module: $module:ident,
+            $($rest:tt)*
+        }
+    )*] $targets:tt) => {
+        $(
+            #[doc = concat!("Conversions from the `", $feature, "` implementation (*requires the `", $feature, "` feature*)")]
+            #[cfg(feature = $feature)]
+            pub mod $module {
+                use crate::{
+                    datatypes::Field,
+                    error::Result,
+                    interop::{BridgeSchema, ForeignView, Owner},
+                    view::View,
+                };
+
+                define_bridges!(@targets { feature: $feature, module: $module, $($rest)* } $targets);
+            }
+        )*
+    };
+    (@targets {
+        feature: $source_feature:literal,
+        module: $source_module:ident,
+        to: ($($source_to:tt)*),
+        owner: $source_owner:ident,
+        array: $source_array:ty,
+        array_ref: $source_array_ref:ty,
+        field: $source_field:ty,
+        schema: $source_schema:ty,
+    } [$(
+        {
+            feature: $feature:literal,
+            module: $module:ident,
+            to: ($to_array:ident, $to_field:ident, $to_schema:ident),
+            owner: $owner:ident,
+            array: $array:ty,
+            array_ref: $array_ref:ty,
+            field: $field:ty,
+            schema: $schema:ty,
+        }
+    )*]) => {
+        $(
+            #[doc = concat!("Convert a `", $source_feature, "` array into a `", $feature, "` array (*requires the `", $feature, "` feature*)")]
+            #[cfg(feature = $feature)]
+            pub fn $to_array(array: &$source_array) -> Result<$array_ref> {
+                let view = View::try_from(array)?;
+                // SAFETY: the owner keeps the buffers of the source array alive, the buffers of
+                // arrays are immutable once shared
+                let view = unsafe { ForeignView::new(view, Owner::new(array.$source_owner())) };
+                <$array_ref>::try_from(view)
+            }
+
+            #[doc = concat!("Convert a `", $source_feature, "` field into a `", $feature, "` field (*requires the `", $feature, "` feature*)")]
+            #[cfg(feature = $feature)]
+            pub fn $to_field(field: &$source_field) -> Result<$field> {
+                <$field>::try_from(&Field::try_from(field)?)
+            }
+
+            #[doc = concat!("Convert a `", $source_feature, "` schema into a `", $feature, "` schema (*requires the `", $feature, "` feature*)")]
+            #[cfg(feature = $feature)]
+            pub fn $to_schema(schema: &$source_schema) -> Result<$schema> {
+                let (fields, metadata) = BridgeSchema::to_marrow(schema)?;
+                <$schema as BridgeSchema>::from_marrow(fields, metadata)
+            }
+        )*
+    };
+}
+
+define_bridges! {
+    // arrow-version:insert:     {{ feature: "arrow-{version}", module: arrow_{version}, to: (to_arrow_{version}, to_arrow_{version}_field, to_arrow_{version}_schema), owner: to_data, array: dyn ::arrow_array_{version}::Array, array_ref: ::arrow_array_{version}::ArrayRef, field: ::arrow_schema_{version}::Field, schema: ::arrow_schema_{version}::Schema, }}
+    { feature: "arrow-58", module: arrow_58, to: (to_arrow_58, to_arrow_58_field, to_arrow_58_schema), owner: to_data, array: dyn ::arrow_array_58::Array, array_ref: ::arrow_array_58::ArrayRef, field: ::arrow_schema_58::Field, schema: ::arrow_schema_58::Schema, }
+    { feature: "arrow-57", module: arrow_57, to: (to_arrow_57, to_arrow_57_field, to_arrow_57_schema), owner: to_data, array: dyn ::arrow_array_57::Array, array_ref: ::arrow_array_57::ArrayRef, field: ::arrow_schema_57::Field, schema: ::arrow_schema_57::Schema, }
+    { feature: "arrow-56", module: arrow_56, to: (to_arrow_56, to_arrow_56_field, to_arrow_56_schema), owner: to_data, array: dyn ::arrow_array_56::Array, array_ref: ::arrow_array_56::ArrayRef, field: ::arrow_schema_56::Field, schema: ::arrow_schema_56::Schema, }
+    { feature: "arrow-55", module: arrow_55, to: (to_arrow_55, to_arrow_55_field, to_arrow_55_schema), owner: to_data, array: dyn ::arrow_array_55::Array, array_ref: ::arrow_array_55::ArrayRef, field: ::arrow_schema_55::Field, schema: ::arrow_schema_55::Schema, }
+    { feature: "arrow-54", module: arrow_54, to: (to_arrow_54, to_arrow_54_field, to_arrow_54_schema), owner: to_data, array: dyn ::arrow_array_54::Array, array_ref: ::arrow_array_54::ArrayRef, field: ::arrow_schema_54::Field, schema: ::arrow_schema_54::Schema, }
+    { feature: "arrow-53", module: arrow_53, to: (to_arrow_53, to_arrow_53_field, to_arrow_53_schema), owner: to_data, array: dyn ::arrow_array_53::Array, array_ref: ::arrow_array_53::ArrayRef, field: ::arrow_schema_53::Field, schema: ::arrow_schema_53::Schema, }
+    { feature: "arrow-52", module: arrow_52, to: (to_arrow_52, to_arrow_52_field, to_arrow_52_schema), owner: to_data, array: dyn ::arrow_array_52::Array, array_ref: ::arrow_array_52::ArrayRef, field: ::arrow_schema_52::Field, schema: ::arrow_schema_52::Schema, }
+    { feature: "arrow-51", module: arrow_51, to: (to_arrow_51, to_arrow_51_field, to_arrow_51_schema), owner: to_data, array: dyn ::arrow_array_51::Array, array_ref: ::arrow_array_51::ArrayRef, field: ::arrow_schema_51::Field, schema: ::arrow_schema_51::Schema, }
+    { feature: "arrow-50", module: arrow_50, to: (to_arrow_50, to_arrow_50_field, to_arrow_50_schema), owner: to_data, array: dyn ::arrow_array_50::Array, array_ref: ::arrow_array_50::ArrayRef, field: ::arrow_schema_50::Field, schema: ::arrow_schema_50::Schema, }
+    { feature: "arrow-49", module: arrow_49, to: (to_arrow_49, to_arrow_49_field, to_arrow_49_schema), owner: to_data, array: dyn ::arrow_array_49::Array, array_ref: ::arrow_array_49::ArrayRef, field: ::arrow_schema_49::Field, schema: ::arrow_schema_49::Schema, }
+    { feature: "arrow-48", module: arrow_48, to: (to_arrow_48, to_arrow_48_field, to_arrow_48_schema), owner: to_data, array: dyn ::arrow_array_48::Array, array_ref: ::arrow_array_48::ArrayRef, field: ::arrow_schema_48::Field, schema: ::arrow_schema_48::Schema, }
+    { feature: "arrow-47", module: arrow_47, to: (to_arrow_47, to_arrow_47_field, to_arrow_47_schema), owner: to_data, array: dyn ::arrow_array_47::Array, array_ref: ::arrow_array_47::ArrayRef, field: ::arrow_schema_47::Field, schema: ::arrow_schema_47::Schema, }
+    { feature: "arrow-46", module: arrow_46, to: (to_arrow_46, to_arrow_46_field, to_arrow_46_schema), owner: to_data, array: dyn ::arrow_array_46::Array, array_ref: ::arrow_array_46::ArrayRef, field: ::arrow_schema_46::Field, schema: ::arrow_schema_46::Schema, }
+    { feature: "arrow-45", module: arrow_45, to: (to_arrow_45, to_arrow_45_field, to_arrow_45_schema), owner: to_data, array: dyn ::arrow_array_45::Array, array_ref: ::arrow_array_45::ArrayRef, field: ::arrow_schema_45::Field, schema: ::arrow_schema_45::Schema, }
+    { feature: "arrow-44", module: arrow_44, to: (to_arrow_44, to_arrow_44_field, to_arrow_44_schema), owner: to_data, array: dyn ::arrow_array_44::Array, array_ref: ::arrow_array_44::ArrayRef, field: ::arrow_schema_44::Field, schema: ::arrow_schema_44::Schema, }
+    { feature: "arrow-43", module: arrow_43, to: (to_arrow_43, to_arrow_43_field, to_arrow_43_schema), owner: to_data, array: dyn ::arrow_array_43::Array, array_ref: ::arrow_array_43::ArrayRef, field: ::arrow_schema_43::Field, schema: ::arrow_schema_43::Schema, }
+    { feature: "arrow-42", module: arrow_42, to: (to_arrow_42, to_arrow_42_field, to_arrow_42_schema), owner: to_data, array: dyn ::arrow_array_42::Array, array_ref: ::arrow_array_42::ArrayRef, field: ::arrow_schema_42::Field, schema: ::arrow_schema_42::Schema, }
+    { feature: "arrow-41", module: arrow_41, to: (to_arrow_41, to_arrow_41_field, to_arrow_41_schema), owner: to_data, array: dyn ::arrow_array_41::Array, array_ref: ::arrow_array_41::ArrayRef, field: ::arrow_schema_41::Field, schema: ::arrow_schema_41::Schema, }
+    { feature: "arrow-40", module: arrow_40, to: (to_arrow_40, to_arrow_40_field, to_arrow_40_schema), owner: to_data, array: dyn ::arrow_array_40::Array, array_ref: ::arrow_array_40::ArrayRef, field: ::arrow_schema_40::Field, schema: ::arrow_schema_40::Schema, }
+    { feature: "arrow-39", module: arrow_39, to: (to_arrow_39, to_arrow_39_field, to_arrow_39_schema), owner: to_data, array: dyn ::arrow_array_39::Array, array_ref: ::arrow_array_39::ArrayRef, field: ::arrow_schema_39::Field, schema: ::arrow_schema_39::Schema, }
+    { feature: "arrow-38", module: arrow_38, to: (to_arrow_38, to_arrow_38_field, to_arrow_38_schema), owner: to_data, array: dyn ::arrow_array_38::Array, array_ref: ::arrow_array_38::ArrayRef, field: ::arrow_schema_38::Field, schema: ::arrow_schema_38::Schema, }
+    { feature: "arrow-37", module: arrow_37, to: (to_arrow_37, to_arrow_37_field, to_arrow_37_schema), owner: to_data, array: dyn ::arrow_array_37::Array, array_ref: ::arrow_array_37::ArrayRef, field: ::arrow_schema_37::Field, schema: ::arrow_schema_37::Schema, }
+    { feature: "arrow2-0-17", module: arrow2_0_17, to: (to_arrow2_0_17, to_arrow2_0_17_field, to_arrow2_0_17_schema), owner: to_boxed, array: dyn ::arrow2_0_17::array::Array, array_ref: Box<dyn ::arrow2_0_17::array::Array>, field: ::arrow2_0_17::datatypes::Field, schema: ::arrow2_0_17::datatypes::Schema, }
+    { feature: "arrow2-0-16", module: arrow2_0_16, to: (to_arrow2_0_16, to_arrow2_0_16_field, to_arrow2_0_16_schema), owner: to_boxed, array: dyn ::arrow2_0_16::array::Array, array_ref: Box<dyn ::arrow2_0_16::array::Array>, field: ::arrow2_0_16::datatypes::Field, schema: ::arrow2_0_16::datatypes::Schema, }
+}
+
+#[cfg(all(feature = "arrow-58", feature = "arrow2-0-17"))]
+#[test]
+fn arrow2_arrow_roundtrip() {
+    use ::arrow2_0_17 as arrow2;
+
+    let mut array: Box<dyn arrow2::array::Array> =
+        Box::new(arrow2::array::Utf8Array::<i32>::from(vec![
+            Some("foo"),
+            None,
+            Some("bar"),
+            Some("baz"),
+        ]));
+    array.slice(1, 3);
+
+    let arrow_array = arrow2_0_17::to_arrow_58(array.as_ref()).unwrap();
+    assert_eq!(arrow_array.len(), 3);
+    assert_eq!(arrow_array.null_count(), 1);
+
+    // the buffers are shared, not copied
+    let source = array
+        .as_any()
+        .downcast_ref::<arrow2::array::Utf8Array<i32>>()
+        .unwrap();
+    let target = arrow_array
+        .as_any()
+        .downcast_ref::<arrow_array_58::StringArray>()
+        .unwrap();
+    assert_eq!(target.value_data().as_ptr(), source.values().as_ptr());
+    assert_eq!(
+        target.value_offsets().as_ptr(),
+        source.offsets().buffer().as_ptr()
+    );
+    assert_eq!(
+        arrow_58::to_arrow2_0_17(arrow_array.as_ref()).unwrap(),
+        array
+    );
+
+    let schema = arrow2::datatypes::Schema {
+        fields: vec![
+            arrow2::datatypes::Field::new("a", arrow2::datatypes::DataType::Int32, false),
+            arrow2::datatypes::Field::new("b", arrow2::datatypes::DataType::Utf8, true),
+        ],
+        metadata: [(String::from("key"), String::from("value"))]
+            .into_iter()
+            .collect(),
+    };
+
+    let arrow_schema = arrow2_0_17::to_arrow_58_schema(&schema).unwrap();
+    assert_eq!(arrow_schema.fields().len(), 2);
+    assert_eq!(arrow_schema.metadata()["key"], "value");
+    assert_eq!(
+        arrow_58::to_arrow2_0_17_schema(&arrow_schema).unwrap(),
+        schema
+    );
+}
+
+#[cfg(feature = "arrow-58")]
+#[test]
+fn arrow_buffers_are_shared() {
+    use arrow_array_58::{self as arrow_array, Array as _};
+
+    let array = arrow_array::Int64Array::from(vec![Some(1), None, Some(3), Some(4), None, Some(6)]);
+    let array = array.slice(3, 3);
+
+    let bridged = arrow_58::to_arrow_58(&array).unwrap();
+    let bridged = bridged
+        .as_any()
+        .downcast_ref::<arrow_array::Int64Array>()
+        .unwrap();
+
+    assert_eq!(bridged, &array);
+    assert_eq!(bridged.values().as_ptr(), array.values().as_ptr());
+    assert_eq!(
+        bridged.nulls().unwrap().buffer().as_ptr(),
+        array.nulls().unwrap().buffer().as_ptr()
+    );
+}
+
+#[cfg(feature = "arrow2-0-17")]
+#[test]
+fn arrow2_buffers_are_shared() {
+    use ::arrow2_0_17 as arrow2;
+
+    let mut array =
+        arrow2::array::Utf8Array::<i32>::from(vec![Some("foo"), None, Some("bar"), Some("baz")]);
+    array.slice(1, 3);
+
+    let bridged = arrow2_0_17::to_arrow2_0_17(&array).unwrap();
+    let bridged = bridged
+        .as_any()
+        .downcast_ref::<arrow2::array::Utf8Array<i32>>()
+        .unwrap();
+
+    assert_eq!(bridged, &array);
+    assert_eq!(bridged.values().as_ptr(), array.values().as_ptr());
+    assert_eq!(
+        bridged.offsets().buffer().as_ptr(),
+        array.offsets().buffer().as_ptr()
+    );
+}
+
+#[cfg(feature = "arrow2-0-17")]
+#[test]
+fn arrow2_bridge_supports_all_layouts() {
+    use ::arrow2_0_17 as arrow2;
+
+    use crate::{
+        array::{
+            Array, BooleanArray, BytesArray, DecimalArray, DictionaryArray, FixedSizeBinaryArray,
+            FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray, StructArray,
+            UnionArray,
+        },
+        datatypes::{meta, MapMeta},
+        types::{DayTimeInterval, MonthDayNanoInterval},
+    };
+
+    fn int32(values: Vec<i32>) -> Array {
+        Array::Int32(PrimitiveArray {
+            validity: Some(vec![0b_1111_1011, 0b_1111_1111]),
+            values,
+        })
+    }
+
+    let arrays = vec![
+        Array::Null(NullArray { len: 4 }),
+        Array::Boolean(BooleanArray {
+            len: 10,
+            validity: Some(vec![0b_1101_1111, 0b_11]),
+            values: vec![0b_0101_0110, 0b_10],
+        }),
+        int32(vec![1, 2, 3, 4, 5]),
+        Array::Decimal128(DecimalArray {
+            precision: 10,
+            scale: 2,
+            validity: None,
+            values: vec![100, 200, 300, 400],
+        }),
+        Array::DayTimeInterval(PrimitiveArray {
+            validity: None,
+            values: vec![DayTimeInterval::default(); 4],
+        }),
+        Array::MonthDayNanoInterval(PrimitiveArray {
+            validity: None,
+            values: vec![MonthDayNanoInterval::default(); 4],
+        }),
+        Array::LargeBinary(BytesArray {
+            validity: Some(vec![0b_1101]),
+            offsets: vec![0, 1, 3, 3, 6],
+            data: b"abbccc".to_vec(),
+        }),
+        Array::FixedSizeBinary(FixedSizeBinaryArray {
+            n: 2,
+            validity: None,
+            data: b"aabbccdd".to_vec(),
+        }),
+        Array::Struct(StructArray {
+            len: 4,
+            validity: Some(vec![0b_0111]),
+            fields: vec![(meta("a", true), int32(vec![1, 2, 3, 4]))],
+        }),
+        Array::List(ListArray {
+            validity: None,
+            offsets: vec![0, 2, 2, 4, 5],
+            meta: meta("element", true),
+            elements: Box::new(int32(vec![1, 2, 3, 4, 5])),
+        }),
+        Array::FixedSizeList(FixedSizeListArray {
+            len: 4,
+            n: 2,
+            validity: None,
+            meta: meta("element", true),
+            elements: Box::new(int32(vec![1, 2, 3, 4, 5, 6, 7, 8])),
+        }),
+        Array::Map(MapArray {
+            validity: None,
+            offsets: vec![0, 1, 1, 3, 4],
+            meta: MapMeta::default(),
+            keys: Box::new(Array::Int32(PrimitiveArray {
+                validity: None,
+                values: vec![1, 2, 3, 4],
+            })),
+            values: Box::new(int32(vec![5, 6, 7, 8])),
+        }),
+        Array::Dictionary(DictionaryArray {
+            keys: Box::new(Array::UInt16(PrimitiveArray {
+                validity: None,
+                values: vec![1, 0, 1, 1],
+            })),
+            values: Box::new(Array::Utf8(BytesArray {
+                validity: None,
+                offsets: vec![0, 1, 3],
+                data: b"abb".to_vec(),
+            })),
+        }),
+        Array::Union(UnionArray {
+            types: vec![0, 1, 1, 0],
+            offsets: Some(vec![0, 0, 1, 1]),
+            fields: vec![
+                (0, meta("a", true), int32(vec![1, 2])),
+                (
+                    1,
+                    meta("b", true),
+                    Array::Float64(PrimitiveArray {
+                        validity: None,
+                        values: vec![3.0, 4.0],
+                    }),
+                ),
+            ],
+        }),
+    ];
+
+    for array in arrays {
+        let array = Box::<dyn arrow2::array::Array>::try_from(array).unwrap();
+        for (offset, len) in [(0, array.len()), (1, array.len() - 1), (2, 1)] {
+            let mut sliced = array.clone();
+            sliced.slice(offset, len);
+
+            let bridged = arrow2_0_17::to_arrow2_0_17(sliced.as_ref()).unwrap();
+            assert_eq!(
+                bridged,
+                sliced,
+                "{:?} {offset}..{}",
+                array.data_type(),
+                offset + len
+            );
+
+            #[cfg(feature = "arrow-58")]
+            {
+                let arrow_array = arrow2_0_17::to_arrow_58(sliced.as_ref()).unwrap();
+                let roundtrip = arrow_58::to_arrow2_0_17(arrow_array.as_ref()).unwrap();
+                assert_eq!(
+                    roundtrip,
+                    sliced,
+                    "{:?} {offset}..{}",
+                    array.data_type(),
+                    offset + len
+                );
+            }
+        }
+    }
+}
@@ -3,6 +3,18 @@ use std::collections::HashMap;
 
 use crate::error::{fail, ErrorKind, MarrowError, Result};
 
+mod c_format;
+mod layout;
+#[cfg(feature = "serde")]
+mod serialize;
+mod text;
+mod validate;
+
+pub use c_format::CSchema;
+pub use layout::{BufferKind, Layout};
+#[cfg(feature = "serde")]
+pub use validate::{deserialize_validated, Validate};
+
 // assert that the `DataType` implements the expected traits
 #[allow(unused)]
 const _: () = {
@@ -16,13 +28,30 @@ const _: () = {
     trait AssertExpectedTraits: Clone + std::fmt::Debug + Default + PartialEq + Send + Sync {}
     impl AssertExpectedTraits for Field {}
     impl AssertExpectedTraits for FieldMeta {}
+    impl AssertExpectedTraits for DictionaryMeta {}
     impl AssertExpectedTraits for MapMeta {}
     impl AssertExpectedTraits for RunEndEncodedMeta {}
 };
 
 /// The data type and metadata of a field
+///
+/// Fields can be converted to and from a compact textual representation of the form
+/// `name: [nullable] DataType [{"key": "value", ...}]`, see [`DataType`] for details:
+///
+/// ```rust
+/// # use marrow::datatypes::{DataType, Field};
+/// let field: Field = "values: nullable List<Int64>".parse()?;
+/// assert_eq!(field.name, "values");
+/// assert!(field.nullable);
+/// assert_eq!(field.to_string(), "values: nullable List<Int64>");
+/// # Ok::<(), marrow::error::MarrowError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serialize::FieldRepr", into = "serialize::FieldRepr")
+)]
 pub struct Field {
     /// The name of the field
     pub name: String,
@@ -32,6 +61,8 @@ pub struct Field {
     pub nullable: bool,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
+    /// The dictionary metadata, only used for dictionary encoded fields
+    pub dictionary: DictionaryMeta,
 }
 
 impl std::default::Default for Field {
@@ -41,12 +72,18 @@ impl std::default::Default for Field {
             name: Default::default(),
             nullable: Default::default(),
             metadata: Default::default(),
+            dictionary: Default::default(),
         }
     }
 }
 
 /// Metadata for a field (everything but the data type)
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serialize::FieldMetaRepr", into = "serialize::FieldMetaRepr")
+)]
 pub struct FieldMeta {
     /// The name of the field
     pub name: String,
@@ -54,6 +91,43 @@ pub struct FieldMeta {
     pub nullable: bool,
     /// Additional metadata of the field
     pub metadata: HashMap<String, String>,
+    /// The dictionary metadata of the field, only used for dictionary encoded fields
+    pub dictionary: DictionaryMeta,
+}
+
+/// Metadata of dictionary encoded fields
+///
+/// The metadata is attached to the field, as in `arrow`, and ignored for fields that are not
+/// dictionary encoded. Dictionaries nested directly in the value type of another dictionary do not
+/// carry metadata.
+///
+/// With the `serde` feature, fields store the dictionary metadata as the flat `dict_id` and
+/// `dict_is_ordered` keys, as `arrow` does. Missing ids are written as `0` and an id of `0` is read
+/// as `None`.
+///
+/// ```rust
+/// # use marrow::datatypes::{DataType, DictionaryMeta, Field};
+/// let field = Field {
+///     name: String::from("category"),
+///     data_type: DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+///     dictionary: DictionaryMeta {
+///         ordered: true,
+///         id: Some(1),
+///     },
+///     ..Field::default()
+/// };
+/// assert!(field.dictionary.ordered);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DictionaryMeta {
+    /// Whether the order of the dictionary values is semantically meaningful (defaults to `false`)
+    pub ordered: bool,
+    /// The id of the dictionary, e.g., for IPC (defaults to `None`)
+    ///
+    /// As `arrow` uses `0` for fields without an explicit id, an id of `0` is converted to `None`
+    /// when converting fields from `arrow`.
+    pub id: Option<i64>,
 }
 
 #[allow(unused)]
@@ -62,6 +136,7 @@ pub(crate) fn meta_from_field(field: Field) -> FieldMeta {
         name: field.name,
         nullable: field.nullable,
         metadata: field.metadata,
+        dictionary: field.dictionary,
     }
 }
 
@@ -71,6 +146,17 @@ pub(crate) fn field_from_meta(data_type: DataType, meta: FieldMeta) -> Field {
         name: meta.name,
         nullable: meta.nullable,
         metadata: meta.metadata,
+        dictionary: meta.dictionary,
+    }
+}
+
+/// Build the metadata of a field without additional metadata, shared by the tests
+#[cfg(test)]
+pub(crate) fn meta(name: &str, nullable: bool) -> FieldMeta {
+    FieldMeta {
+        name: String::from(name),
+        nullable,
+        ..FieldMeta::default()
     }
 }
 
@@ -99,6 +185,7 @@ pub(crate) fn field_from_meta(data_type: DataType, meta: FieldMeta) -> Field {
 /// Note: the defaults follow the defaults of `arrow`'s MapBuilder.
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MapMeta {
     /// The name of the entries field (defaults to `"entries"`)
     pub entries_name: String,
@@ -118,12 +205,12 @@ impl std::default::Default for MapMeta {
             keys: FieldMeta {
                 name: String::from("keys"),
                 nullable: false,
-                metadata: HashMap::new(),
+                ..FieldMeta::default()
             },
             values: FieldMeta {
                 name: String::from("values"),
                 nullable: true,
-                metadata: HashMap::new(),
+                ..FieldMeta::default()
             },
         }
     }
@@ -147,6 +234,7 @@ impl std::default::Default for MapMeta {
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunEndEncodedMeta {
     /// The name for the run ends (defaults to `"run_ends"`)
     pub run_ends_name: String,
@@ -161,7 +249,7 @@ impl std::default::Default for RunEndEncodedMeta {
             values: FieldMeta {
                 name: String::from("values"),
                 nullable: true,
-                metadata: HashMap::new(),
+                ..FieldMeta::default()
             },
         }
     }
@@ -169,6 +257,42 @@ impl std::default::Default for RunEndEncodedMeta {
 
 /// Data types of array
 ///
+/// Data types implement [`Display`][std::fmt::Display] and [`FromStr`][std::str::FromStr] using a
+/// compact textual representation, that round-trips for all data types:
+///
+/// ```rust
+/// # use marrow::datatypes::{DataType, Field, TimeUnit};
+/// let data_type: DataType = r#"List<nullable Struct<a: Int32, b: Timestamp(ms, "UTC")>>"#.parse()?;
+/// assert_eq!(
+///     data_type.to_string(),
+///     r#"List<nullable Struct<a: Int32, b: Timestamp(ms, "UTC")>>"#,
+/// );
+///
+/// let DataType::List(item) = &data_type else { panic!() };
+/// assert_eq!(item.name, "item");
+/// assert!(item.nullable);
+/// # Ok::<(), marrow::error::MarrowError>(())
+/// ```
+///
+/// Parameters are given in parentheses, children in angle brackets:
+///
+/// - Types without parameters use the name of the variant, e.g., `Int32`, `Utf8` or `BinaryView`
+/// - `FixedSizeBinary(n)`, `Decimal128(precision, scale)`, `Interval(unit)` with the
+///   [`IntervalUnit`] variant as the unit
+/// - `Timestamp(unit)`, `Timestamp(unit, "timezone")`, `Time32(unit)`, `Time64(unit)`,
+///   `Duration(unit)` with the unit as one of `s`, `ms`, `us`, `ns`
+/// - `Struct<field, ...>`, `Union(mode)<type_id: field, ...>`, `Dictionary<keys, values>`
+/// - `List<child>`, `LargeList<child>`, `FixedSizeList(n)<child>`, `Map<child>`,
+///   `Map(sorted)<child>`, `RunEndEncoded<child, child>`
+///
+/// Fields are written as `name: [nullable] DataType [{"key": "value", ...}]`. Names that are not
+/// identifiers are quoted. For children, the name can be omitted if it is equal to the default
+/// name (`"item"` for lists, `"entries"` for maps, `"run_ends"` and `"values"` for run end encoded
+/// types). The [`DictionaryMeta`] of dictionary encoded fields is given in front of the data type
+/// as `[ordered] [dict_id(id)]`, e.g., `category: nullable ordered Dictionary<Int8, Utf8>`.
+///
+/// Parse errors are reported with [`ErrorKind::ParseError`] and include the position of the error.
+///
 #[cfg_attr(
 // arrow-version: replace:     feature = "arrow-{version}",
     feature = "arrow-53",
@@ -0,0 +1,601 @@
+//! Format strings of the Arrow C Data Interface
+//!
+//! See the [Arrow documentation](https://arrow.apache.org/docs/format/CDataInterface.html) for the
+//! specification.
+use std::collections::HashMap;
+
+use crate::error::{fail, ErrorKind, MarrowError, Result};
+
+use super::{DataType, DictionaryMeta, Field, IntervalUnit, TimeUnit, UnionMode};
+
+/// An owned description of an `ArrowSchema` of the Arrow C Data Interface
+///
+/// In contrast to the `ArrowSchema` struct, the description does not contain any pointers. It
+/// carries the format string, name, metadata and flags of the schema and the descriptions of its
+/// children and dictionary. It can be converted from and to [`Field`]:
+///
+/// ```rust
+/// # use marrow::datatypes::{CSchema, DataType, Field};
+/// let schema = CSchema {
+///     format: String::from("+l"),
+///     name: String::from("values"),
+///     flags: CSchema::FLAG_NULLABLE,
+///     children: vec![CSchema {
+///         format: String::from("tsu:UTC"),
+///         name: String::from("item"),
+///         ..CSchema::default()
+///     }],
+///     ..CSchema::default()
+/// };
+///
+/// let field = Field::try_from(&schema)?;
+/// assert_eq!(field.to_string(), r#"values: nullable List<Timestamp(us, "UTC")>"#);
+/// assert_eq!(CSchema::try_from(&field)?, schema);
+/// # Ok::<(), marrow::error::MarrowError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CSchema {
+    /// The format string describing the data type
+    pub format: String,
+    /// The name of the field
+    pub name: String,
+    /// The metadata of the field
+    pub metadata: HashMap<String, String>,
+    /// The flags of the field as a combination of the `FLAG_*` constants
+    pub flags: i64,
+    /// The child schemas
+    pub children: Vec<CSchema>,
+    /// The schema of the dictionary values, if the field is dictionary encoded
+    pub dictionary: Option<Box<CSchema>>,
+}
+
+impl CSchema {
+    /// The dictionary indices are ordered (`ARROW_FLAG_DICTIONARY_ORDERED`)
+    pub const FLAG_DICTIONARY_ORDERED: i64 = 1;
+    /// The field is nullable (`ARROW_FLAG_NULLABLE`)
+    pub const FLAG_NULLABLE: i64 = 2;
+    /// The keys of each map are sorted (`ARROW_FLAG_MAP_KEYS_SORTED`)
+    pub const FLAG_MAP_KEYS_SORTED: i64 = 4;
+}
+
+impl DataType {
+    /// Get the format string of the Arrow C Data Interface for this data type
+    ///
+    /// For nested data types only the format of the parent is returned, the children are described
+    /// by separate schemas (see [`CSchema`]). For dictionaries, the format string is that of the
+    /// keys, the values are described by a separate schema.
+    ///
+    /// ```rust
+    /// # use marrow::datatypes::{DataType, TimeUnit};
+    /// assert_eq!(DataType::Int32.to_c_format()?, "i");
+    /// assert_eq!(
+    ///     DataType::Timestamp(TimeUnit::Microsecond, Some(String::from("UTC"))).to_c_format()?,
+    ///     "tsu:UTC",
+    /// );
+    /// assert_eq!(DataType::Decimal128(38, 10).to_c_format()?, "d:38,10");
+    /// # Ok::<(), marrow::error::MarrowError>(())
+    /// ```
+    pub fn to_c_format(&self) -> Result<String> {
+        use {DataType as T, TimeUnit as U};
+        let res = match self {
+            T::Null => "n",
+            T::Boolean => "b",
+            T::Int8 => "c",
+            T::UInt8 => "C",
+            T::Int16 => "s",
+            T::UInt16 => "S",
+            T::Int32 => "i",
+            T::UInt32 => "I",
+            T::Int64 => "l",
+            T::UInt64 => "L",
+            T::Float16 => "e",
+            T::Float32 => "f",
+            T::Float64 => "g",
+            T::Binary => "z",
+            T::LargeBinary => "Z",
+            T::BinaryView => "vz",
+            T::Utf8 => "u",
+            T::LargeUtf8 => "U",
+            T::Utf8View => "vu",
+            T::FixedSizeBinary(n) => return Ok(format!("w:{n}")),
+            T::Decimal128(precision, scale) => return Ok(format!("d:{precision},{scale}")),
+            T::Date32 => "tdD",
+            T::Date64 => "tdm",
+            T::Time32(U::Second) => "tts",
+            T::Time32(U::Millisecond) => "ttm",
+            T::Time64(U::Microsecond) => "ttu",
+            T::Time64(U::Nanosecond) => "ttn",
+            T::Time32(unit) | T::Time64(unit) => fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {self} in the C Data Interface: invalid unit {unit}",
+            ),
+            T::Timestamp(unit, timezone) => {
+                return Ok(format!(
+                    "ts{unit}:{timezone}",
+                    unit = unit_char(*unit),
+                    timezone = timezone.as_deref().unwrap_or_default(),
+                ))
+            }
+            T::Duration(unit) => return Ok(format!("tD{}", unit_char(*unit))),
+            T::Interval(IntervalUnit::YearMonth) => "tiM",
+            T::Interval(IntervalUnit::DayTime) => "tiD",
+            T::Interval(IntervalUnit::MonthDayNano) => "tin",
+            T::List(_) => "+l",
+            T::LargeList(_) => "+L",
+            T::FixedSizeList(_, n) => return Ok(format!("+w:{n}")),
+            T::Struct(_) => "+s",
+            T::Map(_, _) => "+m",
+            T::RunEndEncoded(_, _) => "+r",
+            T::Union(fields, mode) => {
+                let mode = match mode {
+                    UnionMode::Dense => 'd',
+                    UnionMode::Sparse => 's',
+                };
+                let type_ids = fields
+                    .iter()
+                    .map(|(type_id, _)| type_id.to_string())
+                    .collect::<Vec<_>>();
+                return Ok(format!("+u{mode}:{}", type_ids.join(",")));
+            }
+            T::Dictionary(keys, _) => {
+                if !is_integer(keys) {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "Cannot represent dictionary with non-integer keys {keys} in the C Data Interface"
+                    );
+                }
+                return keys.to_c_format();
+            }
+        };
+        Ok(res.to_owned())
+    }
+
+    /// Parse a format string of the Arrow C Data Interface
+    ///
+    /// The `children` are the fields of the child schemas. They are required for nested types
+    /// and must be empty for all other types. Dictionaries are not described by a format string
+    /// alone, use the conversion from [`CSchema`] to parse them.
+    ///
+    /// ```rust
+    /// # use marrow::datatypes::{DataType, Field, UnionMode};
+    /// assert_eq!(DataType::from_c_format("vu", vec![])?, DataType::Utf8View);
+    ///
+    /// let children = vec![
+    ///     Field { name: String::from("a"), data_type: DataType::Int32, ..Field::default() },
+    ///     Field { name: String::from("b"), data_type: DataType::Utf8, ..Field::default() },
+    /// ];
+    /// assert_eq!(
+    ///     DataType::from_c_format("+ud:0,1", children.clone())?,
+    ///     DataType::Union(
+    ///         vec![(0, children[0].clone()), (1, children[1].clone())],
+    ///         UnionMode::Dense,
+    ///     ),
+    /// );
+    /// # Ok::<(), marrow::error::MarrowError>(())
+    /// ```
+    pub fn from_c_format(format: &str, children: Vec<Field>) -> Result<DataType> {
+        parse_c_format(format, children, false)
+    }
+}
+
+fn unit_char(unit: TimeUnit) -> char {
+    match unit {
+        TimeUnit::Second => 's',
+        TimeUnit::Millisecond => 'm',
+        TimeUnit::Microsecond => 'u',
+        TimeUnit::Nanosecond => 'n',
+    }
+}
+
+fn is_integer(data_type: &DataType) -> bool {
+    use DataType as T;
+    matches!(
+        data_type,
+        T::Int8 | T::Int16 | T::Int32 | T::Int64 | T::UInt8 | T::UInt16 | T::UInt32 | T::UInt64
+    )
+}
+
+fn parse_c_format(format: &str, children: Vec<Field>, map_keys_sorted: bool) -> Result<DataType> {
+    use {DataType as T, TimeUnit as U};
+
+    let data_type = match format {
+        "n" => T::Null,
+        "b" => T::Boolean,
+        "c" => T::Int8,
+        "C" => T::UInt8,
+        "s" => T::Int16,
+        "S" => T::UInt16,
+        "i" => T::Int32,
+        "I" => T::UInt32,
+        "l" => T::Int64,
+        "L" => T::UInt64,
+        "e" => T::Float16,
+        "f" => T::Float32,
+        "g" => T::Float64,
+        "z" => T::Binary,
+        "Z" => T::LargeBinary,
+        "vz" => T::BinaryView,
+        "u" => T::Utf8,
+        "U" => T::LargeUtf8,
+        "vu" => T::Utf8View,
+        "tdD" => T::Date32,
+        "tdm" => T::Date64,
+        "tts" => T::Time32(U::Second),
+        "ttm" => T::Time32(U::Millisecond),
+        "ttu" => T::Time64(U::Microsecond),
+        "ttn" => T::Time64(U::Nanosecond),
+        "tDs" => T::Duration(U::Second),
+        "tDm" => T::Duration(U::Millisecond),
+        "tDu" => T::Duration(U::Microsecond),
+        "tDn" => T::Duration(U::Nanosecond),
+        "tiM" => T::Interval(IntervalUnit::YearMonth),
+        "tiD" => T::Interval(IntervalUnit::DayTime),
+        "tin" => T::Interval(IntervalUnit::MonthDayNano),
+        "+l" | "+L" => {
+            let [child] = expect_children(format, children)?;
+            if format == "+l" {
+                return Ok(T::List(Box::new(child)));
+            } else {
+                return Ok(T::LargeList(Box::new(child)));
+            }
+        }
+        "+s" => return Ok(T::Struct(children)),
+        "+m" => {
+            let [child] = expect_children(format, children)?;
+            return Ok(T::Map(Box::new(child), map_keys_sorted));
+        }
+        "+r" => {
+            let [run_ends, values] = expect_children(format, children)?;
+            return Ok(T::RunEndEncoded(Box::new(run_ends), Box::new(values)));
+        }
+        format => {
+            if let Some(n) = format.strip_prefix("w:") {
+                T::FixedSizeBinary(parse_c_format_int(format, n)?)
+            } else if let Some(n) = format.strip_prefix("+w:") {
+                let n = parse_c_format_int(format, n)?;
+                let [child] = expect_children(format, children)?;
+                return Ok(T::FixedSizeList(Box::new(child), n));
+            } else if let Some(params) = format.strip_prefix("d:") {
+                let params = params.split(',').collect::<Vec<_>>();
+                match params.as_slice() {
+                    [precision, scale] | [precision, scale, "128"] => T::Decimal128(
+                        parse_c_format_int(format, precision)?,
+                        parse_c_format_int(format, scale)?,
+                    ),
+                    [_, _, bit_width] => fail!(
+                        ErrorKind::Unsupported,
+                        "Unsupported decimal bit width {bit_width} in C format string {format:?}"
+                    ),
+                    _ => fail!(
+                        ErrorKind::ParseError,
+                        "Invalid decimal C format string {format:?}"
+                    ),
+                }
+            } else if let Some(rest) = format.strip_prefix("ts") {
+                let Some((unit, timezone)) = rest.split_once(':') else {
+                    fail!(
+                        ErrorKind::ParseError,
+                        "Invalid timestamp C format string {format:?}: missing ':'"
+                    );
+                };
+                let unit = match unit {
+                    "s" => U::Second,
+                    "m" => U::Millisecond,
+                    "u" => U::Microsecond,
+                    "n" => U::Nanosecond,
+                    _ => fail!(
+                        ErrorKind::ParseError,
+                        "Invalid time unit in C format string {format:?}"
+                    ),
+                };
+                let timezone = if timezone.is_empty() {
+                    None
+                } else {
+                    Some(timezone.to_owned())
+                };
+                T::Timestamp(unit, timezone)
+            } else if let Some(rest) = format.strip_prefix("+u") {
+                let (mode, type_ids) = match rest.split_once(':') {
+                    Some(("d", type_ids)) => (UnionMode::Dense, type_ids),
+                    Some(("s", type_ids)) => (UnionMode::Sparse, type_ids),
+                    _ => fail!(
+                        ErrorKind::ParseError,
+                        "Invalid union C format string {format:?}"
+                    ),
+                };
+                let mut parsed_type_ids = Vec::new();
+                if !type_ids.is_empty() {
+                    for type_id in type_ids.split(',') {
+                        parsed_type_ids.push(parse_c_format_int::<i8>(format, type_id)?);
+                    }
+                }
+                if parsed_type_ids.len() != children.len() {
+                    fail!(
+                        ErrorKind::ParseError,
+                        "Invalid union C format string {format:?}: expected {} type ids, found {}",
+                        children.len(),
+                        parsed_type_ids.len(),
+                    );
+                }
+                return Ok(T::Union(
+                    std::iter::zip(parsed_type_ids, children).collect(),
+                    mode,
+                ));
+            } else if matches!(format, "+vl" | "+vL") || format.starts_with("+vw") {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "List views (C format string {format:?}) are not supported"
+                );
+            } else {
+                fail!(ErrorKind::ParseError, "Unknown C format string {format:?}");
+            }
+        }
+    };
+
+    if !children.is_empty() {
+        fail!(
+            ErrorKind::ParseError,
+            "C format string {format:?} does not support children"
+        );
+    }
+    Ok(data_type)
+}
+
+fn expect_children<const N: usize>(format: &str, children: Vec<Field>) -> Result<[Field; N]> {
+    let num_children = children.len();
+    match <[Field; N]>::try_from(children) {
+        Ok(children) => Ok(children),
+        Err(_) => fail!(
+            ErrorKind::ParseError,
+            "C format string {format:?} requires {N} children, found {num_children}"
+        ),
+    }
+}
+
+fn parse_c_format_int<T: std::str::FromStr>(format: &str, s: &str) -> Result<T> {
+    match s.parse() {
+        Ok(value) => Ok(value),
+        Err(_) => fail!(
+            ErrorKind::ParseError,
+            "Invalid integer {s:?} in C format string {format:?}"
+        ),
+    }
+}
+
+/// Conversion from descriptions of C Data Interface schemas
+impl TryFrom<&CSchema> for Field {
+    type Error = MarrowError;
+
+    fn try_from(schema: &CSchema) -> Result<Self> {
+        let mut children = Vec::new();
+        for child in &schema.children {
+            children.push(Field::try_from(child)?);
+        }
+
+        let data_type = parse_c_format(
+            &schema.format,
+            children,
+            (schema.flags & CSchema::FLAG_MAP_KEYS_SORTED) != 0,
+        )?;
+        let data_type = match &schema.dictionary {
+            Some(dictionary) => {
+                if !is_integer(&data_type) {
+                    fail!(
+                        ErrorKind::ParseError,
+                        "Invalid dictionary key C format string {:?}",
+                        schema.format
+                    );
+                }
+                let values = Field::try_from(dictionary.as_ref())?;
+                DataType::Dictionary(Box::new(data_type), Box::new(values.data_type))
+            }
+            None => data_type,
+        };
+
+        let dictionary = DictionaryMeta {
+            ordered: schema.dictionary.is_some()
+                && (schema.flags & CSchema::FLAG_DICTIONARY_ORDERED) != 0,
+            id: None,
+        };
+
+        Ok(Field {
+            name: schema.name.clone(),
+            data_type,
+            nullable: (schema.flags & CSchema::FLAG_NULLABLE) != 0,
+            metadata: schema.metadata.clone(),
+            dictionary,
+        })
+    }
+}
+
+/// Conversion to descriptions of C Data Interface schemas
+impl TryFrom<&Field> for CSchema {
+    type Error = MarrowError;
+
+    fn try_from(field: &Field) -> Result<Self> {
+        let mut schema = c_schema_from_data_type(&field.data_type)?;
+        schema.name = field.name.clone();
+        schema.metadata = field.metadata.clone();
+        if field.nullable {
+            schema.flags |= CSchema::FLAG_NULLABLE;
+        }
+        if matches!(field.data_type, DataType::Dictionary(_, _)) && field.dictionary.ordered {
+            schema.flags |= CSchema::FLAG_DICTIONARY_ORDERED;
+        }
+        Ok(schema)
+    }
+}
+
+fn c_schema_from_data_type(data_type: &DataType) -> Result<CSchema> {
+    use DataType as T;
+
+    let mut children = Vec::new();
+    let mut dictionary = None;
+    let mut flags = 0;
+
+    match data_type {
+        T::List(field) | T::LargeList(field) | T::FixedSizeList(field, _) => {
+            children.push(CSchema::try_from(field.as_ref())?);
+        }
+        T::Map(field, sorted) => {
+            children.push(CSchema::try_from(field.as_ref())?);
+            if *sorted {
+                flags |= CSchema::FLAG_MAP_KEYS_SORTED;
+            }
+        }
+        T::Struct(fields) => {
+            for field in fields {
+                children.push(CSchema::try_from(field)?);
+            }
+        }
+        T::Union(fields, _) => {
+            for (_, field) in fields {
+                children.push(CSchema::try_from(field)?);
+            }
+        }
+        T::RunEndEncoded(run_ends, values) => {
+            children.push(CSchema::try_from(run_ends.as_ref())?);
+            children.push(CSchema::try_from(values.as_ref())?);
+        }
+        T::Dictionary(_, values) => {
+            dictionary = Some(Box::new(c_schema_from_data_type(values)?));
+        }
+        _ => {}
+    }
+
+    Ok(CSchema {
+        format: data_type.to_c_format()?,
+        name: String::new(),
+        metadata: HashMap::new(),
+        flags,
+        children,
+        dictionary,
+    })
+}
+
+#[test]
+fn c_format_roundtrip() {
+    for (repr, format) in [
+        ("Null", "n"),
+        ("Boolean", "b"),
+        ("Int8", "c"),
+        ("UInt8", "C"),
+        ("Int16", "s"),
+        ("UInt16", "S"),
+        ("Int32", "i"),
+        ("UInt32", "I"),
+        ("Int64", "l"),
+        ("UInt64", "L"),
+        ("Float16", "e"),
+        ("Float32", "f"),
+        ("Float64", "g"),
+        ("Binary", "z"),
+        ("LargeBinary", "Z"),
+        ("BinaryView", "vz"),
+        ("Utf8", "u"),
+        ("LargeUtf8", "U"),
+        ("Utf8View", "vu"),
+        ("FixedSizeBinary(4)", "w:4"),
+        ("Decimal128(38, 10)", "d:38,10"),
+        ("Decimal128(5, -2)", "d:5,-2"),
+        ("Date32", "tdD"),
+        ("Date64", "tdm"),
+        ("Time32(s)", "tts"),
+        ("Time32(ms)", "ttm"),
+        ("Time64(us)", "ttu"),
+        ("Time64(ns)", "ttn"),
+        ("Timestamp(s)", "tss:"),
+        ("Timestamp(us, \"UTC\")", "tsu:UTC"),
+        ("Timestamp(ns, \"+01:00\")", "tsn:+01:00"),
+        ("Duration(ms)", "tDm"),
+        ("Interval(YearMonth)", "tiM"),
+        ("Interval(DayTime)", "tiD"),
+        ("Interval(MonthDayNano)", "tin"),
+        ("List<nullable Int32>", "+l"),
+        ("LargeList<element: Utf8>", "+L"),
+        ("FixedSizeList(4)<Float32>", "+w:4"),
+        ("Struct<a: Int32, b: nullable Utf8>", "+s"),
+        ("Map<Struct<keys: Utf8, values: nullable Int32>>", "+m"),
+        (
+            "Map(sorted)<Struct<keys: Utf8, values: nullable Int32>>",
+            "+m",
+        ),
+        ("Dictionary<Int16, Utf8>", "s"),
+        ("List<Dictionary<UInt8, LargeUtf8>>", "+l"),
+        ("RunEndEncoded<Int32, nullable Utf8>", "+r"),
+        ("Union(Dense)<0: a: Int32, 1: b: Utf8>", "+ud:0,1"),
+        ("Union(Sparse)<3: a: Int32, 7: b: Utf8>", "+us:3,7"),
+        ("Union(Sparse)<>", "+us:"),
+    ] {
+        let field = Field {
+            name: String::from("field"),
+            data_type: repr.parse().unwrap(),
+            nullable: true,
+            metadata: HashMap::from([(String::from("key"), String::from("value"))]),
+            ..Field::default()
+        };
+        let schema = CSchema::try_from(&field).unwrap();
+        assert_eq!(schema.format, format);
+        assert_eq!(Field::try_from(&schema).unwrap(), field);
+    }
+}
+
+#[test]
+fn c_format_flags() {
+    let data_type: DataType = "Map(sorted)<Struct<keys: Utf8, values: nullable Int32>>"
+        .parse()
+        .unwrap();
+    let field = Field {
+        name: String::from("map"),
+        data_type,
+        ..Field::default()
+    };
+    let schema = CSchema::try_from(&field).unwrap();
+    assert_eq!(schema.flags, CSchema::FLAG_MAP_KEYS_SORTED);
+    assert_eq!(schema.children[0].flags, 0);
+    assert_eq!(schema.children[0].children[1].flags, CSchema::FLAG_NULLABLE);
+
+    let field = Field {
+        name: String::from("dict"),
+        data_type: "Dictionary<Int8, Utf8>".parse().unwrap(),
+        dictionary: DictionaryMeta {
+            ordered: true,
+            id: None,
+        },
+        ..Field::default()
+    };
+    let schema = CSchema::try_from(&field).unwrap();
+    assert_eq!(schema.flags, CSchema::FLAG_DICTIONARY_ORDERED);
+    assert_eq!(Field::try_from(&schema).unwrap(), field);
+}
+
+#[test]
+fn c_format_errors() {
+    for (format, kind) in [
+        ("x", ErrorKind::ParseError),
+        ("w:abc", ErrorKind::ParseError),
+        ("d:38", ErrorKind::ParseError),
+        ("d:38,10,256", ErrorKind::Unsupported),
+        ("tsx:UTC", ErrorKind::ParseError),
+        ("tsu", ErrorKind::ParseError),
+        ("+l", ErrorKind::ParseError),
+        ("+ud:0,1", ErrorKind::ParseError),
+        ("+vl", ErrorKind::Unsupported),
+    ] {
+        let err = DataType::from_c_format(format, vec![]).unwrap_err();
+        assert_eq!(err.kind(), kind, "unexpected error for {format:?}: {err}");
+    }
+
+    let err = DataType::from_c_format("i", vec![Field::default()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseError);
+
+    let err = DataType::Time32(TimeUnit::Nanosecond)
+        .to_c_format()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    let err = DataType::Dictionary(Box::new(DataType::Utf8), Box::new(DataType::Utf8))
+        .to_c_format()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
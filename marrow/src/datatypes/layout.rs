@@ -0,0 +1,276 @@
+//! The physical layout of data types
+use super::{DataType, Field, IntervalUnit, UnionMode};
+
+/// The buffers and children of arrays with a given data type, see [`DataType::layout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// The buffers in the order of the C data interface
+    ///
+    /// Types that support a validity bitmap start with [`BufferKind::Validity`]. The views of
+    /// `Utf8View` and `BinaryView` are followed by [`BufferKind::VariadicData`] that stands for any
+    /// number of data buffers.
+    pub buffers: Vec<BufferKind>,
+    /// The child fields, e.g., the item field of lists or the run ends and values of run end
+    /// encoded arrays
+    pub children: Vec<Field>,
+    /// The data type of the values of dictionary arrays
+    pub dictionary: Option<DataType>,
+}
+
+impl Layout {
+    /// Whether arrays of this layout store their validity in a bitmap
+    ///
+    /// `Null`, `Union` and `RunEndEncoded` arrays do not have a validity bitmap.
+    pub fn has_validity(&self) -> bool {
+        self.buffers.first() == Some(&BufferKind::Validity)
+    }
+}
+
+/// The kind of a buffer in a [`Layout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BufferKind {
+    /// A bitmap with one bit per element that is set for valid elements
+    Validity,
+    /// A bitmap with one bit per element, the values of `Boolean` arrays
+    Bitmap,
+    /// Offsets of the given byte width (4 or 8) into a data buffer or a child array
+    Offsets(usize),
+    /// Values with the given fixed byte width
+    FixedWidth(usize),
+    /// The bytes of variable length values addressed by offsets
+    Data,
+    /// The 16 byte views of `Utf8View` and `BinaryView` arrays
+    Views,
+    /// Any number of data buffers referenced by views
+    VariadicData,
+    /// The `i8` type ids of union arrays
+    TypeIds,
+}
+
+impl DataType {
+    /// The buffers and children of arrays with this data type
+    ///
+    /// The layout of invalid data types (see [`DataType::validate`]) is unspecified.
+    ///
+    /// ```rust
+    /// # use marrow::datatypes::{BufferKind, DataType};
+    /// let layout = "LargeUtf8".parse::<DataType>()?.layout();
+    /// assert_eq!(
+    ///     layout.buffers,
+    ///     vec![BufferKind::Validity, BufferKind::Offsets(8), BufferKind::Data],
+    /// );
+    /// assert!(layout.has_validity());
+    ///
+    /// let layout = "RunEndEncoded<Int32, Utf8>".parse::<DataType>()?.layout();
+    /// assert_eq!(layout.buffers, vec![]);
+    /// assert_eq!(layout.children[0].name, "run_ends");
+    /// # Ok::<(), marrow::error::MarrowError>(())
+    /// ```
+    pub fn layout(&self) -> Layout {
+        use {BufferKind as B, DataType as T};
+
+        let mut children = Vec::new();
+        let mut dictionary = None;
+        let buffers = match self {
+            T::Null => vec![],
+            T::Boolean => vec![B::Validity, B::Bitmap],
+            T::Utf8 | T::Binary => vec![B::Validity, B::Offsets(4), B::Data],
+            T::LargeUtf8 | T::LargeBinary => vec![B::Validity, B::Offsets(8), B::Data],
+            T::Utf8View | T::BinaryView => vec![B::Validity, B::Views, B::VariadicData],
+            T::Struct(fields) => {
+                children.extend(fields.iter().cloned());
+                vec![B::Validity]
+            }
+            T::List(item) | T::Map(item, _) => {
+                children.push(item.as_ref().clone());
+                vec![B::Validity, B::Offsets(4)]
+            }
+            T::LargeList(item) => {
+                children.push(item.as_ref().clone());
+                vec![B::Validity, B::Offsets(8)]
+            }
+            T::FixedSizeList(item, _) => {
+                children.push(item.as_ref().clone());
+                vec![B::Validity]
+            }
+            T::Dictionary(keys, values) => {
+                dictionary = Some(values.as_ref().clone());
+                vec![
+                    B::Validity,
+                    B::FixedWidth(keys.byte_width().unwrap_or_default()),
+                ]
+            }
+            T::RunEndEncoded(run_ends, values) => {
+                children.push(run_ends.as_ref().clone());
+                children.push(values.as_ref().clone());
+                vec![]
+            }
+            T::Union(fields, mode) => {
+                children.extend(fields.iter().map(|(_, field)| field.clone()));
+                match mode {
+                    UnionMode::Sparse => vec![B::TypeIds],
+                    UnionMode::Dense => vec![B::TypeIds, B::Offsets(4)],
+                }
+            }
+            data_type => vec![
+                B::Validity,
+                B::FixedWidth(data_type.byte_width().unwrap_or_default()),
+            ],
+        };
+        Layout {
+            buffers,
+            children,
+            dictionary,
+        }
+    }
+
+    /// The number of bytes per element for types stored in a single fixed width buffer
+    ///
+    /// Returns `None` for all other types, including `Boolean` that stores its values as bits.
+    ///
+    /// ```rust
+    /// # use marrow::datatypes::{DataType, IntervalUnit};
+    /// assert_eq!(DataType::Interval(IntervalUnit::MonthDayNano).byte_width(), Some(16));
+    /// assert_eq!(DataType::FixedSizeBinary(3).byte_width(), Some(3));
+    /// assert_eq!(DataType::Boolean.byte_width(), None);
+    /// assert_eq!(DataType::Utf8.byte_width(), None);
+    /// ```
+    pub fn byte_width(&self) -> Option<usize> {
+        use DataType as T;
+        match self {
+            T::Int8 | T::UInt8 => Some(1),
+            T::Int16 | T::UInt16 | T::Float16 => Some(2),
+            T::Int32 | T::UInt32 | T::Float32 | T::Date32 | T::Time32(_) => Some(4),
+            T::Int64 | T::UInt64 | T::Float64 | T::Date64 | T::Time64(_) => Some(8),
+            T::Timestamp(_, _) | T::Duration(_) => Some(8),
+            T::Interval(IntervalUnit::YearMonth) => Some(4),
+            T::Interval(IntervalUnit::DayTime) => Some(8),
+            T::Interval(IntervalUnit::MonthDayNano) | T::Decimal128(_, _) => Some(16),
+            T::FixedSizeBinary(n) => usize::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    /// Whether the type is a signed or unsigned integer
+    pub fn is_integer(&self) -> bool {
+        use DataType as T;
+        matches!(
+            self,
+            T::Int8 | T::Int16 | T::Int32 | T::Int64 | T::UInt8 | T::UInt16 | T::UInt32 | T::UInt64
+        )
+    }
+
+    /// Whether the type is a floating point number
+    pub fn is_floating(&self) -> bool {
+        matches!(self, Self::Float16 | Self::Float32 | Self::Float64)
+    }
+
+    /// Whether the type stores fixed width numeric or temporal values in a single buffer
+    ///
+    /// Primitive types are integers, floats, dates, times, timestamps, durations, intervals and
+    /// decimals. `Boolean` and `FixedSizeBinary` are not primitive.
+    pub fn is_primitive(&self) -> bool {
+        !matches!(self, Self::FixedSizeBinary(_)) && self.byte_width().is_some()
+    }
+
+    /// Whether the type is a date, time, timestamp, duration or interval
+    pub fn is_temporal(&self) -> bool {
+        use DataType as T;
+        matches!(
+            self,
+            T::Date32
+                | T::Date64
+                | T::Time32(_)
+                | T::Time64(_)
+                | T::Timestamp(_, _)
+                | T::Duration(_)
+                | T::Interval(_)
+        )
+    }
+
+    /// Whether the type has child fields
+    ///
+    /// Run end encoded types are always nested, as the run ends and the values are child fields.
+    /// Dictionaries are nested, if their values are nested.
+    pub fn is_nested(&self) -> bool {
+        use DataType as T;
+        match self {
+            T::Struct(_)
+            | T::List(_)
+            | T::LargeList(_)
+            | T::FixedSizeList(_, _)
+            | T::Map(_, _)
+            | T::Union(_, _)
+            | T::RunEndEncoded(_, _) => true,
+            T::Dictionary(_, values) => values.is_nested(),
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn layouts() {
+    use BufferKind as B;
+
+    let layout = |data_type: &str| data_type.parse::<DataType>().unwrap().layout();
+
+    assert_eq!(layout("Null").buffers, vec![]);
+    assert_eq!(layout("Boolean").buffers, vec![B::Validity, B::Bitmap]);
+    assert_eq!(
+        layout("Decimal128(10, 2)").buffers,
+        vec![B::Validity, B::FixedWidth(16)]
+    );
+    assert_eq!(
+        layout("FixedSizeBinary(7)").buffers,
+        vec![B::Validity, B::FixedWidth(7)]
+    );
+    assert_eq!(
+        layout("BinaryView").buffers,
+        vec![B::Validity, B::Views, B::VariadicData]
+    );
+
+    let list = layout("FixedSizeList(2)<nullable Int32>");
+    assert_eq!(list.buffers, vec![B::Validity]);
+    assert_eq!(list.children.len(), 1);
+    assert_eq!(list.children[0].name, "item");
+
+    let map = layout("Map<Struct<keys: Utf8, values: Int32>>");
+    assert_eq!(map.buffers, vec![B::Validity, B::Offsets(4)]);
+    assert_eq!(map.children[0].name, "entries");
+
+    let dictionary = layout("Dictionary<UInt16, Utf8>");
+    assert_eq!(dictionary.buffers, vec![B::Validity, B::FixedWidth(2)]);
+    assert_eq!(dictionary.children, vec![]);
+    assert_eq!(dictionary.dictionary, Some(DataType::Utf8));
+
+    let union = layout("Union(Dense)<0: a: Int32, 1: b: Utf8>");
+    assert_eq!(union.buffers, vec![B::TypeIds, B::Offsets(4)]);
+    assert!(!union.has_validity());
+    assert_eq!(union.children.len(), 2);
+    assert_eq!(
+        layout("Union(Sparse)<0: a: Int32>").buffers,
+        vec![B::TypeIds]
+    );
+}
+
+#[test]
+fn predicates() {
+    let data_type = |data_type: &str| data_type.parse::<DataType>().unwrap();
+
+    assert!(data_type("UInt64").is_integer());
+    assert!(!data_type("Float16").is_integer());
+    assert!(data_type("Float16").is_floating());
+    assert!(data_type("Interval(DayTime)").is_primitive());
+    assert!(data_type("Interval(DayTime)").is_temporal());
+    assert!(!data_type("Boolean").is_primitive());
+    assert!(!data_type("FixedSizeBinary(4)").is_primitive());
+    assert!(!data_type("Int64").is_temporal());
+    assert!(data_type("List<Int64>").is_nested());
+    assert!(!data_type("Dictionary<Int8, Utf8>").is_nested());
+    assert!(data_type("Dictionary<Int8, List<Int64>>").is_nested());
+    assert!(data_type("RunEndEncoded<Int32, Struct<a: Int8>>").is_nested());
+    assert!(data_type("RunEndEncoded<Int32, Utf8>").is_nested());
+    assert_eq!(data_type("Timestamp(ns)").byte_width(), Some(8));
+    assert_eq!(data_type("Struct<a: Int8>").byte_width(), None);
+}
@@ -0,0 +1,174 @@
+//! The serde representation of fields, following the format of `arrow`
+//!
+//! `arrow` stores the dictionary metadata as the flat `dict_id` and `dict_is_ordered` fields, with
+//! an id of `0` for fields without an explicit id. They are written after the fields of earlier
+//! versions, so that the encodings of non self-describing formats (e.g., `bincode`) only gain
+//! trailing values.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{DataType, DictionaryMeta, Field, FieldMeta};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FieldRepr {
+    name: String,
+    data_type: DataType,
+    nullable: bool,
+    metadata: HashMap<String, String>,
+    #[serde(default)]
+    dict_id: i64,
+    #[serde(default)]
+    dict_is_ordered: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FieldMetaRepr {
+    name: String,
+    nullable: bool,
+    metadata: HashMap<String, String>,
+    #[serde(default)]
+    dict_id: i64,
+    #[serde(default)]
+    dict_is_ordered: bool,
+}
+
+fn dictionary_from_repr(dict_id: i64, dict_is_ordered: bool) -> DictionaryMeta {
+    DictionaryMeta {
+        ordered: dict_is_ordered,
+        id: (dict_id != 0).then_some(dict_id),
+    }
+}
+
+impl From<Field> for FieldRepr {
+    fn from(value: Field) -> Self {
+        Self {
+            name: value.name,
+            data_type: value.data_type,
+            nullable: value.nullable,
+            metadata: value.metadata,
+            dict_id: value.dictionary.id.unwrap_or_default(),
+            dict_is_ordered: value.dictionary.ordered,
+        }
+    }
+}
+
+impl From<FieldRepr> for Field {
+    fn from(value: FieldRepr) -> Self {
+        Self {
+            name: value.name,
+            data_type: value.data_type,
+            nullable: value.nullable,
+            metadata: value.metadata,
+            dictionary: dictionary_from_repr(value.dict_id, value.dict_is_ordered),
+        }
+    }
+}
+
+impl From<FieldMeta> for FieldMetaRepr {
+    fn from(value: FieldMeta) -> Self {
+        Self {
+            name: value.name,
+            nullable: value.nullable,
+            metadata: value.metadata,
+            dict_id: value.dictionary.id.unwrap_or_default(),
+            dict_is_ordered: value.dictionary.ordered,
+        }
+    }
+}
+
+impl From<FieldMetaRepr> for FieldMeta {
+    fn from(value: FieldMetaRepr) -> Self {
+        Self {
+            name: value.name,
+            nullable: value.nullable,
+            metadata: value.metadata,
+            dictionary: dictionary_from_repr(value.dict_id, value.dict_is_ordered),
+        }
+    }
+}
+
+#[test]
+fn fields_round_trip() {
+    let field = Field {
+        name: String::from("category"),
+        data_type: DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+        nullable: true,
+        metadata: HashMap::from([(String::from("key"), String::from("value"))]),
+        dictionary: DictionaryMeta {
+            ordered: true,
+            id: Some(3),
+        },
+    };
+
+    let json = serde_json::to_value(&field).unwrap();
+    assert_eq!(json["dict_id"], 3);
+    assert_eq!(json["dict_is_ordered"], true);
+    assert!(json.get("dictionary").is_none());
+    assert_eq!(serde_json::from_value::<Field>(json).unwrap(), field);
+
+    let bytes = bincode::serialize(&field).unwrap();
+    assert_eq!(bincode::deserialize::<Field>(&bytes).unwrap(), field);
+
+    let meta = super::meta_from_field(field);
+    let json = serde_json::to_value(&meta).unwrap();
+    assert_eq!(json["dict_id"], 3);
+    assert_eq!(serde_json::from_value::<FieldMeta>(json).unwrap(), meta);
+
+    let bytes = bincode::serialize(&meta).unwrap();
+    assert_eq!(bincode::deserialize::<FieldMeta>(&bytes).unwrap(), meta);
+}
+
+#[test]
+fn missing_dictionary_metadata_uses_the_defaults() {
+    let field: Field = serde_json::from_str(
+        r#"{"name": "a", "data_type": "Int8", "nullable": false, "metadata": {}}"#,
+    )
+    .unwrap();
+    assert_eq!(field.dictionary, DictionaryMeta::default());
+
+    let field: Field = serde_json::from_str(concat!(
+        r#"{"name": "a", "data_type": "Int8", "nullable": false, "#,
+        r#""dict_id": 0, "dict_is_ordered": false, "metadata": {}}"#,
+    ))
+    .unwrap();
+    assert_eq!(field.dictionary.id, None);
+}
+
+#[test]
+fn fields_extend_the_previous_binary_layout() {
+    /// The serde representation of `Field` before the dictionary metadata was added
+    #[derive(Serialize)]
+    struct PreviousField {
+        name: String,
+        data_type: DataType,
+        nullable: bool,
+        metadata: HashMap<String, String>,
+    }
+
+    let metadata = HashMap::from([(String::from("key"), String::from("value"))]);
+    let previous = bincode::serialize(&PreviousField {
+        name: String::from("a"),
+        data_type: DataType::Int8,
+        nullable: true,
+        metadata: metadata.clone(),
+    })
+    .unwrap();
+    let field = Field {
+        name: String::from("a"),
+        data_type: DataType::Int8,
+        nullable: true,
+        metadata,
+        dictionary: DictionaryMeta::default(),
+    };
+
+    let bytes = bincode::serialize(&field).unwrap();
+    assert_eq!(bytes[..previous.len()], previous[..]);
+
+    let mut extended = previous.clone();
+    extended.extend(bincode::serialize(&(0_i64, false)).unwrap());
+    assert_eq!(bincode::deserialize::<Field>(&extended).unwrap(), field);
+
+    // non self-describing formats require the trailing values
+    assert!(bincode::deserialize::<Field>(&previous).is_err());
+}
@@ -0,0 +1,733 @@
+//! The textual representation of data types and fields
+//!
+//! See [`DataType`] for a description of the grammar.
+use std::collections::HashMap;
+
+use crate::error::{ErrorKind, MarrowError, Result};
+
+use super::{DataType, DictionaryMeta, Field, IntervalUnit, TimeUnit, UnionMode};
+
+/// The default name of list elements (following `arrow`)
+const DEFAULT_LIST_ELEMENT_NAME: &str = "item";
+/// The default name of map entries
+const DEFAULT_MAP_ENTRIES_NAME: &str = "entries";
+/// The default name of the run ends of run end encoded types
+const DEFAULT_RUN_ENDS_NAME: &str = "run_ends";
+/// The default name of the values of run end encoded types
+const DEFAULT_VALUES_NAME: &str = "values";
+
+impl std::fmt::Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use DataType as T;
+        match self {
+            T::Null => write!(f, "Null"),
+            T::Boolean => write!(f, "Boolean"),
+            T::Int8 => write!(f, "Int8"),
+            T::Int16 => write!(f, "Int16"),
+            T::Int32 => write!(f, "Int32"),
+            T::Int64 => write!(f, "Int64"),
+            T::UInt8 => write!(f, "UInt8"),
+            T::UInt16 => write!(f, "UInt16"),
+            T::UInt32 => write!(f, "UInt32"),
+            T::UInt64 => write!(f, "UInt64"),
+            T::Float16 => write!(f, "Float16"),
+            T::Float32 => write!(f, "Float32"),
+            T::Float64 => write!(f, "Float64"),
+            T::Utf8 => write!(f, "Utf8"),
+            T::LargeUtf8 => write!(f, "LargeUtf8"),
+            T::Utf8View => write!(f, "Utf8View"),
+            T::Binary => write!(f, "Binary"),
+            T::LargeBinary => write!(f, "LargeBinary"),
+            T::BinaryView => write!(f, "BinaryView"),
+            T::FixedSizeBinary(n) => write!(f, "FixedSizeBinary({n})"),
+            T::Date32 => write!(f, "Date32"),
+            T::Date64 => write!(f, "Date64"),
+            T::Timestamp(unit, None) => write!(f, "Timestamp({})", short_unit(*unit)),
+            T::Timestamp(unit, Some(tz)) => write!(f, "Timestamp({}, {tz:?})", short_unit(*unit)),
+            T::Time32(unit) => write!(f, "Time32({})", short_unit(*unit)),
+            T::Time64(unit) => write!(f, "Time64({})", short_unit(*unit)),
+            T::Duration(unit) => write!(f, "Duration({})", short_unit(*unit)),
+            T::Interval(unit) => write!(f, "Interval({unit})"),
+            T::Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale})"),
+            T::Struct(fields) => {
+                write!(f, "Struct<")?;
+                for (idx, field) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write_field(f, field, None)?;
+                }
+                write!(f, ">")
+            }
+            T::List(field) => {
+                write!(f, "List<")?;
+                write_field(f, field, Some(DEFAULT_LIST_ELEMENT_NAME))?;
+                write!(f, ">")
+            }
+            T::LargeList(field) => {
+                write!(f, "LargeList<")?;
+                write_field(f, field, Some(DEFAULT_LIST_ELEMENT_NAME))?;
+                write!(f, ">")
+            }
+            T::FixedSizeList(field, n) => {
+                write!(f, "FixedSizeList({n})<")?;
+                write_field(f, field, Some(DEFAULT_LIST_ELEMENT_NAME))?;
+                write!(f, ">")
+            }
+            T::Map(field, sorted) => {
+                write!(f, "{}<", if *sorted { "Map(sorted)" } else { "Map" })?;
+                write_field(f, field, Some(DEFAULT_MAP_ENTRIES_NAME))?;
+                write!(f, ">")
+            }
+            T::Dictionary(keys, values) => write!(f, "Dictionary<{keys}, {values}>"),
+            T::RunEndEncoded(run_ends, values) => {
+                write!(f, "RunEndEncoded<")?;
+                write_field(f, run_ends, Some(DEFAULT_RUN_ENDS_NAME))?;
+                write!(f, ", ")?;
+                write_field(f, values, Some(DEFAULT_VALUES_NAME))?;
+                write!(f, ">")
+            }
+            T::Union(fields, mode) => {
+                write!(f, "Union({mode})<")?;
+                for (idx, (type_id, field)) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{type_id}: ")?;
+                    write_field(f, field, None)?;
+                }
+                write!(f, ">")
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for DataType {
+    type Err = MarrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = Parser::new(s);
+        let data_type = parser.parse_data_type()?;
+        parser.expect_end()?;
+        Ok(data_type)
+    }
+}
+
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_field(f, self, None)
+    }
+}
+
+impl std::str::FromStr for Field {
+    type Err = MarrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = Parser::new(s);
+        let field = parser.parse_field(None)?;
+        parser.expect_end()?;
+        Ok(field)
+    }
+}
+
+fn short_unit(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    }
+}
+
+/// Write a field, omitting the name if it matches the given default
+fn write_field(
+    f: &mut std::fmt::Formatter<'_>,
+    field: &Field,
+    default_name: Option<&str>,
+) -> std::fmt::Result {
+    if default_name != Some(field.name.as_str()) {
+        write_name(f, &field.name)?;
+        write!(f, ": ")?;
+    }
+    if field.nullable {
+        write!(f, "nullable ")?;
+    }
+    if matches!(field.data_type, DataType::Dictionary(_, _)) {
+        if field.dictionary.ordered {
+            write!(f, "ordered ")?;
+        }
+        if let Some(id) = field.dictionary.id {
+            write!(f, "dict_id({id}) ")?;
+        }
+    }
+    write!(f, "{}", field.data_type)?;
+
+    if !field.metadata.is_empty() {
+        let mut metadata = field.metadata.iter().collect::<Vec<_>>();
+        metadata.sort();
+
+        write!(f, " {{")?;
+        for (idx, (key, value)) in metadata.into_iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")?;
+    }
+    Ok(())
+}
+
+fn write_name(f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+    if is_identifier(name) {
+        write!(f, "{name}")
+    } else {
+        write!(f, "{name:?}")
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn error<T>(&self, pos: usize, msg: impl std::fmt::Display) -> Result<T> {
+        Err(MarrowError::new(
+            ErrorKind::ParseError,
+            format!("{msg} at position {pos} in {src:?}", src = self.src),
+        ))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    fn try_char(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_char(&mut self, c: char) -> Result<()> {
+        if self.try_char(c) {
+            Ok(())
+        } else {
+            self.error(self.pos, format!("Expected {c:?}"))
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<()> {
+        if self.peek().is_some() {
+            self.error(self.pos, "Unexpected trailing input")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let mut end = 0;
+        for (idx, c) in rest.char_indices() {
+            let valid = if idx == 0 {
+                c.is_ascii_alphabetic() || c == '_'
+            } else {
+                c.is_ascii_alphanumeric() || c == '_'
+            };
+            if !valid {
+                break;
+            }
+            end = idx + c.len_utf8();
+        }
+        if end == 0 {
+            return None;
+        }
+        self.pos += end;
+        Some(&rest[..end])
+    }
+
+    fn expect_identifier(&mut self, what: &str) -> Result<&'a str> {
+        let start = self.pos;
+        match self.parse_identifier() {
+            Some(ident) => Ok(ident),
+            None => self.error(start, format!("Expected {what}")),
+        }
+    }
+
+    fn parse_integer<T: std::str::FromStr>(&mut self) -> Result<T> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = self.rest();
+        let mut end = 0;
+        for (idx, c) in rest.char_indices() {
+            if !(c.is_ascii_digit() || (idx == 0 && (c == '-' || c == '+'))) {
+                break;
+            }
+            end = idx + 1;
+        }
+        let Ok(value) = rest[..end].parse::<T>() else {
+            return self.error(start, "Expected an integer");
+        };
+        self.pos += end;
+        Ok(value)
+    }
+
+    /// Parse a string literal using the escapes of Rust's debug representation
+    fn parse_string(&mut self) -> Result<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if !self.try_char('"') {
+            return self.error(start, "Expected a string");
+        }
+
+        let mut res = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += idx + 1;
+                    return Ok(res);
+                }
+                '\\' => {
+                    let escape = match chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\'')) => '\'',
+                        Some((_, '\\')) => '\\',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((_, '0')) => '\0',
+                        Some((_, 'u')) => {
+                            let rest = chars.as_str();
+                            let Some(digits) = rest
+                                .strip_prefix('{')
+                                .and_then(|rest| rest.split_once('}'))
+                                .map(|(digits, _)| digits)
+                            else {
+                                return self.error(self.pos + idx, "Invalid unicode escape");
+                            };
+                            let Some(c) = u32::from_str_radix(digits, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                            else {
+                                return self.error(self.pos + idx, "Invalid unicode escape");
+                            };
+                            // skip the braces and the digits
+                            for _ in 0..digits.len() + 2 {
+                                chars.next();
+                            }
+                            c
+                        }
+                        _ => return self.error(self.pos + idx, "Invalid escape sequence"),
+                    };
+                    res.push(escape);
+                }
+                c => res.push(c),
+            }
+        }
+        self.error(start, "Unterminated string")
+    }
+
+    fn parse_time_unit(&mut self) -> Result<TimeUnit> {
+        let start = self.pos;
+        match self.expect_identifier("a time unit")? {
+            "s" | "Second" => Ok(TimeUnit::Second),
+            "ms" | "Millisecond" => Ok(TimeUnit::Millisecond),
+            "us" | "Microsecond" => Ok(TimeUnit::Microsecond),
+            "ns" | "Nanosecond" => Ok(TimeUnit::Nanosecond),
+            unit => self.error(start, format!("Invalid time unit {unit:?}")),
+        }
+    }
+
+    fn parse_data_type(&mut self) -> Result<DataType> {
+        use DataType as T;
+
+        self.skip_whitespace();
+        let start = self.pos;
+        let name = self.expect_identifier("a data type")?;
+        match name {
+            "Null" => Ok(T::Null),
+            "Boolean" => Ok(T::Boolean),
+            "Int8" => Ok(T::Int8),
+            "Int16" => Ok(T::Int16),
+            "Int32" => Ok(T::Int32),
+            "Int64" => Ok(T::Int64),
+            "UInt8" => Ok(T::UInt8),
+            "UInt16" => Ok(T::UInt16),
+            "UInt32" => Ok(T::UInt32),
+            "UInt64" => Ok(T::UInt64),
+            "Float16" => Ok(T::Float16),
+            "Float32" => Ok(T::Float32),
+            "Float64" => Ok(T::Float64),
+            "Utf8" => Ok(T::Utf8),
+            "LargeUtf8" => Ok(T::LargeUtf8),
+            "Utf8View" => Ok(T::Utf8View),
+            "Binary" => Ok(T::Binary),
+            "LargeBinary" => Ok(T::LargeBinary),
+            "BinaryView" => Ok(T::BinaryView),
+            "Date32" => Ok(T::Date32),
+            "Date64" => Ok(T::Date64),
+            "FixedSizeBinary" => {
+                self.expect_char('(')?;
+                let n = self.parse_integer()?;
+                self.expect_char(')')?;
+                Ok(T::FixedSizeBinary(n))
+            }
+            "Timestamp" => {
+                self.expect_char('(')?;
+                let unit = self.parse_time_unit()?;
+                let timezone = if self.try_char(',') {
+                    Some(self.parse_string()?)
+                } else {
+                    None
+                };
+                self.expect_char(')')?;
+                Ok(T::Timestamp(unit, timezone))
+            }
+            "Time32" | "Time64" | "Duration" => {
+                self.expect_char('(')?;
+                let unit = self.parse_time_unit()?;
+                self.expect_char(')')?;
+                match name {
+                    "Time32" => Ok(T::Time32(unit)),
+                    "Time64" => Ok(T::Time64(unit)),
+                    _ => Ok(T::Duration(unit)),
+                }
+            }
+            "Interval" => {
+                self.expect_char('(')?;
+                let unit_start = self.pos;
+                let unit = self.expect_identifier("an interval unit")?;
+                let Ok(unit) = unit.parse::<IntervalUnit>() else {
+                    return self.error(unit_start, format!("Invalid interval unit {unit:?}"));
+                };
+                self.expect_char(')')?;
+                Ok(T::Interval(unit))
+            }
+            "Decimal128" => {
+                self.expect_char('(')?;
+                let precision = self.parse_integer()?;
+                self.expect_char(',')?;
+                let scale = self.parse_integer()?;
+                self.expect_char(')')?;
+                Ok(T::Decimal128(precision, scale))
+            }
+            "Struct" => {
+                self.expect_char('<')?;
+                let mut fields = Vec::new();
+                if !self.try_char('>') {
+                    loop {
+                        fields.push(self.parse_field(None)?);
+                        if self.try_char('>') {
+                            break;
+                        }
+                        self.expect_char(',')?;
+                    }
+                }
+                Ok(T::Struct(fields))
+            }
+            "List" | "LargeList" => {
+                self.expect_char('<')?;
+                let field = self.parse_field(Some(DEFAULT_LIST_ELEMENT_NAME))?;
+                self.expect_char('>')?;
+                if name == "List" {
+                    Ok(T::List(Box::new(field)))
+                } else {
+                    Ok(T::LargeList(Box::new(field)))
+                }
+            }
+            "FixedSizeList" => {
+                self.expect_char('(')?;
+                let n = self.parse_integer()?;
+                self.expect_char(')')?;
+                self.expect_char('<')?;
+                let field = self.parse_field(Some(DEFAULT_LIST_ELEMENT_NAME))?;
+                self.expect_char('>')?;
+                Ok(T::FixedSizeList(Box::new(field), n))
+            }
+            "Map" => {
+                let sorted = if self.try_char('(') {
+                    let flag_start = self.pos;
+                    if self.expect_identifier("'sorted'")? != "sorted" {
+                        return self.error(flag_start, "Expected 'sorted'");
+                    }
+                    self.expect_char(')')?;
+                    true
+                } else {
+                    false
+                };
+                self.expect_char('<')?;
+                let field = self.parse_field(Some(DEFAULT_MAP_ENTRIES_NAME))?;
+                self.expect_char('>')?;
+                Ok(T::Map(Box::new(field), sorted))
+            }
+            "Dictionary" => {
+                self.expect_char('<')?;
+                let keys = self.parse_data_type()?;
+                self.expect_char(',')?;
+                let values = self.parse_data_type()?;
+                self.expect_char('>')?;
+                Ok(T::Dictionary(Box::new(keys), Box::new(values)))
+            }
+            "RunEndEncoded" => {
+                self.expect_char('<')?;
+                let run_ends = self.parse_field(Some(DEFAULT_RUN_ENDS_NAME))?;
+                self.expect_char(',')?;
+                let values = self.parse_field(Some(DEFAULT_VALUES_NAME))?;
+                self.expect_char('>')?;
+                Ok(T::RunEndEncoded(Box::new(run_ends), Box::new(values)))
+            }
+            "Union" => {
+                self.expect_char('(')?;
+                let mode_start = self.pos;
+                let mode = self.expect_identifier("a union mode")?;
+                let Ok(mode) = mode.parse::<UnionMode>() else {
+                    return self.error(mode_start, format!("Invalid union mode {mode:?}"));
+                };
+                self.expect_char(')')?;
+                self.expect_char('<')?;
+                let mut fields = Vec::new();
+                if !self.try_char('>') {
+                    loop {
+                        let type_id = self.parse_integer()?;
+                        self.expect_char(':')?;
+                        fields.push((type_id, self.parse_field(None)?));
+                        if self.try_char('>') {
+                            break;
+                        }
+                        self.expect_char(',')?;
+                    }
+                }
+                Ok(T::Union(fields, mode))
+            }
+            name => self.error(start, format!("Unknown data type {name:?}")),
+        }
+    }
+
+    /// Parse a field, the name may only be omitted if a default is given
+    fn parse_field(&mut self, default_name: Option<&str>) -> Result<Field> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        let name = if self.peek() == Some('"') {
+            let name = self.parse_string()?;
+            self.expect_char(':')?;
+            name
+        } else {
+            let name = self.expect_identifier("a field")?;
+            if self.try_char(':') {
+                name.to_owned()
+            } else if let Some(default_name) = default_name {
+                // the identifier was not a name, but the start of the type
+                self.pos = start;
+                default_name.to_owned()
+            } else {
+                return self.error(self.pos, "Expected ':'");
+            }
+        };
+
+        let mut type_start = self.pos;
+        let nullable = match self.parse_identifier() {
+            Some("nullable") => true,
+            _ => {
+                self.pos = type_start;
+                false
+            }
+        };
+
+        let mut dictionary = DictionaryMeta::default();
+        self.skip_whitespace();
+        let modifiers_start = self.pos;
+        loop {
+            type_start = self.pos;
+            match self.parse_identifier() {
+                Some("ordered") if !dictionary.ordered => dictionary.ordered = true,
+                Some("dict_id") if dictionary.id.is_none() => {
+                    self.expect_char('(')?;
+                    dictionary.id = Some(self.parse_integer()?);
+                    self.expect_char(')')?;
+                }
+                _ => {
+                    self.pos = type_start;
+                    break;
+                }
+            }
+        }
+
+        let data_type = self.parse_data_type()?;
+        if dictionary != DictionaryMeta::default()
+            && !matches!(data_type, DataType::Dictionary(_, _))
+        {
+            return self.error(
+                modifiers_start,
+                "Dictionary metadata requires a dictionary encoded type",
+            );
+        }
+
+        let mut metadata = HashMap::new();
+        if self.try_char('{') && !self.try_char('}') {
+            loop {
+                let key_start = self.pos;
+                let key = self.parse_string()?;
+                self.expect_char(':')?;
+                let value = self.parse_string()?;
+                if metadata.insert(key, value).is_some() {
+                    return self.error(key_start, "Duplicate metadata key");
+                }
+                if self.try_char('}') {
+                    break;
+                }
+                self.expect_char(',')?;
+            }
+        }
+
+        Ok(Field {
+            name,
+            data_type,
+            nullable,
+            metadata,
+            dictionary,
+        })
+    }
+}
+
+#[test]
+fn data_type_roundtrip() {
+    for repr in [
+        "Null",
+        "Boolean",
+        "Int8",
+        "UInt64",
+        "Float16",
+        "Utf8View",
+        "FixedSizeBinary(16)",
+        "Timestamp(ms)",
+        "Timestamp(ns, \"Europe/Berlin\")",
+        "Time32(s)",
+        "Time64(us)",
+        "Duration(ns)",
+        "Interval(MonthDayNano)",
+        "Decimal128(38, 10)",
+        "Decimal128(5, -2)",
+        "Struct<>",
+        "Struct<a: Int32, b: nullable Timestamp(ms, \"UTC\")>",
+        "List<nullable Struct<a: Int32, b: Timestamp(ms, \"UTC\")>>",
+        "List<element: Int32>",
+        "LargeList<\"\": Utf8>",
+        "FixedSizeList(4)<nullable Float32>",
+        "Map<Struct<keys: Utf8, values: nullable Int64>>",
+        "Map(sorted)<items: Struct<key: Utf8, value: Int64>>",
+        "Dictionary<Int16, Utf8>",
+        "List<nullable ordered dict_id(3) Dictionary<Int8, Utf8>>",
+        "Struct<a: dict_id(-1) Dictionary<UInt16, LargeUtf8>>",
+        "RunEndEncoded<Int32, nullable Utf8>",
+        "Union(Dense)<0: a: Int32, 5: b: nullable Utf8>",
+        "Union(Sparse)<>",
+        "Struct<\"with space\": Int8 {\"key\": \"value\", \"other\": \"\\\"quoted\\\"\"}>",
+    ] {
+        let data_type: DataType = repr.parse().unwrap();
+        assert_eq!(data_type.to_string(), repr);
+    }
+}
+
+#[test]
+fn data_type_parse() {
+    let actual: DataType = " List < nullable Struct<a:Int32,b : Timestamp(Millisecond,\"UTC\")> > "
+        .parse()
+        .unwrap();
+    let expected = DataType::List(Box::new(Field {
+        name: String::from("item"),
+        nullable: true,
+        data_type: DataType::Struct(vec![
+            Field {
+                name: String::from("a"),
+                data_type: DataType::Int32,
+                ..Field::default()
+            },
+            Field {
+                name: String::from("b"),
+                data_type: DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
+                ..Field::default()
+            },
+        ]),
+        ..Field::default()
+    }));
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn field_roundtrip() {
+    let field = Field {
+        name: String::from("nullable"),
+        data_type: DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+        nullable: true,
+        metadata: HashMap::from([
+            (String::from("b\n"), String::from("\u{7f}")),
+            (String::from("a"), String::from("ä")),
+        ]),
+        dictionary: DictionaryMeta {
+            ordered: true,
+            id: Some(42),
+        },
+    };
+    let repr = field.to_string();
+    assert_eq!(
+        repr,
+        "nullable: nullable ordered dict_id(42) Dictionary<Int8, Utf8> {\"a\": \"ä\", \"b\\n\": \"\\u{7f}\"}"
+    );
+    assert_eq!(repr.parse::<Field>().unwrap(), field);
+}
+
+#[test]
+fn parse_errors() {
+    for (repr, pos) in [
+        ("", 0),
+        ("Int33", 0),
+        ("List<Int32", 10),
+        ("Struct<Int32>", 12),
+        ("Timestamp(days)", 10),
+        ("Decimal128(38 10)", 14),
+        ("Int32 Int64", 6),
+        ("Struct<a: \"Int32\">", 10),
+        ("Struct<a: ordered Int32>", 10),
+        ("List<ordered ordered Dictionary<Int8, Utf8>>", 13),
+    ] {
+        let err = repr.parse::<DataType>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+        assert!(
+            err.message().contains(&format!("at position {pos} ")),
+            "unexpected error for {repr:?}: {}",
+            err.message(),
+        );
+    }
+
+    let err = "Int32".parse::<Field>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseError);
+}
@@ -0,0 +1,332 @@
+//! Validation of data types against the rules of the Arrow spec
+use std::collections::HashSet;
+
+use crate::{
+    decimal::check_precision_and_scale,
+    error::{ErrorKind, MarrowError, Result},
+    temporal::canonicalize_timezone,
+};
+
+use super::{DataType, Field, TimeUnit};
+
+impl DataType {
+    /// Check that the data type is valid according to the Arrow spec
+    ///
+    /// The following rules are checked recursively for all children:
+    ///
+    /// - `Time32` uses seconds or milliseconds, `Time64` microseconds or nanoseconds
+    /// - the timezone of `Timestamp` is valid, see [`canonicalize_timezone`]
+    /// - the precision of `Decimal128` is between 1 and 38 and the scale does not exceed it
+    /// - the sizes of `FixedSizeBinary` and `FixedSizeList` are not negative
+    /// - the keys of `Dictionary` are integers
+    /// - the run ends of `RunEndEncoded` are non-nullable `Int16`, `Int32` or `Int64` values
+    /// - the entries of `Map` are a non-nullable struct with two children and non-nullable keys
+    /// - the type ids of `Union` are unique and between 0 and 127
+    ///
+    /// Errors include the dotted path of the offending child, e.g., `items.item.value`. The
+    /// values of dictionaries are called `values`.
+    ///
+    /// ```rust
+    /// # use marrow::datatypes::DataType;
+    /// let data_type: DataType = "Struct<a: List<Time32(ns)>>".parse()?;
+    /// let err = data_type.validate().unwrap_err();
+    /// assert_eq!(
+    ///     err.message(),
+    ///     "Invalid data type at a.item: Time32 does not support the unit Nanosecond",
+    /// );
+    /// # Ok::<(), marrow::error::MarrowError>(())
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        validate_data_type(self, &mut Vec::new())
+    }
+}
+
+impl Field {
+    /// Check that the data type of the field is valid, see [`DataType::validate`]
+    ///
+    /// The paths in error messages start with the name of the field.
+    pub fn validate(&self) -> Result<()> {
+        validate_field(self, &mut Vec::new())
+    }
+}
+
+/// Schema types that can be validated during deserialization, see [`deserialize_validated`]
+///
+/// The trait is implemented for [`DataType`], [`Field`] and `Vec<Field>`. It cannot be implemented
+/// outside of `marrow`.
+#[cfg(feature = "serde")]
+pub trait Validate: private::Sealed {}
+
+#[cfg(feature = "serde")]
+mod private {
+    pub trait Sealed {
+        fn validate_value(&self) -> crate::error::Result<()>;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl private::Sealed for DataType {
+    fn validate_value(&self) -> Result<()> {
+        self.validate()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Validate for DataType {}
+
+#[cfg(feature = "serde")]
+impl private::Sealed for Field {
+    fn validate_value(&self) -> Result<()> {
+        self.validate()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Validate for Field {}
+
+#[cfg(feature = "serde")]
+impl private::Sealed for Vec<Field> {
+    fn validate_value(&self) -> Result<()> {
+        self.iter().try_for_each(Field::validate)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Validate for Vec<Field> {}
+
+/// Deserialize a data type, a field or a list of fields and validate the result
+///
+/// The derived `Deserialize` implementations do not validate data types. This function can be used
+/// with `#[serde(deserialize_with = "...")]` to report invalid types when deserializing, see
+/// [`DataType::validate`] for the rules checked.
+///
+/// ```rust
+/// # use marrow::datatypes::Field;
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "marrow::datatypes::deserialize_validated")]
+///     schema: Vec<Field>,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn deserialize_validated<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de> + Validate,
+{
+    let value = T::deserialize(deserializer)?;
+    match value.validate_value() {
+        Ok(()) => Ok(value),
+        Err(err) => Err(<D::Error as serde::de::Error>::custom(err.message())),
+    }
+}
+
+fn validate_field<'a>(field: &'a Field, path: &mut Vec<&'a str>) -> Result<()> {
+    path.push(&field.name);
+    validate_data_type(&field.data_type, path)?;
+    path.pop();
+    Ok(())
+}
+
+fn validate_data_type<'a>(data_type: &'a DataType, path: &mut Vec<&'a str>) -> Result<()> {
+    use {DataType as T, TimeUnit as U};
+    match data_type {
+        T::Time32(unit @ (U::Microsecond | U::Nanosecond)) => {
+            invalid(path, format!("Time32 does not support the unit {unit:?}"))
+        }
+        T::Time64(unit @ (U::Second | U::Millisecond)) => {
+            invalid(path, format!("Time64 does not support the unit {unit:?}"))
+        }
+        T::Timestamp(_, Some(timezone)) => {
+            canonicalize_timezone(timezone).map_err(|err| error_at(path, err.message()))?;
+            Ok(())
+        }
+        T::Decimal128(precision, scale) => check_precision_and_scale(*precision, *scale)
+            .map_err(|err| error_at(path, err.message())),
+        T::FixedSizeBinary(n) if *n < 0 => invalid(
+            path,
+            format!("FixedSizeBinary requires a non-negative size, found {n}"),
+        ),
+        T::FixedSizeList(_, n) if *n < 0 => invalid(
+            path,
+            format!("FixedSizeList requires a non-negative size, found {n}"),
+        ),
+        T::List(item) | T::LargeList(item) | T::FixedSizeList(item, _) => {
+            validate_field(item, path)
+        }
+        T::Struct(fields) => fields
+            .iter()
+            .try_for_each(|field| validate_field(field, path)),
+        T::Map(entries, _) => {
+            let T::Struct(children) = &entries.data_type else {
+                return invalid(
+                    path,
+                    format!(
+                        "Map requires a struct entries field, found {}",
+                        entries.data_type
+                    ),
+                );
+            };
+            if children.len() != 2 {
+                return invalid(
+                    path,
+                    format!(
+                        "Map requires entries with two children, found {}",
+                        children.len()
+                    ),
+                );
+            }
+            if entries.nullable {
+                return invalid(path, "Map requires non-nullable entries");
+            }
+            if children[0].nullable {
+                return invalid(path, "Map requires non-nullable keys");
+            }
+            validate_field(entries, path)
+        }
+        T::Dictionary(keys, values) => {
+            if !keys.is_integer() {
+                return invalid(
+                    path,
+                    format!("Dictionary requires integer keys, found {keys}"),
+                );
+            }
+            path.push("values");
+            validate_data_type(values, path)?;
+            path.pop();
+            Ok(())
+        }
+        T::RunEndEncoded(run_ends, values) => {
+            if !matches!(run_ends.data_type, T::Int16 | T::Int32 | T::Int64) {
+                return invalid(
+                    path,
+                    format!(
+                        "RunEndEncoded requires Int16, Int32 or Int64 run ends, found {}",
+                        run_ends.data_type
+                    ),
+                );
+            }
+            if run_ends.nullable {
+                return invalid(path, "RunEndEncoded requires non-nullable run ends");
+            }
+            validate_field(values, path)
+        }
+        T::Union(fields, _) => {
+            let mut type_ids = HashSet::new();
+            for (type_id, field) in fields {
+                if *type_id < 0 {
+                    return invalid(
+                        path,
+                        format!("Union type ids must be between 0 and 127, found {type_id}"),
+                    );
+                }
+                if !type_ids.insert(*type_id) {
+                    return invalid(path, format!("Union has duplicate type id {type_id}"));
+                }
+                validate_field(field, path)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn invalid(path: &[&str], message: impl std::fmt::Display) -> Result<()> {
+    Err(error_at(path, message))
+}
+
+fn error_at(path: &[&str], message: impl std::fmt::Display) -> MarrowError {
+    let message = if path.is_empty() {
+        format!("Invalid data type: {message}")
+    } else {
+        format!(
+            "Invalid data type at {path}: {message}",
+            path = path.join(".")
+        )
+    };
+    MarrowError::new(ErrorKind::Unsupported, message)
+}
+
+#[test]
+fn invalid_data_types() {
+    let assert_invalid = |data_type: &str, expected: &str| {
+        let data_type = data_type.parse::<DataType>().unwrap();
+        let err = data_type.validate().unwrap_err();
+        assert_eq!(err.message(), expected);
+    };
+
+    assert_invalid(
+        "Time64(s)",
+        "Invalid data type: Time64 does not support the unit Second",
+    );
+    assert_invalid(
+        "FixedSizeBinary(-1)",
+        "Invalid data type: FixedSizeBinary requires a non-negative size, found -1",
+    );
+    assert_invalid(
+        "Decimal128(50, 0)",
+        "Invalid data type: Decimal128 precision must be between 1 and 38, found 50",
+    );
+    assert_invalid(
+        "Struct<a: Dictionary<Utf8, Utf8>>",
+        "Invalid data type at a: Dictionary requires integer keys, found Utf8",
+    );
+    assert_invalid(
+        "List<Dictionary<Int8, Struct<b: Timestamp(s, \"+25:00\")>>>",
+        "Invalid data type at item.values.b: Invalid timezone \"+25:00\"",
+    );
+    assert_invalid(
+        "RunEndEncoded<Float32, Utf8>",
+        "Invalid data type: RunEndEncoded requires Int16, Int32 or Int64 run ends, found Float32",
+    );
+    assert_invalid(
+        "RunEndEncoded<nullable Int32, Utf8>",
+        "Invalid data type: RunEndEncoded requires non-nullable run ends",
+    );
+    assert_invalid(
+        "Map<Int32>",
+        "Invalid data type: Map requires a struct entries field, found Int32",
+    );
+    assert_invalid(
+        "Map<Struct<keys: Utf8>>",
+        "Invalid data type: Map requires entries with two children, found 1",
+    );
+    assert_invalid(
+        "Map<Struct<keys: nullable Utf8, values: Int32>>",
+        "Invalid data type: Map requires non-nullable keys",
+    );
+    assert_invalid(
+        "Union(Dense)<0: a: Int32, 0: b: Utf8>",
+        "Invalid data type: Union has duplicate type id 0",
+    );
+    assert_invalid(
+        "Union(Sparse)<-1: a: Int32>",
+        "Invalid data type: Union type ids must be between 0 and 127, found -1",
+    );
+
+    let field = "outer: Struct<inner: FixedSizeList(-2)<Int32>>"
+        .parse::<Field>()
+        .unwrap();
+    assert_eq!(
+        field.validate().unwrap_err().message(),
+        "Invalid data type at outer.inner: FixedSizeList requires a non-negative size, found -2",
+    );
+}
+
+#[test]
+fn valid_data_types() {
+    for data_type in [
+        "Time32(ms)",
+        "Time64(ns)",
+        "Timestamp(us, \"+01:00\")",
+        "Decimal128(38, -5)",
+        "FixedSizeBinary(0)",
+        "Dictionary<UInt16, LargeUtf8>",
+        "RunEndEncoded<Int64, nullable Utf8>",
+        "Map(sorted)<Struct<keys: Utf8, values: nullable List<Int32>>>",
+        "Union(Dense)<0: a: Int32, 127: b: Utf8>",
+    ] {
+        let data_type = data_type.parse::<DataType>().unwrap();
+        assert!(data_type.validate().is_ok(), "{data_type}");
+    }
+}
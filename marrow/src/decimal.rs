@@ -0,0 +1,531 @@
+//! Formatting, parsing and rescaling of decimal values
+//!
+//! A decimal value `value` with scale `scale` represents the number `value * 10^-scale`. The
+//! precision is the maximum number of decimal digits of `value`. `Decimal128` supports precisions
+//! up to 38 and scales up to the precision. Negative scales are supported.
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! use marrow::{array::DecimalArray, types::{Overflow, Rounding}};
+//!
+//! let array = DecimalArray::from_strings(
+//!     [Some("1.5"), None, Some("-0.125")],
+//!     5,
+//!     2,
+//!     Rounding::HalfEven,
+//! )?;
+//! assert_eq!(array.values, vec![150, 0, -12]);
+//!
+//! let array = array.rescale(5, 1, Rounding::HalfAwayFromZero, Overflow::Error)?;
+//! let formatted = array.as_view().iter_strings().collect::<Vec<_>>();
+//! assert_eq!(formatted, vec![Some(String::from("1.5")), None, Some(String::from("-0.1"))]);
+//! # Ok(())
+//! # }
+//! ```
+use std::cmp::Ordering;
+
+use crate::{
+    array::DecimalArray,
+    bits::Bitmap,
+    error::{fail, ErrorKind, Result},
+    types::{Overflow, Rounding},
+    view::DecimalView,
+};
+
+/// The maximum precision of `Decimal128` values
+pub const MAX_DECIMAL128_PRECISION: u8 = 38;
+
+/// Format a decimal value with exactly `scale` fractional digits
+///
+/// ```rust
+/// # use marrow::decimal::format_decimal;
+/// assert_eq!(format_decimal(12345, 2), "123.45");
+/// assert_eq!(format_decimal(-5, 3), "-0.005");
+/// assert_eq!(format_decimal(150, 2), "1.50");
+/// assert_eq!(format_decimal(12, -2), "1200");
+/// ```
+pub fn format_decimal(value: i128, scale: i8) -> String {
+    let mut digits = value.unsigned_abs().to_string();
+    match scale.cmp(&0) {
+        Ordering::Greater => {
+            let scale = usize::from(scale.unsigned_abs());
+            if digits.len() <= scale {
+                digits.insert_str(0, &"0".repeat(scale + 1 - digits.len()));
+            }
+            digits.insert(digits.len() - scale, '.');
+        }
+        Ordering::Less if value != 0 => {
+            digits.push_str(&"0".repeat(usize::from(scale.unsigned_abs())));
+        }
+        _ => {}
+    }
+    if value < 0 {
+        digits.insert(0, '-');
+    }
+    digits
+}
+
+/// Parse a decimal number, e.g., `"-12.5"` or `"1.25e3"`, into a value with the given scale
+///
+/// Digits beyond the scale are rounded according to `rounding`. An error is returned, if the
+/// result does not fit into the given precision.
+///
+/// ```rust
+/// # use marrow::{decimal::parse_decimal, types::Rounding};
+/// assert_eq!(parse_decimal("123.45", 5, 2, Rounding::Exact).unwrap(), 12345);
+/// assert_eq!(parse_decimal("-1.255", 5, 2, Rounding::HalfEven).unwrap(), -126);
+/// assert_eq!(parse_decimal("1.5e2", 5, 0, Rounding::Exact).unwrap(), 150);
+/// assert!(parse_decimal("1.255", 5, 2, Rounding::Exact).is_err());
+/// assert!(parse_decimal("1234.5", 5, 2, Rounding::Floor).is_err());
+/// ```
+pub fn parse_decimal(text: &str, precision: u8, scale: i8, rounding: Rounding) -> Result<i128> {
+    check_precision_and_scale(precision, scale)?;
+    let Some(parsed) = ParsedDecimal::parse(text) else {
+        fail!(ErrorKind::ParseError, "Invalid decimal number {text:?}");
+    };
+
+    // the number of digits the decimal point is moved to the right to reach the scale
+    let Some(shift) = i64::from(scale)
+        .checked_add(parsed.exponent)
+        .and_then(|shift| shift.checked_sub(i64::try_from(parsed.fraction_digits).ok()?))
+    else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Exponent of {text:?} is out of range"
+        );
+    };
+
+    let digits = parsed.digits.trim_start_matches('0');
+    let (kept, dropped) = if shift >= 0 {
+        (digits, "")
+    } else {
+        let dropped = usize::try_from(shift.unsigned_abs()).unwrap_or(usize::MAX);
+        digits.split_at(digits.len().saturating_sub(dropped))
+    };
+
+    let mut magnitude = 0_u128;
+    for digit in kept.bytes() {
+        magnitude = checked_push_digit(magnitude, digit).ok_or_else(|| overflow_error(text))?;
+    }
+    if shift > 0 {
+        let factor = u32::try_from(shift)
+            .ok()
+            .and_then(|shift| 10_u128.checked_pow(shift));
+        magnitude = match factor {
+            _ if magnitude == 0 => 0,
+            Some(factor) => magnitude
+                .checked_mul(factor)
+                .ok_or_else(|| overflow_error(text))?,
+            None => return Err(overflow_error(text)),
+        };
+    }
+
+    if dropped.bytes().any(|digit| digit != b'0') {
+        // if digits are implicitly dropped (more dropped digits than available), the first dropped
+        // digit is zero
+        let implicit_zeros =
+            usize::try_from(shift.unsigned_abs()).unwrap_or(usize::MAX) > digits.len();
+        let half = match dropped.as_bytes()[0] {
+            _ if implicit_zeros => Ordering::Less,
+            b'5' if dropped.bytes().skip(1).all(|digit| digit == b'0') => Ordering::Equal,
+            b'5' => Ordering::Greater,
+            digit => digit.cmp(&b'5'),
+        };
+        let Some(away_from_zero) =
+            rounding.rounds_away_from_zero(parsed.negative, magnitude % 2 != 0, half)
+        else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {text:?} with scale {scale} without rounding"
+            );
+        };
+        if away_from_zero {
+            magnitude += 1;
+        }
+    }
+
+    let Some(value) = i128::try_from(magnitude)
+        .ok()
+        .map(|value| if parsed.negative { -value } else { value })
+        .filter(|value| fits_precision(*value, precision))
+    else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Decimal {text:?} does not fit into precision {precision} with scale {scale}"
+        );
+    };
+    Ok(value)
+}
+
+fn checked_push_digit(value: u128, digit: u8) -> Option<u128> {
+    value.checked_mul(10)?.checked_add(u128::from(digit - b'0'))
+}
+
+fn overflow_error(text: &str) -> crate::error::MarrowError {
+    crate::error::MarrowError::new(
+        ErrorKind::Unsupported,
+        format!("Decimal {text:?} is out of range"),
+    )
+}
+
+struct ParsedDecimal {
+    negative: bool,
+    /// The digits of the integer and fractional part
+    digits: String,
+    fraction_digits: usize,
+    exponent: i64,
+}
+
+impl ParsedDecimal {
+    fn parse(text: &str) -> Option<Self> {
+        let (negative, rest) = match text.as_bytes().first()? {
+            b'-' => (true, &text[1..]),
+            b'+' => (false, &text[1..]),
+            _ => (false, text),
+        };
+        let (mantissa, exponent) = match rest.find(['e', 'E']) {
+            Some(pos) => (&rest[..pos], Some(&rest[pos + 1..])),
+            None => (rest, None),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (mantissa, ""),
+        };
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+        let exponent = match exponent {
+            Some(exponent) => {
+                let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if digits.is_empty() || !is_digits(digits) {
+                    return None;
+                }
+                exponent.parse::<i64>().ok()?
+            }
+            None => 0,
+        };
+        Some(Self {
+            negative,
+            digits: format!("{integer}{fraction}"),
+            fraction_digits: fraction.len(),
+            exponent,
+        })
+    }
+}
+
+/// Change the scale of a decimal value
+///
+/// Digits beyond the new scale are rounded according to `rounding`. An error is returned, if the
+/// result does not fit into an `i128`.
+///
+/// ```rust
+/// # use marrow::{decimal::rescale_decimal, types::Rounding};
+/// assert_eq!(rescale_decimal(12345, 2, 4, Rounding::Exact).unwrap(), 1234500);
+/// assert_eq!(rescale_decimal(12345, 2, 0, Rounding::HalfEven).unwrap(), 123);
+/// assert_eq!(rescale_decimal(-12350, 2, 1, Rounding::HalfEven).unwrap(), -1235);
+/// ```
+pub fn rescale_decimal(
+    value: i128,
+    from_scale: i8,
+    to_scale: i8,
+    rounding: Rounding,
+) -> Result<i128> {
+    match rescale_value(value, from_scale, to_scale, rounding)? {
+        Some(value) => Ok(value),
+        None => fail!(
+            ErrorKind::Unsupported,
+            "Overflow when rescaling {value} from scale {from_scale} to {to_scale}"
+        ),
+    }
+}
+
+/// Rescale a value, returns `None` if the result does not fit into an `i128`
+fn rescale_value(
+    value: i128,
+    from_scale: i8,
+    to_scale: i8,
+    rounding: Rounding,
+) -> Result<Option<i128>> {
+    let shift = i32::from(to_scale) - i32::from(from_scale);
+    let factor = 10_i128.checked_pow(shift.unsigned_abs());
+    match (shift.cmp(&0), factor) {
+        (Ordering::Equal, _) => Ok(Some(value)),
+        (Ordering::Greater, _) if value == 0 => Ok(Some(0)),
+        (Ordering::Greater, Some(factor)) => Ok(value.checked_mul(factor)),
+        (Ordering::Greater, None) => Ok(None),
+        (Ordering::Less, Some(factor)) => Ok(Some(rounding.divide(value, factor)?)),
+        // the divisor exceeds twice any i128 value, the result is zero before rounding
+        (Ordering::Less, None) => {
+            if value == 0 {
+                return Ok(Some(0));
+            }
+            let Some(away_from_zero) =
+                rounding.rounds_away_from_zero(value < 0, false, Ordering::Less)
+            else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Cannot rescale {value} from scale {from_scale} to {to_scale} without rounding"
+                );
+            };
+            Ok(Some(match (away_from_zero, value < 0) {
+                (false, _) => 0,
+                (true, false) => 1,
+                (true, true) => -1,
+            }))
+        }
+    }
+}
+
+/// Whether the value has at most `precision` decimal digits
+///
+/// ```rust
+/// # use marrow::decimal::fits_precision;
+/// assert!(fits_precision(-999, 3));
+/// assert!(!fits_precision(1000, 3));
+/// ```
+pub fn fits_precision(value: i128, precision: u8) -> bool {
+    match 10_u128.checked_pow(u32::from(precision)) {
+        Some(bound) => value.unsigned_abs() < bound,
+        None => true,
+    }
+}
+
+fn max_for_precision(precision: u8) -> i128 {
+    match 10_i128.checked_pow(u32::from(precision)) {
+        Some(bound) => bound - 1,
+        None => i128::MAX,
+    }
+}
+
+pub(crate) fn check_precision_and_scale(precision: u8, scale: i8) -> Result<()> {
+    if precision == 0 || precision > MAX_DECIMAL128_PRECISION {
+        fail!(
+            ErrorKind::Unsupported,
+            "Decimal128 precision must be between 1 and {MAX_DECIMAL128_PRECISION}, found {precision}"
+        );
+    }
+    if i16::from(scale) > i16::from(precision) {
+        fail!(
+            ErrorKind::Unsupported,
+            "Decimal scale {scale} must not exceed the precision {precision}"
+        );
+    }
+    Ok(())
+}
+
+impl<'a> DecimalView<'a, i128> {
+    /// Iterate over the elements formatted with [`format_decimal`]
+    pub fn iter_strings(&self) -> impl Iterator<Item = Option<String>> + 'a {
+        let scale = self.scale;
+        let validity = self.validity;
+        self.values.iter().enumerate().map(move |(idx, value)| {
+            let is_valid = validity.map_or(true, |validity| validity.get(idx));
+            is_valid.then(|| format_decimal(*value, scale))
+        })
+    }
+
+    /// Check that all valid elements fit into the precision of the view
+    pub fn check_precision(&self) -> Result<()> {
+        for (idx, value) in self.values.iter().enumerate() {
+            if self.validity.is_some_and(|validity| !validity.get(idx)) {
+                continue;
+            }
+            if !fits_precision(*value, self.precision) {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Element {idx} ({value}) does not fit into precision {precision}",
+                    precision = self.precision,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Change the precision and scale of all elements
+    ///
+    /// Digits beyond the new scale are rounded according to `rounding`. `overflow` determines how
+    /// values that do not fit into the new precision are handled. Saturated values are clamped to
+    /// the largest value of the precision.
+    pub fn rescale(
+        &self,
+        precision: u8,
+        scale: i8,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<DecimalArray<i128>> {
+        check_precision_and_scale(precision, scale)?;
+
+        let mut validity = Bitmap::new();
+        let mut values = Vec::with_capacity(self.values.len());
+        for (idx, value) in self.values.iter().enumerate() {
+            if self.validity.is_some_and(|validity| !validity.get(idx)) {
+                validity.push(false);
+                values.push(0);
+                continue;
+            }
+            let rescaled = rescale_value(*value, self.scale, scale, rounding)?
+                .filter(|value| fits_precision(*value, precision));
+            let rescaled = match (rescaled, overflow) {
+                (Some(value), _) => Some(value),
+                (None, Overflow::Error) => fail!(
+                    ErrorKind::Unsupported,
+                    "Element {idx} ({value}) does not fit into precision {precision} with scale {scale}"
+                ),
+                // rescaling retains the sign
+                (None, Overflow::Saturate) if *value < 0 => Some(-max_for_precision(precision)),
+                (None, Overflow::Saturate) => Some(max_for_precision(precision)),
+                (None, Overflow::Null) => None,
+            };
+            validity.push(rescaled.is_some());
+            values.push(rescaled.unwrap_or_default());
+        }
+
+        Ok(DecimalArray {
+            precision,
+            scale,
+            validity: if validity.count_set_bits() == validity.len() {
+                None
+            } else {
+                Some(validity.into_vec())
+            },
+            values,
+        })
+    }
+}
+
+impl DecimalArray<i128> {
+    /// Parse strings into a decimal array, see [`parse_decimal`]
+    pub fn from_strings<'s>(
+        values: impl IntoIterator<Item = Option<&'s str>>,
+        precision: u8,
+        scale: i8,
+        rounding: Rounding,
+    ) -> Result<Self> {
+        check_precision_and_scale(precision, scale)?;
+        let mut validity = Bitmap::new();
+        let mut result = Vec::new();
+        for value in values {
+            validity.push(value.is_some());
+            result.push(match value {
+                Some(value) => parse_decimal(value, precision, scale, rounding)?,
+                None => 0,
+            });
+        }
+        Ok(Self {
+            precision,
+            scale,
+            validity: if validity.count_set_bits() == validity.len() {
+                None
+            } else {
+                Some(validity.into_vec())
+            },
+            values: result,
+        })
+    }
+
+    /// Check that all valid elements fit into the precision, see
+    /// [`DecimalView::check_precision`]
+    pub fn check_precision(&self) -> Result<()> {
+        self.as_view().check_precision()
+    }
+
+    /// Change the precision and scale of all elements, see [`DecimalView::rescale`]
+    pub fn rescale(
+        &self,
+        precision: u8,
+        scale: i8,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<DecimalArray<i128>> {
+        self.as_view().rescale(precision, scale, rounding, overflow)
+    }
+}
+
+#[test]
+fn formatting_and_parsing() {
+    assert_eq!(format_decimal(0, 2), "0.00");
+    assert_eq!(format_decimal(0, -2), "0");
+    assert_eq!(format_decimal(i128::MIN, 0), i128::MIN.to_string());
+    assert_eq!(format_decimal(-12345, 5), "-0.12345");
+
+    let parse =
+        |text: &str, scale: i8, rounding: Rounding| parse_decimal(text, 38, scale, rounding).ok();
+    assert_eq!(parse("0.00", 2, Rounding::Exact), Some(0));
+    assert_eq!(parse("-.5", 0, Rounding::HalfAwayFromZero), Some(-1));
+    assert_eq!(parse("-.5", 0, Rounding::HalfEven), Some(0));
+    assert_eq!(parse("2.5", 0, Rounding::HalfEven), Some(2));
+    assert_eq!(parse("2.51", 0, Rounding::HalfEven), Some(3));
+    assert_eq!(parse("-0.001", 1, Rounding::Floor), Some(-1));
+    assert_eq!(parse("0.001", 1, Rounding::Ceil), Some(1));
+    assert_eq!(parse("0.001", 1, Rounding::HalfAwayFromZero), Some(0));
+    assert_eq!(parse("1e-50", 2, Rounding::Ceil), Some(1));
+    assert_eq!(parse("12E+1", -1, Rounding::Exact), Some(12));
+    assert_eq!(parse("1250", -2, Rounding::HalfEven), Some(12));
+    assert_eq!(
+        parse(
+            "0000000000000000000000000000000000000000001",
+            0,
+            Rounding::Exact
+        ),
+        Some(1)
+    );
+    assert_eq!(parse("1e40", 0, Rounding::Exact), None);
+    assert_eq!(parse("0e1000", 0, Rounding::Exact), Some(0));
+
+    for invalid in ["", "-", ".", "1.2.3", "1e", "e1", "1x", " 1", "1e+"] {
+        assert_eq!(parse(invalid, 0, Rounding::HalfEven), None, "{invalid:?}");
+    }
+    assert!(parse_decimal("1", 0, 0, Rounding::Exact).is_err());
+    assert!(parse_decimal("1", 3, 4, Rounding::Exact).is_err());
+}
+
+#[test]
+fn rescaling() {
+    assert_eq!(rescale_decimal(5, 0, 40, Rounding::Exact).ok(), None);
+    assert_eq!(rescale_decimal(0, 0, 40, Rounding::Exact).ok(), Some(0));
+    assert_eq!(rescale_decimal(-5, 40, -40, Rounding::Floor).ok(), Some(-1));
+    assert_eq!(
+        rescale_decimal(5, 40, -40, Rounding::HalfEven).ok(),
+        Some(0)
+    );
+
+    let array = DecimalArray {
+        precision: 5,
+        scale: 2,
+        validity: Some(vec![0b_1011]),
+        values: vec![99_999, -99_999, 123_456, 150],
+    };
+    assert!(array.check_precision().is_ok());
+    assert!(array
+        .rescale(5, 3, Rounding::Exact, Overflow::Error)
+        .is_err());
+
+    let actual = array
+        .rescale(5, 3, Rounding::Exact, Overflow::Saturate)
+        .unwrap();
+    assert_eq!(actual.values, vec![99_999, -99_999, 0, 1_500]);
+    assert_eq!(actual.validity, Some(vec![0b_1011]));
+
+    let actual = array
+        .rescale(5, 3, Rounding::Exact, Overflow::Null)
+        .unwrap();
+    assert_eq!(actual.validity, Some(vec![0b_1000]));
+
+    assert!(array
+        .rescale(4, 1, Rounding::Floor, Overflow::Error)
+        .is_err());
+    assert!(array
+        .rescale(5, 1, Rounding::Exact, Overflow::Null)
+        .is_err());
+    let actual = array
+        .rescale(4, 1, Rounding::TowardZero, Overflow::Error)
+        .unwrap();
+    assert_eq!(actual.values, vec![9_999, -9_999, 0, 15]);
+
+    let array = DecimalArray {
+        precision: 3,
+        ..array
+    };
+    assert!(array.check_precision().is_err());
+}
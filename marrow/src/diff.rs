@@ -0,0 +1,1046 @@
+//! Find the differences between views, e.g., to explain failing test assertions
+//!
+//! [`diff_views`] compares two views and reports where they differ: mismatching data types and
+//! field metadata, different lengths and the expected and actual values of differing elements.
+//! The [`assert_views_eq!`][crate::assert_views_eq] macro panics with this report.
+//!
+//! Differences are identified by their path: struct fields are appended as `.name`, the elements
+//! of arrays, lists and maps as `[idx]`. Struct views at the top level are compared field by field
+//! like the columns of a record batch. For example, the path `b[12].list[3]` refers to the 4th
+//! list element of the field `list` in row 12 of the column `b`.
+//!
+//! Elements are compared by their logical value: validity bits of null elements, the offsets of
+//! lists or byte arrays and the encoding of dictionaries and run end encoded arrays are ignored.
+//!
+//! ```rust
+//! # use marrow::{array::{Array, PrimitiveArray, StructArray}, datatypes::FieldMeta, diff::diff_views};
+//! let expected = Array::Struct(StructArray {
+//!     len: 3,
+//!     validity: None,
+//!     fields: vec![(
+//!         FieldMeta { name: String::from("a"), ..FieldMeta::default() },
+//!         Array::Int32(PrimitiveArray { validity: None, values: vec![1, 2, 3] }),
+//!     )],
+//! });
+//! let actual = Array::Struct(StructArray {
+//!     len: 3,
+//!     validity: None,
+//!     fields: vec![(
+//!         FieldMeta { name: String::from("a"), ..FieldMeta::default() },
+//!         Array::Int32(PrimitiveArray { validity: None, values: vec![1, 2, 4] }),
+//!     )],
+//! });
+//!
+//! let diff = diff_views(&expected.as_view(), &actual.as_view()).unwrap();
+//! assert_eq!(diff.to_string(), "a[2]: expected 3, actual 4\n");
+//! ```
+use std::fmt;
+
+use crate::{
+    array::view_len,
+    datatypes::{meta_from_field, DataType, Field, FieldMeta},
+    decimal::format_decimal,
+    error::{fail, ErrorKind, Result},
+    interval::format_year_month_interval,
+    view::{BitsWithOffset, BytesViewView, View},
+};
+
+/// The number of differences reported by [`diff_views`]
+pub const DEFAULT_MAX_DIFFERENCES: usize = 10;
+
+/// The differences between two views, see [`diff_views`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayDiff {
+    /// The differences in the order they were found
+    pub differences: Vec<Difference>,
+    /// Whether further differences were omitted after reaching the maximum number
+    pub truncated: bool,
+}
+
+/// A single difference between two views
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// The path of the differing child or element, empty for the views themselves
+    pub path: String,
+    /// How the views differ
+    pub kind: DifferenceKind,
+}
+
+/// How two views differ at a given path
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DifferenceKind {
+    /// The data types differ, the children are not compared
+    DataType {
+        /// The expected data type
+        expected: DataType,
+        /// The actual data type
+        actual: DataType,
+    },
+    /// The name, nullability, metadata or dictionary metadata of a field differ
+    Field {
+        /// The expected field
+        expected: FieldMeta,
+        /// The actual field
+        actual: FieldMeta,
+    },
+    /// The number of elements differ, common elements are still compared
+    Length {
+        /// The expected number of elements
+        expected: usize,
+        /// The actual number of elements
+        actual: usize,
+    },
+    /// The values of an element differ, given in a human readable form
+    Value {
+        /// The expected value
+        expected: String,
+        /// The actual value
+        actual: String,
+    },
+    /// An element could not be accessed, e.g., because offsets are out of bounds
+    Invalid {
+        /// The error encountered
+        message: String,
+    },
+}
+
+impl fmt::Display for ArrayDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for difference in &self.differences {
+            writeln!(f, "{difference}")?;
+        }
+        if self.truncated {
+            writeln!(f, "...")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+        match &self.kind {
+            DifferenceKind::DataType { expected, actual } => write!(
+                f,
+                "{path}: data types differ, expected {expected}, actual {actual}"
+            ),
+            DifferenceKind::Field { expected, actual } => write!(
+                f,
+                "{path}: fields differ, expected {expected:?}, actual {actual:?}"
+            ),
+            DifferenceKind::Length { expected, actual } => write!(
+                f,
+                "{path}: lengths differ, expected {expected}, actual {actual}"
+            ),
+            DifferenceKind::Value { expected, actual } => {
+                write!(f, "{path}: expected {expected}, actual {actual}")
+            }
+            DifferenceKind::Invalid { message } => write!(f, "{path}: invalid view: {message}"),
+        }
+    }
+}
+
+/// Compare two views and report up to [`DEFAULT_MAX_DIFFERENCES`] differences
+///
+/// Returns `None` if the views are equal. See the [module docs][crate::diff] for details.
+pub fn diff_views(expected: &View<'_>, actual: &View<'_>) -> Option<ArrayDiff> {
+    diff_views_with_limit(expected, actual, DEFAULT_MAX_DIFFERENCES)
+}
+
+/// Compare two views and report up to `max_differences` differences
+///
+/// At least one difference is reported, if the views differ.
+pub fn diff_views_with_limit(
+    expected: &View<'_>,
+    actual: &View<'_>,
+    max_differences: usize,
+) -> Option<ArrayDiff> {
+    let mut diff = Diff {
+        max_differences: max_differences.max(1),
+        differences: Vec::new(),
+        truncated: false,
+    };
+    diff.compare_views(expected, actual);
+
+    if diff.differences.is_empty() {
+        return None;
+    }
+    Some(ArrayDiff {
+        differences: diff.differences,
+        truncated: diff.truncated,
+    })
+}
+
+/// Assert that two views are equal, panic with the differences otherwise
+///
+/// The arguments must be [`View`]s, use [`Array::as_view`][crate::array::Array::as_view] to
+/// compare arrays. An optional message can be given with the same syntax as `assert_eq!`.
+///
+/// ```rust,should_panic
+/// # use marrow::{array::{Array, PrimitiveArray}, assert_views_eq};
+/// let expected = Array::Int64(PrimitiveArray { validity: None, values: vec![1, 2, 3] });
+/// let actual = Array::Int64(PrimitiveArray { validity: None, values: vec![1, 2, 4] });
+///
+/// // panics with "views differ:\n[2]: expected 3, actual 4"
+/// assert_views_eq!(expected.as_view(), actual.as_view());
+/// ```
+#[macro_export]
+macro_rules! assert_views_eq {
+    ($expected:expr, $actual:expr $(,)?) => {
+        if let Some(diff) = $crate::diff::diff_views(&$expected, &$actual) {
+            panic!("views differ:\n{diff}");
+        }
+    };
+    ($expected:expr, $actual:expr, $($arg:tt)+) => {
+        if let Some(diff) = $crate::diff::diff_views(&$expected, &$actual) {
+            panic!("views differ: {}\n{diff}", format_args!($($arg)+));
+        }
+    };
+}
+
+struct Diff {
+    max_differences: usize,
+    differences: Vec<Difference>,
+    truncated: bool,
+}
+
+impl Diff {
+    fn is_full(&self) -> bool {
+        self.truncated
+    }
+
+    fn push(&mut self, path: &str, kind: DifferenceKind) {
+        if self.differences.len() >= self.max_differences {
+            self.truncated = true;
+        } else {
+            self.differences.push(Difference {
+                path: path.to_owned(),
+                kind,
+            });
+        }
+    }
+
+    fn push_value(&mut self, path: &str, expected: String, actual: String) {
+        self.push(path, DifferenceKind::Value { expected, actual });
+    }
+
+    fn compare_views(&mut self, expected: &View<'_>, actual: &View<'_>) {
+        if !self.compare_types("", &expected.data_type(), &actual.data_type()) {
+            return;
+        }
+        let (expected_len, actual_len) = match (view_len(expected), view_len(actual)) {
+            (Ok(expected_len), Ok(actual_len)) => (expected_len, actual_len),
+            (Err(err), _) | (_, Err(err)) => {
+                let message = err.to_string();
+                return self.push("", DifferenceKind::Invalid { message });
+            }
+        };
+        if expected_len != actual_len {
+            self.push(
+                "",
+                DifferenceKind::Length {
+                    expected: expected_len,
+                    actual: actual_len,
+                },
+            );
+        }
+        let len = expected_len.min(actual_len);
+
+        if let (View::Struct(expected), View::Struct(actual)) = (expected, actual) {
+            // only compare the fields of rows valid in both views
+            let mut rows = Vec::with_capacity(len);
+            for idx in 0..len {
+                let path = format!("[{idx}]");
+                match (
+                    is_valid(expected.validity, idx),
+                    is_valid(actual.validity, idx),
+                ) {
+                    (Ok(true), Ok(true)) => rows.push(idx),
+                    (Ok(false), Ok(false)) => {}
+                    (Ok(true), Ok(false)) => self.push_value(&path, value(expected, idx), null()),
+                    (Ok(false), Ok(true)) => self.push_value(&path, null(), value(actual, idx)),
+                    (Err(err), _) | (_, Err(err)) => {
+                        let message = err.to_string();
+                        self.push(&path, DifferenceKind::Invalid { message });
+                    }
+                }
+            }
+            for ((meta, expected), (_, actual)) in expected.fields.iter().zip(&actual.fields) {
+                for &idx in &rows {
+                    if self.is_full() {
+                        return;
+                    }
+                    let path = format!("{name}[{idx}]", name = meta.name);
+                    self.compare_element(&path, expected, idx, actual, idx);
+                }
+            }
+        } else {
+            for idx in 0..len {
+                if self.is_full() {
+                    return;
+                }
+                self.compare_element(&format!("[{idx}]"), expected, idx, actual, idx);
+            }
+        }
+    }
+
+    /// Compare the data types, returns whether the values can be compared
+    fn compare_types(&mut self, path: &str, expected: &DataType, actual: &DataType) -> bool {
+        use DataType as T;
+        match (expected, actual) {
+            (T::Struct(expected), T::Struct(actual)) if expected.len() == actual.len() => {
+                let mut comparable = true;
+                for (expected, actual) in expected.iter().zip(actual) {
+                    let path = child_path(path, &expected.name);
+                    comparable &= self.compare_fields(&path, expected, actual);
+                }
+                comparable
+            }
+            (T::List(expected), T::List(actual))
+            | (T::LargeList(expected), T::LargeList(actual)) => {
+                self.compare_fields(path, expected, actual)
+            }
+            (T::FixedSizeList(expected, expected_n), T::FixedSizeList(actual, actual_n))
+                if expected_n == actual_n =>
+            {
+                self.compare_fields(path, expected, actual)
+            }
+            (T::Map(expected, expected_sorted), T::Map(actual, actual_sorted))
+                if expected_sorted == actual_sorted =>
+            {
+                self.compare_fields(path, expected, actual)
+            }
+            (T::Dictionary(expected_keys, expected), T::Dictionary(actual_keys, actual))
+                if expected_keys == actual_keys =>
+            {
+                self.compare_types(path, expected, actual)
+            }
+            (T::RunEndEncoded(expected_ends, expected), T::RunEndEncoded(actual_ends, actual))
+                if expected_ends == actual_ends =>
+            {
+                self.compare_fields(path, expected, actual)
+            }
+            (T::Union(expected, expected_mode), T::Union(actual, actual_mode))
+                if expected_mode == actual_mode
+                    && expected.len() == actual.len()
+                    && expected.iter().zip(actual).all(|(e, a)| e.0 == a.0) =>
+            {
+                let mut comparable = true;
+                for ((_, expected), (_, actual)) in expected.iter().zip(actual) {
+                    let path = child_path(path, &expected.name);
+                    comparable &= self.compare_fields(&path, expected, actual);
+                }
+                comparable
+            }
+            (expected, actual) if expected == actual => true,
+            (expected, actual) => {
+                self.push(
+                    path,
+                    DifferenceKind::DataType {
+                        expected: expected.clone(),
+                        actual: actual.clone(),
+                    },
+                );
+                false
+            }
+        }
+    }
+
+    fn compare_fields(&mut self, path: &str, expected: &Field, actual: &Field) -> bool {
+        if expected.name != actual.name
+            || expected.nullable != actual.nullable
+            || expected.metadata != actual.metadata
+            || expected.dictionary != actual.dictionary
+        {
+            self.push(
+                path,
+                DifferenceKind::Field {
+                    expected: meta_from_field(expected.clone()),
+                    actual: meta_from_field(actual.clone()),
+                },
+            );
+        }
+        self.compare_types(path, &expected.data_type, &actual.data_type)
+    }
+
+    /// Compare element `expected_idx` of `expected` with element `actual_idx` of `actual`
+    ///
+    /// The data types of both views must have been compared before.
+    fn compare_element(
+        &mut self,
+        path: &str,
+        expected: &View<'_>,
+        expected_idx: usize,
+        actual: &View<'_>,
+        actual_idx: usize,
+    ) {
+        if let Err(err) = self.try_compare_element(path, expected, expected_idx, actual, actual_idx)
+        {
+            let message = err.to_string();
+            self.push(path, DifferenceKind::Invalid { message });
+        }
+    }
+
+    fn try_compare_element(
+        &mut self,
+        path: &str,
+        expected: &View<'_>,
+        expected_idx: usize,
+        actual: &View<'_>,
+        actual_idx: usize,
+    ) -> Result<()> {
+        use View as V;
+
+        if self.is_full() {
+            return Ok(());
+        }
+
+        // resolve the encodings first, their validity is given by the resolved values
+        match (expected, actual) {
+            (V::Dictionary(expected_dict), V::Dictionary(actual_dict)) => {
+                let (Some(expected_key), Some(actual_key)) = (
+                    index(&expected_dict.keys, expected_idx)?,
+                    index(&actual_dict.keys, actual_idx)?,
+                ) else {
+                    return self.compare_rendered(path, expected, expected_idx, actual, actual_idx);
+                };
+                return self.try_compare_element(
+                    path,
+                    &expected_dict.values,
+                    expected_key,
+                    &actual_dict.values,
+                    actual_key,
+                );
+            }
+            (V::RunEndEncoded(expected), V::RunEndEncoded(actual)) => {
+                return self.try_compare_element(
+                    path,
+                    &expected.values,
+                    physical_index(&expected.run_ends, expected_idx)?,
+                    &actual.values,
+                    physical_index(&actual.run_ends, actual_idx)?,
+                );
+            }
+            (V::Union(expected_union), V::Union(actual_union)) => {
+                let (expected_type, expected_child, expected_child_idx) =
+                    union_child(expected_union, expected_idx)?;
+                let (actual_type, actual_child, actual_child_idx) =
+                    union_child(actual_union, actual_idx)?;
+                if expected_type != actual_type {
+                    return self.compare_rendered(path, expected, expected_idx, actual, actual_idx);
+                }
+                return self.try_compare_element(
+                    path,
+                    expected_child,
+                    expected_child_idx,
+                    actual_child,
+                    actual_child_idx,
+                );
+            }
+            _ => {}
+        }
+
+        let (Some(expected_validity), Some(actual_validity)) =
+            (validity(expected), validity(actual))
+        else {
+            return self.compare_rendered(path, expected, expected_idx, actual, actual_idx);
+        };
+        match (
+            is_valid(expected_validity, expected_idx)?,
+            is_valid(actual_validity, actual_idx)?,
+        ) {
+            (false, false) => return Ok(()),
+            (true, true) => {}
+            _ => return self.compare_rendered(path, expected, expected_idx, actual, actual_idx),
+        }
+
+        match (expected, actual) {
+            (V::Struct(expected), V::Struct(actual)) => {
+                for ((meta, expected), (_, actual)) in expected.fields.iter().zip(&actual.fields) {
+                    let path = child_path(path, &meta.name);
+                    self.compare_element(&path, expected, expected_idx, actual, actual_idx);
+                }
+            }
+            (V::List(expected), V::List(actual)) => self.compare_items(
+                path,
+                &expected.elements,
+                range(expected.offsets, expected_idx)?,
+                &actual.elements,
+                range(actual.offsets, actual_idx)?,
+            ),
+            (V::LargeList(expected), V::LargeList(actual)) => self.compare_items(
+                path,
+                &expected.elements,
+                range(expected.offsets, expected_idx)?,
+                &actual.elements,
+                range(actual.offsets, actual_idx)?,
+            ),
+            (V::FixedSizeList(expected), V::FixedSizeList(actual)) => {
+                let n = usize::try_from(expected.n)?;
+                self.compare_items(
+                    path,
+                    &expected.elements,
+                    (expected_idx * n, (expected_idx + 1) * n),
+                    &actual.elements,
+                    (actual_idx * n, (actual_idx + 1) * n),
+                );
+            }
+            (V::Map(expected), V::Map(actual)) => {
+                let (expected_start, expected_end) = range(expected.offsets, expected_idx)?;
+                let (actual_start, actual_end) = range(actual.offsets, actual_idx)?;
+                self.compare_lengths(
+                    path,
+                    expected_end - expected_start,
+                    actual_end - actual_start,
+                );
+
+                for (expected_idx, actual_idx) in
+                    (expected_start..expected_end).zip(actual_start..actual_end)
+                {
+                    let item_path = format!("{path}[{}]", expected_idx - expected_start);
+                    let keys_path = child_path(&item_path, &expected.meta.keys.name);
+                    self.compare_element(
+                        &keys_path,
+                        &expected.keys,
+                        expected_idx,
+                        &actual.keys,
+                        actual_idx,
+                    );
+                    let values_path = child_path(&item_path, &expected.meta.values.name);
+                    self.compare_element(
+                        &values_path,
+                        &expected.values,
+                        expected_idx,
+                        &actual.values,
+                        actual_idx,
+                    );
+                }
+            }
+            _ => self.compare_rendered(path, expected, expected_idx, actual, actual_idx)?,
+        }
+        Ok(())
+    }
+
+    fn compare_lengths(&mut self, path: &str, expected: usize, actual: usize) {
+        if expected != actual {
+            self.push(path, DifferenceKind::Length { expected, actual });
+        }
+    }
+
+    fn compare_items(
+        &mut self,
+        path: &str,
+        expected: &View<'_>,
+        (expected_start, expected_end): (usize, usize),
+        actual: &View<'_>,
+        (actual_start, actual_end): (usize, usize),
+    ) {
+        self.compare_lengths(
+            path,
+            expected_end - expected_start,
+            actual_end - actual_start,
+        );
+        for (expected_idx, actual_idx) in
+            (expected_start..expected_end).zip(actual_start..actual_end)
+        {
+            let item_path = format!("{path}[{}]", expected_idx - expected_start);
+            self.compare_element(&item_path, expected, expected_idx, actual, actual_idx);
+        }
+    }
+
+    /// Compare elements by their human readable form
+    fn compare_rendered(
+        &mut self,
+        path: &str,
+        expected: &View<'_>,
+        expected_idx: usize,
+        actual: &View<'_>,
+        actual_idx: usize,
+    ) -> Result<()> {
+        let expected = render(expected, expected_idx)?;
+        let actual = render(actual, actual_idx)?;
+        if expected != actual {
+            self.push_value(path, expected, actual);
+        }
+        Ok(())
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        String::from(name)
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn null() -> String {
+    String::from("null")
+}
+
+/// Render an element, errors are included in the result
+fn value(view: &crate::view::StructView<'_>, idx: usize) -> String {
+    render(&View::Struct(view.clone()), idx).unwrap_or_else(|err| format!("<{err}>"))
+}
+
+/// The validity of views with a validity bitmap, `None` for views without
+fn validity<'a>(view: &View<'a>) -> Option<Option<BitsWithOffset<'a>>> {
+    use View as V;
+    match view {
+        V::Boolean(view) => Some(view.validity),
+        V::Int8(view) => Some(view.validity),
+        V::Int16(view) => Some(view.validity),
+        V::Int32(view) => Some(view.validity),
+        V::Int64(view) => Some(view.validity),
+        V::UInt8(view) => Some(view.validity),
+        V::UInt16(view) => Some(view.validity),
+        V::UInt32(view) => Some(view.validity),
+        V::UInt64(view) => Some(view.validity),
+        V::Float16(view) => Some(view.validity),
+        V::Float32(view) => Some(view.validity),
+        V::Float64(view) => Some(view.validity),
+        V::Date32(view) => Some(view.validity),
+        V::Date64(view) => Some(view.validity),
+        V::Time32(view) => Some(view.validity),
+        V::Time64(view) => Some(view.validity),
+        V::Timestamp(view) => Some(view.validity),
+        V::Duration(view) => Some(view.validity),
+        V::YearMonthInterval(view) => Some(view.validity),
+        V::DayTimeInterval(view) => Some(view.validity),
+        V::MonthDayNanoInterval(view) => Some(view.validity),
+        V::Decimal128(view) => Some(view.validity),
+        V::Utf8(view) | V::Binary(view) => Some(view.validity),
+        V::LargeUtf8(view) | V::LargeBinary(view) => Some(view.validity),
+        V::Utf8View(view) | V::BinaryView(view) => Some(view.validity),
+        V::FixedSizeBinary(view) => Some(view.validity),
+        V::Struct(view) => Some(view.validity),
+        V::List(view) => Some(view.validity),
+        V::LargeList(view) => Some(view.validity),
+        V::FixedSizeList(view) => Some(view.validity),
+        V::Map(view) => Some(view.validity),
+        V::Null(_) | V::Dictionary(_) | V::RunEndEncoded(_) | V::Union(_) => None,
+    }
+}
+
+fn is_valid(validity: Option<BitsWithOffset<'_>>, idx: usize) -> Result<bool> {
+    let Some(bits) = validity else {
+        return Ok(true);
+    };
+    if (bits.offset + idx) / 8 >= bits.data.len() {
+        fail!(
+            ErrorKind::Unsupported,
+            "Validity bit {idx} is out of bounds"
+        );
+    }
+    Ok(bits.get(idx))
+}
+
+fn get<T: Copy>(values: &[T], idx: usize) -> Result<T> {
+    let Some(value) = values.get(idx) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Element {idx} is out of bounds for {len} elements",
+            len = values.len(),
+        );
+    };
+    Ok(*value)
+}
+
+fn range<O: Copy>(offsets: &[O], idx: usize) -> Result<(usize, usize)>
+where
+    usize: TryFrom<O>,
+{
+    let (start, end) = (get(offsets, idx)?, get(offsets, idx + 1)?);
+    let (Ok(start), Ok(end)) = (usize::try_from(start), usize::try_from(end)) else {
+        fail!(ErrorKind::Unsupported, "Invalid offsets for element {idx}");
+    };
+    if end < start {
+        fail!(
+            ErrorKind::Unsupported,
+            "Decreasing offsets for element {idx}"
+        );
+    }
+    Ok((start, end))
+}
+
+/// The value of an integer view as an index, `None` for null elements
+fn index(view: &View<'_>, idx: usize) -> Result<Option<usize>> {
+    macro_rules! index {
+        ($view:expr) => {{
+            if !is_valid($view.validity, idx)? {
+                return Ok(None);
+            }
+            let value = get($view.values, idx)?;
+            let Some(value) = usize::try_from(value).ok() else {
+                fail!(ErrorKind::Unsupported, "Invalid index {value}");
+            };
+            Ok(Some(value))
+        }};
+    }
+    match view {
+        View::Int8(view) => index!(view),
+        View::Int16(view) => index!(view),
+        View::Int32(view) => index!(view),
+        View::Int64(view) => index!(view),
+        View::UInt8(view) => index!(view),
+        View::UInt16(view) => index!(view),
+        View::UInt32(view) => index!(view),
+        View::UInt64(view) => index!(view),
+        _ => fail!(ErrorKind::Unsupported, "Indices must be integers"),
+    }
+}
+
+/// The index of the run containing the logical element `idx`
+fn physical_index(run_ends: &View<'_>, idx: usize) -> Result<usize> {
+    fn find<T: Copy>(run_ends: &[T], idx: usize) -> Result<usize>
+    where
+        i128: From<T>,
+    {
+        let idx = idx as i128;
+        let run = run_ends.partition_point(|run_end| i128::from(*run_end) <= idx);
+        if run >= run_ends.len() {
+            fail!(
+                ErrorKind::Unsupported,
+                "Element {idx} is not part of any run"
+            );
+        }
+        Ok(run)
+    }
+    match run_ends {
+        View::Int16(view) => find(view.values, idx),
+        View::Int32(view) => find(view.values, idx),
+        View::Int64(view) => find(view.values, idx),
+        _ => fail!(
+            ErrorKind::Unsupported,
+            "Run ends must be Int16, Int32 or Int64"
+        ),
+    }
+}
+
+/// The type id, child and index into the child of a union element
+fn union_child<'a, 'b>(
+    view: &'b crate::view::UnionView<'a>,
+    idx: usize,
+) -> Result<(i8, &'b View<'a>, usize)> {
+    let type_id = get(view.types, idx)?;
+    let Some((_, _, child)) = view.fields.iter().find(|(id, _, _)| *id == type_id) else {
+        fail!(ErrorKind::Unsupported, "Unknown union type id {type_id}");
+    };
+    let child_idx = match view.offsets {
+        Some(offsets) => usize::try_from(get(offsets, idx)?)?,
+        None => idx,
+    };
+    Ok((type_id, child, child_idx))
+}
+
+fn bytes_view_value(view: &BytesViewView<'_>, idx: usize) -> Result<Vec<u8>> {
+    let data = get(view.data, idx)?.to_le_bytes();
+    let len = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+    if len <= 12 {
+        return Ok(data[4..4 + len].to_vec());
+    }
+    let buffer = u32::from_le_bytes([data[8], data[9], data[10], data[11]]) as usize;
+    let offset = u32::from_le_bytes([data[12], data[13], data[14], data[15]]) as usize;
+    let Some(value) = view
+        .buffers
+        .get(buffer)
+        .and_then(|buffer| buffer.get(offset..offset + len))
+    else {
+        fail!(ErrorKind::Unsupported, "Byte view {idx} is out of bounds");
+    };
+    Ok(value.to_vec())
+}
+
+fn render_bytes(bytes: &[u8]) -> String {
+    format!("b\"{}\"", bytes.escape_ascii())
+}
+
+fn render_str(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => format!("{s:?}"),
+        Err(_) => render_bytes(bytes),
+    }
+}
+
+/// Render an element in a human readable form
+fn render(view: &View<'_>, idx: usize) -> Result<String> {
+    use View as V;
+
+    if let Some(validity) = validity(view) {
+        if !is_valid(validity, idx)? {
+            return Ok(null());
+        }
+    }
+
+    macro_rules! debug {
+        ($view:expr) => {
+            format!("{:?}", get($view.values, idx)?)
+        };
+    }
+
+    let res = match view {
+        V::Null(_) => null(),
+        V::Boolean(view) => {
+            if idx >= view.len {
+                fail!(ErrorKind::Unsupported, "Element {idx} is out of bounds");
+            }
+            view.values.get(idx).to_string()
+        }
+        V::Int8(view) => debug!(view),
+        V::Int16(view) => debug!(view),
+        V::Int32(view) => debug!(view),
+        V::Int64(view) => debug!(view),
+        V::UInt8(view) => debug!(view),
+        V::UInt16(view) => debug!(view),
+        V::UInt32(view) => debug!(view),
+        V::UInt64(view) => debug!(view),
+        V::Float16(view) => debug!(view),
+        V::Float32(view) => debug!(view),
+        V::Float64(view) => debug!(view),
+        V::Date32(view) => debug!(view),
+        V::Date64(view) => debug!(view),
+        V::Time32(view) => debug!(view),
+        V::Time64(view) => debug!(view),
+        V::Timestamp(view) => debug!(view),
+        V::Duration(view) => debug!(view),
+        V::YearMonthInterval(view) => format_year_month_interval(get(view.values, idx)?),
+        V::DayTimeInterval(view) => get(view.values, idx)?.to_string(),
+        V::MonthDayNanoInterval(view) => get(view.values, idx)?.to_string(),
+        V::Decimal128(view) => format_decimal(get(view.values, idx)?, view.scale),
+        V::Utf8(view) => {
+            let (start, end) = range(view.offsets, idx)?;
+            render_str(slice(view.data, start, end)?)
+        }
+        V::LargeUtf8(view) => {
+            let (start, end) = range(view.offsets, idx)?;
+            render_str(slice(view.data, start, end)?)
+        }
+        V::Binary(view) => {
+            let (start, end) = range(view.offsets, idx)?;
+            render_bytes(slice(view.data, start, end)?)
+        }
+        V::LargeBinary(view) => {
+            let (start, end) = range(view.offsets, idx)?;
+            render_bytes(slice(view.data, start, end)?)
+        }
+        V::Utf8View(view) => render_str(&bytes_view_value(view, idx)?),
+        V::BinaryView(view) => render_bytes(&bytes_view_value(view, idx)?),
+        V::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            render_bytes(slice(view.data, idx * n, (idx + 1) * n)?)
+        }
+        V::Struct(view) => {
+            let mut fields = Vec::new();
+            for (meta, child) in &view.fields {
+                fields.push(format!("{}: {}", meta.name, render(child, idx)?));
+            }
+            format!("{{{}}}", fields.join(", "))
+        }
+        V::List(view) => render_items(&view.elements, range(view.offsets, idx)?)?,
+        V::LargeList(view) => render_items(&view.elements, range(view.offsets, idx)?)?,
+        V::FixedSizeList(view) => {
+            let n = usize::try_from(view.n)?;
+            render_items(&view.elements, (idx * n, (idx + 1) * n))?
+        }
+        V::Map(view) => {
+            let (start, end) = range(view.offsets, idx)?;
+            let mut entries = Vec::new();
+            for idx in start..end {
+                let key = render(&view.keys, idx)?;
+                let value = render(&view.values, idx)?;
+                entries.push(format!("{key}: {value}"));
+            }
+            format!("{{{}}}", entries.join(", "))
+        }
+        V::Dictionary(view) => match index(&view.keys, idx)? {
+            Some(key) => render(&view.values, key)?,
+            None => null(),
+        },
+        V::RunEndEncoded(view) => render(&view.values, physical_index(&view.run_ends, idx)?)?,
+        V::Union(view) => {
+            let (type_id, child, child_idx) = union_child(view, idx)?;
+            let name = view
+                .fields
+                .iter()
+                .find(|(id, _, _)| *id == type_id)
+                .map(|(_, meta, _)| meta.name.as_str())
+                .unwrap_or_default();
+            format!("{{{name}: {}}}", render(child, child_idx)?)
+        }
+    };
+    Ok(res)
+}
+
+fn render_items(view: &View<'_>, (start, end): (usize, usize)) -> Result<String> {
+    let mut items = Vec::new();
+    for idx in start..end {
+        items.push(render(view, idx)?);
+    }
+    Ok(format!("[{}]", items.join(", ")))
+}
+
+fn slice(data: &[u8], start: usize, end: usize) -> Result<&[u8]> {
+    let Some(data) = data.get(start..end) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Bytes {start}..{end} are out of bounds for {len} bytes",
+            len = data.len(),
+        );
+    };
+    Ok(data)
+}
+
+#[cfg(test)]
+fn list(offsets: Vec<i32>, values: Vec<i64>) -> crate::array::Array {
+    use crate::{
+        array::{Array, ListArray, PrimitiveArray},
+        datatypes::meta,
+    };
+
+    Array::List(ListArray {
+        validity: None,
+        offsets,
+        meta: meta("item", false),
+        elements: Box::new(Array::Int64(PrimitiveArray {
+            validity: None,
+            values,
+        })),
+    })
+}
+
+#[cfg(test)]
+fn batch(b: crate::array::Array) -> crate::array::Array {
+    use crate::{
+        array::{Array, PrimitiveArray, StructArray},
+        datatypes::meta,
+    };
+
+    Array::Struct(StructArray {
+        len: 2,
+        validity: None,
+        fields: vec![
+            (
+                meta("a", false),
+                Array::Int32(PrimitiveArray::from(vec![1, 2])),
+            ),
+            (
+                meta("b", false),
+                Array::Struct(StructArray {
+                    len: 2,
+                    validity: None,
+                    fields: vec![(meta("list", false), b)],
+                }),
+            ),
+        ],
+    })
+}
+
+#[test]
+fn nested_paths() {
+    // the same logical values with different offsets are equal
+    let expected = batch(list(vec![0, 2, 5], vec![1, 2, 3, 4, 5]));
+    let actual = batch(list(vec![3, 5, 8], vec![0, 0, 0, 1, 2, 3, 4, 5]));
+    assert_eq!(diff_views(&expected.as_view(), &actual.as_view()), None);
+    crate::assert_views_eq!(expected.as_view(), actual.as_view());
+
+    let actual = batch(list(vec![0, 2, 4], vec![1, 2, 3, 7]));
+    let diff = diff_views(&expected.as_view(), &actual.as_view()).unwrap();
+    assert_eq!(
+        diff.to_string(),
+        concat!(
+            "b[1].list: lengths differ, expected 3, actual 2\n",
+            "b[1].list[1]: expected 4, actual 7\n",
+        ),
+    );
+}
+
+#[test]
+fn nulls_and_limits() {
+    use crate::array::{Array, PrimitiveArray};
+
+    let expected = Array::Int64(PrimitiveArray {
+        validity: Some(vec![0b_0111]),
+        values: vec![1, 2, 3, 4],
+    });
+    let actual = Array::Int64(PrimitiveArray {
+        validity: Some(vec![0b_1010]),
+        values: vec![5, 2, 6, 4],
+    });
+    let diff = diff_views(&expected.as_view(), &actual.as_view()).unwrap();
+    assert_eq!(
+        diff.to_string(),
+        concat!(
+            "[0]: expected 1, actual null\n",
+            "[2]: expected 3, actual null\n",
+            "[3]: expected null, actual 4\n",
+        ),
+    );
+
+    let diff = diff_views_with_limit(&expected.as_view(), &actual.as_view(), 1).unwrap();
+    assert_eq!(diff.differences.len(), 1);
+    assert!(diff.truncated);
+}
+
+#[test]
+fn structural_differences() {
+    use std::collections::HashMap;
+
+    use crate::{
+        array::{Array, PrimitiveArray},
+        datatypes::DataType,
+    };
+
+    let expected = Array::Int64(PrimitiveArray::from(vec![1, 2]));
+    let actual = Array::Int32(PrimitiveArray::from(vec![1, 2]));
+    assert_eq!(
+        diff_views(&expected.as_view(), &actual.as_view())
+            .unwrap()
+            .differences,
+        vec![Difference {
+            path: String::new(),
+            kind: DifferenceKind::DataType {
+                expected: DataType::Int64,
+                actual: DataType::Int32,
+            },
+        }],
+    );
+
+    let expected = batch(list(vec![0, 1, 2], vec![1, 2]));
+    let Array::Struct(mut actual) = expected.clone() else {
+        unreachable!();
+    };
+    actual.fields[0].0.metadata = HashMap::from([(String::from("key"), String::from("value"))]);
+    let actual = Array::Struct(actual);
+
+    let diff = diff_views(&expected.as_view(), &actual.as_view()).unwrap();
+    assert_eq!(diff.differences.len(), 1);
+    assert_eq!(diff.differences[0].path, "a");
+    assert!(matches!(
+        diff.differences[0].kind,
+        DifferenceKind::Field { .. }
+    ));
+}
+
+#[test]
+fn dictionaries_are_compared_by_value() {
+    use crate::array::{Array, DictionaryArray, PrimitiveArray};
+
+    let dictionary = |keys: Vec<i8>, values: Vec<&str>| {
+        Array::Dictionary(DictionaryArray {
+            keys: Box::new(Array::Int8(PrimitiveArray::from(keys))),
+            values: Box::new(Array::Utf8(values.into_iter().map(Some).collect())),
+        })
+    };
+    let expected = dictionary(vec![0, 1, 0], vec!["a", "b"]);
+    let actual = dictionary(vec![1, 0, 1], vec!["b", "a"]);
+    assert_eq!(diff_views(&expected.as_view(), &actual.as_view()), None);
+
+    let actual = dictionary(vec![1, 0, 0], vec!["b", "a"]);
+    let diff = diff_views(&expected.as_view(), &actual.as_view()).unwrap();
+    assert_eq!(diff.to_string(), "[2]: expected \"a\", actual \"b\"\n");
+}
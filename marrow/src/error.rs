@@ -164,6 +164,12 @@ impl From<std::num::TryFromIntError> for MarrowError {
     }
 }
 
+impl From<std::str::Utf8Error> for MarrowError {
+    fn from(err: std::str::Utf8Error) -> MarrowError {
+        MarrowError::with_cause(ErrorKind::ParseError, format!("Utf8Error: {err}"), err)
+    }
+}
+
 impl From<bytemuck::PodCastError> for MarrowError {
     fn from(err: bytemuck::PodCastError) -> Self {
         let err = match err {
@@ -180,3 +186,28 @@ impl From<bytemuck::PodCastError> for MarrowError {
         )
     }
 }
+
+#[cfg(feature = "integration-json")]
+impl From<serde_json::Error> for MarrowError {
+    fn from(err: serde_json::Error) -> Self {
+        MarrowError::with_cause(
+            ErrorKind::ParseError,
+            format!("serde_json::Error: {err}"),
+            err,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for MarrowError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        MarrowError::new(ErrorKind::Unsupported, msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for MarrowError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        MarrowError::new(ErrorKind::ParseError, msg.to_string())
+    }
+}
@@ -4,12 +4,18 @@ use std::sync::Arc;
 use half::f16;
 
 use crate::{
-    array::Array,
+    array::{
+        view_len, Array, BooleanArray, BytesArray, DecimalArray, DictionaryArray, FixedSizeListArray,
+        ListArray, MapArray, NullArray, PrimitiveArray, RunEndEncodedArray, StructArray,
+        TimeArray, TimestampArray, UnionArray,
+    },
     datatypes::{
-        meta_from_field, DataType, Field, FieldMeta, IntervalUnit, RunEndEncodedMeta, TimeUnit,
-        UnionMode,
+        meta_from_field, DataType, DictionaryMeta, Field, FieldMeta, IntervalUnit,
+        RunEndEncodedMeta, TimeUnit, UnionMode,
     },
     error::{fail, ErrorKind, MarrowError, Result},
+    interop::{ForeignView, Owner},
+    shared::SharedArray,
     view::{
         BitsWithOffset, BooleanView, BytesView, DecimalView, DictionaryView, FixedSizeListView,
         ListView, MapView, NullView, PrimitiveView, RunEndEncodedView, StructView, TimeView,
@@ -99,11 +105,17 @@ impl TryFrom<&arrow_schema::Field> for Field {
     type Error = MarrowError;
 
     fn try_from(field: &arrow_schema::Field) -> Result<Self> {
+        #[allow(deprecated)]
+        let dictionary = DictionaryMeta {
+            ordered: field.dict_is_ordered().unwrap_or_default(),
+            id: field.dict_id().filter(|id| *id != 0),
+        };
         Ok(Field {
             name: field.name().to_owned(),
             data_type: DataType::try_from(field.data_type())?,
             metadata: field.metadata().clone(),
             nullable: field.is_nullable(),
+            dictionary,
         })
     }
 }
@@ -182,16 +194,38 @@ impl TryFrom<&Field> for arrow_schema::Field {
     type Error = MarrowError;
 
     fn try_from(value: &Field) -> Result<Self> {
-        let mut field = arrow_schema::Field::new(
+        let mut field = new_field(
             &value.name,
             arrow_schema::DataType::try_from(&value.data_type)?,
             value.nullable,
+            &value.dictionary,
         );
         field.set_metadata(value.metadata.clone());
         Ok(field)
     }
 }
 
+/// Build a field, using the dictionary metadata for dictionary encoded types
+fn new_field(
+    name: &str,
+    data_type: arrow_schema::DataType,
+    nullable: bool,
+    dictionary: &DictionaryMeta,
+) -> arrow_schema::Field {
+    if matches!(data_type, arrow_schema::DataType::Dictionary(_, _)) {
+        #[allow(deprecated)]
+        arrow_schema::Field::new_dict(
+            name,
+            data_type,
+            nullable,
+            dictionary.id.unwrap_or_default(),
+            dictionary.ordered,
+        )
+    } else {
+        arrow_schema::Field::new(name, data_type, nullable)
+    }
+}
+
 /// Conversion to `arrow` time units (*requires one of the `arrow-{version}` features*)
 impl TryFrom<TimeUnit> for arrow_schema::TimeUnit {
     type Error = MarrowError;
@@ -253,6 +287,514 @@ impl TryFrom<Array> for Arc<dyn arrow_array::Array> {
     }
 }
 
+/// Conversion of views to `arrow` arrays (*requires one of the `arrow-{version}` features*)
+///
+/// The data referenced by the view is copied, see the conversion from views to [`Array`].
+impl TryFrom<View<'_>> for Arc<dyn arrow_array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: View<'_>) -> Result<Arc<dyn arrow_array::Array>> {
+        Array::try_from(value)?.try_into()
+    }
+}
+
+/// Hand the buffers referenced by a foreign view to `arrow` without copying
+///
+/// The buffers keep the owner of the view alive. Interval arrays are copied, as the alignment of
+/// their values differs between `arrow` versions.
+impl TryFrom<ForeignView<'_>> for Arc<dyn arrow_array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: ForeignView<'_>) -> Result<Arc<dyn arrow_array::Array>> {
+        Ok(arrow_array::make_array(data_from_foreign_view(
+            value.view(),
+            value.owner(),
+        )?))
+    }
+}
+
+/// Conversion from `arrow` arrays taking ownership (*requires one of the `arrow-{version}`
+/// features*)
+///
+/// Buffers that are not shared with other arrays, are not offset and were allocated with the
+/// layout of a `Vec` (e.g., arrays created by `marrow`) are moved into the result without copying.
+/// All other buffers are copied. Arrays of data types without support for moving buffers (e.g.,
+/// byte views) are copied via their [`View`].
+impl TryFrom<Arc<dyn arrow_array::Array>> for Array {
+    type Error = MarrowError;
+
+    fn try_from(array: Arc<dyn arrow_array::Array>) -> Result<Array> {
+        let data = array.to_data();
+        // release the references of the array to the buffers
+        drop(array);
+        array_from_data(data)
+    }
+}
+
+/// Conversion of shared arrays to `arrow` arrays (*requires one of the `arrow-{version}`
+/// features*)
+///
+/// The buffers are handed over without copying: they are moved if the array is neither shared nor
+/// sliced and reference the shared data otherwise.
+impl TryFrom<SharedArray> for Arc<dyn arrow_array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: SharedArray) -> Result<Arc<dyn arrow_array::Array>> {
+        value.hand_over()
+    }
+}
+
+/// Conversion from `arrow` arrays to shared arrays (*requires one of the `arrow-{version}`
+/// features*)
+///
+/// See the conversion of `arrow` arrays to [`Array`] for which buffers are copied.
+impl TryFrom<Arc<dyn arrow_array::Array>> for SharedArray {
+    type Error = MarrowError;
+
+    fn try_from(array: Arc<dyn arrow_array::Array>) -> Result<SharedArray> {
+        SharedArray::try_from(Array::try_from(array)?)
+    }
+}
+
+#[allow(clippy::clone_on_copy)]
+fn array_from_data(data: arrow_data::ArrayData) -> Result<Array> {
+    use arrow_schema::DataType as AT;
+    use Array as A;
+
+    match data.data_type() {
+        AT::Null
+        | AT::Boolean
+        | AT::Int8
+        | AT::Int16
+        | AT::Int32
+        | AT::Int64
+        | AT::UInt8
+        | AT::UInt16
+        | AT::UInt32
+        | AT::UInt64
+        | AT::Float16
+        | AT::Float32
+        | AT::Float64
+        | AT::Date32
+        | AT::Date64
+        | AT::Time32(_)
+        | AT::Time64(_)
+        | AT::Timestamp(_, _)
+        | AT::Duration(_)
+        | AT::Decimal128(_, _)
+        | AT::Utf8
+        | AT::LargeUtf8
+        | AT::Binary
+        | AT::LargeBinary
+        | AT::Struct(_)
+        | AT::List(_)
+        | AT::LargeList(_)
+        | AT::FixedSizeList(_, _)
+        | AT::Map(_, _)
+        | AT::Dictionary(_, _) => {}
+        AT::Union(_, _) => return union_from_data(data),
+        AT::RunEndEncoded(_, _) => return run_end_encoded_from_data(data),
+        _ => {
+            let array = arrow_array::make_array(data);
+            return Array::try_from(View::try_from(array.as_ref())?);
+        }
+    }
+
+    // take the parts out of the data to release its references to the buffers
+    let data_type = data.data_type().clone();
+    let len = data.len();
+    let offset = data.offset();
+    let nulls = data.nulls().cloned();
+    let mut buffers = data.buffers().to_vec().into_iter();
+    let mut children = data.child_data().to_vec().into_iter();
+    drop(data);
+
+    let validity = nulls.map(|nulls| {
+        let (offset, len) = (nulls.offset(), nulls.len());
+        bits_into_vec(nulls.into_inner().into_inner(), offset, len)
+    });
+
+    let mut next_buffer = || -> Result<arrow_buffer::Buffer> {
+        let Some(buffer) = buffers.next() else {
+            fail!(ErrorKind::Unsupported, "Missing buffer for {data_type}");
+        };
+        Ok(buffer)
+    };
+    let mut next_child = || -> Result<arrow_data::ArrayData> {
+        let Some(child) = children.next() else {
+            fail!(ErrorKind::Unsupported, "Missing child data for {data_type}");
+        };
+        Ok(child)
+    };
+
+    macro_rules! primitive {
+        ($variant:ident) => {
+            A::$variant(PrimitiveArray {
+                validity,
+                values: buffer_into_vec(next_buffer()?, offset, len),
+            })
+        };
+    }
+    macro_rules! time {
+        ($variant:ident, $unit:expr) => {
+            A::$variant(TimeArray {
+                unit: $unit.clone().try_into()?,
+                validity,
+                values: buffer_into_vec(next_buffer()?, offset, len),
+            })
+        };
+    }
+    macro_rules! bytes {
+        ($variant:ident) => {{
+            let offsets = buffer_into_vec(next_buffer()?, offset, len + 1);
+            let (offsets, start, end) = rebase_offsets(offsets)?;
+            A::$variant(BytesArray {
+                validity,
+                offsets,
+                data: buffer_into_vec(next_buffer()?, start, end - start),
+            })
+        }};
+    }
+    macro_rules! list {
+        ($variant:ident, $field:expr) => {{
+            let offsets = buffer_into_vec(next_buffer()?, offset, len + 1);
+            let (offsets, start, end) = rebase_offsets(offsets)?;
+            A::$variant(ListArray {
+                validity,
+                offsets,
+                meta: meta_from_field(Field::try_from($field.as_ref())?),
+                elements: Box::new(array_from_data(slice_data(
+                    next_child()?,
+                    start,
+                    end - start,
+                ))?),
+            })
+        }};
+    }
+
+    let array = match &data_type {
+        AT::Null => A::Null(NullArray { len }),
+        AT::Boolean => A::Boolean(BooleanArray {
+            len,
+            validity,
+            values: bits_into_vec(next_buffer()?, offset, len),
+        }),
+        AT::Int8 => primitive!(Int8),
+        AT::Int16 => primitive!(Int16),
+        AT::Int32 => primitive!(Int32),
+        AT::Int64 => primitive!(Int64),
+        AT::UInt8 => primitive!(UInt8),
+        AT::UInt16 => primitive!(UInt16),
+        AT::UInt32 => primitive!(UInt32),
+        AT::UInt64 => primitive!(UInt64),
+        AT::Float16 => {
+            type ArrowF16 =
+                <arrow_array::types::Float16Type as arrow_array::types::ArrowPrimitiveType>::Native;
+            let values = buffer_into_vec::<ArrowF16>(next_buffer()?, offset, len);
+            A::Float16(PrimitiveArray {
+                validity,
+                values: values
+                    .into_iter()
+                    .map(|v| f16::from_bits(v.to_bits()))
+                    .collect(),
+            })
+        }
+        AT::Float32 => primitive!(Float32),
+        AT::Float64 => primitive!(Float64),
+        AT::Date32 => primitive!(Date32),
+        AT::Date64 => primitive!(Date64),
+        AT::Time32(unit) => time!(Time32, unit),
+        AT::Time64(unit) => time!(Time64, unit),
+        AT::Duration(unit) => time!(Duration, unit),
+        AT::Timestamp(unit, timezone) => A::Timestamp(TimestampArray {
+            unit: unit.clone().try_into()?,
+            timezone: timezone.as_ref().map(|timezone| timezone.to_string()),
+            validity,
+            values: buffer_into_vec(next_buffer()?, offset, len),
+        }),
+        AT::Decimal128(precision, scale) => A::Decimal128(DecimalArray {
+            precision: *precision,
+            scale: *scale,
+            validity,
+            values: buffer_into_vec(next_buffer()?, offset, len),
+        }),
+        AT::Utf8 => bytes!(Utf8),
+        AT::LargeUtf8 => bytes!(LargeUtf8),
+        AT::Binary => bytes!(Binary),
+        AT::LargeBinary => bytes!(LargeBinary),
+        AT::List(field) => list!(List, field),
+        AT::LargeList(field) => list!(LargeList, field),
+        AT::FixedSizeList(field, n) => {
+            let size = usize::try_from(*n)?;
+            A::FixedSizeList(FixedSizeListArray {
+                len,
+                n: *n,
+                validity,
+                meta: meta_from_field(Field::try_from(field.as_ref())?),
+                elements: Box::new(array_from_data(slice_data(
+                    next_child()?,
+                    offset * size,
+                    len * size,
+                ))?),
+            })
+        }
+        AT::Struct(struct_fields) => {
+            let mut fields = Vec::new();
+            for field in struct_fields.iter() {
+                let mut child = next_child()?;
+                // NOTE: slicing struct data also slices the children
+                if child.len() != len {
+                    child = slice_data(child, offset, len);
+                }
+                fields.push((
+                    meta_from_field(Field::try_from(field.as_ref())?),
+                    array_from_data(child)?,
+                ));
+            }
+            A::Struct(StructArray {
+                len,
+                validity,
+                fields,
+            })
+        }
+        AT::Map(_, _) => {
+            let Some((entries_name, sorted)) = map_meta_from_data_type(&data_type) else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "invalid data type for map array: {data_type}"
+                );
+            };
+            let offsets = buffer_into_vec(next_buffer()?, offset, len + 1);
+            let (offsets, start, end) = rebase_offsets(offsets)?;
+            let entries = array_from_data(slice_data(next_child()?, start, end - start))?;
+
+            A::Map(MapArray::from_logical_array(
+                entries,
+                entries_name,
+                sorted,
+                validity,
+                offsets,
+            )?)
+        }
+        AT::Dictionary(key_type, _) => {
+            let keys = match key_type.as_ref() {
+                AT::Int8 => primitive!(Int8),
+                AT::Int16 => primitive!(Int16),
+                AT::Int32 => primitive!(Int32),
+                AT::Int64 => primitive!(Int64),
+                AT::UInt8 => primitive!(UInt8),
+                AT::UInt16 => primitive!(UInt16),
+                AT::UInt32 => primitive!(UInt32),
+                AT::UInt64 => primitive!(UInt64),
+                dt => fail!(
+                    ErrorKind::Unsupported,
+                    "Unsupported dictionary key type {dt}"
+                ),
+            };
+            // NOTE: the offset of dictionary arrays only applies to the keys
+            let values = array_from_data(next_child()?)?;
+            A::Dictionary(DictionaryArray {
+                keys: Box::new(keys),
+                values: Box::new(values),
+            })
+        }
+        dt => fail!(ErrorKind::Unsupported, "Cannot move arrays of type {dt}"),
+    };
+    Ok(array)
+}
+
+fn union_from_data(data: arrow_data::ArrayData) -> Result<Array> {
+    let arrow_schema::DataType::Union(union_fields, mode) = data.data_type() else {
+        fail!(ErrorKind::Unsupported, "Invalid data type for union array");
+    };
+    let (offset, len) = (data.offset(), data.len());
+    let buffers = data.buffers().to_vec();
+    let Some(types) = buffers.first() else {
+        fail!(ErrorKind::Unsupported, "Missing type ids for union array");
+    };
+    let types = buffer_into_vec::<i8>(types.clone(), offset, len);
+
+    let offsets = match mode {
+        arrow_schema::UnionMode::Dense => {
+            let Some(offsets) = buffers.get(1) else {
+                fail!(ErrorKind::Unsupported, "Dense unions must have an offset array");
+            };
+            Some(buffer_into_vec::<i32>(offsets.clone(), offset, len))
+        }
+        arrow_schema::UnionMode::Sparse => None,
+    };
+
+    let mut fields = Vec::new();
+    for ((type_id, field), child) in union_fields.iter().zip(data.child_data()) {
+        let mut child = child.clone();
+        // NOTE: the offset of dense unions only applies to the types and offsets
+        if offsets.is_none() && child.len() != len {
+            child = slice_data(child, offset, len);
+        }
+        fields.push((
+            type_id,
+            meta_from_field(Field::try_from(field.as_ref())?),
+            array_from_data(child)?,
+        ));
+    }
+    Ok(Array::Union(UnionArray {
+        types,
+        offsets,
+        fields,
+    }))
+}
+
+/// Convert run end encoded data, slicing the runs if the data is offset
+fn run_end_encoded_from_data(data: arrow_data::ArrayData) -> Result<Array> {
+    use arrow_schema::DataType as AT;
+
+    let AT::RunEndEncoded(run_ends_field, values_field) = data.data_type() else {
+        fail!(ErrorKind::Unsupported, "Invalid data type for run end encoded array");
+    };
+    let [run_ends, values] = data.child_data() else {
+        fail!(ErrorKind::Unsupported, "Run end encoded arrays must have two children");
+    };
+    let meta = RunEndEncodedMeta {
+        run_ends_name: run_ends_field.name().clone(),
+        values: meta_from_field(Field::try_from(values_field.as_ref())?),
+    };
+
+    macro_rules! run_ends {
+        ($variant:ident, $ty:ty) => {{
+            let run_ends = arrow_buffer::ScalarBuffer::<$ty>::new(
+                run_ends.buffers()[0].clone(),
+                run_ends.offset(),
+                run_ends.len(),
+            );
+            let run_ends = arrow_buffer::RunEndBuffer::new(run_ends, data.offset(), data.len());
+            let start = run_ends.get_start_physical_index();
+            let (run_ends, values_len) = if data.is_empty() {
+                (Vec::new(), 0)
+            } else {
+                let end = run_ends.get_end_physical_index();
+                let mut sliced = Vec::with_capacity(end + 1 - start);
+                for run_end in &run_ends.values()[start..=end] {
+                    let run_end = arrow_buffer::ArrowNativeType::as_usize(*run_end)
+                        .min(data.offset() + data.len());
+                    sliced.push(<$ty>::try_from(run_end - data.offset())?);
+                }
+                (sliced, end + 1 - start)
+            };
+            let run_ends = Array::$variant(PrimitiveArray {
+                validity: None,
+                values: run_ends,
+            });
+            let values = array_from_data(slice_data(values.clone(), start, values_len))?;
+            (run_ends, values)
+        }};
+    }
+
+    let (run_ends, values) = match run_ends.data_type() {
+        AT::Int16 => run_ends!(Int16, i16),
+        AT::Int32 => run_ends!(Int32, i32),
+        AT::Int64 => run_ends!(Int64, i64),
+        dt => fail!(ErrorKind::Unsupported, "Unsupported run end type {dt}"),
+    };
+    Ok(Array::RunEndEncoded(RunEndEncodedArray {
+        meta,
+        run_ends: Box::new(run_ends),
+        values: Box::new(values),
+    }))
+}
+
+/// Move the buffer into a vec if possible, copy the referenced elements otherwise
+fn buffer_into_vec<T: arrow_buffer::ArrowNativeType>(
+    buffer: arrow_buffer::Buffer,
+    offset: usize,
+    len: usize,
+) -> Vec<T> {
+    let buffer = if offset == 0 {
+        match buffer.into_vec::<T>() {
+            Ok(mut vec) if vec.len() >= len => {
+                vec.truncate(len);
+                return vec;
+            }
+            Ok(vec) => arrow_buffer::Buffer::from_vec(vec),
+            Err(buffer) => buffer,
+        }
+    } else {
+        buffer
+    };
+    arrow_buffer::ScalarBuffer::<T>::new(buffer, offset, len).to_vec()
+}
+
+/// Move the bits into a vec if possible, copy them otherwise
+///
+/// Bits with a non-zero offset are shifted into a new buffer.
+fn bits_into_vec(buffer: arrow_buffer::Buffer, offset: usize, len: usize) -> Vec<u8> {
+    let num_bytes = len / 8 + if len % 8 != 0 { 1 } else { 0 };
+    let buffer = if offset != 0 {
+        buffer.bit_slice(offset, len)
+    } else {
+        buffer
+    };
+    match buffer.into_vec::<u8>() {
+        Ok(mut vec) if vec.len() >= num_bytes => {
+            vec.truncate(num_bytes);
+            vec
+        }
+        Ok(vec) => vec,
+        Err(buffer) => buffer.as_slice()[..num_bytes].to_vec(),
+    }
+}
+
+/// Rebase the offsets to start at zero, returns the new offsets and the referenced range
+fn rebase_offsets<O: arrow_buffer::ArrowNativeType + std::ops::Sub<Output = O>>(
+    mut offsets: Vec<O>,
+) -> Result<(Vec<O>, usize, usize)> {
+    let (Some(&first), Some(&last)) = (offsets.first(), offsets.last()) else {
+        fail!(ErrorKind::Unsupported, "Offsets must not be empty");
+    };
+    let (Some(start), Some(end)) = (first.to_usize(), last.to_usize()) else {
+        fail!(ErrorKind::Unsupported, "Invalid offsets");
+    };
+    if start != 0 {
+        for offset in &mut offsets {
+            *offset = *offset - first;
+        }
+    }
+    Ok((offsets, start, end))
+}
+
+/// Slice the data if it does not cover exactly the given range
+fn slice_data(data: arrow_data::ArrayData, offset: usize, len: usize) -> arrow_data::ArrayData {
+    if offset == 0 && data.len() == len {
+        data
+    } else {
+        data.slice(offset, len)
+    }
+}
+
+impl crate::interop::BridgeSchema for arrow_schema::Schema {
+    fn to_marrow(&self) -> Result<(Vec<Field>, std::collections::HashMap<String, String>)> {
+        let mut fields = Vec::new();
+        for field in self.fields().iter() {
+            let field: &arrow_schema::Field = field;
+            fields.push(Field::try_from(field)?);
+        }
+        Ok((fields, self.metadata().clone()))
+    }
+
+    fn from_marrow(
+        fields: Vec<Field>,
+        metadata: std::collections::HashMap<String, String>,
+    ) -> Result<Self> {
+        let mut arrow_fields = Vec::new();
+        for field in &fields {
+            arrow_fields.push(arrow_schema::Field::try_from(field)?);
+        }
+        Ok(arrow_schema::Schema::new_with_metadata(
+            arrow_fields,
+            metadata,
+        ))
+    }
+}
+
 /// Conversion from `arrow` interval units (*requires one of the `arrow2-{version}` features*)
 impl TryFrom<arrow_schema::IntervalUnit> for IntervalUnit {
     type Error = MarrowError;
@@ -572,6 +1114,164 @@ fn build_array_data(value: Array) -> Result<arrow_data::ArrayData> {
     }
 }
 
+fn data_from_foreign_view(view: &View<'_>, owner: &Owner) -> Result<arrow_data::ArrayData> {
+    use View as V;
+
+    let data_type = arrow_schema::DataType::try_from(&view.data_type())?;
+    let len = view_len(view)?;
+    let data = arrow_data::ArrayData::builder(data_type.clone()).len(len);
+
+    macro_rules! primitive {
+        ($view:expr) => {
+            data.nulls(foreign_nulls($view.validity, len, owner)?)
+                .add_buffer(foreign_buffer($view.values, owner))
+        };
+    }
+    macro_rules! bytes {
+        ($view:expr) => {
+            data.nulls(foreign_nulls($view.validity, len, owner)?)
+                .add_buffer(foreign_buffer($view.offsets, owner))
+                .add_buffer(foreign_buffer($view.data, owner))
+        };
+    }
+    macro_rules! list {
+        ($view:expr) => {
+            data.nulls(foreign_nulls($view.validity, len, owner)?)
+                .add_buffer(foreign_buffer($view.offsets, owner))
+                .add_child_data(data_from_foreign_view(&$view.elements, owner)?)
+        };
+    }
+    macro_rules! bytes_view {
+        ($view:expr) => {
+            data.nulls(foreign_nulls($view.validity, len, owner)?)
+                .buffers(
+                    std::iter::once(foreign_buffer($view.data, owner))
+                        .chain($view.buffers.iter().map(|buffer| foreign_buffer(buffer, owner)))
+                        .collect(),
+                )
+        };
+    }
+
+    let data = match view {
+        V::Null(_) => return Ok(arrow_data::ArrayData::new_null(&data_type, len)),
+        V::Boolean(view) => data
+            .offset(view.values.offset)
+            .nulls(foreign_nulls(view.validity, len, owner)?)
+            .add_buffer(foreign_buffer(view.values.data, owner)),
+        V::Int8(view) => primitive!(view),
+        V::Int16(view) => primitive!(view),
+        V::Int32(view) => primitive!(view),
+        V::Int64(view) => primitive!(view),
+        V::UInt8(view) => primitive!(view),
+        V::UInt16(view) => primitive!(view),
+        V::UInt32(view) => primitive!(view),
+        V::UInt64(view) => primitive!(view),
+        V::Float16(view) => primitive!(view),
+        V::Float32(view) => primitive!(view),
+        V::Float64(view) => primitive!(view),
+        V::Date32(view) => primitive!(view),
+        V::Date64(view) => primitive!(view),
+        V::Time32(view) => primitive!(view),
+        V::Time64(view) => primitive!(view),
+        V::Duration(view) => primitive!(view),
+        V::Timestamp(view) => primitive!(view),
+        V::YearMonthInterval(view) => primitive!(view),
+        V::Decimal128(view) => primitive!(view),
+        V::DayTimeInterval(_) | V::MonthDayNanoInterval(_) => {
+            return build_array_data(Array::try_from(view.clone())?);
+        }
+        V::Utf8(view) => bytes!(view),
+        V::LargeUtf8(view) => bytes!(view),
+        V::Binary(view) => bytes!(view),
+        V::LargeBinary(view) => bytes!(view),
+        V::Utf8View(view) => bytes_view!(view),
+        V::BinaryView(view) => bytes_view!(view),
+        V::FixedSizeBinary(view) => data
+            .nulls(foreign_nulls(view.validity, len, owner)?)
+            .add_buffer(foreign_buffer(view.data, owner)),
+        V::List(view) => list!(view),
+        V::LargeList(view) => list!(view),
+        V::FixedSizeList(view) => data
+            .nulls(foreign_nulls(view.validity, len, owner)?)
+            .add_child_data(data_from_foreign_view(&view.elements, owner)?),
+        V::Struct(view) => {
+            let mut children = Vec::new();
+            for (_, field) in &view.fields {
+                children.push(data_from_foreign_view(field, owner)?);
+            }
+            data.nulls(foreign_nulls(view.validity, len, owner)?)
+                .child_data(children)
+        }
+        V::Map(view) => {
+            let arrow_schema::DataType::Map(entries_field, _) = &data_type else {
+                fail!(ErrorKind::Unsupported, "Invalid data type for map array");
+            };
+            let entries = arrow_data::ArrayData::builder(entries_field.data_type().clone())
+                .len(view_len(&view.keys)?)
+                .add_child_data(data_from_foreign_view(&view.keys, owner)?)
+                .add_child_data(data_from_foreign_view(&view.values, owner)?)
+                .build()?;
+            data.nulls(foreign_nulls(view.validity, len, owner)?)
+                .add_buffer(foreign_buffer(view.offsets, owner))
+                .add_child_data(entries)
+        }
+        V::Dictionary(view) => data_from_foreign_view(&view.keys, owner)?
+            .into_builder()
+            .data_type(data_type)
+            .child_data(vec![data_from_foreign_view(&view.values, owner)?]),
+        V::RunEndEncoded(view) => data
+            .add_child_data(data_from_foreign_view(&view.run_ends, owner)?)
+            .add_child_data(data_from_foreign_view(&view.values, owner)?),
+        V::Union(view) => {
+            let mut data = data.add_buffer(foreign_buffer(view.types, owner));
+            if let Some(offsets) = view.offsets {
+                data = data.add_buffer(foreign_buffer(offsets, owner));
+            }
+            let mut children = Vec::new();
+            for (_, _, field) in &view.fields {
+                children.push(data_from_foreign_view(field, owner)?);
+            }
+            data.child_data(children)
+        }
+    };
+    Ok(data.build()?)
+}
+
+/// Reference the data of a foreign view without copying it
+fn foreign_buffer<T: Copy>(data: &[T], owner: &Owner) -> arrow_buffer::Buffer {
+    let ptr = std::ptr::NonNull::from(data).cast::<u8>();
+    // SAFETY: the owner keeps the data alive, see `ForeignView::new`
+    unsafe {
+        arrow_buffer::Buffer::from_custom_allocation(
+            ptr,
+            std::mem::size_of_val(data),
+            Arc::new(owner.clone()),
+        )
+    }
+}
+
+fn foreign_nulls(
+    validity: Option<BitsWithOffset<'_>>,
+    len: usize,
+    owner: &Owner,
+) -> Result<Option<arrow_buffer::NullBuffer>> {
+    let Some(validity) = validity else {
+        return Ok(None);
+    };
+    if validity.data.len() * 8 < validity.offset + len {
+        fail!(
+            ErrorKind::Unsupported,
+            "Validity with {bytes} bytes cannot contain {len} bits starting at {offset}",
+            bytes = validity.data.len(),
+            offset = validity.offset,
+        );
+    }
+    let buffer = foreign_buffer(validity.data, owner);
+    Ok(Some(arrow_buffer::NullBuffer::new(
+        arrow_buffer::BooleanBuffer::new(buffer, validity.offset, len),
+    )))
+}
+
 fn get_ree_len_from_indices(indices: &Array) -> Result<usize> {
     let cand = match indices {
         Array::Int16(array) => array.values.last().copied().map(usize::try_from),
@@ -1013,8 +1713,13 @@ fn map_meta_from_data_type(data_type: &arrow_schema::DataType) -> Option<(String
 }
 
 fn field_from_data_and_meta(data: &arrow_data::ArrayData, meta: FieldMeta) -> arrow_schema::Field {
-    arrow_schema::Field::new(meta.name, data.data_type().clone(), meta.nullable)
-        .with_metadata(meta.metadata)
+    new_field(
+        &meta.name,
+        data.data_type().clone(),
+        meta.nullable,
+        &meta.dictionary,
+    )
+    .with_metadata(meta.metadata)
 }
 
 fn primitive_into_data<T: arrow_buffer::ArrowNativeType>(
@@ -1101,6 +1806,14 @@ where
             "Nullable run ends are not supported"
         );
     }
+    // the run ends of views are positions in the array and cannot express an offset, truncated
+    // arrays (e.g., the children of structs) are viewed including the trailing elements
+    if array.run_ends().offset() != 0 {
+        fail!(
+            ErrorKind::Unsupported,
+            "Run end encoded arrays with an offset cannot be viewed, convert them into owned arrays"
+        );
+    }
 
     let run_ends = wrap(PrimitiveView {
         validity: None,
@@ -1,9 +1,18 @@
 use std::borrow::Cow;
 
 use crate::{
-    array::{Array, PrimitiveArray},
-    datatypes::{meta_from_field, DataType, Field, FieldMeta, IntervalUnit, TimeUnit, UnionMode},
+    array::{
+        Array, BooleanArray, BytesArray, DecimalArray, ListArray, PrimitiveArray, TimeArray,
+        TimestampArray,
+    },
+    datatypes::{
+        meta_from_field, DataType, DictionaryMeta, Field, FieldMeta, IntervalUnit, TimeUnit,
+        UnionMode,
+    },
     error::{fail, ErrorKind, MarrowError, Result},
+    interop::{FfiArray, ForeignView},
+    shared::SharedArray,
+    types::{DayTimeInterval, MonthDayNanoInterval},
     view::{
         BitsWithOffset, BooleanView, BytesView, DecimalView, DictionaryView, FixedSizeBinaryView,
         FixedSizeListView, ListView, MapView, NullView, PrimitiveView, StructView, TimeView,
@@ -122,11 +131,16 @@ impl TryFrom<&arrow2::datatypes::Field> for Field {
     type Error = MarrowError;
 
     fn try_from(field: &arrow2::datatypes::Field) -> Result<Self> {
+        let ordered = matches!(
+            field.data_type,
+            arrow2::datatypes::DataType::Dictionary(_, _, true)
+        );
         Ok(Field {
             name: field.name.to_owned(),
             data_type: DataType::try_from(&field.data_type)?,
             nullable: field.is_nullable,
             metadata: field.metadata.clone().into_iter().collect(),
+            dictionary: DictionaryMeta { ordered, id: None },
         })
     }
 }
@@ -266,9 +280,13 @@ impl TryFrom<&Field> for arrow2::datatypes::Field {
     type Error = MarrowError;
 
     fn try_from(value: &Field) -> Result<Self> {
+        let mut data_type = arrow2::datatypes::DataType::try_from(&value.data_type)?;
+        if let arrow2::datatypes::DataType::Dictionary(_, _, sorted) = &mut data_type {
+            *sorted = value.dictionary.ordered;
+        }
         Ok(arrow2::datatypes::Field {
             name: value.name.to_owned(),
-            data_type: arrow2::datatypes::DataType::try_from(&value.data_type)?,
+            data_type,
             is_nullable: value.nullable,
             metadata: value.metadata.clone().into_iter().collect(),
         })
@@ -353,6 +371,294 @@ impl TryFrom<IntervalUnit> for arrow2::datatypes::IntervalUnit {
     }
 }
 
+impl crate::interop::BridgeSchema for arrow2::datatypes::Schema {
+    fn to_marrow(&self) -> Result<(Vec<Field>, std::collections::HashMap<String, String>)> {
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            fields.push(Field::try_from(field)?);
+        }
+        Ok((fields, self.metadata.clone().into_iter().collect()))
+    }
+
+    fn from_marrow(
+        fields: Vec<Field>,
+        metadata: std::collections::HashMap<String, String>,
+    ) -> Result<Self> {
+        let mut arrow2_fields = Vec::new();
+        for field in &fields {
+            arrow2_fields.push(arrow2::datatypes::Field::try_from(field)?);
+        }
+        Ok(arrow2::datatypes::Schema {
+            fields: arrow2_fields,
+            metadata: metadata.into_iter().collect(),
+        })
+    }
+}
+
+/// Conversion of views to `arrow2` arrays (*requires one of the `arrow2-{version}` features*)
+///
+/// The data referenced by the view is copied, see the conversion from views to [`Array`].
+impl TryFrom<View<'_>> for Box<dyn arrow2::array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: View<'_>) -> Result<Self> {
+        Array::try_from(value)?.try_into()
+    }
+}
+
+/// Hand the buffers referenced by a foreign view to `arrow2` without copying
+///
+/// The view is exported via the C data interface and imported by `arrow2`. The imported buffers
+/// keep the owner of the view alive.
+impl TryFrom<ForeignView<'_>> for Box<dyn arrow2::array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: ForeignView<'_>) -> Result<Self> {
+        let data_type = arrow2::datatypes::DataType::try_from(&value.view().data_type())?;
+        let array = FfiArray::try_from(&value)?;
+        // SAFETY: `FfiArray` has the same layout as `arrow2::ffi::ArrowArray`. The exported array
+        // is valid for the data type and keeps the referenced data alive until it is released.
+        unsafe {
+            let array = std::mem::transmute::<FfiArray, arrow2::ffi::ArrowArray>(array);
+            Ok(arrow2::ffi::import_array_from_c(array, data_type)?)
+        }
+    }
+}
+
+/// Conversion from `arrow2` arrays taking ownership (*requires one of the `arrow2-{version}`
+/// features*)
+///
+/// The values of primitive arrays, the offsets and data of bytes arrays and the offsets of lists
+/// are moved without copying, if they are not shared with other arrays and the arrays are not
+/// sliced. The elements of lists are converted recursively. All other data, e.g., validity bitmaps
+/// or the children of structs, is copied, see the conversion from views to [`Array`].
+impl TryFrom<Box<dyn arrow2::array::Array>> for Array {
+    type Error = MarrowError;
+
+    fn try_from(array: Box<dyn arrow2::array::Array>) -> Result<Array> {
+        use {arrow2::datatypes::DataType as AT, Array as A};
+
+        macro_rules! primitive {
+            ($variant:ident) => {{
+                let (validity, values) = take_primitive_parts(array)?;
+                A::$variant(PrimitiveArray { validity, values })
+            }};
+        }
+        macro_rules! bytes {
+            ($variant:ident, $array:ty) => {{
+                let Some(typed) = array.as_any().downcast_ref::<$array>() else {
+                    fail!(ErrorKind::Unsupported, "Unexpected array type for {dt:?}", dt = array.data_type());
+                };
+                let validity = typed.validity().cloned();
+                let offsets = typed.offsets().buffer().clone();
+                let data = typed.values().clone();
+                // release the references of the array to the buffers
+                drop(array);
+                A::$variant(bytes_from_parts(validity, offsets, data)?)
+            }};
+        }
+        macro_rules! list {
+            ($variant:ident, $array:ty, $field:expr) => {{
+                let meta = meta_from_field($field.as_ref().try_into()?);
+                let Some(typed) = array.as_any().downcast_ref::<$array>() else {
+                    fail!(ErrorKind::Unsupported, "Unexpected array type for {dt:?}", dt = array.data_type());
+                };
+                let validity = typed.validity().cloned();
+                let offsets = typed.offsets().buffer().clone();
+                let elements = typed.values().clone();
+                // release the references of the array to the buffers
+                drop(array);
+                A::$variant(list_from_parts(validity, offsets, meta, elements)?)
+            }};
+        }
+        macro_rules! time {
+            ($variant:ident, $unit:expr) => {{
+                let unit = (*$unit).try_into()?;
+                let (validity, values) = take_primitive_parts(array)?;
+                A::$variant(TimeArray {
+                    unit,
+                    validity,
+                    values,
+                })
+            }};
+        }
+
+        let array = match array.data_type() {
+            AT::Boolean => {
+                let Some(typed) = array.as_any().downcast_ref::<arrow2::array::BooleanArray>() else {
+                    fail!(ErrorKind::Unsupported, "Unexpected array type for {dt:?}", dt = array.data_type());
+                };
+                let len = typed.len();
+                let validity = typed.validity().cloned();
+                let values = typed.values().clone();
+                // release the references of the array to the buffers
+                drop(array);
+                A::Boolean(BooleanArray {
+                    len,
+                    validity: bitmap_into_vec(validity)?,
+                    values: bits_into_vec(values)?,
+                })
+            }
+            AT::Int8 => primitive!(Int8),
+            AT::Int16 => primitive!(Int16),
+            AT::Int32 => primitive!(Int32),
+            AT::Int64 => primitive!(Int64),
+            AT::UInt8 => primitive!(UInt8),
+            AT::UInt16 => primitive!(UInt16),
+            AT::UInt32 => primitive!(UInt32),
+            AT::UInt64 => primitive!(UInt64),
+            AT::Float32 => primitive!(Float32),
+            AT::Float64 => primitive!(Float64),
+            AT::Date32 => primitive!(Date32),
+            AT::Date64 => primitive!(Date64),
+            AT::Time32(unit) => time!(Time32, unit),
+            AT::Time64(unit) => time!(Time64, unit),
+            AT::Duration(unit) => time!(Duration, unit),
+            AT::Timestamp(unit, timezone) => {
+                let unit = (*unit).try_into()?;
+                let timezone = timezone.to_owned();
+                let (validity, values) = take_primitive_parts(array)?;
+                A::Timestamp(TimestampArray {
+                    unit,
+                    timezone,
+                    validity,
+                    values,
+                })
+            }
+            AT::Decimal(precision, scale) => {
+                let precision = (*precision).try_into()?;
+                let scale = (*scale).try_into()?;
+                let (validity, values) = take_primitive_parts(array)?;
+                A::Decimal128(DecimalArray {
+                    precision,
+                    scale,
+                    validity,
+                    values,
+                })
+            }
+            AT::Utf8 => bytes!(Utf8, arrow2::array::Utf8Array<i32>),
+            AT::LargeUtf8 => bytes!(LargeUtf8, arrow2::array::Utf8Array<i64>),
+            AT::Binary => bytes!(Binary, arrow2::array::BinaryArray<i32>),
+            AT::LargeBinary => bytes!(LargeBinary, arrow2::array::BinaryArray<i64>),
+            AT::List(field) => list!(List, arrow2::array::ListArray<i32>, field),
+            AT::LargeList(field) => list!(LargeList, arrow2::array::ListArray<i64>, field),
+            _ => Array::try_from(View::try_from(array.as_ref())?)?,
+        };
+        Ok(array)
+    }
+}
+
+/// Conversion of shared arrays to `arrow2` arrays (*requires one of the `arrow2-{version}`
+/// features*)
+///
+/// The buffers are handed over without copying: they are moved if the array is neither shared nor
+/// sliced and reference the shared data otherwise.
+impl TryFrom<SharedArray> for Box<dyn arrow2::array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: SharedArray) -> Result<Self> {
+        value.hand_over()
+    }
+}
+
+/// Conversion from `arrow2` arrays to shared arrays (*requires one of the `arrow2-{version}`
+/// features*)
+///
+/// See the conversion of `arrow2` arrays to [`Array`] for which buffers are copied.
+impl TryFrom<Box<dyn arrow2::array::Array>> for SharedArray {
+    type Error = MarrowError;
+
+    fn try_from(array: Box<dyn arrow2::array::Array>) -> Result<SharedArray> {
+        SharedArray::try_from(Array::try_from(array)?)
+    }
+}
+
+/// Take the validity and values out of a primitive array, moving the values if possible
+fn take_primitive_parts<T: arrow2::types::NativeType>(
+    array: Box<dyn arrow2::array::Array>,
+) -> Result<(Option<Vec<u8>>, Vec<T>)> {
+    let Some(typed) = array
+        .as_any()
+        .downcast_ref::<arrow2::array::PrimitiveArray<T>>()
+        .cloned()
+    else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Unexpected array type for {dt:?}",
+            dt = array.data_type()
+        );
+    };
+    // release the reference of the boxed array to the buffers
+    drop(array);
+
+    let (_, values, validity) = typed.into_inner();
+    Ok((bitmap_into_vec(validity)?, buffer_into_vec(values)))
+}
+
+/// Build a bytes array from the buffers of an `arrow2` array, moving them if possible
+fn bytes_from_parts<O: arrow2::types::Offset>(
+    validity: Option<arrow2::bitmap::Bitmap>,
+    offsets: arrow2::buffer::Buffer<O>,
+    data: arrow2::buffer::Buffer<u8>,
+) -> Result<BytesArray<O>> {
+    let validity = bitmap_into_vec(validity)?;
+    let start = offsets.first().map_or(0, |offset| offset.to_usize());
+    let end = offsets.last().map_or(0, |offset| offset.to_usize());
+
+    if start != 0 {
+        // sliced arrays are rebased to start at 0, copying the referenced data
+        return Ok(BytesArray {
+            validity,
+            offsets: offsets.iter().map(|&offset| offset - offsets[0]).collect(),
+            data: data[start..end].to_vec(),
+        });
+    }
+
+    let mut data = buffer_into_vec(data);
+    data.truncate(end);
+    Ok(BytesArray {
+        validity,
+        offsets: buffer_into_vec(offsets),
+        data,
+    })
+}
+
+/// Build a list array from the parts of an `arrow2` array, moving the offsets if possible
+fn list_from_parts<O: arrow2::types::Offset>(
+    validity: Option<arrow2::bitmap::Bitmap>,
+    offsets: arrow2::buffer::Buffer<O>,
+    meta: FieldMeta,
+    elements: Box<dyn arrow2::array::Array>,
+) -> Result<ListArray<O>> {
+    let validity = bitmap_into_vec(validity)?;
+    let start = offsets.first().map_or(0, |offset| offset.to_usize());
+    let end = offsets.last().map_or(0, |offset| offset.to_usize());
+
+    let offsets = if start != 0 {
+        // sliced arrays are rebased to start at 0
+        offsets.iter().map(|&offset| offset - offsets[0]).collect()
+    } else {
+        buffer_into_vec(offsets)
+    };
+    let elements = if start != 0 || end != elements.len() {
+        slice_array(elements, start, end - start)
+    } else {
+        elements
+    };
+
+    Ok(ListArray {
+        validity,
+        offsets,
+        meta,
+        elements: Box::new(Array::try_from(elements)?),
+    })
+}
+
+/// Convert an optional bitmap into a vector starting at offset 0, moving the bits if possible
+fn bitmap_into_vec(bitmap: Option<arrow2::bitmap::Bitmap>) -> Result<Option<Vec<u8>>> {
+    bitmap.map(bits_into_vec).transpose()
+}
+
 /// Conversion to `arrow2` arrays (*requires one of the `arrow2-{version}` features*)
 impl TryFrom<Array> for Box<dyn arrow2::array::Array> {
     type Error = MarrowError;
@@ -526,9 +832,26 @@ impl TryFrom<Array> for Box<dyn arrow2::array::Array> {
                     validity,
                 )?))
             }
-            A::YearMonthInterval(_) | A::DayTimeInterval(_) | A::MonthDayNanoInterval(_) => fail!(
-                ErrorKind::Unsupported,
-                "Interval arrays are not supported for arrow2"
+            A::YearMonthInterval(arr) => build_primitive_array(
+                AT::Interval(arrow2::datatypes::IntervalUnit::YearMonth),
+                arr.values,
+                arr.validity,
+            ),
+            A::DayTimeInterval(arr) => build_primitive_array(
+                AT::Interval(arrow2::datatypes::IntervalUnit::DayTime),
+                arr.values
+                    .into_iter()
+                    .map(|v| arrow2::types::days_ms::new(v.days, v.milliseconds))
+                    .collect(),
+                arr.validity,
+            ),
+            A::MonthDayNanoInterval(arr) => build_primitive_array(
+                AT::Interval(arrow2::datatypes::IntervalUnit::MonthDayNano),
+                arr.values
+                    .into_iter()
+                    .map(|v| arrow2::types::months_days_ns::new(v.months, v.days, v.nanoseconds))
+                    .collect(),
+                arr.validity,
             ),
             A::Utf8View(_) | A::BinaryView(_) => fail!(
                 ErrorKind::Unsupported,
@@ -686,6 +1009,9 @@ impl<'a> TryFrom<&'a dyn arrow2::array::Array> for View<'a> {
             match array.data_type() {
                 AT::Int32 => Ok(V::Int32(view_primitive_array(array))),
                 AT::Date32 => Ok(V::Date32(view_primitive_array(array))),
+                AT::Interval(arrow2::datatypes::IntervalUnit::YearMonth) => {
+                    Ok(V::YearMonthInterval(view_primitive_array(array)))
+                }
                 AT::Time32(unit) => Ok(V::Time32(TimeView {
                     unit: (*unit).try_into()?,
                     validity: bits_with_offset_from_bitmap(array.validity()),
@@ -751,6 +1077,24 @@ impl<'a> TryFrom<&'a dyn arrow2::array::Array> for View<'a> {
                 ),
                 validity: bits_with_offset_from_bitmap(array.validity()),
             }))
+        } else if let Some(array) =
+            any.downcast_ref::<arrow2::array::PrimitiveArray<arrow2::types::days_ms>>()
+        {
+            Ok(V::DayTimeInterval(PrimitiveView {
+                values: bytemuck::cast_slice::<arrow2::types::days_ms, DayTimeInterval>(
+                    array.values().as_slice(),
+                ),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
+        } else if let Some(array) =
+            any.downcast_ref::<arrow2::array::PrimitiveArray<arrow2::types::months_days_ns>>()
+        {
+            Ok(V::MonthDayNanoInterval(PrimitiveView {
+                values: bytemuck::cast_slice::<arrow2::types::months_days_ns, MonthDayNanoInterval>(
+                    array.values().as_slice(),
+                ),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
         } else if let Some(array) = any.downcast_ref::<arrow2::array::PrimitiveArray<f32>>() {
             Ok(V::Float32(view_primitive_array(array)))
         } else if let Some(array) = any.downcast_ref::<arrow2::array::PrimitiveArray<f64>>() {
@@ -988,3 +1332,226 @@ fn bits_with_offset_from_bitmap(
     let (data, offset, _) = bitmap?.as_slice();
     Some(BitsWithOffset { data, offset })
 }
+
+#[test]
+fn sorted_dictionaries() {
+    let field: Field = "category: nullable ordered Dictionary<Int16, Utf8>"
+        .parse()
+        .unwrap();
+    let arrow2_field = arrow2::datatypes::Field::try_from(&field).unwrap();
+    assert!(matches!(
+        arrow2_field.data_type,
+        arrow2::datatypes::DataType::Dictionary(arrow2::datatypes::IntegerType::Int16, _, true),
+    ));
+    assert_eq!(Field::try_from(&arrow2_field).unwrap(), field);
+}
+
+#[test]
+fn interval_arrays_roundtrip() {
+    let arrays = [
+        Array::YearMonthInterval(PrimitiveArray {
+            validity: Some(vec![0b101]),
+            values: vec![1, 0, -3],
+        }),
+        Array::DayTimeInterval(PrimitiveArray {
+            validity: None,
+            values: vec![
+                DayTimeInterval {
+                    days: 1,
+                    milliseconds: 2,
+                },
+                DayTimeInterval {
+                    days: -3,
+                    milliseconds: 4,
+                },
+            ],
+        }),
+        Array::MonthDayNanoInterval(PrimitiveArray {
+            validity: Some(vec![0b10]),
+            values: vec![
+                MonthDayNanoInterval {
+                    months: 0,
+                    days: 0,
+                    nanoseconds: 0,
+                },
+                MonthDayNanoInterval {
+                    months: 1,
+                    days: -2,
+                    nanoseconds: 3_000_000_000,
+                },
+            ],
+        }),
+    ];
+
+    for array in arrays {
+        let arrow2_array = Box::<dyn arrow2::array::Array>::try_from(array.clone()).unwrap();
+        assert_eq!(
+            DataType::try_from(arrow2_array.data_type()).unwrap(),
+            array.data_type()
+        );
+        assert_eq!(
+            View::try_from(arrow2_array.as_ref()).unwrap(),
+            array.as_view()
+        );
+    }
+}
+
+#[test]
+fn sliced_views_into_arrays() {
+    let views = [
+        View::Int32(PrimitiveView {
+            validity: Some(BitsWithOffset {
+                offset: 3,
+                data: &[0b_0010_1000],
+            }),
+            values: &[1, 0, 3],
+        }),
+        View::Utf8(BytesView {
+            validity: Some(BitsWithOffset {
+                offset: 5,
+                data: &[0b_1010_0000],
+            }),
+            offsets: &[3, 6, 6, 8],
+            data: b"...foobaz",
+        }),
+        View::Boolean(BooleanView {
+            len: 3,
+            validity: None,
+            values: BitsWithOffset {
+                offset: 7,
+                data: &[0b_1000_0000, 0b_0000_0010],
+            },
+        }),
+    ];
+    let expected: [Box<dyn arrow2::array::Array>; 3] = [
+        Box::new(arrow2::array::Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+        ])),
+        Box::new(arrow2::array::Utf8Array::<i32>::from(vec![
+            Some("foo"),
+            None,
+            Some("ba"),
+        ])),
+        Box::new(arrow2::array::BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+        ])),
+    ];
+
+    for (view, expected) in std::iter::zip(views, expected) {
+        let actual = Box::<dyn arrow2::array::Array>::try_from(view).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn owned_arrays_into_arrays() {
+    let values = vec![1_i64, 2, 3];
+    let ptr = values.as_ptr();
+    let array = Box::<dyn arrow2::array::Array>::try_from(Array::Int64(PrimitiveArray {
+        validity: Some(vec![0b101]),
+        values,
+    }))
+    .unwrap();
+
+    let Array::Int64(actual) = Array::try_from(array).unwrap() else {
+        panic!("Unexpected array type");
+    };
+    assert_eq!(actual.validity, Some(vec![0b101]));
+    assert_eq!(actual.values, vec![1, 2, 3]);
+    assert_eq!(actual.values.as_ptr(), ptr);
+
+    let arrays = [
+        Array::Timestamp(TimestampArray {
+            unit: TimeUnit::Millisecond,
+            timezone: Some(String::from("UTC")),
+            validity: None,
+            values: vec![1, 2, 3],
+        }),
+        Array::Utf8(crate::array::BytesArray {
+            validity: Some(vec![0b10]),
+            offsets: vec![0, 3, 6],
+            data: b"foobar".to_vec(),
+        }),
+    ];
+    for array in arrays {
+        let arrow2_array = Box::<dyn arrow2::array::Array>::try_from(array.clone()).unwrap();
+        // shared buffers are copied
+        let shared = arrow2_array.clone();
+        assert_eq!(Array::try_from(arrow2_array).unwrap(), array);
+        assert_eq!(View::try_from(shared.as_ref()).unwrap(), array.as_view());
+    }
+}
+
+#[test]
+fn owned_bytes_and_lists_are_moved() {
+    let offsets = vec![0_i32, 3, 3, 6];
+    let data = b"foobar".to_vec();
+    let (offsets_ptr, data_ptr) = (offsets.as_ptr(), data.as_ptr());
+    let array = Box::<dyn arrow2::array::Array>::try_from(Array::Utf8(BytesArray {
+        validity: Some(vec![0b101]),
+        offsets,
+        data,
+    }))
+    .unwrap();
+
+    let Array::Utf8(actual) = Array::try_from(array).unwrap() else {
+        panic!("Unexpected array type");
+    };
+    assert_eq!(actual.offsets, vec![0, 3, 3, 6]);
+    assert_eq!(actual.data, b"foobar");
+    assert_eq!(actual.offsets.as_ptr(), offsets_ptr);
+    assert_eq!(actual.data.as_ptr(), data_ptr);
+
+    let list = Array::LargeList(ListArray {
+        validity: None,
+        offsets: vec![0, 2, 2, 3],
+        meta: FieldMeta {
+            name: String::from("element"),
+            ..FieldMeta::default()
+        },
+        elements: Box::new(Array::Int32(PrimitiveArray {
+            validity: None,
+            values: vec![1, 2, 3],
+        })),
+    });
+    let array = Box::<dyn arrow2::array::Array>::try_from(list.clone()).unwrap();
+    let offsets_ptr = array
+        .as_any()
+        .downcast_ref::<arrow2::array::ListArray<i64>>()
+        .unwrap()
+        .offsets()
+        .buffer()
+        .as_ptr();
+
+    let Array::LargeList(actual) = Array::try_from(array.clone()).unwrap() else {
+        panic!("Unexpected array type");
+    };
+    assert_eq!(Array::LargeList(actual), list);
+
+    let Array::LargeList(actual) = Array::try_from(array).unwrap() else {
+        panic!("Unexpected array type");
+    };
+    assert_eq!(actual.offsets.as_ptr(), offsets_ptr);
+
+    // sliced arrays are rebased
+    let array = Box::<dyn arrow2::array::Array>::try_from(list).unwrap();
+    assert_eq!(
+        Array::try_from(slice_array(array, 1, 2)).unwrap(),
+        Array::LargeList(ListArray {
+            validity: None,
+            offsets: vec![0, 0, 1],
+            meta: FieldMeta {
+                name: String::from("element"),
+                ..FieldMeta::default()
+            },
+            elements: Box::new(Array::Int32(PrimitiveArray {
+                validity: None,
+                values: vec![3],
+            })),
+        })
+    );
+}
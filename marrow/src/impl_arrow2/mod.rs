@@ -4,11 +4,115 @@
 #[cfg(feature = "arrow2-0-17")]
 mod arrow2_0_17 {
     use arrow2_0_17 as arrow2;
+
+    /// Move the values into a vec if the buffer is not shared, copy them otherwise
+    fn buffer_into_vec<T: arrow2::types::NativeType>(buffer: arrow2::buffer::Buffer<T>) -> Vec<T> {
+        let (offset, len) = (buffer.offset(), buffer.len());
+        buffer.into_mut().either(
+            |buffer| buffer.as_slice().to_vec(),
+            |mut vec| {
+                // the vec contains the full data, ignoring the offset of the buffer
+                vec.truncate(offset + len);
+                vec.drain(..offset);
+                vec
+            },
+        )
+    }
+
+    /// Move the bits into a vec if the bitmap is neither shared nor offset, copy them otherwise
+    fn bits_into_vec(bitmap: arrow2::bitmap::Bitmap) -> crate::error::Result<Vec<u8>> {
+        let (bytes, offset, len, _) = bitmap.into_inner();
+        if offset != 0 {
+            let data = &bytes[offset / 8..];
+            return crate::array::copy_bits(crate::view::BitsWithOffset { offset: offset % 8, data }, 0, len);
+        }
+
+        let num_bytes = (len + 7) / 8;
+        let total = bytes.len();
+        // SAFETY: the buffer covers exactly the bytes of the bitmap
+        let buffer = unsafe { arrow2::buffer::Buffer::from_inner_unchecked(bytes, 0, total) };
+        Ok(buffer.into_mut().either(
+            |buffer| buffer[..num_bytes].to_vec(),
+            |mut vec| {
+                vec.truncate(num_bytes);
+                vec
+            },
+        ))
+    }
+
+    /// Slice an array, releasing the references of the original array
+    fn slice_array(array: Box<dyn arrow2::array::Array>, offset: usize, len: usize) -> Box<dyn arrow2::array::Array> {
+        array.sliced(offset, len)
+    }
+
     include!("impl.rs");
+
+    #[test]
+    fn owned_bitmaps_are_moved() {
+        let values = vec![0b_0101_u8];
+        let validity = vec![0b_1101_u8];
+        let (values_ptr, validity_ptr) = (values.as_ptr(), validity.as_ptr());
+        let array = Box::<dyn arrow2::array::Array>::try_from(Array::Boolean(BooleanArray {
+            len: 4,
+            validity: Some(validity),
+            values,
+        }))
+        .unwrap();
+
+        let Array::Boolean(actual) = Array::try_from(array.clone()).unwrap() else {
+            panic!("Unexpected array type");
+        };
+        assert_ne!(actual.values.as_ptr(), values_ptr);
+
+        let Array::Boolean(actual) = Array::try_from(array).unwrap() else {
+            panic!("Unexpected array type");
+        };
+        assert_eq!(actual.values, vec![0b_0101]);
+        assert_eq!(actual.validity, Some(vec![0b_1101]));
+        assert_eq!(actual.values.as_ptr(), values_ptr);
+        assert_eq!(actual.validity.as_ref().map(|v| v.as_ptr()), Some(validity_ptr));
+
+        // offset bitmaps are copied
+        let array = Box::<dyn arrow2::array::Array>::try_from(Array::Boolean(BooleanArray {
+            len: 4,
+            validity: None,
+            values: vec![0b_0101],
+        }))
+        .unwrap();
+        let Array::Boolean(actual) = Array::try_from(slice_array(array, 1, 3)).unwrap() else {
+            panic!("Unexpected array type");
+        };
+        assert_eq!(actual.values, vec![0b_010]);
+    }
 }
 
 #[cfg(feature = "arrow2-0-16")]
 mod arrow2_0_16 {
     use arrow2_0_16 as arrow2;
+
+    /// Move the values into a vec if the buffer is not shared, copy them otherwise
+    fn buffer_into_vec<T: arrow2::types::NativeType>(mut buffer: arrow2::buffer::Buffer<T>) -> Vec<T> {
+        let len = buffer.len();
+        match buffer.get_mut() {
+            Some(vec) => {
+                let mut vec = std::mem::take(vec);
+                vec.truncate(len);
+                vec
+            }
+            None => buffer.as_slice().to_vec(),
+        }
+    }
+
+    /// Copy the bits into a vec, `arrow2=0.16` does not allow to take the data of bitmaps
+    fn bits_into_vec(bitmap: arrow2::bitmap::Bitmap) -> crate::error::Result<Vec<u8>> {
+        let (data, offset, len) = bitmap.as_slice();
+        crate::array::copy_bits(crate::view::BitsWithOffset { offset, data }, 0, len)
+    }
+
+    /// Slice an array, releasing the references of the original array
+    fn slice_array(array: Box<dyn arrow2::array::Array>, offset: usize, len: usize) -> Box<dyn arrow2::array::Array> {
+        array.slice(offset, len)
+    }
+
     include!("impl.rs");
 }
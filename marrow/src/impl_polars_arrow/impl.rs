@@ -0,0 +1,1296 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{Array, BytesViewArray, PrimitiveArray},
+    datatypes::{
+        meta_from_field, DataType, DictionaryMeta, Field, FieldMeta, IntervalUnit, TimeUnit,
+        UnionMode,
+    },
+    error::{fail, ErrorKind, MarrowError, Result},
+    types::{DayTimeInterval, MonthDayNanoInterval},
+    view::{
+        BitsWithOffset, BooleanView, BytesView, BytesViewView, DecimalView, DictionaryView,
+        FixedSizeBinaryView, FixedSizeListView, ListView, MapView, NullView, PrimitiveView,
+        StructView, TimeView, TimestampView, UnionView, View,
+    },
+};
+
+impl From<polars_arrow::legacy::error::PolarsError> for MarrowError {
+    fn from(err: polars_arrow::legacy::error::PolarsError) -> MarrowError {
+        MarrowError::with_cause(
+            ErrorKind::ArrowError,
+            format!("polars_arrow::Error: {err}"),
+            err,
+        )
+    }
+}
+
+/// Conversion from `polars-arrow` data types (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<&polars_arrow::datatypes::ArrowDataType> for DataType {
+    type Error = MarrowError;
+
+    fn try_from(value: &polars_arrow::datatypes::ArrowDataType) -> Result<DataType> {
+        use {
+            polars_arrow::datatypes::ArrowDataType as AT,
+            polars_arrow::datatypes::IntegerType as I, DataType as T, Field as F,
+        };
+        match value {
+            AT::Null => Ok(T::Null),
+            AT::Boolean => Ok(T::Boolean),
+            AT::Int8 => Ok(T::Int8),
+            AT::Int16 => Ok(T::Int16),
+            AT::Int32 => Ok(T::Int32),
+            AT::Int64 => Ok(T::Int64),
+            AT::UInt8 => Ok(T::UInt8),
+            AT::UInt16 => Ok(T::UInt16),
+            AT::UInt32 => Ok(T::UInt32),
+            AT::UInt64 => Ok(T::UInt64),
+            AT::Float16 => Ok(T::Float16),
+            AT::Float32 => Ok(T::Float32),
+            AT::Float64 => Ok(T::Float64),
+            AT::Date32 => Ok(T::Date32),
+            AT::Date64 => Ok(T::Date64),
+            AT::Time32(unit) => Ok(T::Time32((*unit).try_into()?)),
+            AT::Time64(unit) => Ok(T::Time64((*unit).try_into()?)),
+            AT::Duration(unit) => Ok(T::Duration((*unit).try_into()?)),
+            AT::Interval(unit) => Ok(T::Interval((*unit).try_into()?)),
+            AT::Timestamp(unit, tz) => Ok(T::Timestamp(
+                (*unit).try_into()?,
+                tz.as_ref().map(|tz| tz.to_string()),
+            )),
+            AT::Decimal(precision, scale) => {
+                if *precision > u8::MAX as usize || *scale > i8::MAX as usize {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "cannot represent precision / scale of the decimal"
+                    );
+                }
+                Ok(T::Decimal128(*precision as u8, *scale as i8))
+            }
+            AT::Utf8 => Ok(T::Utf8),
+            AT::LargeUtf8 => Ok(T::LargeUtf8),
+            AT::Utf8View => Ok(T::Utf8View),
+            AT::Binary => Ok(T::Binary),
+            AT::LargeBinary => Ok(T::LargeBinary),
+            AT::BinaryView => Ok(T::BinaryView),
+            AT::FixedSizeBinary(n) => Ok(T::FixedSizeBinary(i32::try_from(*n)?)),
+            AT::List(entry) => Ok(T::List(Box::new(entry.as_ref().try_into()?))),
+            AT::LargeList(entry) => Ok(T::LargeList(Box::new(entry.as_ref().try_into()?))),
+            AT::FixedSizeList(entry, n) => Ok(T::FixedSizeList(
+                Box::new(entry.as_ref().try_into()?),
+                i32::try_from(*n)?,
+            )),
+            AT::Map(field, sorted) => Ok(T::Map(Box::new(field.as_ref().try_into()?), *sorted)),
+            AT::Struct(fields) => {
+                let mut res_fields = Vec::new();
+                for field in fields {
+                    res_fields.push(Field::try_from(field)?);
+                }
+                Ok(T::Struct(res_fields))
+            }
+            AT::Dictionary(key, value, _) => {
+                let key = match key {
+                    I::Int8 => T::Int8,
+                    I::Int16 => T::Int16,
+                    I::Int32 => T::Int32,
+                    I::Int64 => T::Int64,
+                    I::UInt8 => T::UInt8,
+                    I::UInt16 => T::UInt16,
+                    I::UInt32 => T::UInt32,
+                    I::UInt64 => T::UInt64,
+                    key => fail!(
+                        ErrorKind::Unsupported,
+                        "unsupported dictionary key type {key:?}",
+                    ),
+                };
+                Ok(T::Dictionary(
+                    Box::new(key),
+                    Box::new(value.as_ref().try_into()?),
+                ))
+            }
+            AT::Union(union_type) => {
+                let in_type_ids = match union_type.ids.as_ref() {
+                    Some(in_type_ids) => in_type_ids.clone(),
+                    None => {
+                        let mut type_ids = Vec::new();
+                        for id in 0..union_type.fields.len() {
+                            type_ids.push(id.try_into()?);
+                        }
+                        type_ids
+                    }
+                };
+
+                let mut fields = Vec::new();
+                for (type_id, field) in in_type_ids.iter().zip(&union_type.fields) {
+                    fields.push(((*type_id).try_into()?, F::try_from(field)?));
+                }
+                Ok(T::Union(fields, union_type.mode.try_into()?))
+            }
+            dt => fail!(
+                ErrorKind::Unsupported,
+                "Cannot convert data type {dt:?} to internal data type"
+            ),
+        }
+    }
+}
+
+/// Conversion from `polars-arrow` fields (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<&polars_arrow::datatypes::Field> for Field {
+    type Error = MarrowError;
+
+    fn try_from(field: &polars_arrow::datatypes::Field) -> Result<Self> {
+        let ordered = matches!(
+            field.dtype,
+            polars_arrow::datatypes::ArrowDataType::Dictionary(_, _, true)
+        );
+        Ok(Field {
+            name: field.name.to_string(),
+            data_type: DataType::try_from(&field.dtype)?,
+            nullable: field.is_nullable,
+            metadata: field
+                .metadata
+                .iter()
+                .flat_map(|metadata| metadata.iter())
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            dictionary: DictionaryMeta { ordered, id: None },
+        })
+    }
+}
+
+/// Conversion to `polars-arrow` data types (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<&DataType> for polars_arrow::datatypes::ArrowDataType {
+    type Error = MarrowError;
+
+    fn try_from(value: &DataType) -> std::result::Result<Self, Self::Error> {
+        use {
+            polars_arrow::datatypes::ArrowDataType as AT, polars_arrow::datatypes::Field as AF,
+            polars_arrow::datatypes::IntegerType as I, DataType as T,
+        };
+        match value {
+            T::Null => Ok(AT::Null),
+            T::Boolean => Ok(AT::Boolean),
+            T::Int8 => Ok(AT::Int8),
+            T::Int16 => Ok(AT::Int16),
+            T::Int32 => Ok(AT::Int32),
+            T::Int64 => Ok(AT::Int64),
+            T::UInt8 => Ok(AT::UInt8),
+            T::UInt16 => Ok(AT::UInt16),
+            T::UInt32 => Ok(AT::UInt32),
+            T::UInt64 => Ok(AT::UInt64),
+            T::Float16 => Ok(AT::Float16),
+            T::Float32 => Ok(AT::Float32),
+            T::Float64 => Ok(AT::Float64),
+            T::Date32 => Ok(AT::Date32),
+            T::Date64 => Ok(AT::Date64),
+            T::Duration(unit) => Ok(AT::Duration((*unit).try_into()?)),
+            T::Interval(unit) => Ok(AT::Interval((*unit).try_into()?)),
+            T::Time32(unit) => Ok(AT::Time32((*unit).try_into()?)),
+            T::Time64(unit) => Ok(AT::Time64((*unit).try_into()?)),
+            T::Timestamp(unit, tz) => Ok(AT::Timestamp(
+                (*unit).try_into()?,
+                tz.as_deref().map(Into::into),
+            )),
+            T::Decimal128(precision, scale) => {
+                if *scale < 0 {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "polars-arrow does not support decimals with negative scale"
+                    );
+                }
+                Ok(AT::Decimal((*precision).into(), (*scale).try_into()?))
+            }
+            T::Binary => Ok(AT::Binary),
+            T::LargeBinary => Ok(AT::LargeBinary),
+            T::BinaryView => Ok(AT::BinaryView),
+            T::FixedSizeBinary(n) => Ok(AT::FixedSizeBinary((*n).try_into()?)),
+            T::Utf8 => Ok(AT::Utf8),
+            T::LargeUtf8 => Ok(AT::LargeUtf8),
+            T::Utf8View => Ok(AT::Utf8View),
+            T::Dictionary(key, value) => {
+                let key = match key.as_ref() {
+                    T::Int8 => I::Int8,
+                    T::Int16 => I::Int16,
+                    T::Int32 => I::Int32,
+                    T::Int64 => I::Int64,
+                    T::UInt8 => I::UInt8,
+                    T::UInt16 => I::UInt16,
+                    T::UInt32 => I::UInt32,
+                    T::UInt64 => I::UInt64,
+                    dt => fail!(
+                        ErrorKind::Unsupported,
+                        "unsupported dictionary key type {dt:?}",
+                    ),
+                };
+                Ok(AT::Dictionary(
+                    key,
+                    AT::try_from(value.as_ref())?.into(),
+                    false,
+                ))
+            }
+            T::RunEndEncoded(_, _) => fail!(
+                ErrorKind::Unsupported,
+                "RunEndEncoded is not supported by polars-arrow"
+            ),
+            T::List(field) => Ok(AT::List(AF::try_from(field.as_ref())?.into())),
+            T::LargeList(field) => Ok(AT::LargeList(AF::try_from(field.as_ref())?.into())),
+            T::FixedSizeList(field, n) => Ok(AT::FixedSizeList(
+                AF::try_from(field.as_ref())?.into(),
+                (*n).try_into()?,
+            )),
+            T::Map(field, sorted) => Ok(AT::Map(AF::try_from(field.as_ref())?.into(), *sorted)),
+            T::Struct(in_fields) => {
+                let mut fields = Vec::new();
+                for field in in_fields {
+                    fields.push(AF::try_from(field)?);
+                }
+                Ok(AT::Struct(fields))
+            }
+            T::Union(in_fields, mode) => {
+                let mut fields = Vec::new();
+                let mut type_ids = Vec::new();
+
+                for (type_id, field) in in_fields {
+                    fields.push(AF::try_from(field)?);
+                    type_ids.push((*type_id).into());
+                }
+                Ok(AT::Union(Box::new(polars_arrow::datatypes::UnionType {
+                    fields,
+                    ids: Some(type_ids),
+                    mode: (*mode).try_into()?,
+                })))
+            }
+        }
+    }
+}
+
+/// Conversion to `polars-arrow` fields (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<&Field> for polars_arrow::datatypes::Field {
+    type Error = MarrowError;
+
+    fn try_from(value: &Field) -> Result<Self> {
+        let mut dtype = polars_arrow::datatypes::ArrowDataType::try_from(&value.data_type)?;
+        if let polars_arrow::datatypes::ArrowDataType::Dictionary(_, _, sorted) = &mut dtype {
+            *sorted = value.dictionary.ordered;
+        }
+        Ok(polars_arrow::datatypes::Field {
+            name: value.name.as_str().into(),
+            dtype,
+            is_nullable: value.nullable,
+            metadata: metadata_from_marrow(&value.metadata),
+        })
+    }
+}
+
+/// Conversion to `polars-arrow` time units (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<TimeUnit> for polars_arrow::datatypes::TimeUnit {
+    type Error = MarrowError;
+
+    fn try_from(value: TimeUnit) -> Result<polars_arrow::datatypes::TimeUnit> {
+        match value {
+            TimeUnit::Second => Ok(polars_arrow::datatypes::TimeUnit::Second),
+            TimeUnit::Millisecond => Ok(polars_arrow::datatypes::TimeUnit::Millisecond),
+            TimeUnit::Microsecond => Ok(polars_arrow::datatypes::TimeUnit::Microsecond),
+            TimeUnit::Nanosecond => Ok(polars_arrow::datatypes::TimeUnit::Nanosecond),
+        }
+    }
+}
+
+/// Conversion from `polars-arrow` time units (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<polars_arrow::datatypes::TimeUnit> for TimeUnit {
+    type Error = MarrowError;
+
+    fn try_from(value: polars_arrow::datatypes::TimeUnit) -> Result<TimeUnit> {
+        match value {
+            polars_arrow::datatypes::TimeUnit::Second => Ok(TimeUnit::Second),
+            polars_arrow::datatypes::TimeUnit::Millisecond => Ok(TimeUnit::Millisecond),
+            polars_arrow::datatypes::TimeUnit::Microsecond => Ok(TimeUnit::Microsecond),
+            polars_arrow::datatypes::TimeUnit::Nanosecond => Ok(TimeUnit::Nanosecond),
+        }
+    }
+}
+
+/// Conversion from `polars-arrow` union modes (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<polars_arrow::datatypes::UnionMode> for UnionMode {
+    type Error = MarrowError;
+
+    fn try_from(value: polars_arrow::datatypes::UnionMode) -> Result<Self> {
+        match value {
+            polars_arrow::datatypes::UnionMode::Dense => Ok(UnionMode::Dense),
+            polars_arrow::datatypes::UnionMode::Sparse => Ok(UnionMode::Sparse),
+        }
+    }
+}
+
+/// Conversion to `polars-arrow` union modes (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<UnionMode> for polars_arrow::datatypes::UnionMode {
+    type Error = MarrowError;
+
+    fn try_from(value: UnionMode) -> Result<Self> {
+        match value {
+            UnionMode::Dense => Ok(polars_arrow::datatypes::UnionMode::Dense),
+            UnionMode::Sparse => Ok(polars_arrow::datatypes::UnionMode::Sparse),
+        }
+    }
+}
+
+/// Conversion from `polars-arrow` interval units (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<polars_arrow::datatypes::IntervalUnit> for IntervalUnit {
+    type Error = MarrowError;
+
+    fn try_from(value: polars_arrow::datatypes::IntervalUnit) -> Result<Self> {
+        match value {
+            polars_arrow::datatypes::IntervalUnit::YearMonth => Ok(IntervalUnit::YearMonth),
+            polars_arrow::datatypes::IntervalUnit::DayTime => Ok(IntervalUnit::DayTime),
+            polars_arrow::datatypes::IntervalUnit::MonthDayNano => Ok(IntervalUnit::MonthDayNano),
+            // NOTE: `MonthDayMillis` is only present for `polars-arrow>=0.50`
+            #[allow(unreachable_patterns)]
+            unit => fail!(
+                ErrorKind::Unsupported,
+                "Unsupported polars-arrow interval unit {unit:?}"
+            ),
+        }
+    }
+}
+
+/// Conversion to `polars-arrow` interval units (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<IntervalUnit> for polars_arrow::datatypes::IntervalUnit {
+    type Error = MarrowError;
+
+    fn try_from(value: IntervalUnit) -> Result<Self> {
+        match value {
+            IntervalUnit::YearMonth => Ok(polars_arrow::datatypes::IntervalUnit::YearMonth),
+            IntervalUnit::DayTime => Ok(polars_arrow::datatypes::IntervalUnit::DayTime),
+            IntervalUnit::MonthDayNano => Ok(polars_arrow::datatypes::IntervalUnit::MonthDayNano),
+        }
+    }
+}
+
+/// Conversion of views to `polars-arrow` arrays (*requires one of the `polars-arrow-{version}` features*)
+///
+/// The data referenced by the view is copied, see the conversion from views to [`Array`].
+impl TryFrom<View<'_>> for Box<dyn polars_arrow::array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: View<'_>) -> Result<Self> {
+        Array::try_from(value)?.try_into()
+    }
+}
+
+/// Conversion to `polars-arrow` arrays (*requires one of the `polars-arrow-{version}` features*)
+impl TryFrom<Array> for Box<dyn polars_arrow::array::Array> {
+    type Error = MarrowError;
+
+    fn try_from(value: Array) -> Result<Self> {
+        use {
+            polars_arrow::datatypes::ArrowDataType as AT,
+            polars_arrow::datatypes::IntegerType as AI, Array as A,
+        };
+        match value {
+            A::Null(arr) => Ok(Box::new(polars_arrow::array::NullArray::try_new(
+                AT::Null,
+                arr.len,
+            )?)),
+            A::Boolean(arr) => Ok(Box::new(polars_arrow::array::BooleanArray::try_new(
+                AT::Boolean,
+                polars_arrow::bitmap::Bitmap::from_u8_vec(arr.values, arr.len),
+                arr.validity
+                    .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, arr.len)),
+            )?)),
+            A::Int8(arr) => build_primitive_array(AT::Int8, arr.values, arr.validity),
+            A::Int16(arr) => build_primitive_array(AT::Int16, arr.values, arr.validity),
+            A::Int32(arr) => build_primitive_array(AT::Int32, arr.values, arr.validity),
+            A::Int64(arr) => build_primitive_array(AT::Int64, arr.values, arr.validity),
+            A::UInt8(arr) => build_primitive_array(AT::UInt8, arr.values, arr.validity),
+            A::UInt16(arr) => build_primitive_array(AT::UInt16, arr.values, arr.validity),
+            A::UInt32(arr) => build_primitive_array(AT::UInt32, arr.values, arr.validity),
+            A::UInt64(arr) => build_primitive_array(AT::UInt64, arr.values, arr.validity),
+            A::Float16(arr) => build_primitive_array(
+                AT::Float16,
+                arr.values
+                    .into_iter()
+                    .map(|v| polars_arrow::types::f16(v.to_bits()))
+                    .collect(),
+                arr.validity,
+            ),
+            A::Float32(arr) => build_primitive_array(AT::Float32, arr.values, arr.validity),
+            A::Float64(arr) => build_primitive_array(AT::Float64, arr.values, arr.validity),
+            A::Date32(arr) => build_primitive_array(AT::Date32, arr.values, arr.validity),
+            A::Date64(arr) => build_primitive_array(AT::Date64, arr.values, arr.validity),
+            A::Duration(arr) => {
+                build_primitive_array(AT::Duration(arr.unit.try_into()?), arr.values, arr.validity)
+            }
+            A::Time32(arr) => {
+                build_primitive_array(AT::Time32(arr.unit.try_into()?), arr.values, arr.validity)
+            }
+            A::Time64(arr) => {
+                build_primitive_array(AT::Time64(arr.unit.try_into()?), arr.values, arr.validity)
+            }
+            A::Timestamp(arr) => build_primitive_array(
+                AT::Timestamp(arr.unit.try_into()?, arr.timezone.map(Into::into)),
+                arr.values,
+                arr.validity,
+            ),
+            A::Decimal128(arr) => build_primitive_array(
+                AT::Decimal(arr.precision as usize, usize::try_from(arr.scale)?),
+                arr.values,
+                arr.validity,
+            ),
+            A::Utf8(arr) => build_utf8_array(AT::Utf8, arr.offsets, arr.data, arr.validity),
+            A::LargeUtf8(arr) => {
+                build_utf8_array(AT::LargeUtf8, arr.offsets, arr.data, arr.validity)
+            }
+            A::Utf8View(arr) => {
+                let (views, buffers, validity) = convert_bytes_view_array(arr)?;
+                Ok(Box::new(polars_arrow::array::Utf8ViewArray::try_new(
+                    AT::Utf8View,
+                    views,
+                    buffers,
+                    validity,
+                )?))
+            }
+            A::Binary(arr) => build_binary_array(AT::Binary, arr.offsets, arr.data, arr.validity),
+            A::LargeBinary(arr) => {
+                build_binary_array(AT::LargeBinary, arr.offsets, arr.data, arr.validity)
+            }
+            A::BinaryView(arr) => {
+                let (views, buffers, validity) = convert_bytes_view_array(arr)?;
+                Ok(Box::new(polars_arrow::array::BinaryViewArray::try_new(
+                    AT::BinaryView,
+                    views,
+                    buffers,
+                    validity,
+                )?))
+            }
+            A::Dictionary(arr) => match *arr.keys {
+                A::Int8(keys) => build_dictionary_array(AI::Int8, keys, *arr.values),
+                A::Int16(keys) => build_dictionary_array(AI::Int16, keys, *arr.values),
+                A::Int32(keys) => build_dictionary_array(AI::Int32, keys, *arr.values),
+                A::Int64(keys) => build_dictionary_array(AI::Int64, keys, *arr.values),
+                A::UInt8(keys) => build_dictionary_array(AI::UInt8, keys, *arr.values),
+                A::UInt16(keys) => build_dictionary_array(AI::UInt16, keys, *arr.values),
+                A::UInt32(keys) => build_dictionary_array(AI::UInt32, keys, *arr.values),
+                A::UInt64(keys) => build_dictionary_array(AI::UInt64, keys, *arr.values),
+                keys => fail!(
+                    ErrorKind::Unsupported,
+                    "Unsupported dictionary index array with data type {:?} during polars-arrow conversion",
+                    keys.data_type(),
+                ),
+            },
+            A::RunEndEncoded(_) => fail!(
+                ErrorKind::Unsupported,
+                "RunEndEncoded is not supported by polars-arrow"
+            ),
+            A::List(arr) => build_list_array(
+                AT::List,
+                arr.offsets,
+                arr.meta,
+                (*arr.elements).try_into()?,
+                arr.validity,
+            ),
+            A::LargeList(arr) => build_list_array(
+                AT::LargeList,
+                arr.offsets,
+                arr.meta,
+                (*arr.elements).try_into()?,
+                arr.validity,
+            ),
+            A::Struct(arr) => {
+                let mut values = Vec::new();
+                let mut fields = Vec::new();
+                for (meta, child) in arr.fields {
+                    let child: Box<dyn polars_arrow::array::Array> = child.try_into()?;
+                    let field = field_from_array_and_meta(child.as_ref(), meta);
+                    values.push(child);
+                    fields.push(field);
+                }
+                Ok(Box::new(polars_arrow::array::StructArray::try_new(
+                    AT::Struct(fields),
+                    arr.len,
+                    values,
+                    arr.validity
+                        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, arr.len)),
+                )?))
+            }
+            A::Map(arr) => {
+                let (entries, entries_name, sorted, validity, offsets) =
+                    arr.into_logical_array()?;
+                let entries = Box::<dyn polars_arrow::array::Array>::try_from(entries)?;
+                let field = field_from_array_and_meta(
+                    entries.as_ref(),
+                    FieldMeta {
+                        name: entries_name,
+                        ..FieldMeta::default()
+                    },
+                );
+                let validity = validity.map(|v| {
+                    polars_arrow::bitmap::Bitmap::from_u8_vec(v, offsets.len().saturating_sub(1))
+                });
+                Ok(Box::new(polars_arrow::array::MapArray::try_new(
+                    AT::Map(Box::new(field), sorted),
+                    offsets.try_into()?,
+                    entries,
+                    validity,
+                )?))
+            }
+            A::Union(arr) => {
+                let (type_ids, fields, values) = convert_union_fields(arr.fields)?;
+                let (offsets, mode) = if let Some(offsets) = arr.offsets {
+                    (
+                        Some(offsets.into()),
+                        polars_arrow::datatypes::UnionMode::Dense,
+                    )
+                } else {
+                    (None, polars_arrow::datatypes::UnionMode::Sparse)
+                };
+                Ok(Box::new(polars_arrow::array::UnionArray::try_new(
+                    AT::Union(Box::new(polars_arrow::datatypes::UnionType {
+                        fields,
+                        ids: Some(type_ids),
+                        mode,
+                    })),
+                    arr.types.into(),
+                    values,
+                    offsets,
+                )?))
+            }
+            A::FixedSizeList(arr) => {
+                let child: Box<dyn polars_arrow::array::Array> = (*arr.elements).try_into()?;
+                let child_field = field_from_array_and_meta(child.as_ref(), arr.meta);
+                let data_type = AT::FixedSizeList(Box::new(child_field), arr.n.try_into()?);
+                let validity = arr
+                    .validity
+                    .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, arr.len));
+                Ok(Box::new(polars_arrow::array::FixedSizeListArray::try_new(
+                    data_type, arr.len, child, validity,
+                )?))
+            }
+            A::FixedSizeBinary(arr) => {
+                let n = usize::try_from(arr.n)?;
+                let len = arr.data.len() / n;
+                let validity = arr
+                    .validity
+                    .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, len));
+
+                Ok(Box::new(polars_arrow::array::FixedSizeBinaryArray::try_new(
+                    AT::FixedSizeBinary(n),
+                    polars_arrow::buffer::Buffer::from(arr.data),
+                    validity,
+                )?))
+            }
+            A::YearMonthInterval(arr) => build_primitive_array(
+                AT::Interval(polars_arrow::datatypes::IntervalUnit::YearMonth),
+                arr.values,
+                arr.validity,
+            ),
+            A::DayTimeInterval(arr) => build_primitive_array(
+                AT::Interval(polars_arrow::datatypes::IntervalUnit::DayTime),
+                arr.values
+                    .into_iter()
+                    .map(|v| polars_arrow::types::days_ms::new(v.days, v.milliseconds))
+                    .collect(),
+                arr.validity,
+            ),
+            A::MonthDayNanoInterval(arr) => build_primitive_array(
+                AT::Interval(polars_arrow::datatypes::IntervalUnit::MonthDayNano),
+                arr.values
+                    .into_iter()
+                    .map(|v| {
+                        polars_arrow::types::months_days_ns::new(v.months, v.days, v.nanoseconds)
+                    })
+                    .collect(),
+                arr.validity,
+            ),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn convert_union_fields(
+    union_fields: Vec<(i8, FieldMeta, Array)>,
+) -> Result<(
+    Vec<i32>,
+    Vec<polars_arrow::datatypes::Field>,
+    Vec<Box<dyn polars_arrow::array::Array>>,
+)> {
+    let mut values = Vec::new();
+    let mut fields = Vec::new();
+    let mut type_ids = Vec::new();
+
+    for (type_id, meta, child) in union_fields {
+        let child: Box<dyn polars_arrow::array::Array> = child.try_into()?;
+        let field = field_from_array_and_meta(child.as_ref(), meta);
+
+        type_ids.push(type_id.into());
+        values.push(child);
+        fields.push(field);
+    }
+
+    Ok((type_ids, fields, values))
+}
+
+#[allow(clippy::type_complexity)]
+fn convert_bytes_view_array(
+    array: BytesViewArray,
+) -> Result<(
+    polars_arrow::buffer::Buffer<polars_arrow::array::View>,
+    Arc<[polars_arrow::buffer::Buffer<u8>]>,
+    Option<polars_arrow::bitmap::Bitmap>,
+)> {
+    let len = array.data.len();
+    let mut views = Vec::new();
+    for view in array.data {
+        views.push(bytemuck::try_cast::<u128, polars_arrow::array::View>(view)?);
+    }
+    let buffers = array
+        .buffers
+        .into_iter()
+        .map(polars_arrow::buffer::Buffer::from)
+        .collect();
+    let validity = array
+        .validity
+        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, len));
+    Ok((views.into(), buffers, validity))
+}
+
+fn build_primitive_array<T: polars_arrow::types::NativeType>(
+    data_type: polars_arrow::datatypes::ArrowDataType,
+    buffer: Vec<T>,
+    validity: Option<Vec<u8>>,
+) -> Result<Box<dyn polars_arrow::array::Array>> {
+    let validity = validity.map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, buffer.len()));
+    let buffer = polars_arrow::buffer::Buffer::from(buffer);
+    Ok(Box::new(polars_arrow::array::PrimitiveArray::try_new(
+        data_type, buffer, validity,
+    )?))
+}
+
+fn build_utf8_array<O: polars_arrow::offset::Offset>(
+    data_type: polars_arrow::datatypes::ArrowDataType,
+    offsets: Vec<O>,
+    data: Vec<u8>,
+    validity: Option<Vec<u8>>,
+) -> Result<Box<dyn polars_arrow::array::Array>> {
+    let validity = validity
+        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, offsets.len().saturating_sub(1)));
+    Ok(Box::new(polars_arrow::array::Utf8Array::try_new(
+        data_type,
+        offsets.try_into()?,
+        polars_arrow::buffer::Buffer::from(data),
+        validity,
+    )?))
+}
+
+fn build_binary_array<O: polars_arrow::offset::Offset>(
+    data_type: polars_arrow::datatypes::ArrowDataType,
+    offsets: Vec<O>,
+    data: Vec<u8>,
+    validity: Option<Vec<u8>>,
+) -> Result<Box<dyn polars_arrow::array::Array>> {
+    let validity = validity
+        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, offsets.len().saturating_sub(1)));
+    Ok(Box::new(polars_arrow::array::BinaryArray::try_new(
+        data_type,
+        offsets.try_into()?,
+        polars_arrow::buffer::Buffer::from(data),
+        validity,
+    )?))
+}
+
+fn build_list_array<
+    F: FnOnce(Box<polars_arrow::datatypes::Field>) -> polars_arrow::datatypes::ArrowDataType,
+    O: polars_arrow::offset::Offset,
+>(
+    data_type: F,
+    offsets: Vec<O>,
+    meta: FieldMeta,
+    values: Box<dyn polars_arrow::array::Array>,
+    validity: Option<Vec<u8>>,
+) -> Result<Box<dyn polars_arrow::array::Array>> {
+    let validity = validity
+        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, offsets.len().saturating_sub(1)));
+    Ok(Box::new(polars_arrow::array::ListArray::try_new(
+        data_type(Box::new(field_from_array_and_meta(values.as_ref(), meta))),
+        offsets.try_into()?,
+        values,
+        validity,
+    )?))
+}
+
+fn field_from_array_and_meta(
+    arr: &dyn polars_arrow::array::Array,
+    meta: FieldMeta,
+) -> polars_arrow::datatypes::Field {
+    polars_arrow::datatypes::Field {
+        name: meta.name.into(),
+        dtype: arr.dtype().clone(),
+        is_nullable: meta.nullable,
+        metadata: metadata_from_marrow(&meta.metadata),
+    }
+}
+
+fn metadata_from_marrow(
+    metadata: &std::collections::HashMap<String, String>,
+) -> Option<Arc<polars_arrow::datatypes::Metadata>> {
+    if metadata.is_empty() {
+        return None;
+    }
+    Some(Arc::new(
+        metadata
+            .iter()
+            .map(|(key, value)| (key.as_str().into(), value.as_str().into()))
+            .collect(),
+    ))
+}
+
+fn build_dictionary_array<K: polars_arrow::array::DictionaryKey>(
+    keys_type: polars_arrow::datatypes::IntegerType,
+    keys: PrimitiveArray<K>,
+    values: Array,
+) -> Result<Box<dyn polars_arrow::array::Array>> {
+    let values: Box<dyn polars_arrow::array::Array> = values.try_into()?;
+    let validity = keys
+        .validity
+        .map(|v| polars_arrow::bitmap::Bitmap::from_u8_vec(v, keys.values.len()));
+    let keys = polars_arrow::array::PrimitiveArray::try_new(
+        keys_type.into(),
+        keys.values.into(),
+        validity,
+    )?;
+    Ok(Box::new(polars_arrow::array::DictionaryArray::try_new(
+        polars_arrow::datatypes::ArrowDataType::Dictionary(
+            keys_type,
+            Box::new(values.dtype().clone()),
+            false,
+        ),
+        keys,
+        values,
+    )?))
+}
+
+/// Conversion from `polars-arrow` arrays (*requires one of the `polars-arrow-{version}` features*)
+impl<'a> TryFrom<&'a dyn polars_arrow::array::Array> for View<'a> {
+    type Error = MarrowError;
+
+    fn try_from(array: &'a dyn polars_arrow::array::Array) -> Result<Self> {
+        use {polars_arrow::datatypes::ArrowDataType as AT, View as V};
+
+        use polars_arrow::array::Array as _;
+
+        let any = array.as_any();
+        if let Some(array) = any.downcast_ref::<polars_arrow::array::NullArray>() {
+            Ok(V::Null(NullView { len: array.len() }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::BooleanArray>() {
+            let (values_data, values_offset, _) = array.values().as_slice();
+            Ok(V::Boolean(BooleanView {
+                len: array.len(),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                values: BitsWithOffset {
+                    offset: values_offset,
+                    data: values_data,
+                },
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<i8>>() {
+            Ok(V::Int8(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<i16>>()
+        {
+            Ok(V::Int16(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<i32>>()
+        {
+            match array.dtype() {
+                AT::Int32 => Ok(V::Int32(view_primitive_array(array))),
+                AT::Date32 => Ok(V::Date32(view_primitive_array(array))),
+                AT::Interval(polars_arrow::datatypes::IntervalUnit::YearMonth) => {
+                    Ok(V::YearMonthInterval(view_primitive_array(array)))
+                }
+                AT::Time32(unit) => Ok(V::Time32(TimeView {
+                    unit: (*unit).try_into()?,
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: array.values().as_slice(),
+                })),
+                dt => fail!(
+                    ErrorKind::Unsupported,
+                    "unsupported data type {dt:?} for i32 polars-arrow array"
+                ),
+            }
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<i64>>()
+        {
+            match array.dtype() {
+                AT::Int64 => Ok(V::Int64(view_primitive_array(array))),
+                AT::Date64 => Ok(V::Date64(view_primitive_array(array))),
+                AT::Timestamp(unit, tz) => Ok(V::Timestamp(TimestampView {
+                    unit: (*unit).try_into()?,
+                    timezone: tz.as_ref().map(|tz| tz.to_string()),
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: array.values().as_slice(),
+                })),
+                AT::Time64(unit) => Ok(V::Time64(TimeView {
+                    unit: (*unit).try_into()?,
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: array.values().as_slice(),
+                })),
+                AT::Duration(unit) => Ok(V::Duration(TimeView {
+                    unit: (*unit).try_into()?,
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: array.values().as_slice(),
+                })),
+                dt => fail!(
+                    ErrorKind::Unsupported,
+                    "unsupported data type {dt:?} for i64 polars-arrow array"
+                ),
+            }
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<i128>>()
+        {
+            match array.dtype() {
+                AT::Decimal(precision, scale) => Ok(V::Decimal128(DecimalView {
+                    precision: (*precision).try_into()?,
+                    scale: (*scale).try_into()?,
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: array.values().as_slice(),
+                })),
+                dt => fail!(
+                    ErrorKind::Unsupported,
+                    "unsupported data type {dt:?} for i128 polars-arrow array"
+                ),
+            }
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<u8>>() {
+            Ok(V::UInt8(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<u16>>()
+        {
+            Ok(V::UInt16(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<u32>>()
+        {
+            Ok(V::UInt32(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<u64>>()
+        {
+            Ok(V::UInt64(view_primitive_array(array)))
+        } else if let Some(array) =
+            any.downcast_ref::<polars_arrow::array::PrimitiveArray<polars_arrow::types::f16>>()
+        {
+            Ok(V::Float16(PrimitiveView {
+                values: bytemuck::cast_slice::<polars_arrow::types::f16, half::f16>(
+                    array.values().as_slice(),
+                ),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
+        } else if let Some(array) = any
+            .downcast_ref::<polars_arrow::array::PrimitiveArray<polars_arrow::types::days_ms>>()
+        {
+            Ok(V::DayTimeInterval(PrimitiveView {
+                values: bytemuck::cast_slice::<polars_arrow::types::days_ms, DayTimeInterval>(
+                    array.values().as_slice(),
+                ),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<
+            polars_arrow::types::months_days_ns,
+        >>() {
+            Ok(V::MonthDayNanoInterval(PrimitiveView {
+                values: bytemuck::cast_slice::<
+                    polars_arrow::types::months_days_ns,
+                    MonthDayNanoInterval,
+                >(array.values().as_slice()),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<f32>>()
+        {
+            Ok(V::Float32(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::PrimitiveArray<f64>>()
+        {
+            Ok(V::Float64(view_primitive_array(array)))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::Utf8Array<i32>>() {
+            Ok(V::Utf8(BytesView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                data: array.values().as_slice(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::Utf8Array<i64>>() {
+            Ok(V::LargeUtf8(BytesView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                data: array.values().as_slice(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::Utf8ViewArray>() {
+            Ok(V::Utf8View(BytesViewView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                data: bytemuck::try_cast_slice(array.views().as_slice())?,
+                buffers: array.data_buffers().iter().map(|b| b.as_slice()).collect(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::BinaryArray<i32>>() {
+            Ok(V::Binary(BytesView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                data: array.values().as_slice(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::BinaryArray<i64>>() {
+            Ok(V::LargeBinary(BytesView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                data: array.values().as_slice(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::BinaryViewArray>() {
+            Ok(V::BinaryView(BytesViewView {
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                data: bytemuck::try_cast_slice(array.views().as_slice())?,
+                buffers: array.data_buffers().iter().map(|b| b.as_slice()).collect(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<i8>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::Int8, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<i16>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::Int16, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<i32>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::Int32, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<i64>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::Int64, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<u8>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::UInt8, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<u16>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::UInt16, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<u32>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::UInt32, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::DictionaryArray<u64>>()
+        {
+            Ok(V::Dictionary(view_dictionary_array(V::UInt64, array)?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::ListArray<i32>>() {
+            let AT::List(field) = array.dtype() else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "invalid data type for polars-arrow List array: {:?}",
+                    array.dtype()
+                );
+            };
+            Ok(V::List(ListView {
+                meta: meta_from_field(field.as_ref().try_into()?),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                elements: Box::new(array.values().as_ref().try_into()?),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::ListArray<i64>>() {
+            let AT::LargeList(field) = array.dtype() else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "invalid data type for polars-arrow LargeList array: {:?}",
+                    array.dtype()
+                );
+            };
+            Ok(V::LargeList(ListView {
+                meta: meta_from_field(field.as_ref().try_into()?),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                offsets: array.offsets().as_slice(),
+                elements: Box::new(array.values().as_ref().try_into()?),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::StructArray>() {
+            let mut fields = Vec::new();
+            for (child_field, child) in array.fields().iter().zip(array.values()) {
+                fields.push((
+                    meta_from_field(child_field.try_into()?),
+                    child.as_ref().try_into()?,
+                ));
+            }
+
+            Ok(V::Struct(StructView {
+                len: array.len(),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                fields,
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::MapArray>() {
+            let Some((entries_name, sorted)) = map_meta_from_data_type(array.dtype()) else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "invalid data type for polars-arrow Map array: {:?}",
+                    array.dtype(),
+                );
+            };
+            let entries_view: View<'_> = array.field().as_ref().try_into()?;
+
+            Ok(View::Map(MapView::from_logical_view(
+                entries_view,
+                entries_name,
+                sorted,
+                bits_with_offset_from_bitmap(array.validity()),
+                array.offsets().as_slice(),
+            )?))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::UnionArray>() {
+            let AT::Union(union_type) = array.dtype() else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "invalid data type for polars-arrow Union array: {:?}",
+                    array.dtype(),
+                );
+            };
+
+            let type_ids = if let Some(type_ids) = union_type.ids.as_ref() {
+                Cow::Borrowed(type_ids)
+            } else {
+                let mut type_ids = Vec::new();
+                for idx in 0..union_type.fields.len() {
+                    type_ids.push(idx.try_into()?);
+                }
+                Cow::Owned(type_ids)
+            };
+
+            let types = array.types().as_slice();
+
+            let mut fields = Vec::new();
+            for ((type_id, child), child_field) in type_ids
+                .iter()
+                .zip(array.fields().iter())
+                .zip(&union_type.fields)
+            {
+                fields.push((
+                    (*type_id).try_into()?,
+                    meta_from_field(child_field.try_into()?),
+                    child.as_ref().try_into()?,
+                ));
+            }
+
+            let offsets = match union_type.mode {
+                polars_arrow::datatypes::UnionMode::Dense => {
+                    let Some(offsets) = array.offsets() else {
+                        fail!(
+                            ErrorKind::Unsupported,
+                            "DenseUnion array without offsets are not supported"
+                        );
+                    };
+                    Some(offsets.as_slice())
+                }
+                polars_arrow::datatypes::UnionMode::Sparse => {
+                    if array.offsets().is_some() {
+                        fail!(
+                            ErrorKind::Unsupported,
+                            "SparseUnion array with offsets are not supported"
+                        );
+                    };
+                    None
+                }
+            };
+            Ok(V::Union(UnionView {
+                types,
+                offsets,
+                fields,
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::FixedSizeListArray>() {
+            let AT::FixedSizeList(field, _) = array.dtype() else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Invalid type: expected FixedSizeList"
+                );
+            };
+
+            let child_view: View<'_> = array.values().as_ref().try_into()?;
+
+            Ok(V::FixedSizeList(FixedSizeListView {
+                len: array.len(),
+                n: array.size().try_into()?,
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                meta: meta_from_field(field.as_ref().try_into()?),
+                elements: Box::new(child_view),
+            }))
+        } else if let Some(array) = any.downcast_ref::<polars_arrow::array::FixedSizeBinaryArray>()
+        {
+            Ok(V::FixedSizeBinary(FixedSizeBinaryView {
+                n: array.size().try_into()?,
+                validity: bits_with_offset_from_bitmap(array.validity()),
+                data: array.values().as_slice(),
+            }))
+        } else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot convert array with data type {:?} into an array view",
+                array.dtype()
+            );
+        }
+    }
+}
+
+fn map_meta_from_data_type(
+    data_type: &polars_arrow::datatypes::ArrowDataType,
+) -> Option<(String, bool)> {
+    let polars_arrow::datatypes::ArrowDataType::Map(entries_field, sorted) = data_type else {
+        return None;
+    };
+    if entries_field.is_nullable
+        || entries_field
+            .metadata
+            .as_ref()
+            .is_some_and(|metadata| !metadata.is_empty())
+    {
+        return None;
+    }
+    Some((entries_field.name.to_string(), *sorted))
+}
+
+fn view_primitive_array<T: polars_arrow::types::NativeType>(
+    array: &polars_arrow::array::PrimitiveArray<T>,
+) -> PrimitiveView<'_, T> {
+    PrimitiveView {
+        values: array.values().as_slice(),
+        validity: bits_with_offset_from_bitmap(array.validity()),
+    }
+}
+
+fn view_dictionary_array<
+    'a,
+    K: polars_arrow::array::DictionaryKey,
+    I: FnOnce(PrimitiveView<'a, K>) -> View<'a>,
+>(
+    index_type: I,
+    array: &'a polars_arrow::array::DictionaryArray<K>,
+) -> Result<DictionaryView<'a>> {
+    Ok(DictionaryView {
+        keys: Box::new(index_type(view_primitive_array(array.keys()))),
+        values: Box::new(array.values().as_ref().try_into()?),
+    })
+}
+
+fn bits_with_offset_from_bitmap(
+    bitmap: Option<&polars_arrow::bitmap::Bitmap>,
+) -> Option<BitsWithOffset<'_>> {
+    let (data, offset, _) = bitmap?.as_slice();
+    Some(BitsWithOffset { data, offset })
+}
+
+#[test]
+fn arrays_roundtrip() {
+    use crate::array::{BooleanArray, BytesArray, DictionaryArray, StructArray, TimestampArray};
+
+    let arrays = [
+        Array::Boolean(BooleanArray {
+            len: 3,
+            validity: Some(vec![0b110]),
+            values: vec![0b011],
+        }),
+        Array::Int64(PrimitiveArray {
+            validity: None,
+            values: vec![1, -2, 3],
+        }),
+        Array::Timestamp(TimestampArray {
+            unit: TimeUnit::Millisecond,
+            timezone: Some(String::from("UTC")),
+            validity: Some(vec![0b01]),
+            values: vec![13, 21],
+        }),
+        Array::LargeUtf8(BytesArray {
+            validity: None,
+            offsets: vec![0, 3, 3, 6],
+            data: b"foobar".to_vec(),
+        }),
+        Array::Utf8View(BytesViewArray {
+            validity: Some(vec![0b101]),
+            data: vec![
+                u128::from_le_bytes(*b"\x03\x00\x00\x00foo\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+                0,
+                u128::from_le_bytes(*b"\x0f\x00\x00\x00long\x00\x00\x00\x00\x00\x00\x00\x00"),
+            ],
+            buffers: vec![b"long string 123".to_vec()],
+        }),
+        Array::Dictionary(DictionaryArray {
+            keys: Box::new(Array::UInt16(PrimitiveArray {
+                validity: None,
+                values: vec![1, 0, 1],
+            })),
+            values: Box::new(Array::Utf8(BytesArray {
+                validity: None,
+                offsets: vec![0, 1, 2],
+                data: b"ab".to_vec(),
+            })),
+        }),
+        Array::Struct(StructArray {
+            len: 2,
+            validity: None,
+            fields: vec![
+                (
+                    FieldMeta {
+                        name: String::from("a"),
+                        nullable: true,
+                        metadata: [(String::from("key"), String::from("value"))]
+                            .into_iter()
+                            .collect(),
+                        ..FieldMeta::default()
+                    },
+                    Array::MonthDayNanoInterval(PrimitiveArray {
+                        validity: Some(vec![0b10]),
+                        values: vec![
+                            MonthDayNanoInterval {
+                                months: 0,
+                                days: 0,
+                                nanoseconds: 0,
+                            },
+                            MonthDayNanoInterval {
+                                months: 1,
+                                days: -2,
+                                nanoseconds: 3,
+                            },
+                        ],
+                    }),
+                ),
+                (
+                    FieldMeta {
+                        name: String::from("b"),
+                        ..FieldMeta::default()
+                    },
+                    Array::DayTimeInterval(PrimitiveArray {
+                        validity: None,
+                        values: vec![
+                            DayTimeInterval {
+                                days: 1,
+                                milliseconds: 2,
+                            },
+                            DayTimeInterval {
+                                days: 3,
+                                milliseconds: 4,
+                            },
+                        ],
+                    }),
+                ),
+            ],
+        }),
+    ];
+
+    for array in arrays {
+        let polars_array = Box::<dyn polars_arrow::array::Array>::try_from(array.clone()).unwrap();
+        assert_eq!(
+            DataType::try_from(polars_array.dtype()).unwrap(),
+            array.data_type()
+        );
+        assert_eq!(
+            View::try_from(polars_array.as_ref()).unwrap(),
+            array.as_view()
+        );
+    }
+}
+
+#[test]
+fn data_types_roundtrip() {
+    for repr in [
+        "Float16",
+        "Decimal128(10, 2)",
+        r#"Timestamp(ns, "Europe/Berlin")"#,
+        "Interval(DayTime)",
+        "BinaryView",
+        "FixedSizeList(3)<nullable Float32>",
+        "Map(sorted)<Struct<keys: Utf8, values: nullable Int32>>",
+        "Dictionary<Int8, Utf8View>",
+        "Struct<a: ordered Dictionary<UInt16, Utf8>>",
+        "Union(Sparse)<3: a: Int32, 7: b: Utf8>",
+    ] {
+        let field = Field {
+            name: String::from("field"),
+            data_type: repr.parse().unwrap(),
+            nullable: true,
+            metadata: [(String::from("key"), String::from("value"))]
+                .into_iter()
+                .collect(),
+            ..Field::default()
+        };
+        let polars_field = polars_arrow::datatypes::Field::try_from(&field).unwrap();
+        assert_eq!(Field::try_from(&polars_field).unwrap(), field);
+    }
+}
@@ -0,0 +1,20 @@
+//! Support for polars-arrow
+#![cfg_attr(any(), rustfmt::skip)]
+
+#[cfg(feature = "polars-arrow-0-51")]
+mod polars_arrow_0_51 {
+    use polars_arrow_0_51 as polars_arrow;
+    include!("impl.rs");
+}
+
+#[cfg(feature = "polars-arrow-0-50")]
+mod polars_arrow_0_50 {
+    use polars_arrow_0_50 as polars_arrow;
+    include!("impl.rs");
+}
+
+#[cfg(feature = "polars-arrow-0-46")]
+mod polars_arrow_0_46 {
+    use polars_arrow_0_46 as polars_arrow;
+    include!("impl.rs");
+}
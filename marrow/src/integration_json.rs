@@ -0,0 +1,706 @@
+//! Read and write the JSON format of the Arrow integration tests
+//!
+//! The Arrow implementations test their interoperability with a JSON format that describes a
+//! schema, its record batches and the dictionaries referenced by dictionary encoded fields. See
+//! the [Arrow documentation](https://arrow.apache.org/docs/format/Integration.html#json-test-data-format)
+//! for the specification.
+//!
+//! [`from_str`] parses a document into a [`JsonFile`] and [`to_string`] writes a [`JsonFile`] as
+//! a document:
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! # use marrow::{array::{Array, PrimitiveArray}, datatypes::{DataType, Field}, integration_json};
+//! let file = integration_json::JsonFile {
+//!     fields: vec![Field {
+//!         name: String::from("values"),
+//!         data_type: DataType::Int64,
+//!         nullable: true,
+//!         ..Field::default()
+//!     }],
+//!     batches: vec![vec![Array::Int64(PrimitiveArray {
+//!         validity: Some(vec![0b_101]),
+//!         values: vec![1, 0, 3],
+//!     })]],
+//!     ..integration_json::JsonFile::default()
+//! };
+//!
+//! let document = integration_json::to_string(&file)?;
+//! assert_eq!(integration_json::from_str(&document)?, file);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Dictionary encoded fields are written with the id of their
+//! [`DictionaryMeta`][crate::datatypes::DictionaryMeta], fields without an id are assigned unused
+//! ids in the order of the fields, depth first. When reading, the ids and the ordered flags are
+//! stored in the dictionary metadata of the fields. As the dictionaries are shared between all
+//! batches, the dictionaries of a field must agree between batches when writing. Delta
+//! dictionaries, list views and decimals with a bit width other than 128 are not supported.
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    array::Array,
+    datatypes::{DataType, Field},
+    error::{fail, ErrorKind, Result},
+};
+
+mod read;
+mod write;
+
+/// The contents of a JSON file of the Arrow integration tests
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonFile {
+    /// The fields of the schema
+    pub fields: Vec<Field>,
+    /// The metadata of the schema
+    pub metadata: HashMap<String, String>,
+    /// The record batches, each with one array per field
+    pub batches: Vec<Vec<Array>>,
+}
+
+/// Parse a JSON document in the integration test format
+///
+/// Dictionary encoded fields are resolved against the dictionaries of the document, i.e., each
+/// dictionary array contains the full dictionary.
+pub fn from_str(s: &str) -> Result<JsonFile> {
+    let value: serde_json::Value = serde_json::from_str(s)?;
+    read::read_file(&value)
+}
+
+/// Write a JSON document in the integration test format
+pub fn to_string(file: &JsonFile) -> Result<String> {
+    let value = write::write_file(file)?;
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// A field together with the dictionary ids of it and its children
+///
+/// For dictionary encoded fields, the children are those of the value type, as in the JSON
+/// format.
+#[derive(Debug, Clone)]
+struct Node {
+    field: Field,
+    dictionary: Option<i64>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Build the node of a field, assigning missing dictionary ids depth first
+    fn from_field(field: &Field, ids: &mut DictionaryIds) -> Result<Self> {
+        let (dictionary, data_type) = match &field.data_type {
+            DataType::Dictionary(_, values) => {
+                (Some(ids.assign(field.dictionary.id)), values.as_ref())
+            }
+            data_type => (None, data_type),
+        };
+        let mut children = Vec::new();
+        for child in child_fields(data_type)? {
+            children.push(Node::from_field(child, ids)?);
+        }
+        Ok(Node {
+            field: field.clone(),
+            dictionary,
+            children,
+        })
+    }
+
+    /// The node of the values of a dictionary encoded field
+    fn dictionary_values(&self) -> Result<Node> {
+        let DataType::Dictionary(_, values) = &self.field.data_type else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Field {name:?} is not dictionary encoded",
+                name = self.field.name,
+            );
+        };
+        Ok(Node {
+            field: Field {
+                name: format!("DICT{id}", id = self.dictionary.unwrap_or_default()),
+                data_type: values.as_ref().clone(),
+                nullable: true,
+                ..Field::default()
+            },
+            dictionary: None,
+            children: self.children.clone(),
+        })
+    }
+}
+
+/// The dictionary ids of a schema: the explicit ids of the fields and the next id to assign
+struct DictionaryIds {
+    used: HashSet<i64>,
+    next: i64,
+}
+
+impl DictionaryIds {
+    fn new(fields: &[Field]) -> Result<Self> {
+        fn collect(field: &Field, used: &mut HashSet<i64>) -> Result<()> {
+            let data_type = match &field.data_type {
+                DataType::Dictionary(_, values) => {
+                    if let Some(id) = field.dictionary.id {
+                        if !used.insert(id) {
+                            fail!(
+                                ErrorKind::Unsupported,
+                                "Duplicate dictionary id {id} (field {name:?})",
+                                name = field.name,
+                            );
+                        }
+                    }
+                    values.as_ref()
+                }
+                data_type => data_type,
+            };
+            for child in child_fields(data_type)? {
+                collect(child, used)?;
+            }
+            Ok(())
+        }
+
+        let mut used = HashSet::new();
+        for field in fields {
+            collect(field, &mut used)?;
+        }
+        Ok(Self { used, next: 0 })
+    }
+
+    /// Use the given id or assign the next unused one
+    fn assign(&mut self, id: Option<i64>) -> i64 {
+        if let Some(id) = id {
+            return id;
+        }
+        while self.used.contains(&self.next) {
+            self.next += 1;
+        }
+        let id = self.next;
+        self.next += 1;
+        id
+    }
+}
+
+fn child_fields(data_type: &DataType) -> Result<Vec<&Field>> {
+    use DataType as T;
+    Ok(match data_type {
+        T::Struct(fields) => fields.iter().collect(),
+        T::List(item) | T::LargeList(item) | T::FixedSizeList(item, _) => vec![item.as_ref()],
+        T::Map(entries, _) => vec![entries.as_ref()],
+        T::RunEndEncoded(run_ends, values) => vec![run_ends.as_ref(), values.as_ref()],
+        T::Union(fields, _) => fields.iter().map(|(_, field)| field).collect(),
+        T::Dictionary(_, _) => fail!(
+            ErrorKind::Unsupported,
+            "Dictionaries with dictionary encoded values are not supported"
+        ),
+        _ => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+fn file(arrays: Vec<(crate::datatypes::FieldMeta, Array)>) -> JsonFile {
+    use std::collections::HashMap;
+
+    use crate::datatypes::field_from_meta;
+
+    let fields = arrays
+        .iter()
+        .map(|(meta, array)| field_from_meta(array.data_type(), meta.clone()))
+        .collect();
+    let batches = vec![arrays.into_iter().map(|(_, array)| array).collect()];
+    JsonFile {
+        fields,
+        metadata: HashMap::from([(String::from("key"), String::from("value"))]),
+        batches,
+    }
+}
+
+#[cfg(test)]
+fn assert_round_trip(file: &JsonFile) {
+    let document = to_string(file).unwrap();
+    assert_eq!(&from_str(&document).unwrap(), file, "{document}");
+}
+
+#[test]
+fn primitive_arrays() {
+    use crate::{
+        array::{
+            Array, BooleanArray, DecimalArray, NullArray, PrimitiveArray, TimeArray, TimestampArray,
+        },
+        datatypes::{meta, TimeUnit},
+        types::{DayTimeInterval, MonthDayNanoInterval},
+    };
+
+    assert_round_trip(&file(vec![
+        (meta("null", true), Array::Null(NullArray { len: 3 })),
+        (
+            meta("bool", true),
+            Array::Boolean(BooleanArray {
+                len: 3,
+                validity: Some(vec![0b_011]),
+                values: vec![0b_001],
+            }),
+        ),
+        (
+            meta("i8", false),
+            Array::Int8(PrimitiveArray {
+                validity: None,
+                values: vec![-1, 0, 1],
+            }),
+        ),
+        (
+            meta("u64", false),
+            Array::UInt64(PrimitiveArray {
+                validity: None,
+                values: vec![0, 1, u64::MAX],
+            }),
+        ),
+        (
+            meta("f16", false),
+            Array::Float16(PrimitiveArray {
+                validity: None,
+                values: vec![half::f16::from_f32(0.5); 3],
+            }),
+        ),
+        (
+            meta("f32", true),
+            Array::Float32(PrimitiveArray {
+                validity: Some(vec![0b_110]),
+                values: vec![0.0, 0.1, -2.5],
+            }),
+        ),
+        (
+            meta("date64", false),
+            Array::Date64(PrimitiveArray {
+                validity: None,
+                values: vec![0, 86_400_000, -86_400_000],
+            }),
+        ),
+        (
+            meta("time32", false),
+            Array::Time32(TimeArray {
+                unit: TimeUnit::Millisecond,
+                validity: None,
+                values: vec![0, 1, 2],
+            }),
+        ),
+        (
+            meta("timestamp", false),
+            Array::Timestamp(TimestampArray {
+                unit: TimeUnit::Microsecond,
+                timezone: Some(String::from("UTC")),
+                validity: None,
+                values: vec![0, i64::MIN, i64::MAX],
+            }),
+        ),
+        (
+            meta("day_time", false),
+            Array::DayTimeInterval(PrimitiveArray {
+                validity: None,
+                values: vec![
+                    DayTimeInterval {
+                        days: 1,
+                        milliseconds: -2,
+                    };
+                    3
+                ],
+            }),
+        ),
+        (
+            meta("month_day_nano", false),
+            Array::MonthDayNanoInterval(PrimitiveArray {
+                validity: None,
+                values: vec![
+                    MonthDayNanoInterval {
+                        months: 1,
+                        days: 2,
+                        nanoseconds: -3,
+                    };
+                    3
+                ],
+            }),
+        ),
+        (
+            meta("decimal", false),
+            Array::Decimal128(DecimalArray {
+                precision: 38,
+                scale: 2,
+                validity: None,
+                values: vec![i128::MIN, 0, i128::MAX],
+            }),
+        ),
+    ]));
+}
+
+#[test]
+fn bytes_arrays() {
+    use crate::{
+        array::{Array, BytesArray, BytesViewArray, FixedSizeBinaryArray},
+        datatypes::meta,
+    };
+
+    let long = b"a string that is not inlined";
+    assert_round_trip(&file(vec![
+        (
+            meta("utf8", true),
+            Array::Utf8(BytesArray {
+                validity: Some(vec![0b_101]),
+                offsets: vec![0, 3, 3, 6],
+                data: b"foobar".to_vec(),
+            }),
+        ),
+        (
+            meta("large_binary", false),
+            Array::LargeBinary(BytesArray {
+                validity: None,
+                offsets: vec![0, 1, 3, 3],
+                data: vec![0x00, 0xab, 0xff],
+            }),
+        ),
+        (
+            meta("fixed_size_binary", false),
+            Array::FixedSizeBinary(FixedSizeBinaryArray {
+                n: 2,
+                validity: None,
+                data: vec![1, 2, 3, 4, 5, 6],
+            }),
+        ),
+        (
+            meta("utf8_view", false),
+            Array::Utf8View(BytesViewArray {
+                validity: None,
+                data: vec![
+                    u128::from_le_bytes(*b"\x03\0\0\0foo\0\0\0\0\0\0\0\0\0"),
+                    0,
+                    u128::from(long.len() as u32)
+                        | (u128::from(u32::from_le_bytes(*b"a st")) << 32)
+                        | (1 << 64),
+                ],
+                buffers: vec![b"unused".to_vec(), long.to_vec()],
+            }),
+        ),
+        (
+            meta("binary_view", false),
+            Array::BinaryView(BytesViewArray {
+                validity: None,
+                data: vec![
+                    0,
+                    u128::from_le_bytes(*b"\x01\0\0\0\xff\0\0\0\0\0\0\0\0\0\0\0"),
+                    0,
+                ],
+                buffers: vec![],
+            }),
+        ),
+    ]));
+}
+
+#[test]
+fn nested_arrays() {
+    use crate::{
+        array::{
+            Array, BytesArray, FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray,
+            RunEndEncodedArray, StructArray, UnionArray,
+        },
+        datatypes::{meta, MapMeta, RunEndEncodedMeta},
+    };
+
+    let int32 = |values: Vec<i32>| {
+        Array::Int32(PrimitiveArray {
+            validity: None,
+            values,
+        })
+    };
+    assert_round_trip(&file(vec![
+        (
+            meta("struct", true),
+            Array::Struct(StructArray {
+                len: 2,
+                validity: Some(vec![0b_10]),
+                fields: vec![(meta("a", false), int32(vec![1, 2]))],
+            }),
+        ),
+        (
+            meta("list", false),
+            Array::LargeList(ListArray {
+                validity: None,
+                offsets: vec![0, 3, 4],
+                meta: meta("item", false),
+                elements: Box::new(int32(vec![1, 2, 3, 4])),
+            }),
+        ),
+        (
+            meta("fixed_size_list", false),
+            Array::FixedSizeList(FixedSizeListArray {
+                len: 2,
+                n: 2,
+                validity: None,
+                meta: meta("item", false),
+                elements: Box::new(int32(vec![1, 2, 3, 4])),
+            }),
+        ),
+        (
+            meta("map", true),
+            Array::Map(MapArray {
+                validity: Some(vec![0b_01]),
+                offsets: vec![0, 2, 2],
+                meta: MapMeta {
+                    keys: meta("key", false),
+                    values: meta("value", true),
+                    ..MapMeta::default()
+                },
+                keys: Box::new(Array::Utf8(BytesArray {
+                    validity: None,
+                    offsets: vec![0, 1, 2],
+                    data: b"ab".to_vec(),
+                })),
+                values: Box::new(Array::Int32(PrimitiveArray {
+                    validity: Some(vec![0b_10]),
+                    values: vec![0, 2],
+                })),
+            }),
+        ),
+        (
+            meta("dense_union", false),
+            Array::Union(UnionArray {
+                types: vec![3, 5],
+                offsets: Some(vec![0, 0]),
+                fields: vec![
+                    (3, meta("int", false), int32(vec![1])),
+                    (5, meta("null", true), Array::Null(NullArray { len: 1 })),
+                ],
+            }),
+        ),
+        (
+            meta("sparse_union", false),
+            Array::Union(UnionArray {
+                types: vec![1, 0],
+                offsets: None,
+                fields: vec![
+                    (0, meta("a", false), int32(vec![1, 2])),
+                    (1, meta("b", false), int32(vec![3, 4])),
+                ],
+            }),
+        ),
+        (
+            meta("run_end_encoded", false),
+            Array::RunEndEncoded(RunEndEncodedArray {
+                meta: RunEndEncodedMeta::default(),
+                run_ends: Box::new(Array::Int16(PrimitiveArray {
+                    validity: None,
+                    values: vec![1, 2],
+                })),
+                values: Box::new(Array::Int32(PrimitiveArray {
+                    validity: Some(vec![0b_01]),
+                    values: vec![1, 0],
+                })),
+            }),
+        ),
+    ]));
+}
+
+#[test]
+fn dictionaries() {
+    use crate::{
+        array::{Array, BytesArray, DictionaryArray, ListArray, PrimitiveArray},
+        datatypes::{meta, DictionaryMeta, FieldMeta},
+    };
+
+    let dictionary = |keys: Vec<i8>| {
+        Array::Dictionary(DictionaryArray {
+            keys: Box::new(Array::Int8(PrimitiveArray {
+                validity: Some(vec![0b_011]),
+                values: keys,
+            })),
+            values: Box::new(Array::Utf8(BytesArray {
+                validity: Some(vec![0b_11]),
+                offsets: vec![0, 3, 6],
+                data: b"foobar".to_vec(),
+            })),
+        })
+    };
+    let mut file = file(vec![
+        (
+            FieldMeta {
+                dictionary: DictionaryMeta {
+                    ordered: true,
+                    id: None,
+                },
+                ..meta("a", true)
+            },
+            dictionary(vec![0, 1, 0]),
+        ),
+        (
+            meta("b", false),
+            Array::List(ListArray {
+                validity: None,
+                offsets: vec![0, 1, 3, 3],
+                meta: FieldMeta {
+                    dictionary: DictionaryMeta {
+                        ordered: false,
+                        id: Some(0),
+                    },
+                    ..meta("item", true)
+                },
+                elements: Box::new(dictionary(vec![1, 1, 0])),
+            }),
+        ),
+    ]);
+    file.batches.push(file.batches[0].clone());
+
+    // the missing id is assigned, skipping the explicit id of the list items
+    let document = to_string(&file).unwrap();
+    assert!(document.contains("\"DICT1\""));
+    assert!(document.contains("\"isOrdered\": true"));
+    assert_eq!(
+        from_str(&document).unwrap().fields[0].dictionary.id,
+        Some(1)
+    );
+
+    file.fields[0].dictionary.id = Some(1);
+    assert_round_trip(&file);
+
+    file.fields[0].dictionary.id = Some(0);
+    assert!(to_string(&file).is_err());
+    file.fields[0].dictionary.id = Some(1);
+
+    let Array::Dictionary(array) = &mut file.batches[1][0] else {
+        unreachable!();
+    };
+    *array.values = Array::Utf8(BytesArray {
+        validity: Some(vec![0b_11]),
+        offsets: vec![0, 3, 6],
+        data: b"barfoo".to_vec(),
+    });
+    assert!(to_string(&file).is_err());
+}
+
+#[test]
+fn golden_document() {
+    use std::collections::HashMap;
+
+    use crate::{
+        array::{Array, BytesArray, PrimitiveArray},
+        datatypes::{DataType, DictionaryMeta, Field, IntervalUnit},
+    };
+
+    let document = r#"{
+        "schema": {
+            "fields": [
+                {
+                    "name": "ints",
+                    "nullable": true,
+                    "type": {"name": "int", "isSigned": true, "bitWidth": 64},
+                    "children": []
+                },
+                {
+                    "name": "strs",
+                    "nullable": true,
+                    "type": {"name": "utf8"},
+                    "children": [],
+                    "dictionary": {
+                        "id": 7,
+                        "indexType": {"name": "int", "isSigned": false, "bitWidth": 16},
+                        "isOrdered": false
+                    }
+                },
+                {
+                    "name": "interval",
+                    "nullable": false,
+                    "type": {"name": "interval", "unit": "YEAR_MONTH"},
+                    "children": [],
+                    "metadata": [{"key": "k", "value": "v"}]
+                }
+            ]
+        },
+        "dictionaries": [
+            {
+                "id": 7,
+                "data": {
+                    "count": 2,
+                    "columns": [
+                        {
+                            "name": "DICT7",
+                            "count": 2,
+                            "VALIDITY": [1, 1],
+                            "OFFSET": [0, 1, 2],
+                            "DATA": ["x", "y"]
+                        }
+                    ]
+                }
+            }
+        ],
+        "batches": [
+            {
+                "count": 2,
+                "columns": [
+                    {"name": "ints", "count": 2, "VALIDITY": [0, 1], "DATA": ["0", "-9007199254740993"]},
+                    {"name": "strs", "count": 2, "VALIDITY": [1, 1], "DATA": [1, 0]},
+                    {"name": "interval", "count": 2, "VALIDITY": [1, 1], "DATA": [12, -1]}
+                ]
+            }
+        ]
+    }"#;
+    let file = from_str(document).unwrap();
+    assert_eq!(file.fields.len(), 3);
+    assert_eq!(
+        file.fields[1].data_type,
+        DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8))
+    );
+    assert_eq!(
+        file.fields[1].dictionary,
+        DictionaryMeta {
+            ordered: false,
+            id: Some(7),
+        }
+    );
+    assert_eq!(
+        file.fields[2],
+        Field {
+            name: String::from("interval"),
+            data_type: DataType::Interval(IntervalUnit::YearMonth),
+            metadata: HashMap::from([(String::from("k"), String::from("v"))]),
+            ..Field::default()
+        }
+    );
+    assert_eq!(
+        file.batches[0][0],
+        Array::Int64(PrimitiveArray {
+            validity: Some(vec![0b_10]),
+            values: vec![0, -9_007_199_254_740_993],
+        })
+    );
+    assert_eq!(
+        file.batches[0][2],
+        Array::YearMonthInterval(PrimitiveArray {
+            validity: None,
+            values: vec![12, -1],
+        })
+    );
+    let Array::Dictionary(array) = &file.batches[0][1] else {
+        panic!("Expected a dictionary array");
+    };
+    assert_eq!(
+        array.values.as_ref(),
+        &Array::Utf8(BytesArray {
+            validity: Some(vec![0b_11]),
+            offsets: vec![0, 1, 2],
+            data: b"xy".to_vec(),
+        })
+    );
+
+    assert!(from_str(&document.replacen("\"id\": 7", "\"id\": 8", 1)).is_err());
+    assert!(from_str(&document.replace(
+        "\"count\": 2, \"VALIDITY\": [0, 1]",
+        "\"count\": 3, \"VALIDITY\": [0, 1]"
+    ))
+    .is_err());
+}
+
+#[test]
+fn mismatched_arrays() {
+    use crate::{
+        array::{Array, NullArray},
+        datatypes::{meta, DataType},
+    };
+
+    let mut file = file(vec![(meta("a", false), Array::Null(NullArray { len: 1 }))]);
+    file.fields[0].data_type = DataType::Int32;
+    assert!(to_string(&file).is_err());
+}
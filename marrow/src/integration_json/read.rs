@@ -0,0 +1,750 @@
+//! Read arrays and fields from the JSON format
+use std::{collections::HashMap, num::TryFromIntError};
+
+use half::f16;
+use serde_json::Value;
+
+use crate::{
+    array::{
+        Array, BooleanArray, BytesArray, BytesViewArray, DecimalArray, DictionaryArray,
+        FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray,
+        RunEndEncodedArray, StructArray, TimeArray, TimestampArray, UnionArray,
+    },
+    bits::Bitmap,
+    datatypes::{
+        meta_from_field, DataType, DictionaryMeta, Field, FieldMeta, IntervalUnit, MapMeta,
+        RunEndEncodedMeta, TimeUnit, UnionMode,
+    },
+    error::{fail, ErrorKind, Result},
+    types::{DayTimeInterval, MonthDayNanoInterval},
+};
+
+use super::{JsonFile, Node};
+
+pub(super) fn read_file(value: &Value) -> Result<JsonFile> {
+    let schema = get(value, "schema")?;
+    let mut nodes = Vec::new();
+    for field in get_array(schema, "fields")? {
+        nodes.push(read_node(field)?);
+    }
+    let metadata = read_metadata(schema)?;
+
+    let mut dictionaries = HashMap::new();
+    for dictionary in get_array_or_empty(value, "dictionaries")? {
+        let id = as_int::<i64>(get(dictionary, "id")?)?;
+        let [column] = get_array(get(dictionary, "data")?, "columns")? else {
+            fail!(
+                ErrorKind::ParseError,
+                "The dictionary batch {id} must have exactly one column"
+            );
+        };
+        if dictionaries.insert(id, column).is_some() {
+            fail!(
+                ErrorKind::Unsupported,
+                "Delta dictionaries are not supported (dictionary id {id})"
+            );
+        }
+    }
+
+    let mut batches = Vec::new();
+    for batch in get_array_or_empty(value, "batches")? {
+        let columns = get_array(batch, "columns")?;
+        if columns.len() != nodes.len() {
+            fail!(
+                ErrorKind::ParseError,
+                "The batch has {len} columns, but the schema {expected} fields",
+                len = columns.len(),
+                expected = nodes.len(),
+            );
+        }
+        let mut arrays = Vec::with_capacity(columns.len());
+        for (node, column) in nodes.iter().zip(columns) {
+            arrays.push(read_array(node, column, &dictionaries)?);
+        }
+        batches.push(arrays);
+    }
+
+    Ok(JsonFile {
+        fields: nodes.into_iter().map(|node| node.field).collect(),
+        metadata,
+        batches,
+    })
+}
+
+fn read_node(value: &Value) -> Result<Node> {
+    let name = get_str(value, "name")?;
+    let nullable = match value.get("nullable") {
+        Some(nullable) => as_bool(nullable)?,
+        None => false,
+    };
+
+    let mut children = Vec::new();
+    for child in get_array_or_empty(value, "children")? {
+        children.push(read_node(child)?);
+    }
+    let mut data_type = read_data_type(get(value, "type")?, &children)?;
+
+    let dictionary = match value.get("dictionary") {
+        Some(dictionary) => {
+            let keys = read_data_type(get(dictionary, "indexType")?, &[])?;
+            if !keys.is_integer() {
+                fail!(
+                    ErrorKind::ParseError,
+                    "The dictionary of field {name:?} must have integer keys"
+                );
+            }
+            data_type = DataType::Dictionary(Box::new(keys), Box::new(data_type));
+            let ordered = match dictionary.get("isOrdered") {
+                Some(ordered) => as_bool(ordered)?,
+                None => false,
+            };
+            Some(DictionaryMeta {
+                ordered,
+                id: Some(as_int(get(dictionary, "id")?)?),
+            })
+        }
+        None => None,
+    };
+    let id = dictionary.as_ref().and_then(|dictionary| dictionary.id);
+
+    Ok(Node {
+        field: Field {
+            name: name.to_owned(),
+            data_type,
+            nullable,
+            metadata: read_metadata(value)?,
+            dictionary: dictionary.unwrap_or_default(),
+        },
+        dictionary: id,
+        children,
+    })
+}
+
+fn read_metadata(value: &Value) -> Result<HashMap<String, String>> {
+    let mut metadata = HashMap::new();
+    for item in get_array_or_empty(value, "metadata")? {
+        metadata.insert(
+            get_str(item, "key")?.to_owned(),
+            get_str(item, "value")?.to_owned(),
+        );
+    }
+    Ok(metadata)
+}
+
+fn read_data_type(value: &Value, children: &[Node]) -> Result<DataType> {
+    use DataType as T;
+
+    let name = get_str(value, "name")?;
+    let data_type = match name {
+        "null" => T::Null,
+        "bool" => T::Boolean,
+        "int" => {
+            let signed = as_bool(get(value, "isSigned")?)?;
+            match (signed, as_int::<i64>(get(value, "bitWidth")?)?) {
+                (true, 8) => T::Int8,
+                (true, 16) => T::Int16,
+                (true, 32) => T::Int32,
+                (true, 64) => T::Int64,
+                (false, 8) => T::UInt8,
+                (false, 16) => T::UInt16,
+                (false, 32) => T::UInt32,
+                (false, 64) => T::UInt64,
+                (_, bit_width) => fail!(
+                    ErrorKind::ParseError,
+                    "Invalid bit width {bit_width} for integers"
+                ),
+            }
+        }
+        "floatingpoint" => match get_str(value, "precision")? {
+            "HALF" => T::Float16,
+            "SINGLE" => T::Float32,
+            "DOUBLE" => T::Float64,
+            precision => fail!(
+                ErrorKind::ParseError,
+                "Invalid floating point precision {precision:?}"
+            ),
+        },
+        "utf8" => T::Utf8,
+        "largeutf8" => T::LargeUtf8,
+        "utf8view" => T::Utf8View,
+        "binary" => T::Binary,
+        "largebinary" => T::LargeBinary,
+        "binaryview" => T::BinaryView,
+        "fixedsizebinary" => T::FixedSizeBinary(as_int(get(value, "byteWidth")?)?),
+        "date" => match get_str(value, "unit")? {
+            "DAY" => T::Date32,
+            "MILLISECOND" => T::Date64,
+            unit => fail!(ErrorKind::ParseError, "Invalid date unit {unit:?}"),
+        },
+        "time" => {
+            let unit = read_time_unit(value)?;
+            match as_int::<i64>(get(value, "bitWidth")?)? {
+                32 => T::Time32(unit),
+                64 => T::Time64(unit),
+                bit_width => fail!(
+                    ErrorKind::ParseError,
+                    "Invalid bit width {bit_width} for times"
+                ),
+            }
+        }
+        "timestamp" => {
+            let timezone = match value.get("timezone") {
+                Some(Value::Null) | None => None,
+                Some(timezone) => Some(as_str(timezone)?.to_owned()),
+            };
+            T::Timestamp(read_time_unit(value)?, timezone)
+        }
+        "duration" => T::Duration(read_time_unit(value)?),
+        "interval" => match get_str(value, "unit")? {
+            "YEAR_MONTH" => T::Interval(IntervalUnit::YearMonth),
+            "DAY_TIME" => T::Interval(IntervalUnit::DayTime),
+            "MONTH_DAY_NANO" => T::Interval(IntervalUnit::MonthDayNano),
+            unit => fail!(ErrorKind::ParseError, "Invalid interval unit {unit:?}"),
+        },
+        "decimal" => {
+            let bit_width = match value.get("bitWidth") {
+                Some(bit_width) => as_int::<i64>(bit_width)?,
+                None => 128,
+            };
+            if bit_width != 128 {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Decimals with bit width {bit_width} are not supported"
+                );
+            }
+            T::Decimal128(
+                as_int(get(value, "precision")?)?,
+                as_int(get(value, "scale")?)?,
+            )
+        }
+        "struct" => T::Struct(children.iter().map(|node| node.field.clone()).collect()),
+        "list" => T::List(Box::new(single_child(name, children)?)),
+        "largelist" => T::LargeList(Box::new(single_child(name, children)?)),
+        "fixedsizelist" => T::FixedSizeList(
+            Box::new(single_child(name, children)?),
+            as_int(get(value, "listSize")?)?,
+        ),
+        "map" => T::Map(
+            Box::new(single_child(name, children)?),
+            as_bool(get(value, "keysSorted")?)?,
+        ),
+        "union" => {
+            let mode = match get_str(value, "mode")? {
+                "SPARSE" => UnionMode::Sparse,
+                "DENSE" => UnionMode::Dense,
+                mode => fail!(ErrorKind::ParseError, "Invalid union mode {mode:?}"),
+            };
+            let type_ids = get_array(value, "typeIds")?;
+            if type_ids.len() != children.len() {
+                fail!(
+                    ErrorKind::ParseError,
+                    "The number of type ids must match the number of union fields"
+                );
+            }
+            let mut fields = Vec::with_capacity(children.len());
+            for (type_id, node) in type_ids.iter().zip(children) {
+                fields.push((as_int(type_id)?, node.field.clone()));
+            }
+            T::Union(fields, mode)
+        }
+        "runendencoded" => {
+            let [run_ends, values] = children else {
+                fail!(
+                    ErrorKind::ParseError,
+                    "Run end encoded fields require exactly two children"
+                );
+            };
+            T::RunEndEncoded(
+                Box::new(run_ends.field.clone()),
+                Box::new(values.field.clone()),
+            )
+        }
+        "listview" | "largelistview" => fail!(
+            ErrorKind::Unsupported,
+            "The data type {name:?} is not supported"
+        ),
+        name => fail!(ErrorKind::ParseError, "Unknown data type {name:?}"),
+    };
+    Ok(data_type)
+}
+
+fn read_time_unit(value: &Value) -> Result<TimeUnit> {
+    match get_str(value, "unit")? {
+        "SECOND" => Ok(TimeUnit::Second),
+        "MILLISECOND" => Ok(TimeUnit::Millisecond),
+        "MICROSECOND" => Ok(TimeUnit::Microsecond),
+        "NANOSECOND" => Ok(TimeUnit::Nanosecond),
+        unit => fail!(ErrorKind::ParseError, "Invalid time unit {unit:?}"),
+    }
+}
+
+fn single_child(name: &str, children: &[Node]) -> Result<Field> {
+    let [child] = children else {
+        fail!(
+            ErrorKind::ParseError,
+            "Fields of type {name:?} require exactly one child"
+        );
+    };
+    Ok(child.field.clone())
+}
+
+fn read_array(node: &Node, data: &Value, dictionaries: &HashMap<i64, &Value>) -> Result<Array> {
+    use {Array as A, DataType as T};
+
+    let count = as_int::<usize>(get(data, "count")?)?;
+    let validity = || read_validity(data, count, node.field.nullable);
+
+    macro_rules! primitive {
+        ($variant:ident, $parse:expr) => {
+            A::$variant(PrimitiveArray {
+                validity: validity()?,
+                values: read_values(data, "DATA", count, $parse)?,
+            })
+        };
+    }
+    macro_rules! time {
+        ($variant:ident, $unit:expr) => {
+            A::$variant(TimeArray {
+                unit: *$unit,
+                validity: validity()?,
+                values: read_values(data, "DATA", count, as_int)?,
+            })
+        };
+    }
+
+    let array = match &node.field.data_type {
+        T::Null => A::Null(NullArray { len: count }),
+        T::Boolean => A::Boolean(BooleanArray {
+            len: count,
+            validity: validity()?,
+            values: Bitmap::from(read_values(data, "DATA", count, as_bool)?).into_vec(),
+        }),
+        T::Int8 => primitive!(Int8, as_int),
+        T::Int16 => primitive!(Int16, as_int),
+        T::Int32 => primitive!(Int32, as_int),
+        T::Int64 => primitive!(Int64, as_int),
+        T::UInt8 => primitive!(UInt8, as_int),
+        T::UInt16 => primitive!(UInt16, as_int),
+        T::UInt32 => primitive!(UInt32, as_int),
+        T::UInt64 => primitive!(UInt64, as_int),
+        T::Float16 => primitive!(Float16, |value| Ok(f16::from_f64(as_f64(value)?))),
+        T::Float32 => primitive!(Float32, |value| Ok(as_f64(value)? as f32)),
+        T::Float64 => primitive!(Float64, as_f64),
+        T::Date32 => primitive!(Date32, as_int),
+        T::Date64 => primitive!(Date64, as_int),
+        T::Time32(unit) => time!(Time32, unit),
+        T::Time64(unit) => time!(Time64, unit),
+        T::Duration(unit) => time!(Duration, unit),
+        T::Timestamp(unit, timezone) => A::Timestamp(TimestampArray {
+            unit: *unit,
+            timezone: timezone.clone(),
+            validity: validity()?,
+            values: read_values(data, "DATA", count, as_int)?,
+        }),
+        T::Interval(IntervalUnit::YearMonth) => primitive!(YearMonthInterval, as_int),
+        T::Interval(IntervalUnit::DayTime) => primitive!(DayTimeInterval, |value| {
+            Ok(DayTimeInterval {
+                days: as_int(get(value, "days")?)?,
+                milliseconds: as_int(get(value, "milliseconds")?)?,
+            })
+        }),
+        T::Interval(IntervalUnit::MonthDayNano) => primitive!(MonthDayNanoInterval, |value| {
+            Ok(MonthDayNanoInterval {
+                months: as_int(get(value, "months")?)?,
+                days: as_int(get(value, "days")?)?,
+                nanoseconds: as_int(get(value, "nanoseconds")?)?,
+            })
+        }),
+        T::Decimal128(precision, scale) => A::Decimal128(DecimalArray {
+            precision: *precision,
+            scale: *scale,
+            validity: validity()?,
+            values: read_values(data, "DATA", count, as_i128)?,
+        }),
+        T::Utf8 => A::Utf8(read_bytes(data, count, validity()?, as_utf8)?),
+        T::LargeUtf8 => A::LargeUtf8(read_bytes(data, count, validity()?, as_utf8)?),
+        T::Binary => A::Binary(read_bytes(data, count, validity()?, as_hex)?),
+        T::LargeBinary => A::LargeBinary(read_bytes(data, count, validity()?, as_hex)?),
+        T::Utf8View => A::Utf8View(read_bytes_view(data, count, validity()?, as_utf8)?),
+        T::BinaryView => A::BinaryView(read_bytes_view(data, count, validity()?, as_hex)?),
+        T::FixedSizeBinary(n) => {
+            let mut bytes = Vec::new();
+            for value in read_values(data, "DATA", count, as_hex)? {
+                if value.len() != usize::try_from(*n)? {
+                    fail!(
+                        ErrorKind::ParseError,
+                        "Fixed size binary values of field {name:?} must have {n} bytes",
+                        name = node.field.name,
+                    );
+                }
+                bytes.extend(value);
+            }
+            A::FixedSizeBinary(FixedSizeBinaryArray {
+                n: *n,
+                validity: validity()?,
+                data: bytes,
+            })
+        }
+        T::Struct(_) => {
+            let children = read_children(node, data, dictionaries)?;
+            A::Struct(StructArray {
+                len: count,
+                validity: validity()?,
+                fields: children,
+            })
+        }
+        T::List(_) => {
+            let [(meta, elements)] = read_children_array(node, data, dictionaries)?;
+            A::List(ListArray {
+                validity: validity()?,
+                offsets: read_offsets(data, count + 1)?,
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::LargeList(_) => {
+            let [(meta, elements)] = read_children_array(node, data, dictionaries)?;
+            A::LargeList(ListArray {
+                validity: validity()?,
+                offsets: read_offsets(data, count + 1)?,
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::FixedSizeList(_, n) => {
+            let [(meta, elements)] = read_children_array(node, data, dictionaries)?;
+            A::FixedSizeList(FixedSizeListArray {
+                len: count,
+                n: *n,
+                validity: validity()?,
+                meta,
+                elements: Box::new(elements),
+            })
+        }
+        T::Map(_, sorted) => {
+            let [entries] = node.children.as_slice() else {
+                fail!(ErrorKind::ParseError, "Map fields require a single child");
+            };
+            let [entries_data] = get_array(data, "children")? else {
+                fail!(ErrorKind::ParseError, "Map columns require a single child");
+            };
+            let [(keys_meta, keys), (values_meta, values)] =
+                read_children_array(entries, entries_data, dictionaries)?;
+            A::Map(MapArray {
+                validity: validity()?,
+                offsets: read_offsets(data, count + 1)?,
+                meta: MapMeta {
+                    entries_name: entries.field.name.clone(),
+                    sorted: *sorted,
+                    keys: keys_meta,
+                    values: values_meta,
+                },
+                keys: Box::new(keys),
+                values: Box::new(values),
+            })
+        }
+        T::Union(fields, mode) => {
+            let children = read_children(node, data, dictionaries)?;
+            let offsets = match mode {
+                UnionMode::Dense => Some(read_offsets(data, count)?),
+                UnionMode::Sparse => None,
+            };
+            A::Union(UnionArray {
+                types: read_values(data, "TYPE_ID", count, as_int)?,
+                offsets,
+                fields: fields
+                    .iter()
+                    .zip(children)
+                    .map(|((type_id, _), (meta, array))| (*type_id, meta, array))
+                    .collect(),
+            })
+        }
+        T::RunEndEncoded(_, _) => {
+            let [(run_ends_meta, run_ends), (values_meta, values)] =
+                read_children_array(node, data, dictionaries)?;
+            A::RunEndEncoded(RunEndEncodedArray {
+                meta: RunEndEncodedMeta {
+                    run_ends_name: run_ends_meta.name,
+                    values: values_meta,
+                },
+                run_ends: Box::new(run_ends),
+                values: Box::new(values),
+            })
+        }
+        T::Dictionary(_, _) => {
+            let Some(id) = node.dictionary else {
+                fail!(
+                    ErrorKind::ParseError,
+                    "Dictionary field {name:?} without dictionary id",
+                    name = node.field.name,
+                );
+            };
+            let Some(values_data) = dictionaries.get(&id) else {
+                fail!(ErrorKind::ParseError, "Unknown dictionary id {id}");
+            };
+            let DataType::Dictionary(key_type, _) = &node.field.data_type else {
+                unreachable!();
+            };
+            let keys_node = Node {
+                field: Field {
+                    data_type: key_type.as_ref().clone(),
+                    ..node.field.clone()
+                },
+                dictionary: None,
+                children: Vec::new(),
+            };
+            A::Dictionary(DictionaryArray {
+                keys: Box::new(read_array(&keys_node, data, dictionaries)?),
+                values: Box::new(read_array(
+                    &node.dictionary_values()?,
+                    values_data,
+                    dictionaries,
+                )?),
+            })
+        }
+    };
+    Ok(array)
+}
+
+fn read_children(
+    node: &Node,
+    data: &Value,
+    dictionaries: &HashMap<i64, &Value>,
+) -> Result<Vec<(FieldMeta, Array)>> {
+    let columns = get_array(data, "children")?;
+    if columns.len() != node.children.len() {
+        fail!(
+            ErrorKind::ParseError,
+            "The column of field {name:?} has {len} children, expected {expected}",
+            name = node.field.name,
+            len = columns.len(),
+            expected = node.children.len(),
+        );
+    }
+    let mut children = Vec::with_capacity(columns.len());
+    for (child, column) in node.children.iter().zip(columns) {
+        children.push((
+            meta_from_field(child.field.clone()),
+            read_array(child, column, dictionaries)?,
+        ));
+    }
+    Ok(children)
+}
+
+fn read_children_array<const N: usize>(
+    node: &Node,
+    data: &Value,
+    dictionaries: &HashMap<i64, &Value>,
+) -> Result<[(FieldMeta, Array); N]> {
+    let children = read_children(node, data, dictionaries)?;
+    let Ok(children) = <[_; N]>::try_from(children) else {
+        fail!(
+            ErrorKind::ParseError,
+            "The field {name:?} requires {N} children",
+            name = node.field.name,
+        );
+    };
+    Ok(children)
+}
+
+fn read_validity(data: &Value, count: usize, nullable: bool) -> Result<Option<Vec<u8>>> {
+    let Some(validity) = data.get("VALIDITY") else {
+        return Ok(nullable.then(|| Bitmap::from_value(true, count).into_vec()));
+    };
+    let validity = check_count(as_array(validity)?, count, "VALIDITY")?;
+    let mut bitmap = Bitmap::new();
+    for value in validity {
+        bitmap.push(as_int::<u8>(value)? != 0);
+    }
+    if !nullable && bitmap.count_set_bits() == count {
+        return Ok(None);
+    }
+    Ok(Some(bitmap.into_vec()))
+}
+
+fn read_values<T>(
+    data: &Value,
+    key: &str,
+    count: usize,
+    parse: impl Fn(&Value) -> Result<T>,
+) -> Result<Vec<T>> {
+    let values = check_count(get_array(data, key)?, count, key)?;
+    values.iter().map(parse).collect()
+}
+
+fn read_offsets<O: TryFrom<i128, Error = TryFromIntError>>(
+    data: &Value,
+    len: usize,
+) -> Result<Vec<O>> {
+    let offsets = check_count(get_array(data, "OFFSET")?, len, "OFFSET")?;
+    offsets.iter().map(as_int).collect()
+}
+
+fn read_bytes<O: TryFrom<usize, Error = TryFromIntError>>(
+    data: &Value,
+    count: usize,
+    validity: Option<Vec<u8>>,
+    parse: fn(&Value) -> Result<Vec<u8>>,
+) -> Result<BytesArray<O>> {
+    let mut offsets = vec![O::try_from(0)?];
+    let mut bytes = Vec::new();
+    for value in check_count(get_array(data, "DATA")?, count, "DATA")? {
+        bytes.extend(parse(value)?);
+        offsets.push(O::try_from(bytes.len())?);
+    }
+    Ok(BytesArray {
+        validity,
+        offsets,
+        data: bytes,
+    })
+}
+
+fn read_bytes_view(
+    data: &Value,
+    count: usize,
+    validity: Option<Vec<u8>>,
+    parse: fn(&Value) -> Result<Vec<u8>>,
+) -> Result<BytesViewArray> {
+    let mut buffers = Vec::new();
+    for buffer in get_array_or_empty(data, "VARIADIC_DATA_BUFFERS")? {
+        buffers.push(as_hex(buffer)?);
+    }
+
+    let mut views = Vec::with_capacity(count);
+    for view in check_count(get_array(data, "VIEWS")?, count, "VIEWS")? {
+        let len = as_int::<u32>(get(view, "SIZE")?)?;
+        let mut bytes = [0_u8; 16];
+        bytes[0..4].copy_from_slice(&len.to_le_bytes());
+        if let Some(inlined) = view.get("INLINED") {
+            let inlined = parse(inlined)?;
+            if inlined.len() != len as usize || inlined.len() > 12 {
+                fail!(
+                    ErrorKind::ParseError,
+                    "Inlined views must contain SIZE bytes, at most 12"
+                );
+            }
+            bytes[4..4 + inlined.len()].copy_from_slice(&inlined);
+        } else {
+            let prefix = as_hex(get(view, "PREFIX_HEX")?)?;
+            if prefix.len() != 4 {
+                fail!(ErrorKind::ParseError, "View prefixes must have 4 bytes");
+            }
+            bytes[4..8].copy_from_slice(&prefix);
+            bytes[8..12].copy_from_slice(&as_int::<u32>(get(view, "BUFFER_INDEX")?)?.to_le_bytes());
+            bytes[12..16].copy_from_slice(&as_int::<u32>(get(view, "OFFSET")?)?.to_le_bytes());
+        }
+        views.push(u128::from_le_bytes(bytes));
+    }
+
+    Ok(BytesViewArray {
+        validity,
+        data: views,
+        buffers,
+    })
+}
+
+fn check_count<'v>(values: &'v [Value], count: usize, key: &str) -> Result<&'v [Value]> {
+    if values.len() != count {
+        fail!(
+            ErrorKind::ParseError,
+            "{key} has {len} entries, expected {count}",
+            len = values.len(),
+        );
+    }
+    Ok(values)
+}
+
+fn get<'v>(value: &'v Value, key: &str) -> Result<&'v Value> {
+    match value.get(key) {
+        Some(value) => Ok(value),
+        None => fail!(ErrorKind::ParseError, "Missing key {key:?}"),
+    }
+}
+
+fn get_str<'v>(value: &'v Value, key: &str) -> Result<&'v str> {
+    as_str(get(value, key)?)
+}
+
+fn get_array<'v>(value: &'v Value, key: &str) -> Result<&'v [Value]> {
+    as_array(get(value, key)?)
+}
+
+fn get_array_or_empty<'v>(value: &'v Value, key: &str) -> Result<&'v [Value]> {
+    match value.get(key) {
+        Some(value) => as_array(value),
+        None => Ok(&[]),
+    }
+}
+
+fn as_str(value: &Value) -> Result<&str> {
+    match value {
+        Value::String(value) => Ok(value),
+        value => fail!(ErrorKind::ParseError, "Expected a string, found {value}"),
+    }
+}
+
+fn as_array(value: &Value) -> Result<&[Value]> {
+    match value {
+        Value::Array(values) => Ok(values),
+        value => fail!(ErrorKind::ParseError, "Expected an array, found {value}"),
+    }
+}
+
+fn as_bool(value: &Value) -> Result<bool> {
+    match value {
+        Value::Bool(value) => Ok(*value),
+        value => fail!(ErrorKind::ParseError, "Expected a boolean, found {value}"),
+    }
+}
+
+fn as_int<T: TryFrom<i128, Error = TryFromIntError>>(value: &Value) -> Result<T> {
+    Ok(T::try_from(as_i128(value)?)?)
+}
+
+/// Parse an integer given as a number or, for 64 bit and wider integers, as a string
+fn as_i128(value: &Value) -> Result<i128> {
+    Ok(match value {
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => value.into(),
+            (None, Some(value)) => value.into(),
+            (None, None) => fail!(ErrorKind::ParseError, "Expected an integer, found {number}"),
+        },
+        Value::String(value) => match value.parse() {
+            Ok(value) => value,
+            Err(_) => fail!(
+                ErrorKind::ParseError,
+                "Expected an integer, found {value:?}"
+            ),
+        },
+        value => fail!(ErrorKind::ParseError, "Expected an integer, found {value}"),
+    })
+}
+
+fn as_f64(value: &Value) -> Result<f64> {
+    match value {
+        Value::Number(number) => match number.as_f64() {
+            Some(value) => Ok(value),
+            None => fail!(ErrorKind::ParseError, "Expected a float, found {number}"),
+        },
+        value => fail!(ErrorKind::ParseError, "Expected a float, found {value}"),
+    }
+}
+
+fn as_utf8(value: &Value) -> Result<Vec<u8>> {
+    Ok(as_str(value)?.as_bytes().to_vec())
+}
+
+fn as_hex(value: &Value) -> Result<Vec<u8>> {
+    let s = as_str(value)?;
+    if s.len() % 2 != 0 || !s.is_ascii() {
+        fail!(ErrorKind::ParseError, "Invalid hex string {s:?}");
+    }
+    let mut bytes = Vec::with_capacity(s.len() / 2);
+    for idx in (0..s.len()).step_by(2) {
+        match u8::from_str_radix(&s[idx..idx + 2], 16) {
+            Ok(byte) => bytes.push(byte),
+            Err(_) => fail!(ErrorKind::ParseError, "Invalid hex string {s:?}"),
+        }
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,513 @@
+//! Write arrays and fields in the JSON format
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Number, Value};
+
+use crate::{
+    array::{view_len, Array, BytesArray, BytesViewArray},
+    datatypes::{DataType, IntervalUnit, TimeUnit, UnionMode},
+    error::{fail, ErrorKind, Result},
+    view::BitsWithOffset,
+};
+
+use super::{DictionaryIds, JsonFile, Node};
+
+pub(super) fn write_file(file: &JsonFile) -> Result<Value> {
+    let mut ids = DictionaryIds::new(&file.fields)?;
+    let mut nodes = Vec::with_capacity(file.fields.len());
+    for field in &file.fields {
+        nodes.push(Node::from_field(field, &mut ids)?);
+    }
+
+    let mut schema = Map::new();
+    schema.insert(
+        String::from("fields"),
+        Value::Array(nodes.iter().map(write_node).collect::<Result<_>>()?),
+    );
+    if !file.metadata.is_empty() {
+        schema.insert(String::from("metadata"), write_metadata(&file.metadata));
+    }
+
+    let mut dictionaries = BTreeMap::new();
+    let mut batches = Vec::with_capacity(file.batches.len());
+    for batch in &file.batches {
+        if batch.len() != nodes.len() {
+            fail!(
+                ErrorKind::Unsupported,
+                "The batch has {len} arrays, but the schema {expected} fields",
+                len = batch.len(),
+                expected = nodes.len(),
+            );
+        }
+        let mut count = None;
+        let mut columns = Vec::with_capacity(batch.len());
+        for (node, array) in nodes.iter().zip(batch) {
+            if array.data_type() != node.field.data_type {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "The array of field {name:?} has data type {actual}, expected {expected}",
+                    name = node.field.name,
+                    actual = array.data_type(),
+                    expected = node.field.data_type,
+                );
+            }
+            let len = view_len(&array.as_view())?;
+            if *count.get_or_insert(len) != len {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "All arrays of a batch must have the same length"
+                );
+            }
+            columns.push(write_array(node, array, &mut dictionaries)?);
+        }
+        batches.push(json!({
+            "count": count.unwrap_or_default(),
+            "columns": columns,
+        }));
+    }
+
+    let mut res = Map::new();
+    res.insert(String::from("schema"), Value::Object(schema));
+    res.insert(String::from("batches"), Value::Array(batches));
+    if !dictionaries.is_empty() {
+        let dictionaries = dictionaries
+            .into_iter()
+            .map(|(id, (count, column))| {
+                json!({
+                    "id": id,
+                    "data": {"count": count, "columns": [column]},
+                })
+            })
+            .collect();
+        res.insert(String::from("dictionaries"), Value::Array(dictionaries));
+    }
+    Ok(Value::Object(res))
+}
+
+fn write_node(node: &Node) -> Result<Value> {
+    let mut res = Map::new();
+    res.insert(String::from("name"), Value::from(node.field.name.as_str()));
+    res.insert(String::from("nullable"), Value::from(node.field.nullable));
+
+    let data_type = match &node.field.data_type {
+        DataType::Dictionary(keys, values) => {
+            res.insert(
+                String::from("dictionary"),
+                json!({
+                    "id": node.dictionary.unwrap_or_default(),
+                    "indexType": write_data_type(keys)?,
+                    "isOrdered": node.field.dictionary.ordered,
+                }),
+            );
+            values.as_ref()
+        }
+        data_type => data_type,
+    };
+    res.insert(String::from("type"), write_data_type(data_type)?);
+    res.insert(
+        String::from("children"),
+        Value::Array(
+            node.children
+                .iter()
+                .map(write_node)
+                .collect::<Result<_>>()?,
+        ),
+    );
+    if !node.field.metadata.is_empty() {
+        res.insert(
+            String::from("metadata"),
+            write_metadata(&node.field.metadata),
+        );
+    }
+    Ok(Value::Object(res))
+}
+
+fn write_metadata(metadata: &std::collections::HashMap<String, String>) -> Value {
+    let metadata = metadata.iter().collect::<BTreeMap<_, _>>();
+    Value::Array(
+        metadata
+            .into_iter()
+            .map(|(key, value)| json!({"key": key, "value": value}))
+            .collect(),
+    )
+}
+
+fn write_data_type(data_type: &DataType) -> Result<Value> {
+    use DataType as T;
+
+    let int = |signed: bool, bit_width: i32| json!({"name": "int", "isSigned": signed, "bitWidth": bit_width});
+    let float = |precision: &str| json!({"name": "floatingpoint", "precision": precision});
+
+    let value = match data_type {
+        T::Null => json!({"name": "null"}),
+        T::Boolean => json!({"name": "bool"}),
+        T::Int8 => int(true, 8),
+        T::Int16 => int(true, 16),
+        T::Int32 => int(true, 32),
+        T::Int64 => int(true, 64),
+        T::UInt8 => int(false, 8),
+        T::UInt16 => int(false, 16),
+        T::UInt32 => int(false, 32),
+        T::UInt64 => int(false, 64),
+        T::Float16 => float("HALF"),
+        T::Float32 => float("SINGLE"),
+        T::Float64 => float("DOUBLE"),
+        T::Utf8 => json!({"name": "utf8"}),
+        T::LargeUtf8 => json!({"name": "largeutf8"}),
+        T::Utf8View => json!({"name": "utf8view"}),
+        T::Binary => json!({"name": "binary"}),
+        T::LargeBinary => json!({"name": "largebinary"}),
+        T::BinaryView => json!({"name": "binaryview"}),
+        T::FixedSizeBinary(n) => json!({"name": "fixedsizebinary", "byteWidth": n}),
+        T::Date32 => json!({"name": "date", "unit": "DAY"}),
+        T::Date64 => json!({"name": "date", "unit": "MILLISECOND"}),
+        T::Time32(unit) => json!({"name": "time", "unit": time_unit(*unit), "bitWidth": 32}),
+        T::Time64(unit) => json!({"name": "time", "unit": time_unit(*unit), "bitWidth": 64}),
+        T::Timestamp(unit, None) => json!({"name": "timestamp", "unit": time_unit(*unit)}),
+        T::Timestamp(unit, Some(timezone)) => json!({
+            "name": "timestamp",
+            "unit": time_unit(*unit),
+            "timezone": timezone,
+        }),
+        T::Duration(unit) => json!({"name": "duration", "unit": time_unit(*unit)}),
+        T::Interval(unit) => {
+            let unit = match unit {
+                IntervalUnit::YearMonth => "YEAR_MONTH",
+                IntervalUnit::DayTime => "DAY_TIME",
+                IntervalUnit::MonthDayNano => "MONTH_DAY_NANO",
+            };
+            json!({"name": "interval", "unit": unit})
+        }
+        T::Decimal128(precision, scale) => json!({
+            "name": "decimal",
+            "precision": precision,
+            "scale": scale,
+            "bitWidth": 128,
+        }),
+        T::Struct(_) => json!({"name": "struct"}),
+        T::List(_) => json!({"name": "list"}),
+        T::LargeList(_) => json!({"name": "largelist"}),
+        T::FixedSizeList(_, n) => json!({"name": "fixedsizelist", "listSize": n}),
+        T::Map(_, sorted) => json!({"name": "map", "keysSorted": sorted}),
+        T::Union(fields, mode) => {
+            let mode = match mode {
+                UnionMode::Sparse => "SPARSE",
+                UnionMode::Dense => "DENSE",
+            };
+            let type_ids = fields
+                .iter()
+                .map(|(type_id, _)| *type_id)
+                .collect::<Vec<_>>();
+            json!({"name": "union", "mode": mode, "typeIds": type_ids})
+        }
+        T::RunEndEncoded(_, _) => json!({"name": "runendencoded"}),
+        T::Dictionary(_, _) => fail!(
+            ErrorKind::Unsupported,
+            "Dictionaries with dictionary encoded values are not supported"
+        ),
+    };
+    Ok(value)
+}
+
+fn time_unit(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "SECOND",
+        TimeUnit::Millisecond => "MILLISECOND",
+        TimeUnit::Microsecond => "MICROSECOND",
+        TimeUnit::Nanosecond => "NANOSECOND",
+    }
+}
+
+/// The dictionaries by id with the length and the column of their values
+type Dictionaries = BTreeMap<i64, (usize, Value)>;
+
+fn write_array(node: &Node, array: &Array, dictionaries: &mut Dictionaries) -> Result<Value> {
+    use Array as A;
+
+    let count = view_len(&array.as_view())?;
+    let mut res = Map::new();
+    res.insert(String::from("name"), Value::from(node.field.name.as_str()));
+    res.insert(String::from("count"), Value::from(count));
+
+    let mut insert = |key: &str, value: Value| {
+        res.insert(key.to_owned(), value);
+    };
+
+    macro_rules! primitive {
+        ($array:expr, $write:expr) => {{
+            insert(
+                "VALIDITY",
+                write_validity($array.validity.as_deref(), count),
+            );
+            insert("DATA", write_values(&$array.values, $write)?);
+        }};
+    }
+
+    match array {
+        A::Null(_) => {}
+        A::Boolean(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            let values = BitsWithOffset {
+                offset: 0,
+                data: &array.values,
+            };
+            insert("DATA", Value::from(values.to_vec_bool(count)));
+        }
+        A::Int8(array) => primitive!(array, int),
+        A::Int16(array) => primitive!(array, int),
+        A::Int32(array) => primitive!(array, int),
+        A::Int64(array) => primitive!(array, large_int),
+        A::UInt8(array) => primitive!(array, int),
+        A::UInt16(array) => primitive!(array, int),
+        A::UInt32(array) => primitive!(array, int),
+        A::UInt64(array) => primitive!(array, large_int),
+        A::Float16(array) => primitive!(array, |value: &half::f16| float(value.to_f64())),
+        A::Float32(array) => primitive!(array, |value: &f32| float(f64::from(*value))),
+        A::Float64(array) => primitive!(array, |value: &f64| float(*value)),
+        A::Date32(array) | A::YearMonthInterval(array) => primitive!(array, int),
+        A::Date64(array) => primitive!(array, large_int),
+        A::Time32(array) => primitive!(array, int),
+        A::Time64(array) | A::Duration(array) => primitive!(array, large_int),
+        A::Timestamp(array) => primitive!(array, large_int),
+        A::DayTimeInterval(array) => primitive!(array, |value: &crate::types::DayTimeInterval| {
+            Ok(json!({"days": value.days, "milliseconds": value.milliseconds}))
+        }),
+        A::MonthDayNanoInterval(array) => {
+            primitive!(array, |value: &crate::types::MonthDayNanoInterval| {
+                Ok(json!({
+                    "months": value.months,
+                    "days": value.days,
+                    "nanoseconds": value.nanoseconds,
+                }))
+            })
+        }
+        A::Decimal128(array) => primitive!(array, large_int),
+        A::Utf8(array) => write_bytes(&mut insert, array, count, int, utf8)?,
+        A::LargeUtf8(array) => write_bytes(&mut insert, array, count, large_int, utf8)?,
+        A::Binary(array) => write_bytes(&mut insert, array, count, int, hex)?,
+        A::LargeBinary(array) => write_bytes(&mut insert, array, count, large_int, hex)?,
+        A::Utf8View(array) => write_bytes_view(&mut insert, array, count, utf8)?,
+        A::BinaryView(array) => write_bytes_view(&mut insert, array, count, hex)?,
+        A::FixedSizeBinary(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            let n = usize::try_from(array.n)?;
+            let mut values = Vec::with_capacity(count);
+            for idx in 0..count {
+                values.push(hex(slice(&array.data, idx * n, n)?)?);
+            }
+            insert("DATA", Value::Array(values));
+        }
+        A::Struct(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            let arrays = array.fields.iter().map(|(_, array)| array);
+            insert("children", write_children(node, arrays, dictionaries)?);
+        }
+        A::List(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            insert("OFFSET", write_values(&array.offsets, int)?);
+            let elements = [array.elements.as_ref()];
+            insert("children", write_children(node, elements, dictionaries)?);
+        }
+        A::LargeList(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            insert("OFFSET", write_values(&array.offsets, large_int)?);
+            let elements = [array.elements.as_ref()];
+            insert("children", write_children(node, elements, dictionaries)?);
+        }
+        A::FixedSizeList(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            let elements = [array.elements.as_ref()];
+            insert("children", write_children(node, elements, dictionaries)?);
+        }
+        A::Map(array) => {
+            insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+            insert("OFFSET", write_values(&array.offsets, int)?);
+            let [entries_node] = node.children.as_slice() else {
+                fail!(ErrorKind::Unsupported, "Map fields require a single child");
+            };
+            let entries_count = view_len(&array.keys.as_view())?;
+            let arrays = [array.keys.as_ref(), array.values.as_ref()];
+            let entries = json!({
+                "name": entries_node.field.name,
+                "count": entries_count,
+                "VALIDITY": write_validity(None, entries_count),
+                "children": write_children(entries_node, arrays, dictionaries)?,
+            });
+            insert("children", Value::Array(vec![entries]));
+        }
+        A::Union(array) => {
+            insert("TYPE_ID", write_values(&array.types, int)?);
+            if let Some(offsets) = &array.offsets {
+                insert("OFFSET", write_values(offsets, int)?);
+            }
+            let arrays = array.fields.iter().map(|(_, _, array)| array);
+            insert("children", write_children(node, arrays, dictionaries)?);
+        }
+        A::RunEndEncoded(array) => {
+            let arrays = [array.run_ends.as_ref(), array.values.as_ref()];
+            insert("children", write_children(node, arrays, dictionaries)?);
+        }
+        A::Dictionary(array) => {
+            let keys = write_array(node, &array.keys, dictionaries)?;
+            let Value::Object(keys) = keys else {
+                unreachable!();
+            };
+            for (key, value) in keys {
+                if key == "VALIDITY" || key == "DATA" {
+                    insert(&key, value);
+                }
+            }
+
+            let Some(id) = node.dictionary else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Dictionary field {name:?} without dictionary id",
+                    name = node.field.name,
+                );
+            };
+            let values_count = view_len(&array.values.as_view())?;
+            let values = write_array(&node.dictionary_values()?, &array.values, dictionaries)?;
+            match dictionaries.get(&id) {
+                Some((_, existing)) if *existing != values => fail!(
+                    ErrorKind::Unsupported,
+                    "The dictionary of field {name:?} differs between batches",
+                    name = node.field.name,
+                ),
+                Some(_) => {}
+                None => {
+                    dictionaries.insert(id, (values_count, values));
+                }
+            }
+        }
+    }
+    Ok(Value::Object(res))
+}
+
+fn write_children<'a>(
+    node: &Node,
+    arrays: impl IntoIterator<Item = &'a Array>,
+    dictionaries: &mut Dictionaries,
+) -> Result<Value> {
+    let mut children = Vec::with_capacity(node.children.len());
+    for (child, array) in node.children.iter().zip(arrays) {
+        children.push(write_array(child, array, dictionaries)?);
+    }
+    Ok(Value::Array(children))
+}
+
+fn write_validity(validity: Option<&[u8]>, count: usize) -> Value {
+    let values = (0..count).map(|idx| match validity {
+        Some(data) => u8::from(BitsWithOffset { offset: 0, data }.get(idx)),
+        None => 1,
+    });
+    Value::Array(values.map(Value::from).collect())
+}
+
+fn write_values<T>(values: &[T], write: impl Fn(&T) -> Result<Value>) -> Result<Value> {
+    Ok(Value::Array(
+        values.iter().map(write).collect::<Result<_>>()?,
+    ))
+}
+
+fn write_bytes<O: Copy + TryInto<usize>>(
+    insert: &mut impl FnMut(&str, Value),
+    array: &BytesArray<O>,
+    count: usize,
+    write_offset: fn(&O) -> Result<Value>,
+    write_value: fn(&[u8]) -> Result<Value>,
+) -> Result<()>
+where
+    crate::error::MarrowError: From<O::Error>,
+{
+    insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+    insert("OFFSET", write_values(&array.offsets, write_offset)?);
+    let mut values = Vec::with_capacity(count);
+    for window in array.offsets.windows(2) {
+        let start = window[0].try_into()?;
+        let end = window[1].try_into()?;
+        values.push(write_value(slice(
+            &array.data,
+            start,
+            end.saturating_sub(start),
+        )?)?);
+    }
+    insert("DATA", Value::Array(values));
+    Ok(())
+}
+
+fn write_bytes_view(
+    insert: &mut impl FnMut(&str, Value),
+    array: &BytesViewArray,
+    count: usize,
+    write_inlined: fn(&[u8]) -> Result<Value>,
+) -> Result<()> {
+    insert("VALIDITY", write_validity(array.validity.as_deref(), count));
+    let mut views = Vec::with_capacity(array.data.len());
+    for view in &array.data {
+        let bytes = view.to_le_bytes();
+        let len = *view as u32;
+        if len <= 12 {
+            views.push(json!({
+                "SIZE": len,
+                "INLINED": write_inlined(&bytes[4..4 + len as usize])?,
+            }));
+        } else {
+            views.push(json!({
+                "SIZE": len,
+                "PREFIX_HEX": hex(&bytes[4..8])?,
+                "BUFFER_INDEX": (*view >> 64) as u32,
+                "OFFSET": (*view >> 96) as u32,
+            }));
+        }
+    }
+    insert("VIEWS", Value::Array(views));
+    let buffers = array.buffers.iter().map(|buffer| hex(buffer));
+    insert(
+        "VARIADIC_DATA_BUFFERS",
+        Value::Array(buffers.collect::<Result<_>>()?),
+    );
+    Ok(())
+}
+
+fn slice<T>(data: &[T], start: usize, len: usize) -> Result<&[T]> {
+    match data.get(start..start + len) {
+        Some(data) => Ok(data),
+        None => fail!(
+            ErrorKind::Unsupported,
+            "Out of bounds access {start}..{end} of data with length {data_len}",
+            end = start + len,
+            data_len = data.len(),
+        ),
+    }
+}
+
+fn int<T: Copy + Into<i64>>(value: &T) -> Result<Value> {
+    Ok(Value::from((*value).into()))
+}
+
+/// Write integers with 64 or more bits as strings, as they may not be representable in JSON
+fn large_int<T: ToString>(value: &T) -> Result<Value> {
+    Ok(Value::String(value.to_string()))
+}
+
+fn float(value: f64) -> Result<Value> {
+    match Number::from_f64(value) {
+        Some(number) => Ok(Value::Number(number)),
+        None => fail!(
+            ErrorKind::Unsupported,
+            "Cannot write the non-finite float {value} in JSON"
+        ),
+    }
+}
+
+fn utf8(value: &[u8]) -> Result<Value> {
+    Ok(Value::from(std::str::from_utf8(value)?))
+}
+
+fn hex(value: &[u8]) -> Result<Value> {
+    let mut res = String::with_capacity(2 * value.len());
+    for byte in value {
+        res.push_str(&format!("{byte:02X}"));
+    }
+    Ok(Value::String(res))
+}
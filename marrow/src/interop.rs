@@ -0,0 +1,69 @@
+//! Support for exchanging data between different arrow implementations
+//!
+//! A [`ForeignView`] pairs a view with an [`Owner`] that keeps the viewed data alive. Foreign
+//! views are converted into `arrow` and `arrow2` arrays whose buffers reference the viewed data
+//! instead of copying it. Only data whose layout differs between the implementations is copied.
+use std::{
+    any::Any,
+    collections::HashMap,
+    panic::{RefUnwindSafe, UnwindSafe},
+    sync::Arc,
+};
+
+use crate::{datatypes::Field, error::Result, view::View};
+
+#[cfg(any(feature = "arrow2-0-17", feature = "arrow2-0-16"))]
+mod ffi;
+
+#[cfg(any(feature = "arrow2-0-17", feature = "arrow2-0-16"))]
+pub(crate) use ffi::FfiArray;
+
+/// Conversion of schemas from and to `marrow` fields and metadata
+pub(crate) trait BridgeSchema: Sized {
+    fn to_marrow(&self) -> Result<(Vec<Field>, HashMap<String, String>)>;
+    fn from_marrow(fields: Vec<Field>, metadata: HashMap<String, String>) -> Result<Self>;
+}
+
+/// Keeps the data referenced by a [`ForeignView`] alive
+#[derive(Clone)]
+pub(crate) struct Owner {
+    _data: Arc<dyn Any + Send + Sync>,
+}
+
+// the data is never accessed, only dropped
+impl UnwindSafe for Owner {}
+impl RefUnwindSafe for Owner {}
+
+impl Owner {
+    pub(crate) fn new<T: Any + Send + Sync>(data: T) -> Self {
+        Self {
+            _data: Arc::new(data),
+        }
+    }
+}
+
+/// A view of data that is kept alive by an [`Owner`]
+pub(crate) struct ForeignView<'a> {
+    view: View<'a>,
+    owner: Owner,
+}
+
+impl<'a> ForeignView<'a> {
+    /// Pair the view with the owner of its data
+    ///
+    /// # Safety
+    ///
+    /// All data referenced by the view must stay valid and unchanged as long as `owner` or any of
+    /// its clones is alive.
+    pub(crate) unsafe fn new(view: View<'a>, owner: Owner) -> Self {
+        Self { view, owner }
+    }
+
+    pub(crate) fn view(&self) -> &View<'a> {
+        &self.view
+    }
+
+    pub(crate) fn owner(&self) -> &Owner {
+        &self.owner
+    }
+}
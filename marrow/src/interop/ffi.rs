@@ -0,0 +1,317 @@
+//! Export of foreign views via the C data interface
+//!
+//! `arrow2` buffers cannot reference external data directly. Instead, foreign views are exported
+//! as arrays of the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and imported by `arrow2` without copying.
+use std::{borrow::Cow, ffi::c_void};
+
+use crate::{
+    array::view_len,
+    error::{fail, ErrorKind, Result},
+    view::{BitsWithOffset, View},
+};
+
+use super::{ForeignView, Owner};
+
+/// The `ArrowArray` struct of the C data interface
+///
+/// The layout matches the structs of other implementations, e.g., `arrow2::ffi::ArrowArray`.
+/// Dropping an exported array releases it.
+#[repr(C)]
+pub(crate) struct FfiArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut FfiArray,
+    dictionary: *mut FfiArray,
+    release: Option<unsafe extern "C" fn(*mut FfiArray)>,
+    private_data: *mut c_void,
+}
+
+impl Drop for FfiArray {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: the array was exported by `FfiArray::try_from` and was not yet released
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// Export a foreign view, the exported array references the buffers of the view
+impl TryFrom<&ForeignView<'_>> for FfiArray {
+    type Error = crate::error::MarrowError;
+
+    fn try_from(value: &ForeignView<'_>) -> Result<Self> {
+        export(value.view(), value.owner())
+    }
+}
+
+/// The data kept alive by an exported array
+struct PrivateData {
+    _owner: Owner,
+    _copies: Vec<Vec<u8>>,
+    buffers: Box<[*const c_void]>,
+    children: Box<[*mut FfiArray]>,
+    dictionary: *mut FfiArray,
+}
+
+unsafe extern "C" fn release_array(array: *mut FfiArray) {
+    // SAFETY: the consumer passes an array exported by `export`
+    let Some(array) = (unsafe { array.as_mut() }) else {
+        return;
+    };
+    // SAFETY: the private data was created by `Exporter::finish` and is released only once
+    let private_data = unsafe { Box::from_raw(array.private_data.cast::<PrivateData>()) };
+    for &child in private_data.children.iter() {
+        // SAFETY: children are allocated by `Exporter::finish`, dropping them releases them
+        drop(unsafe { Box::from_raw(child) });
+    }
+    if !private_data.dictionary.is_null() {
+        // SAFETY: see children
+        drop(unsafe { Box::from_raw(private_data.dictionary) });
+    }
+    array.release = None;
+}
+
+/// Collect the buffers and children of an exported array
+struct Exporter<'a> {
+    len: usize,
+    offset: usize,
+    null_count: usize,
+    buffers: Vec<Option<Cow<'a, [u8]>>>,
+    children: Vec<FfiArray>,
+    dictionary: Option<FfiArray>,
+}
+
+impl<'a> Exporter<'a> {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            offset: 0,
+            null_count: 0,
+            buffers: Vec::new(),
+            children: Vec::new(),
+            dictionary: None,
+        }
+    }
+
+    /// Add the validity, must be called after the offset is set
+    fn validity(&mut self, validity: Option<BitsWithOffset<'a>>) {
+        match validity {
+            Some(bits) => {
+                self.null_count = self.len - bits.count_set_bits(self.len);
+                self.buffers
+                    .push(Some(align_bits(bits, self.offset, self.len)));
+            }
+            None => {
+                // missing validities are exported as null pointers
+                self.null_count = 0;
+                self.buffers.push(None);
+            }
+        }
+    }
+
+    fn buffer<T: bytemuck::NoUninit>(&mut self, data: &'a [T]) {
+        self.buffers
+            .push(Some(Cow::Borrowed(bytemuck::cast_slice::<T, u8>(data))));
+    }
+
+    fn finish(self, owner: &Owner) -> FfiArray {
+        let mut copies = Vec::new();
+        let mut buffers = Vec::new();
+        for buffer in self.buffers {
+            match buffer {
+                None => buffers.push(std::ptr::null()),
+                Some(Cow::Borrowed(buffer)) => buffers.push(buffer.as_ptr().cast::<c_void>()),
+                Some(Cow::Owned(buffer)) => {
+                    buffers.push(buffer.as_ptr().cast::<c_void>());
+                    copies.push(buffer);
+                }
+            }
+        }
+        let children = self
+            .children
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(child)))
+            .collect::<Box<[_]>>();
+        let dictionary = match self.dictionary {
+            Some(dictionary) => Box::into_raw(Box::new(dictionary)),
+            None => std::ptr::null_mut(),
+        };
+
+        let mut private_data = Box::new(PrivateData {
+            _owner: owner.clone(),
+            _copies: copies,
+            buffers: buffers.into_boxed_slice(),
+            children,
+            dictionary,
+        });
+
+        FfiArray {
+            length: self.len as i64,
+            null_count: self.null_count as i64,
+            offset: self.offset as i64,
+            n_buffers: private_data.buffers.len() as i64,
+            n_children: private_data.children.len() as i64,
+            buffers: private_data.buffers.as_mut_ptr(),
+            children: private_data.children.as_mut_ptr(),
+            dictionary: private_data.dictionary,
+            release: Some(release_array),
+            private_data: Box::into_raw(private_data).cast::<c_void>(),
+        }
+    }
+}
+
+/// Get `len` bits that start at bit `offset` of the result, copying them only if required
+///
+/// Bits can be referenced without copying, if the bits are offset by a multiple of 8 relative to
+/// the target offset.
+fn align_bits(bits: BitsWithOffset<'_>, offset: usize, len: usize) -> Cow<'_, [u8]> {
+    if bits.offset >= offset && (bits.offset - offset) % 8 == 0 {
+        return Cow::Borrowed(bits.data.get((bits.offset - offset) / 8..).unwrap_or(&[]));
+    }
+
+    let mut data = vec![0; (offset + len + 7) / 8];
+    for idx in 0..len {
+        if bits.get(idx) {
+            let bit = offset + idx;
+            data[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+    Cow::Owned(data)
+}
+
+fn export(view: &View<'_>, owner: &Owner) -> Result<FfiArray> {
+    let mut exporter = Exporter::new(view_len(view)?);
+    export_into(&mut exporter, view, owner)?;
+    Ok(exporter.finish(owner))
+}
+
+fn export_into<'a>(exporter: &mut Exporter<'a>, view: &View<'a>, owner: &Owner) -> Result<()> {
+    macro_rules! export_primitive {
+        ($view:expr) => {{
+            exporter.validity($view.validity);
+            exporter.buffer($view.values);
+        }};
+    }
+    macro_rules! export_bytes {
+        ($view:expr) => {{
+            exporter.validity($view.validity);
+            exporter.buffer($view.offsets);
+            exporter.buffer($view.data);
+        }};
+    }
+
+    match view {
+        View::Null(view) => exporter.null_count = view.len,
+        View::Boolean(view) => {
+            exporter.offset = view.values.offset;
+            exporter.validity(view.validity);
+            exporter.buffers.push(Some(Cow::Borrowed(view.values.data)));
+        }
+        View::Int8(view) => export_primitive!(view),
+        View::Int16(view) => export_primitive!(view),
+        View::Int32(view) => export_primitive!(view),
+        View::Int64(view) => export_primitive!(view),
+        View::UInt8(view) => export_primitive!(view),
+        View::UInt16(view) => export_primitive!(view),
+        View::UInt32(view) => export_primitive!(view),
+        View::UInt64(view) => export_primitive!(view),
+        View::Float16(view) => export_primitive!(view),
+        View::Float32(view) => export_primitive!(view),
+        View::Float64(view) => export_primitive!(view),
+        View::Date32(view) => export_primitive!(view),
+        View::Date64(view) => export_primitive!(view),
+        View::Time32(view) => export_primitive!(view),
+        View::Time64(view) => export_primitive!(view),
+        View::Duration(view) => export_primitive!(view),
+        View::Timestamp(view) => export_primitive!(view),
+        View::YearMonthInterval(view) => export_primitive!(view),
+        View::DayTimeInterval(view) => export_primitive!(view),
+        View::MonthDayNanoInterval(view) => export_primitive!(view),
+        View::Decimal128(view) => export_primitive!(view),
+        View::Utf8(view) => export_bytes!(view),
+        View::LargeUtf8(view) => export_bytes!(view),
+        View::Binary(view) => export_bytes!(view),
+        View::LargeBinary(view) => export_bytes!(view),
+        View::FixedSizeBinary(view) => {
+            exporter.validity(view.validity);
+            exporter.buffer(view.data);
+        }
+        View::Struct(view) => {
+            exporter.validity(view.validity);
+            for (_, field) in &view.fields {
+                exporter.children.push(export(field, owner)?);
+            }
+        }
+        View::List(view) => {
+            exporter.validity(view.validity);
+            exporter.buffer(view.offsets);
+            exporter.children.push(export(&view.elements, owner)?);
+        }
+        View::LargeList(view) => {
+            exporter.validity(view.validity);
+            exporter.buffer(view.offsets);
+            exporter.children.push(export(&view.elements, owner)?);
+        }
+        View::FixedSizeList(view) => {
+            exporter.validity(view.validity);
+            exporter.children.push(export(&view.elements, owner)?);
+        }
+        View::Map(view) => {
+            exporter.validity(view.validity);
+            exporter.buffer(view.offsets);
+
+            let mut entries = Exporter::new(view_len(&view.keys)?);
+            entries.validity(None);
+            entries.children.push(export(&view.keys, owner)?);
+            entries.children.push(export(&view.values, owner)?);
+            exporter.children.push(entries.finish(owner));
+        }
+        View::Dictionary(view) => {
+            export_into(exporter, &view.keys, owner)?;
+            exporter.dictionary = Some(export(&view.values, owner)?);
+        }
+        View::RunEndEncoded(view) => {
+            exporter.children.push(export(&view.run_ends, owner)?);
+            exporter.children.push(export(&view.values, owner)?);
+        }
+        View::Union(view) => {
+            exporter.buffer(view.types);
+            if let Some(offsets) = view.offsets {
+                exporter.buffer(offsets);
+            }
+            for (_, _, field) in &view.fields {
+                exporter.children.push(export(field, owner)?);
+            }
+        }
+        View::Utf8View(_) | View::BinaryView(_) => fail!(
+            ErrorKind::Unsupported,
+            "Exporting byte views via the C data interface is not supported"
+        ),
+    }
+    Ok(())
+}
+
+#[test]
+fn aligned_bits_are_borrowed() {
+    let data = [0b_1010_0000, 0b_0000_0101];
+    let bits = BitsWithOffset {
+        offset: 5,
+        data: &data,
+    };
+
+    assert!(matches!(align_bits(bits, 5, 8), Cow::Borrowed(b) if b == data));
+    assert!(matches!(align_bits(bits.slice(3, 5), 0, 5), Cow::Borrowed(b) if *b == data[1..]));
+    assert_eq!(
+        align_bits(bits, 0, 8),
+        Cow::<[u8]>::Owned(vec![0b_0010_1101])
+    );
+    assert_eq!(
+        align_bits(bits, 6, 8),
+        Cow::<[u8]>::Owned(vec![0b_0100_0000, 0b_0000_1011])
+    );
+}
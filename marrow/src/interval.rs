@@ -0,0 +1,883 @@
+//! Value semantics of calendar intervals
+//!
+//! Intervals are stored in one of three layouts, see
+//! [`IntervalUnit`][crate::datatypes::IntervalUnit]:
+//!
+//! - `YearMonth`: the number of months as `i32`
+//! - `DayTime`: days and milliseconds as [`DayTimeInterval`]
+//! - `MonthDayNano`: months, days and nanoseconds as [`MonthDayNanoInterval`]
+//!
+//! The components are kept separately, as the length of months and days depends on the date the
+//! interval is added to. Intervals are formatted and parsed as ISO 8601 durations (e.g.,
+//! `"P1Y2M3DT4H5M6.5S"`), offer checked arithmetic (e.g.,
+//! [`MonthDayNanoInterval::checked_add`]) and can be converted between the layouts where no
+//! information is lost. All layouts can be converted losslessly into [`MonthDayNanoInterval`],
+//! which can be added to timestamps and dates.
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! use marrow::{datatypes::TimeUnit, types::{DayTimeInterval, MonthDayNanoInterval}};
+//!
+//! let interval: MonthDayNanoInterval = "P1M1D".parse()?;
+//! assert_eq!(interval, MonthDayNanoInterval { months: 1, days: 1, nanoseconds: 0 });
+//!
+//! // 2024-01-31T12:00:00Z plus one month and one day is 2024-03-01T12:00:00Z
+//! let timestamp = interval.add_to_timestamp(1_706_702_400, TimeUnit::Second, None)?;
+//! assert_eq!(timestamp, 1_709_294_400);
+//!
+//! let interval = DayTimeInterval { days: 0, milliseconds: -1_500 };
+//! assert_eq!(interval.to_string(), "-PT1.5S");
+//! assert_eq!(MonthDayNanoInterval::from(interval).nanoseconds, -1_500_000_000);
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use crate::{
+    datatypes::TimeUnit,
+    error::{fail, ErrorKind, MarrowError, Result},
+    temporal::{nanoseconds_per_unit, ResolvedTimeZone, NANOSECONDS_PER_SECOND},
+    types::{DayTimeInterval, MonthDayNanoInterval},
+};
+
+const NANOSECONDS_PER_MILLISECOND: i128 = 1_000_000;
+const NANOSECONDS_PER_MINUTE: i128 = 60 * NANOSECONDS_PER_SECOND;
+const NANOSECONDS_PER_HOUR: i128 = 60 * NANOSECONDS_PER_MINUTE;
+const NANOSECONDS_PER_DAY: i128 = 24 * NANOSECONDS_PER_HOUR;
+const MILLISECONDS_PER_DAY: i128 = 86_400_000;
+const DAYS_PER_MONTH: i128 = 30;
+
+/// Which components of an interval are carried into larger units by `normalize`
+///
+/// Normalization assumes days of 24 hours and months of 30 days, similar to `justify_hours` and
+/// `justify_days` of PostgreSQL. The components that take part in the normalization end up with
+/// the same sign, e.g., `"P1DT-1H"` is normalized to `"PT23H"`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalNormalization {
+    /// Carry multiples of 24 hours of the time component into days
+    pub time_into_days: bool,
+    /// Carry multiples of 30 days into months, ignored for [`DayTimeInterval`]
+    pub days_into_months: bool,
+}
+
+/// Format a `YearMonth` interval as an ISO 8601 duration
+///
+/// ```rust
+/// # use marrow::interval::format_year_month_interval;
+/// assert_eq!(format_year_month_interval(14), "P1Y2M");
+/// assert_eq!(format_year_month_interval(-3), "-P3M");
+/// assert_eq!(format_year_month_interval(0), "PT0S");
+/// ```
+pub fn format_year_month_interval(months: i32) -> String {
+    MonthDayNanoInterval::from_year_month(months).to_string()
+}
+
+/// Parse an ISO 8601 duration into a `YearMonth` interval
+///
+/// An error is returned, if the duration contains days or a time component.
+///
+/// ```rust
+/// # use marrow::interval::parse_year_month_interval;
+/// assert_eq!(parse_year_month_interval("P1Y2M").unwrap(), 14);
+/// assert_eq!(parse_year_month_interval("-P3M").unwrap(), -3);
+/// assert!(parse_year_month_interval("P1D").is_err());
+/// ```
+pub fn parse_year_month_interval(s: &str) -> Result<i32> {
+    let duration = Duration::parse(s)?;
+    if duration.days != 0 || duration.nanoseconds != 0 {
+        fail!(
+            ErrorKind::ParseError,
+            "Cannot represent {s:?} as a YearMonth interval: only years and months are supported"
+        );
+    }
+    duration.component(duration.months, s)
+}
+
+impl DayTimeInterval {
+    /// Add two intervals component-wise, returns `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self {
+            days: self.days.checked_add(other.days)?,
+            milliseconds: self.milliseconds.checked_add(other.milliseconds)?,
+        })
+    }
+
+    /// Subtract two intervals component-wise, returns `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self {
+            days: self.days.checked_sub(other.days)?,
+            milliseconds: self.milliseconds.checked_sub(other.milliseconds)?,
+        })
+    }
+
+    /// Negate all components, returns `None` on overflow
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            days: self.days.checked_neg()?,
+            milliseconds: self.milliseconds.checked_neg()?,
+        })
+    }
+
+    /// Multiply all components with `factor`, returns `None` on overflow
+    pub fn checked_mul(self, factor: i32) -> Option<Self> {
+        Some(Self {
+            days: self.days.checked_mul(factor)?,
+            milliseconds: self.milliseconds.checked_mul(factor)?,
+        })
+    }
+
+    /// Carry components into larger units, see [`IntervalNormalization`]
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// ```rust
+    /// # use marrow::{interval::IntervalNormalization, types::DayTimeInterval};
+    /// let interval = DayTimeInterval { days: 1, milliseconds: 90_000_000 };
+    /// let options = IntervalNormalization { time_into_days: true, ..Default::default() };
+    /// assert_eq!(
+    ///     interval.normalize(options),
+    ///     Some(DayTimeInterval { days: 2, milliseconds: 3_600_000 }),
+    /// );
+    /// ```
+    pub fn normalize(self, options: IntervalNormalization) -> Option<Self> {
+        if !options.time_into_days {
+            return Some(self);
+        }
+        let total = i128::from(self.days) * MILLISECONDS_PER_DAY + i128::from(self.milliseconds);
+        Some(Self {
+            days: i32::try_from(total / MILLISECONDS_PER_DAY).ok()?,
+            milliseconds: i32::try_from(total % MILLISECONDS_PER_DAY).ok()?,
+        })
+    }
+}
+
+impl MonthDayNanoInterval {
+    /// Construct the interval from a `YearMonth` interval, i.e., a number of months
+    pub fn from_year_month(months: i32) -> Self {
+        Self {
+            months,
+            days: 0,
+            nanoseconds: 0,
+        }
+    }
+
+    /// Convert the interval into a `YearMonth` interval
+    ///
+    /// An error is returned, if the interval contains days or nanoseconds.
+    pub fn to_year_month(self) -> Result<i32> {
+        if self.days != 0 || self.nanoseconds != 0 {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the interval {self} as a YearMonth interval without loss"
+            );
+        }
+        Ok(self.months)
+    }
+
+    /// Add two intervals component-wise, returns `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            nanoseconds: self.nanoseconds.checked_add(other.nanoseconds)?,
+        })
+    }
+
+    /// Subtract two intervals component-wise, returns `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            nanoseconds: self.nanoseconds.checked_sub(other.nanoseconds)?,
+        })
+    }
+
+    /// Negate all components, returns `None` on overflow
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            nanoseconds: self.nanoseconds.checked_neg()?,
+        })
+    }
+
+    /// Multiply all components with `factor`, returns `None` on overflow
+    pub fn checked_mul(self, factor: i32) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            nanoseconds: self.nanoseconds.checked_mul(i64::from(factor))?,
+        })
+    }
+
+    /// Carry components into larger units, see [`IntervalNormalization`]
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// ```rust
+    /// # use marrow::{interval::IntervalNormalization, types::MonthDayNanoInterval};
+    /// let interval: MonthDayNanoInterval = "P1M-1DT-1H".parse().unwrap();
+    /// let options = IntervalNormalization { time_into_days: true, days_into_months: true };
+    /// assert_eq!(interval.normalize(options).unwrap().to_string(), "P28DT23H");
+    /// ```
+    pub fn normalize(self, options: IntervalNormalization) -> Option<Self> {
+        let months = i128::from(self.months);
+        let days = i128::from(self.days);
+        let nanoseconds = i128::from(self.nanoseconds);
+
+        let (months, days, nanoseconds) = match (options.days_into_months, options.time_into_days) {
+            (false, false) => (months, days, nanoseconds),
+            (false, true) => {
+                let total = days * NANOSECONDS_PER_DAY + nanoseconds;
+                (
+                    months,
+                    total / NANOSECONDS_PER_DAY,
+                    total % NANOSECONDS_PER_DAY,
+                )
+            }
+            (true, false) => {
+                let total = months * DAYS_PER_MONTH + days;
+                (total / DAYS_PER_MONTH, total % DAYS_PER_MONTH, nanoseconds)
+            }
+            (true, true) => {
+                let total = (months * DAYS_PER_MONTH + days) * NANOSECONDS_PER_DAY + nanoseconds;
+                let nanoseconds_per_month = DAYS_PER_MONTH * NANOSECONDS_PER_DAY;
+                (
+                    total / nanoseconds_per_month,
+                    total % nanoseconds_per_month / NANOSECONDS_PER_DAY,
+                    total % NANOSECONDS_PER_DAY,
+                )
+            }
+        };
+        Some(Self {
+            months: i32::try_from(months).ok()?,
+            days: i32::try_from(days).ok()?,
+            nanoseconds: i64::try_from(nanoseconds).ok()?,
+        })
+    }
+
+    /// Add the interval to a timestamp with the given unit and timezone
+    ///
+    /// Months are added to the local date, clamping the day to the end of the month, e.g.,
+    /// 2024-01-31 plus one month is 2024-02-29. Days are added to the local date and keep the
+    /// local time. Finally, the nanoseconds are added as an exact duration. Timestamps without
+    /// timezone are interpreted as UTC, IANA timezones require the `chrono` or `jiff` feature.
+    ///
+    /// An error is returned, if the result cannot be represented exactly in the given unit.
+    pub fn add_to_timestamp(
+        &self,
+        timestamp: i64,
+        unit: TimeUnit,
+        timezone: Option<&str>,
+    ) -> Result<i64> {
+        let factor = i128::from(nanoseconds_per_unit(unit));
+        let mut nanoseconds = i128::from(timestamp) * factor;
+
+        if self.months != 0 || self.days != 0 {
+            let timezone = ResolvedTimeZone::new(timezone)?;
+            let offset = timezone.offset_from_utc(seconds(nanoseconds)?)?;
+            let local = nanoseconds + i128::from(offset) * NANOSECONDS_PER_SECOND;
+
+            let days = i64::try_from(local.div_euclid(NANOSECONDS_PER_DAY))?;
+            let days = add_months(days, self.months) + i64::from(self.days);
+            let local =
+                i128::from(days) * NANOSECONDS_PER_DAY + local.rem_euclid(NANOSECONDS_PER_DAY);
+
+            let offset = timezone.offset_from_local(seconds(local)?)?;
+            nanoseconds = local - i128::from(offset) * NANOSECONDS_PER_SECOND;
+        }
+        nanoseconds += i128::from(self.nanoseconds);
+
+        if nanoseconds % factor != 0 {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the sum of {timestamp} and {self} in the unit {unit}"
+            );
+        }
+        Ok(i64::try_from(nanoseconds / factor)?)
+    }
+
+    /// Add the interval to a `Date32` value, i.e., a number of days since the Unix epoch
+    ///
+    /// Months are added as in [`add_to_timestamp`][Self::add_to_timestamp]. An error is returned,
+    /// if the nanoseconds are not a multiple of 24 hours.
+    ///
+    /// ```rust
+    /// # use marrow::types::MonthDayNanoInterval;
+    /// let interval = MonthDayNanoInterval::from_year_month(1);
+    /// // 2023-01-31 plus one month is 2023-02-28
+    /// assert_eq!(interval.add_to_date32(19_388).unwrap(), 19_416);
+    /// ```
+    pub fn add_to_date32(&self, days: i32) -> Result<i32> {
+        Ok(i32::try_from(self.add_to_days(i64::from(days))?)?)
+    }
+
+    /// Add the interval to a `Date64` value, i.e., a number of milliseconds since the Unix epoch
+    ///
+    /// See [`add_to_date32`][Self::add_to_date32].
+    pub fn add_to_date64(&self, milliseconds: i64) -> Result<i64> {
+        let milliseconds_per_day = MILLISECONDS_PER_DAY as i64;
+        let days = self.add_to_days(milliseconds.div_euclid(milliseconds_per_day))?;
+        let result = i128::from(days) * MILLISECONDS_PER_DAY
+            + i128::from(milliseconds.rem_euclid(milliseconds_per_day));
+        Ok(i64::try_from(result)?)
+    }
+
+    fn add_to_days(&self, days: i64) -> Result<i64> {
+        let nanoseconds = i128::from(self.nanoseconds);
+        if nanoseconds % NANOSECONDS_PER_DAY != 0 {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot add the interval {self} to a date: the time is not a multiple of days"
+            );
+        }
+        let days = add_months(days, self.months)
+            + i64::from(self.days)
+            + i64::try_from(nanoseconds / NANOSECONDS_PER_DAY)?;
+        Ok(days)
+    }
+}
+
+impl From<DayTimeInterval> for MonthDayNanoInterval {
+    fn from(value: DayTimeInterval) -> Self {
+        Self {
+            months: 0,
+            days: value.days,
+            nanoseconds: i64::from(value.milliseconds) * NANOSECONDS_PER_MILLISECOND as i64,
+        }
+    }
+}
+
+impl TryFrom<MonthDayNanoInterval> for DayTimeInterval {
+    type Error = MarrowError;
+
+    fn try_from(value: MonthDayNanoInterval) -> Result<Self> {
+        let nanoseconds = i128::from(value.nanoseconds);
+        if value.months != 0 || nanoseconds % NANOSECONDS_PER_MILLISECOND != 0 {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the interval {value} as a DayTime interval without loss"
+            );
+        }
+        Ok(Self {
+            days: value.days,
+            milliseconds: i32::try_from(nanoseconds / NANOSECONDS_PER_MILLISECOND)?,
+        })
+    }
+}
+
+impl fmt::Display for DayTimeInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&MonthDayNanoInterval::from(*self), f)
+    }
+}
+
+impl fmt::Display for MonthDayNanoInterval {
+    /// Format the interval as an ISO 8601 duration
+    ///
+    /// If all components are negative or zero, the duration is prefixed with `-`. Otherwise,
+    /// negative components carry their own sign, e.g., `"P1M-2D"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.months == 0 && self.days == 0 && self.nanoseconds == 0 {
+            return write!(f, "PT0S");
+        }
+        let negative = self.months <= 0 && self.days <= 0 && self.nanoseconds <= 0;
+        let sign = |value_is_negative: bool| {
+            if value_is_negative && !negative {
+                "-"
+            } else {
+                ""
+            }
+        };
+
+        if negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+
+        let months = self.months.unsigned_abs();
+        let sign_months = sign(self.months < 0);
+        if months / 12 != 0 {
+            write!(f, "{sign_months}{}Y", months / 12)?;
+        }
+        if months % 12 != 0 {
+            write!(f, "{sign_months}{}M", months % 12)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}{}D", sign(self.days < 0), self.days.unsigned_abs())?;
+        }
+
+        if self.nanoseconds != 0 {
+            let nanoseconds = u128::from(self.nanoseconds.unsigned_abs());
+            let sign = sign(self.nanoseconds < 0);
+            let hours = nanoseconds / NANOSECONDS_PER_HOUR as u128;
+            let minutes = nanoseconds / NANOSECONDS_PER_MINUTE as u128 % 60;
+            let seconds = nanoseconds / NANOSECONDS_PER_SECOND as u128 % 60;
+            let subsec = nanoseconds % NANOSECONDS_PER_SECOND as u128;
+
+            write!(f, "T")?;
+            if hours != 0 {
+                write!(f, "{sign}{hours}H")?;
+            }
+            if minutes != 0 {
+                write!(f, "{sign}{minutes}M")?;
+            }
+            if seconds != 0 || subsec != 0 {
+                write!(f, "{sign}{seconds}")?;
+                if subsec != 0 {
+                    let digits = format!("{subsec:09}");
+                    write!(f, ".{}", digits.trim_end_matches('0'))?;
+                }
+                write!(f, "S")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for DayTimeInterval {
+    type Err = MarrowError;
+
+    /// Parse an ISO 8601 duration without years, months or sub-millisecond components
+    fn from_str(s: &str) -> Result<Self> {
+        let duration = Duration::parse(s)?;
+        if duration.months != 0 || duration.nanoseconds % NANOSECONDS_PER_MILLISECOND != 0 {
+            fail!(
+                ErrorKind::ParseError,
+                "Cannot represent {s:?} as a DayTime interval: only days and milliseconds are supported"
+            );
+        }
+        Ok(Self {
+            days: duration.component(duration.days, s)?,
+            milliseconds: duration
+                .component(duration.nanoseconds / NANOSECONDS_PER_MILLISECOND, s)?,
+        })
+    }
+}
+
+impl std::str::FromStr for MonthDayNanoInterval {
+    type Err = MarrowError;
+
+    /// Parse an ISO 8601 duration, e.g., `"P1Y2M3W4DT5H6M7.5S"` or `"-P1D"`
+    ///
+    /// Years are converted to 12 months, weeks to 7 days and hours, minutes and seconds to
+    /// nanoseconds. Only seconds may have a fraction. Individual components may be negative.
+    fn from_str(s: &str) -> Result<Self> {
+        let duration = Duration::parse(s)?;
+        Ok(Self {
+            months: duration.component(duration.months, s)?,
+            days: duration.component(duration.days, s)?,
+            nanoseconds: duration.component(duration.nanoseconds, s)?,
+        })
+    }
+}
+
+/// A parsed ISO 8601 duration
+#[derive(Debug, Default)]
+struct Duration {
+    months: i128,
+    days: i128,
+    nanoseconds: i128,
+}
+
+impl Duration {
+    fn parse(s: &str) -> Result<Self> {
+        let Some(duration) = Self::parse_components(s) else {
+            fail!(ErrorKind::ParseError, "Invalid ISO 8601 duration {s:?}");
+        };
+        Ok(duration)
+    }
+
+    fn parse_components(s: &str) -> Option<Self> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let rest = rest.strip_prefix('P')?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if !time.is_empty() => (date, Some(time)),
+            Some(_) => return None,
+            None => (rest, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return None;
+        }
+
+        let mut duration = Self::default();
+        for_each_component(date, b"YMWD", |unit, value, fraction| {
+            if fraction.is_some() {
+                return None;
+            }
+            match unit {
+                b'Y' => duration.months = duration.months.checked_add(value.checked_mul(12)?)?,
+                b'M' => duration.months = duration.months.checked_add(value)?,
+                b'W' => duration.days = duration.days.checked_add(value.checked_mul(7)?)?,
+                _ => duration.days = duration.days.checked_add(value)?,
+            }
+            Some(())
+        })?;
+        for_each_component(time.unwrap_or_default(), b"HMS", |unit, value, fraction| {
+            let nanoseconds = match unit {
+                b'H' if fraction.is_none() => value.checked_mul(NANOSECONDS_PER_HOUR)?,
+                b'M' if fraction.is_none() => value.checked_mul(NANOSECONDS_PER_MINUTE)?,
+                b'S' => value
+                    .checked_mul(NANOSECONDS_PER_SECOND)?
+                    .checked_add(fraction.unwrap_or_default())?,
+                _ => return None,
+            };
+            duration.nanoseconds = duration.nanoseconds.checked_add(nanoseconds)?;
+            Some(())
+        })?;
+
+        if negative {
+            duration.months = -duration.months;
+            duration.days = -duration.days;
+            duration.nanoseconds = -duration.nanoseconds;
+        }
+        Some(duration)
+    }
+
+    fn component<T: TryFrom<i128>>(&self, value: i128, s: &str) -> Result<T> {
+        match T::try_from(value) {
+            Ok(value) => Ok(value),
+            Err(_) => fail!(
+                ErrorKind::ParseError,
+                "The duration {s:?} is out of the range of the interval"
+            ),
+        }
+    }
+}
+
+/// Call `f` with the unit, the signed value and the signed fraction in nanoseconds of each
+/// component, the units must appear in the given order
+fn for_each_component(
+    mut s: &str,
+    units: &[u8],
+    mut f: impl FnMut(u8, i128, Option<i128>) -> Option<()>,
+) -> Option<()> {
+    let mut units = units;
+    while !s.is_empty() {
+        let end = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let (number, rest) = s.split_at(end);
+        let unit = rest.as_bytes()[0];
+        let position = units.iter().position(|candidate| *candidate == unit)?;
+        units = &units[position + 1..];
+        s = &rest[1..];
+
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (integer, fraction) = match number.split_once(['.', ',']) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+        let value = parse_digits(integer)?;
+        let fraction = match fraction {
+            Some(fraction) => Some(parse_fraction(fraction)?),
+            None => None,
+        };
+
+        let sign = if negative { -1 } else { 1 };
+        f(unit, sign * value, fraction.map(|fraction| sign * fraction))?;
+    }
+    Some(())
+}
+
+fn parse_digits(s: &str) -> Option<i128> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parse the digits of a fraction of a second into nanoseconds, digits beyond nanoseconds must be
+/// zero
+fn parse_fraction(s: &str) -> Option<i128> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (digits, rest) = s.split_at(s.len().min(9));
+    if rest.bytes().any(|b| b != b'0') {
+        return None;
+    }
+    let value = digits.parse::<i128>().ok()?;
+    Some(value * 10_i128.pow(9 - digits.len() as u32))
+}
+
+fn seconds(nanoseconds: i128) -> Result<i64> {
+    Ok(i64::try_from(
+        nanoseconds.div_euclid(NANOSECONDS_PER_SECOND),
+    )?)
+}
+
+/// Add months to a number of days since the Unix epoch, clamping the day to the end of the month
+fn add_months(days: i64, months: i32) -> i64 {
+    if months == 0 {
+        return days;
+    }
+    let (year, month, day) = civil_from_days(days);
+    let index = year * 12 + i64::from(month) - 1 + i64::from(months);
+    let year = index.div_euclid(12);
+    let month = index.rem_euclid(12) as u32 + 1;
+    days_from_civil(year, month, day.min(days_in_month(year, month)))
+}
+
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The year, month and day of a number of days since the Unix epoch in the proleptic Gregorian
+/// calendar
+///
+/// See Howard Hinnant's [`chrono`-Compatible Low-Level Date Algorithms][algorithms].
+///
+/// [algorithms]: https://howardhinnant.github.io/date_algorithms.html
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day as u32)
+}
+
+/// The number of days since the Unix epoch of a date in the proleptic Gregorian calendar
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = i64::from((month + 9) % 12);
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[test]
+fn iso_8601_durations() {
+    let parse = |s: &str| s.parse::<MonthDayNanoInterval>().ok();
+    let interval = |months, days, nanoseconds| MonthDayNanoInterval {
+        months,
+        days,
+        nanoseconds,
+    };
+
+    assert_eq!(
+        parse("P1Y2M3W4DT5H6M7.5S"),
+        Some(interval(14, 25, 18_367_500_000_000))
+    );
+    assert_eq!(parse("-P1DT1H"), Some(interval(0, -1, -3_600_000_000_000)));
+    assert_eq!(parse("P1M-2D"), Some(interval(1, -2, 0)));
+    assert_eq!(parse("PT0,000000001S"), Some(interval(0, 0, 1)));
+    assert_eq!(parse("PT0.0000000010S"), Some(interval(0, 0, 1)));
+    assert_eq!(parse("P0D"), Some(interval(0, 0, 0)));
+    for invalid in [
+        "",
+        "P",
+        "PT",
+        "P1DT",
+        "1D",
+        "P1.5D",
+        "PT1.5M",
+        "P1D1Y",
+        "P1M1M",
+        "PT1S1H",
+        "P1S",
+        "PT0.0000000001S",
+        "P1 D",
+        "P99999999999M",
+    ] {
+        assert_eq!(parse(invalid), None, "{invalid:?}");
+    }
+
+    for (value, expected) in [
+        (interval(0, 0, 0), "PT0S"),
+        (interval(14, 25, 18_367_500_000_000), "P1Y2M25DT5H6M7.5S"),
+        (interval(-1, -1, -1), "-P1M1DT0.000000001S"),
+        (interval(1, -2, 3_000_000_000), "P1M-2DT3S"),
+        (
+            interval(i32::MIN, i32::MIN, i64::MIN),
+            "-P178956970Y8M2147483648DT2562047H47M16.854775808S",
+        ),
+    ] {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(parse(expected), Some(value));
+    }
+
+    let day_time = "P1DT0.5S".parse::<DayTimeInterval>().unwrap();
+    assert_eq!(
+        day_time,
+        DayTimeInterval {
+            days: 1,
+            milliseconds: 500
+        }
+    );
+    assert_eq!(day_time.to_string(), "P1DT0.5S");
+    assert!("P1M".parse::<DayTimeInterval>().is_err());
+    assert!("PT0.0001S".parse::<DayTimeInterval>().is_err());
+
+    assert_eq!(parse_year_month_interval("P2Y").unwrap(), 24);
+    assert!(parse_year_month_interval("PT1H").is_err());
+    assert_eq!(format_year_month_interval(-25), "-P2Y1M");
+}
+
+#[test]
+fn arithmetic_and_conversions() {
+    let interval = MonthDayNanoInterval {
+        months: 1,
+        days: 2,
+        nanoseconds: 3,
+    };
+    assert_eq!(
+        interval.checked_add(interval),
+        Some(MonthDayNanoInterval {
+            months: 2,
+            days: 4,
+            nanoseconds: 6
+        })
+    );
+    assert_eq!(
+        interval.checked_sub(interval),
+        Some(MonthDayNanoInterval::default())
+    );
+    assert_eq!(
+        interval.checked_mul(-2).unwrap().to_string(),
+        "-P2M4DT0.000000006S"
+    );
+    assert_eq!(interval.checked_mul(i32::MAX), None);
+    assert_eq!(
+        MonthDayNanoInterval::from_year_month(i32::MIN).checked_neg(),
+        None
+    );
+
+    let day_time = DayTimeInterval {
+        days: -1,
+        milliseconds: 5,
+    };
+    assert_eq!(
+        day_time.checked_neg(),
+        Some(DayTimeInterval {
+            days: 1,
+            milliseconds: -5
+        })
+    );
+    let converted = MonthDayNanoInterval::from(day_time);
+    assert_eq!(converted.nanoseconds, 5_000_000);
+    assert_eq!(DayTimeInterval::try_from(converted).unwrap(), day_time);
+    assert!(DayTimeInterval::try_from(interval).is_err());
+    assert_eq!(
+        MonthDayNanoInterval::from_year_month(5)
+            .to_year_month()
+            .unwrap(),
+        5
+    );
+    assert!(interval.to_year_month().is_err());
+
+    let both = IntervalNormalization {
+        time_into_days: true,
+        days_into_months: true,
+    };
+    let interval = "P-1M45DT-25H".parse::<MonthDayNanoInterval>().unwrap();
+    assert_eq!(
+        interval.normalize(IntervalNormalization::default()),
+        Some(interval)
+    );
+    let days_into_months = IntervalNormalization {
+        days_into_months: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        interval.normalize(days_into_months).unwrap().to_string(),
+        "P15DT-25H"
+    );
+    assert_eq!(interval.normalize(both).unwrap().to_string(), "P13DT23H");
+    assert_eq!(
+        DayTimeInterval {
+            days: i32::MAX,
+            milliseconds: 86_400_000
+        }
+        .normalize(both),
+        None
+    );
+}
+
+#[test]
+fn calendar_arithmetic() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    for days in [-800_000, -1, 0, 59, 60, 11_016, 19_782, 2_932_896] {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+
+    // 2024-01-31 + 1 month = 2024-02-29, 2024-03-31 - 1 month = 2024-02-29
+    assert_eq!(add_months(19_753, 1), 19_782);
+    assert_eq!(add_months(19_813, -1), 19_782);
+    // 2024-02-29 + 12 months = 2025-02-28
+    assert_eq!(add_months(19_782, 12), 20_147);
+
+    let interval = |s: &str| s.parse::<MonthDayNanoInterval>().unwrap();
+    assert_eq!(interval("P1M").add_to_date32(19_753).unwrap(), 19_782);
+    assert_eq!(interval("-P1D").add_to_date32(0).unwrap(), -1);
+    assert_eq!(interval("PT48H").add_to_date32(0).unwrap(), 2);
+    assert!(interval("PT1H").add_to_date32(0).is_err());
+    assert_eq!(
+        interval("P1M").add_to_date64(19_753 * 86_400_000).unwrap(),
+        19_782 * 86_400_000
+    );
+
+    // 2024-01-31T23:30:00Z
+    let timestamp = 1_706_743_800;
+    assert_eq!(
+        interval("P1MT1H")
+            .add_to_timestamp(timestamp, TimeUnit::Second, None)
+            .unwrap(),
+        // 2024-03-01T00:30:00Z
+        1_709_253_000
+    );
+    assert_eq!(
+        interval("P1M")
+            .add_to_timestamp(timestamp * 1_000, TimeUnit::Millisecond, Some("+01:00"))
+            .unwrap(),
+        // 2024-02-01T00:30:00+01:00 + 1 month = 2024-03-01T00:30:00+01:00
+        1_709_249_400_000
+    );
+    assert!(interval("PT0.5S")
+        .add_to_timestamp(timestamp, TimeUnit::Second, None)
+        .is_err());
+    assert!(interval("P1D")
+        .add_to_timestamp(i64::MAX, TimeUnit::Nanosecond, None)
+        .is_err());
+}
+
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+#[test]
+fn calendar_arithmetic_in_named_timezones() {
+    let interval = |s: &str| s.parse::<MonthDayNanoInterval>().unwrap();
+    // 2024-03-30T12:00:00+01:00, the day before the switch to daylight saving time
+    let timestamp = 1_711_796_400;
+    let add = |s: &str| {
+        interval(s)
+            .add_to_timestamp(timestamp, TimeUnit::Second, Some("Europe/Berlin"))
+            .unwrap()
+    };
+    // days keep the local time: 2024-03-31T12:00:00+02:00
+    assert_eq!(add("P1D"), timestamp + 23 * 3600);
+    // the time is added as an exact duration
+    assert_eq!(add("PT24H"), timestamp + 24 * 3600);
+}
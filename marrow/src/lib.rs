@@ -8,6 +8,7 @@
 //!
 //! - [`arrow`](https://docs.rs/arrow/)
 //! - [`arrow2`](https://docs.rs/arrow2)
+//! - [`polars-arrow`](https://docs.rs/polars-arrow)
 //!
 //! The main types are
 //!
@@ -25,6 +26,8 @@
 //! From `marrow` to `arrow`:
 //!
 //! - `TryFrom<`[`marrow::array::Array`][crate::array::Array]`> for arrow::array::ArrayRef`
+//! - `TryFrom<`[`marrow::view::View<'_>`][crate::view::View]`> for arrow::array::ArrayRef` (copies
+//!   the referenced data)
 //! - `TryFrom<&`[`marrow::datatypes::Field`][crate::datatypes::Field]`> for arrow::datatypes::Field`
 //! - `TryFrom<&`[`marrow::datatypes::DataType`][crate::datatypes::DataType]`> for arrow::datatypes::DataType`
 //! - `TryFrom<`[`marrow::datatypes::TimeUnit`][crate::datatypes::TimeUnit]`> for arrow::datatypes::TimeUnit`
@@ -33,12 +36,18 @@
 //! From `arrow` to `marrow`:
 //!
 //! - `TryFrom<&dyn arrow::array::Array> for `[`marrow::view::View<'_>`][crate::view::View]
+//! - `TryFrom<arrow::array::ArrayRef> for `[`marrow::array::Array`][crate::array::Array] (moves
+//!   buffers that are not shared, copies otherwise)
 //! - `TryFrom<&arrow::datatypes::Field> for `[`marrow::datatypes::Field`][crate::datatypes::Field]
 //! - `TryFrom<&arrow::datatypes::DataType> for `[`marrow::datatypes::DataType`][crate::datatypes::DataType]
 //! - `TryFrom<arrow::datatypes::TimeUnit> for `[`marrow::datatypes::TimeUnit`][crate::datatypes::TimeUnit]
 //! - `TryFrom<arrow::datatypes::UnionMode> for `[`marrow::datatypes::UnionMode`][crate::datatypes::UnionMode]
 //!
-//! For `arrow2` the corresponding conversions are implemented.
+//! For `arrow2` and `polars-arrow` the corresponding conversions are implemented.
+//!
+//! To convert directly between different `arrow` and `arrow2` versions, see the [`bridge`] module.
+//!
+//! To share arrays cheaply, e.g., between threads, see the [`shared`] module.
 //!
 //! For example to access the data in an arrow array:
 //!
@@ -86,13 +95,26 @@
 //!
 //! - `serde`: enable Serde serialization / deserialization for schema types
 //!   ([Field][crate::datatypes::Field], [DataType][crate::datatypes::DataType], ...). The format
-//!   will match the `arrow` crate
+//!   will match the `arrow` crate. Arrays ([Array][crate::array::Array]) are serialized as their
+//!   buffers and can be deserialized again. Views ([View][crate::view::View]) are serialized in
+//!   the same format without copying their data. The [`rows`] module converts
+//!   between rows of Rust values and struct arrays
+//! - `integration-json`: read and write the JSON format of the Arrow integration tests, see the
+//!   `marrow::integration_json` module
+//! - `arbitrary`: generate random data types, fields and arrays with the `arbitrary` crate, see the
+//!   `marrow::arbitrary` module
+//! - `proptest`: `proptest` strategies for data types, fields and arrays, see the
+//!   `marrow::proptest` module
 //! - `arrow-{version}`: enable conversions between `marrow` and `arrow={version}`
 //! - `arrow2-{version}`: enable conversions between `marrow` and `arrow2={version}`
+//! - `polars-arrow-{version}`: enable conversions between `marrow` and `polars-arrow={version}`
+//! - `chrono`, `time`, `jiff`: enable conversions between temporal arrays and the date and time
+//!   types of the respective crates, see the [`temporal`] module
 //!
-//! This crate supports conversions from and to different version of `arrow` or `arrow2`. These
-//! conversions can be enabled by selecting the relevant features. Any combination of features can
-//! be selected, e.g., both `arrow-53` and `arrow-52` can be used at the same time.
+//! This crate supports conversions from and to different version of `arrow`, `arrow2` or
+//! `polars-arrow`. These conversions can be enabled by selecting the relevant features. Any
+//! combination of features can be selected, e.g., both `arrow-53` and `arrow-52` can be used at
+//! the same time.
 //!
 //! Supported arrow versions:
 //!
@@ -122,15 +144,45 @@
 //! | `arrow-37`    | `arrow=37`    |
 //! | `arrow2-0-17` | `arrow2=0.17` |
 //! | `arrow2-0-16` | `arrow2=0.16` |
+//! | `polars-arrow-0-51` | `polars-arrow=0.51` |
+//! | `polars-arrow-0-50` | `polars-arrow=0.50` |
+//! | `polars-arrow-0-46` | `polars-arrow=0.46` |
 //!
 //! Note, `arrow2=0.18` is not supported as the source code was not tagged on GitHub.
 //!
+//! ## Breaking changes
+//!
+//! [`Field`][crate::datatypes::Field] and [`FieldMeta`][crate::datatypes::FieldMeta] have the new
+//! public field `dictionary` with the [`DictionaryMeta`][crate::datatypes::DictionaryMeta] of
+//! dictionary encoded fields. Struct literals of these types must set it, e.g., via
+//! `..Default::default()`.
+//!
+#[cfg(feature = "arbitrary")]
+#[deny(missing_docs)]
+pub mod arbitrary;
 #[deny(missing_docs)]
 pub mod array;
 #[deny(missing_docs)]
 pub mod datatypes;
 #[deny(missing_docs)]
+pub mod decimal;
+#[deny(missing_docs)]
+pub mod diff;
+#[deny(missing_docs)]
 pub mod error;
+#[cfg(feature = "integration-json")]
+#[deny(missing_docs)]
+pub mod integration_json;
+#[deny(missing_docs)]
+pub mod interval;
+#[cfg(feature = "proptest")]
+#[deny(missing_docs)]
+pub mod proptest;
+#[cfg(feature = "serde")]
+#[deny(missing_docs)]
+pub mod rows;
+#[deny(missing_docs)]
+pub mod temporal;
 #[deny(missing_docs)]
 pub mod types;
 #[deny(missing_docs)]
@@ -139,8 +191,42 @@ pub mod view;
 #[deny(missing_docs)]
 pub mod bits;
 
+#[deny(missing_docs)]
+pub mod bridge;
+#[deny(missing_docs)]
+pub mod shared;
+
 mod impl_arrow;
 mod impl_arrow2;
+mod impl_polars_arrow;
+#[cfg(any(
+    // arrow-version:insert:     feature = "arrow-{version}",
+    feature = "arrow-58",
+    feature = "arrow-57",
+    feature = "arrow-56",
+    feature = "arrow-55",
+    feature = "arrow-54",
+    feature = "arrow-53",
+    feature = "arrow-52",
+    feature = "arrow-51",
+    feature = "arrow-50",
+    feature = "arrow-49",
+    feature = "arrow-48",
+    feature = "arrow-47",
+    feature = "arrow-46",
+    feature = "arrow-45",
+    feature = "arrow-44",
+    feature = "arrow-43",
+    feature = "arrow-42",
+    feature = "arrow-41",
+    feature = "arrow-40",
+    feature = "arrow-39",
+    feature = "arrow-38",
+    feature = "arrow-37",
+    feature = "arrow2-0-17",
+    feature = "arrow2-0-16",
+))]
+mod interop;
 
 #[doc(hidden)]
 pub mod r#impl;
@@ -0,0 +1,75 @@
+//! [`proptest`] strategies for data types, fields and arrays
+//!
+//! The strategies generate random bytes and interpret them with the generators of the
+//! [`arbitrary`][crate::arbitrary] module. Shrinking the bytes results in simpler values, e.g.,
+//! shorter arrays or less deeply nested data types.
+//!
+//! ```rust
+//! # use marrow::proptest::field_and_array;
+//! # use marrow::arbitrary::Options;
+//! use proptest::{
+//!     strategy::{Strategy, ValueTree},
+//!     test_runner::TestRunner,
+//! };
+//!
+//! let mut runner = TestRunner::deterministic();
+//! let (field, array) = field_and_array(Options::default())
+//!     .new_tree(&mut runner)
+//!     .unwrap()
+//!     .current();
+//!
+//! assert_eq!(array.data_type(), field.data_type);
+//! ```
+use ::arbitrary::Unstructured;
+use ::proptest::{arbitrary::any, collection::vec, strategy::Strategy};
+
+use crate::{
+    arbitrary::{arbitrary_array, arbitrary_data_type, arbitrary_field, Options},
+    array::Array,
+    datatypes::{DataType, Field},
+};
+
+const MAX_BYTES: usize = 4096;
+
+fn from_bytes<T: std::fmt::Debug>(
+    what: &'static str,
+    generate: impl Fn(&mut Unstructured<'_>) -> ::arbitrary::Result<T>,
+) -> impl Strategy<Value = T> {
+    vec(any::<u8>(), 0..=MAX_BYTES).prop_filter_map(what, move |data| {
+        generate(&mut Unstructured::new(&data)).ok()
+    })
+}
+
+/// A strategy to generate data types
+pub fn data_type(options: Options) -> impl Strategy<Value = DataType> {
+    from_bytes("invalid data type", move |u| {
+        arbitrary_data_type(u, &options)
+    })
+}
+
+/// A strategy to generate fields
+pub fn field(options: Options) -> impl Strategy<Value = Field> {
+    from_bytes("invalid field", move |u| arbitrary_field(u, &options))
+}
+
+/// A strategy to generate arrays of the given data type
+///
+/// If `nullable` is `false`, the arrays do not contain missing values.
+pub fn array(
+    data_type: DataType,
+    nullable: bool,
+    options: Options,
+) -> impl Strategy<Value = Array> {
+    from_bytes("invalid array", move |u| {
+        arbitrary_array(u, &data_type, nullable, &options)
+    })
+}
+
+/// A strategy to generate a field together with an array conforming to it
+pub fn field_and_array(options: Options) -> impl Strategy<Value = (Field, Array)> {
+    from_bytes("invalid field or array", move |u| {
+        let field = arbitrary_field(u, &options)?;
+        let array = arbitrary_array(u, &field.data_type, field.nullable, &options)?;
+        Ok((field, array))
+    })
+}
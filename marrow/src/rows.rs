@@ -0,0 +1,347 @@
+//! Convert between rows of Rust values and struct arrays with serde
+//!
+//! [`from_struct_view`] and [`iter_struct_view`] deserialize each row of a [`StructView`] into a
+//! value of type `T`, with the fields of the struct as the fields of `T`. [`to_struct_array`]
+//! serializes a slice of values into a [`StructArray`] with the given fields.
+//!
+//! The Rust values are mapped onto the Arrow data types as follows:
+//!
+//! | Rust                           | Arrow                                                      |
+//! |--------------------------------|------------------------------------------------------------|
+//! | `bool`                         | `Boolean`                                                  |
+//! | integers and floats            | integers, floats and `Decimal128`                          |
+//! | `String`, `&str`               | `Utf8`, `LargeUtf8`, `Utf8View`, dictionaries of strings   |
+//! | `String`, `&str`               | `Date32`, `Date64` as `YYYY-MM-DD`                         |
+//! | `String`, `&str`               | `Timestamp` as `YYYY-MM-DDTHH:MM:SS[.fraction][Z]`         |
+//! | `String`                       | `Decimal128` and intervals in their textual representation |
+//! | `Vec<u8>`, `&[u8]`             | `Binary`, `LargeBinary`, `BinaryView`, `FixedSizeBinary`   |
+//! | `Option<T>`                    | nullable fields                                            |
+//! | `Vec<T>`, arrays               | `List`, `LargeList`, `FixedSizeList`                       |
+//! | `HashMap<K, V>`, `BTreeMap`    | `Map`                                                      |
+//! | structs and tuples             | `Struct`                                                   |
+//! | enums                          | `Union` with one field per variant                         |
+//! | enums without data             | `Utf8` or dictionaries of strings with the variant name    |
+//!
+//! Timestamps with a timezone are formatted with the suffix `Z`. When serializing, timestamps
+//! with a fixed offset are converted to UTC. Dates, times, durations and timestamps can also be
+//! used as plain integers.
+//!
+//! When serializing, unknown struct fields are ignored and missing fields are serialized as null.
+//! Serializing into `Utf8View`, `BinaryView` and run-end encoded fields is not
+//! supported.
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! # use marrow::{datatypes::{DataType, Field}, rows};
+//! #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Row {
+//!     id: u32,
+//!     name: Option<String>,
+//! }
+//!
+//! let fields = vec![
+//!     Field {
+//!         name: String::from("id"),
+//!         data_type: DataType::UInt32,
+//!         ..Field::default()
+//!     },
+//!     Field {
+//!         name: String::from("name"),
+//!         data_type: DataType::Utf8,
+//!         nullable: true,
+//!         ..Field::default()
+//!     },
+//! ];
+//! let items = vec![
+//!     Row { id: 0, name: Some(String::from("foo")) },
+//!     Row { id: 1, name: None },
+//! ];
+//!
+//! let array = rows::to_struct_array(&fields, &items)?;
+//! assert_eq!(rows::from_struct_view::<Row>(&array.as_view())?, items);
+//! # Ok(())
+//! # }
+//! ```
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    array::{Array, StructArray},
+    datatypes::{DataType, Field},
+    error::{fail, ErrorKind, Result},
+    view::StructView,
+};
+
+mod de;
+mod ser;
+
+/// Deserialize the rows of a struct view into a vector
+///
+/// The validity of the struct itself is ignored, i.e., each row is deserialized from its fields.
+pub fn from_struct_view<'a, T: Deserialize<'a>>(view: &StructView<'a>) -> Result<Vec<T>> {
+    iter_struct_view(view).collect()
+}
+
+/// Iterate over the rows of a struct view, deserializing them one by one
+///
+/// See [`from_struct_view`].
+pub fn iter_struct_view<'v, 'a, T: Deserialize<'a>>(view: &'v StructView<'a>) -> Rows<'v, 'a, T> {
+    Rows {
+        view,
+        idx: 0,
+        _phantom: PhantomData,
+    }
+}
+
+/// An iterator over the deserialized rows of a struct view, see [`iter_struct_view`]
+pub struct Rows<'v, 'a, T> {
+    view: &'v StructView<'a>,
+    idx: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Deserialize<'a>> Iterator for Rows<'_, 'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.view.len {
+            return None;
+        }
+        let deserializer = de::RowDeserializer {
+            view: self.view,
+            idx: self.idx,
+        };
+        self.idx += 1;
+        Some(T::deserialize(deserializer))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len.saturating_sub(self.idx);
+        (len, Some(len))
+    }
+}
+
+/// Serialize the items into a struct array with the given fields
+///
+/// Each item must serialize as a struct, a map with string keys or a tuple. The returned array
+/// has no validity.
+pub fn to_struct_array<T: Serialize>(fields: &[Field], items: &[T]) -> Result<StructArray> {
+    let data_type = DataType::Struct(fields.to_vec());
+    let mut builder = ser::Builder::new("$", &data_type, false)?;
+    for item in items {
+        item.serialize(&mut builder)?;
+    }
+    let Array::Struct(array) = builder.finish()? else {
+        fail!(ErrorKind::Unsupported, "Expected a struct array");
+    };
+    Ok(array)
+}
+
+#[cfg(test)]
+fn field(name: &str, data_type: DataType, nullable: bool) -> Field {
+    Field {
+        name: name.to_owned(),
+        data_type,
+        nullable,
+        ..Field::default()
+    }
+}
+
+#[test]
+fn round_trip() {
+    use std::collections::BTreeMap;
+
+    use crate::datatypes::{TimeUnit, UnionMode};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        id: i64,
+        label: Option<String>,
+        shape: Shape,
+        color: Color,
+        tags: BTreeMap<String, i32>,
+        matrix: Vec<Vec<u8>>,
+        at: String,
+        day: Option<String>,
+    }
+
+    let list = |item| DataType::List(Box::new(field("element", item, false)));
+    let fields = vec![
+        field("id", DataType::Int64, false),
+        field("label", DataType::LargeUtf8, true),
+        field(
+            "shape",
+            DataType::Union(
+                vec![
+                    (0, field("Point", DataType::Null, true)),
+                    (1, field("Circle", DataType::Float64, false)),
+                    (
+                        2,
+                        field(
+                            "Rect",
+                            DataType::Struct(vec![
+                                field("width", DataType::Float64, false),
+                                field("height", DataType::Float64, false),
+                            ]),
+                            false,
+                        ),
+                    ),
+                ],
+                UnionMode::Dense,
+            ),
+            false,
+        ),
+        field(
+            "color",
+            DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8)),
+            false,
+        ),
+        field(
+            "tags",
+            DataType::Map(
+                Box::new(field(
+                    "entries",
+                    DataType::Struct(vec![
+                        field("key", DataType::Utf8, false),
+                        field("value", DataType::Int32, false),
+                    ]),
+                    false,
+                )),
+                false,
+            ),
+            false,
+        ),
+        field("matrix", list(list(DataType::UInt8)), false),
+        field(
+            "at",
+            DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
+            false,
+        ),
+        field("day", DataType::Date32, true),
+    ];
+    let items = vec![
+        Row {
+            id: 1,
+            label: Some(String::from("first")),
+            shape: Shape::Circle(0.5),
+            color: Color::Green,
+            tags: BTreeMap::from([(String::from("a"), 1), (String::from("b"), 2)]),
+            matrix: vec![vec![1, 2], vec![]],
+            at: String::from("2023-11-14T22:13:20.123Z"),
+            day: Some(String::from("2024-02-29")),
+        },
+        Row {
+            id: -2,
+            label: None,
+            shape: Shape::Point,
+            color: Color::Red,
+            tags: BTreeMap::new(),
+            matrix: vec![],
+            at: String::from("1969-12-31T23:59:59.000Z"),
+            day: None,
+        },
+        Row {
+            id: 3,
+            label: Some(String::new()),
+            shape: Shape::Rect {
+                width: 1.0,
+                height: 2.0,
+            },
+            color: Color::Green,
+            tags: BTreeMap::from([(String::from("c"), -3)]),
+            matrix: vec![vec![3]],
+            at: String::from("2000-01-01T00:00:00.000Z"),
+            day: Some(String::from("1970-01-01")),
+        },
+    ];
+
+    let array = to_struct_array(&fields, &items).unwrap();
+    assert_eq!(array.len, 3);
+    assert_eq!(array.validity, None);
+
+    let Array::Dictionary(color) = &array.fields[3].1 else {
+        panic!("Expected a dictionary array");
+    };
+    assert_eq!(color.values.as_view().as_bytes().unwrap().data, b"GreenRed");
+
+    let view = array.as_view();
+    assert_eq!(from_struct_view::<Row>(&view).unwrap(), items);
+
+    let ids = iter_struct_view::<(i64,)>(&view)
+        .map(|row| row.unwrap().0)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [1, -2, 3]);
+}
+
+#[test]
+fn borrowed_strings_and_maps() {
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row<'a> {
+        name: &'a str,
+        #[serde(borrow)]
+        counts: HashMap<&'a str, u64>,
+    }
+
+    let fields = vec![
+        field("name", DataType::Utf8, false),
+        field(
+            "counts",
+            DataType::Map(
+                Box::new(field(
+                    "entries",
+                    DataType::Struct(vec![
+                        field("key", DataType::Utf8, false),
+                        field("value", DataType::UInt64, false),
+                    ]),
+                    false,
+                )),
+                false,
+            ),
+            false,
+        ),
+    ];
+    let items = vec![
+        Row {
+            name: "foo",
+            counts: HashMap::from([("x", 1)]),
+        },
+        Row {
+            name: "bar",
+            counts: HashMap::new(),
+        },
+    ];
+    let array = to_struct_array(&fields, &items).unwrap();
+    assert_eq!(from_struct_view::<Row>(&array.as_view()).unwrap(), items);
+}
+
+#[test]
+fn errors() {
+    #[derive(Serialize)]
+    struct Row {
+        value: Option<i64>,
+    }
+
+    let fields = vec![field("value", DataType::Int8, false)];
+    assert!(to_struct_array(&fields, &[Row { value: None }]).is_err());
+    assert!(to_struct_array(&fields, &[Row { value: Some(1000) }]).is_err());
+    assert!(to_struct_array(&fields, &[Row { value: Some(-1) }]).is_ok());
+
+    let fields = vec![field("value", DataType::Utf8View, false)];
+    assert!(to_struct_array(&fields, &[Row { value: None }]).is_err());
+}
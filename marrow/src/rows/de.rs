@@ -0,0 +1,674 @@
+//! Deserialize rows from views
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+use crate::{
+    datatypes::FieldMeta,
+    decimal::format_decimal,
+    error::{fail, ErrorKind, MarrowError, Result},
+    temporal::{format_date, format_timestamp},
+    view::{BitsWithOffset, BytesView, BytesViewView, StructView, View},
+};
+
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// Deserialize a single row of a struct view, ignoring the validity of the row
+pub(super) struct RowDeserializer<'v, 'a> {
+    pub view: &'v StructView<'a>,
+    pub idx: usize,
+}
+
+impl<'a> Deserializer<'a> for RowDeserializer<'_, 'a> {
+    type Error = MarrowError;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(StructFields::new(&self.view.fields, self.idx))
+    }
+
+    fn deserialize_seq<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(StructFields::new(&self.view.fields, self.idx))
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(self, _: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserialize the value at `idx` of a view
+struct ValueDeserializer<'v, 'a> {
+    view: &'v View<'a>,
+    idx: usize,
+}
+
+impl<'v, 'a> ValueDeserializer<'v, 'a> {
+    /// Follow dictionaries and run end encoded views to the view containing the value
+    ///
+    /// Returns `None` for missing values.
+    fn resolve(&self) -> Result<Option<(&'v View<'a>, usize)>> {
+        let mut view = self.view;
+        let mut idx = self.idx;
+        loop {
+            match view {
+                View::Null(_) => return Ok(None),
+                View::Dictionary(dictionary) => {
+                    let Some((keys, key_idx)) = (ValueDeserializer {
+                        view: &dictionary.keys,
+                        idx,
+                    })
+                    .resolve()?
+                    else {
+                        return Ok(None);
+                    };
+                    idx = dictionary_key(keys, key_idx)?;
+                    view = &dictionary.values;
+                }
+                View::RunEndEncoded(ree) => {
+                    idx = run_index(&ree.run_ends, idx)?;
+                    view = &ree.values;
+                }
+                View::Union(_) => return Ok(Some((view, idx))),
+                view => {
+                    return match validity(view) {
+                        Some(validity) if !bit(validity, idx)? => Ok(None),
+                        _ => Ok(Some((view, idx))),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Deserializer<'a> for ValueDeserializer<'_, 'a> {
+    type Error = MarrowError;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        use View as V;
+
+        let Some((view, idx)) = self.resolve()? else {
+            return visitor.visit_unit();
+        };
+        match view {
+            V::Boolean(view) => visitor.visit_bool(bit(view.values, idx)?),
+            V::Int8(view) => visitor.visit_i8(value(view.values, idx)?),
+            V::Int16(view) => visitor.visit_i16(value(view.values, idx)?),
+            V::Int32(view) => visitor.visit_i32(value(view.values, idx)?),
+            V::Int64(view) => visitor.visit_i64(value(view.values, idx)?),
+            V::UInt8(view) => visitor.visit_u8(value(view.values, idx)?),
+            V::UInt16(view) => visitor.visit_u16(value(view.values, idx)?),
+            V::UInt32(view) => visitor.visit_u32(value(view.values, idx)?),
+            V::UInt64(view) => visitor.visit_u64(value(view.values, idx)?),
+            V::Float16(view) => visitor.visit_f32(value(view.values, idx)?.to_f32()),
+            V::Float32(view) => visitor.visit_f32(value(view.values, idx)?),
+            V::Float64(view) => visitor.visit_f64(value(view.values, idx)?),
+            V::Date32(view) => visitor.visit_i32(value(view.values, idx)?),
+            V::Date64(view) => visitor.visit_i64(value(view.values, idx)?),
+            V::Time32(view) => visitor.visit_i32(value(view.values, idx)?),
+            V::Time64(view) => visitor.visit_i64(value(view.values, idx)?),
+            V::Timestamp(view) => visitor.visit_i64(value(view.values, idx)?),
+            V::Duration(view) => visitor.visit_i64(value(view.values, idx)?),
+            V::YearMonthInterval(view) => visitor.visit_i32(value(view.values, idx)?),
+            V::DayTimeInterval(view) => visitor.visit_string(value(view.values, idx)?.to_string()),
+            V::MonthDayNanoInterval(view) => {
+                visitor.visit_string(value(view.values, idx)?.to_string())
+            }
+            V::Decimal128(view) => {
+                visitor.visit_string(format_decimal(value(view.values, idx)?, view.scale))
+            }
+            V::Utf8(view) => visitor.visit_borrowed_str(str(bytes(view, idx)?)?),
+            V::Binary(view) => visitor.visit_borrowed_bytes(bytes(view, idx)?),
+            V::LargeUtf8(view) => visitor.visit_borrowed_str(str(bytes(view, idx)?)?),
+            V::LargeBinary(view) => visitor.visit_borrowed_bytes(bytes(view, idx)?),
+            V::Utf8View(view) => visitor.visit_borrowed_str(str(bytes_view(view, idx)?)?),
+            V::BinaryView(view) => visitor.visit_borrowed_bytes(bytes_view(view, idx)?),
+            V::FixedSizeBinary(view) => {
+                let n = usize::try_from(view.n)?;
+                visitor.visit_borrowed_bytes(slice(view.data, idx * n, n)?)
+            }
+            V::Struct(view) => visitor.visit_map(StructFields::new(&view.fields, idx)),
+            V::List(view) => {
+                let start = offset(view.offsets, idx)?;
+                let end = offset(view.offsets, idx + 1)?;
+                visitor.visit_seq(Elements::new(&view.elements, start, end))
+            }
+            V::LargeList(view) => {
+                let start = offset(view.offsets, idx)?;
+                let end = offset(view.offsets, idx + 1)?;
+                visitor.visit_seq(Elements::new(&view.elements, start, end))
+            }
+            V::FixedSizeList(view) => {
+                let n = usize::try_from(view.n)?;
+                visitor.visit_seq(Elements::new(&view.elements, idx * n, (idx + 1) * n))
+            }
+            V::Map(view) => {
+                let start = offset(view.offsets, idx)?;
+                let end = offset(view.offsets, idx + 1)?;
+                visitor.visit_map(Entries {
+                    keys: &view.keys,
+                    values: &view.values,
+                    next: start,
+                    end,
+                })
+            }
+            V::Union(_) => {
+                let (_, child, child_idx) = union_variant(view, idx)?;
+                ValueDeserializer {
+                    view: child,
+                    idx: child_idx,
+                }
+                .deserialize_any(visitor)
+            }
+            V::Null(_) | V::Dictionary(_) | V::RunEndEncoded(_) => {
+                fail!(ErrorKind::Unsupported, "Unresolved view")
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        if self.resolve()?.is_some() {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_str<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let Some((view, idx)) = self.resolve()? else {
+            return visitor.visit_unit();
+        };
+        match view {
+            View::Date32(view) => {
+                visitor.visit_string(format_date(i64::from(value(view.values, idx)?)))
+            }
+            View::Date64(view) => visitor.visit_string(format_date(
+                value(view.values, idx)?.div_euclid(MILLISECONDS_PER_DAY),
+            )),
+            View::Timestamp(view) => visitor.visit_string(format_timestamp(
+                value(view.values, idx)?,
+                view.unit,
+                view.timezone.is_some(),
+            )),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        match self.resolve()? {
+            Some((View::Decimal128(view), idx)) => {
+                let value = format_decimal(value(view.values, idx)?, view.scale);
+                match value.parse::<f64>() {
+                    Ok(value) => visitor.visit_f64(value),
+                    Err(_) => fail!(ErrorKind::ParseError, "Invalid decimal {value}"),
+                }
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        match self.resolve()? {
+            Some((View::Struct(view), idx)) => {
+                visitor.visit_seq(StructFields::new(&view.fields, idx))
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(self, _: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'a>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.resolve()? {
+            Some((view @ View::Union(_), idx)) => {
+                let (meta, child, child_idx) = union_variant(view, idx)?;
+                visitor.visit_enum(Variant {
+                    name: &meta.name,
+                    value: ValueDeserializer {
+                        view: child,
+                        idx: child_idx,
+                    },
+                })
+            }
+            Some((View::Utf8(view), idx)) => {
+                visitor.visit_enum(str(bytes(view, idx)?)?.into_deserializer())
+            }
+            Some((View::LargeUtf8(view), idx)) => {
+                visitor.visit_enum(str(bytes(view, idx)?)?.into_deserializer())
+            }
+            Some((View::Utf8View(view), idx)) => {
+                visitor.visit_enum(str(bytes_view(view, idx)?)?.into_deserializer())
+            }
+            Some(_) => fail!(
+                ErrorKind::Unsupported,
+                "Enums can only be deserialized from unions and strings"
+            ),
+            None => fail!(
+                ErrorKind::Unsupported,
+                "Cannot deserialize an enum from null"
+            ),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char bytes byte_buf map struct identifier
+    }
+}
+
+/// The fields of a struct, as a map of names to values or as a sequence of values
+struct StructFields<'v, 'a> {
+    fields: &'v [(FieldMeta, View<'a>)],
+    idx: usize,
+    next: usize,
+}
+
+impl<'v, 'a> StructFields<'v, 'a> {
+    fn new(fields: &'v [(FieldMeta, View<'a>)], idx: usize) -> Self {
+        Self {
+            fields,
+            idx,
+            next: 0,
+        }
+    }
+}
+
+impl<'a> MapAccess<'a> for StructFields<'_, 'a> {
+    type Error = MarrowError;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((meta, _)) = self.fields.get(self.next) else {
+            return Ok(None);
+        };
+        seed.deserialize(meta.name.as_str().into_deserializer())
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some((_, view)) = self.fields.get(self.next) else {
+            fail!(ErrorKind::Unsupported, "No more struct fields");
+        };
+        self.next += 1;
+        seed.deserialize(ValueDeserializer {
+            view,
+            idx: self.idx,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.next)
+    }
+}
+
+impl<'a> SeqAccess<'a> for StructFields<'_, 'a> {
+    type Error = MarrowError;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        let Some((_, view)) = self.fields.get(self.next) else {
+            return Ok(None);
+        };
+        self.next += 1;
+        seed.deserialize(ValueDeserializer {
+            view,
+            idx: self.idx,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.next)
+    }
+}
+
+/// The elements `next..end` of a child view
+struct Elements<'v, 'a> {
+    view: &'v View<'a>,
+    next: usize,
+    end: usize,
+}
+
+impl<'v, 'a> Elements<'v, 'a> {
+    fn new(view: &'v View<'a>, start: usize, end: usize) -> Self {
+        Self {
+            view,
+            next: start,
+            end,
+        }
+    }
+}
+
+impl<'a> SeqAccess<'a> for Elements<'_, 'a> {
+    type Error = MarrowError;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.next >= self.end {
+            return Ok(None);
+        }
+        let idx = self.next;
+        self.next += 1;
+        seed.deserialize(ValueDeserializer {
+            view: self.view,
+            idx,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.end.saturating_sub(self.next))
+    }
+}
+
+/// The entries `next..end` of a map
+struct Entries<'v, 'a> {
+    keys: &'v View<'a>,
+    values: &'v View<'a>,
+    next: usize,
+    end: usize,
+}
+
+impl<'a> MapAccess<'a> for Entries<'_, 'a> {
+    type Error = MarrowError;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.next >= self.end {
+            return Ok(None);
+        }
+        seed.deserialize(ValueDeserializer {
+            view: self.keys,
+            idx: self.next,
+        })
+        .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(&mut self, seed: V) -> Result<V::Value> {
+        let idx = self.next;
+        self.next += 1;
+        seed.deserialize(ValueDeserializer {
+            view: self.values,
+            idx,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.end.saturating_sub(self.next))
+    }
+}
+
+/// The variant of a union, identified by the name of its field
+struct Variant<'v, 'a> {
+    name: &'v str,
+    value: ValueDeserializer<'v, 'a>,
+}
+
+impl<'v, 'a> EnumAccess<'a> for Variant<'v, 'a> {
+    type Error = MarrowError;
+    type Variant = ValueDeserializer<'v, 'a>;
+
+    fn variant_seed<V: DeserializeSeed<'a>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let variant = seed.deserialize(IntoDeserializer::<MarrowError>::into_deserializer(
+            self.name,
+        ))?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'a> VariantAccess<'a> for ValueDeserializer<'_, 'a> {
+    type Error = MarrowError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'a>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'a>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'a>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+fn validity<'a>(view: &View<'a>) -> Option<BitsWithOffset<'a>> {
+    use View as V;
+    match view {
+        V::Boolean(view) => view.validity,
+        V::Int8(view) => view.validity,
+        V::Int16(view) => view.validity,
+        V::Int32(view) => view.validity,
+        V::Int64(view) => view.validity,
+        V::UInt8(view) => view.validity,
+        V::UInt16(view) => view.validity,
+        V::UInt32(view) => view.validity,
+        V::UInt64(view) => view.validity,
+        V::Float16(view) => view.validity,
+        V::Float32(view) => view.validity,
+        V::Float64(view) => view.validity,
+        V::Date32(view) => view.validity,
+        V::Date64(view) => view.validity,
+        V::Time32(view) => view.validity,
+        V::Time64(view) => view.validity,
+        V::Timestamp(view) => view.validity,
+        V::Duration(view) => view.validity,
+        V::YearMonthInterval(view) => view.validity,
+        V::DayTimeInterval(view) => view.validity,
+        V::MonthDayNanoInterval(view) => view.validity,
+        V::Decimal128(view) => view.validity,
+        V::Utf8(view) | V::Binary(view) => view.validity,
+        V::LargeUtf8(view) | V::LargeBinary(view) => view.validity,
+        V::Utf8View(view) | V::BinaryView(view) => view.validity,
+        V::FixedSizeBinary(view) => view.validity,
+        V::Struct(view) => view.validity,
+        V::List(view) => view.validity,
+        V::LargeList(view) => view.validity,
+        V::FixedSizeList(view) => view.validity,
+        V::Map(view) => view.validity,
+        V::Null(_) | V::Dictionary(_) | V::RunEndEncoded(_) | V::Union(_) => None,
+    }
+}
+
+fn bit(bits: BitsWithOffset<'_>, idx: usize) -> Result<bool> {
+    let pos = bits.offset + idx;
+    if pos / 8 >= bits.data.len() {
+        fail!(ErrorKind::Unsupported, "Bit {idx} is out of bounds");
+    }
+    Ok(bits.get(idx))
+}
+
+fn value<T: Copy>(values: &[T], idx: usize) -> Result<T> {
+    let Some(value) = values.get(idx) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Index {idx} is out of bounds for {len} values",
+            len = values.len(),
+        );
+    };
+    Ok(*value)
+}
+
+fn slice<T>(data: &[T], start: usize, len: usize) -> Result<&[T]> {
+    let Some(res) = data.get(start..start + len) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot access {len} elements starting at {start} of {total} elements",
+            total = data.len(),
+        );
+    };
+    Ok(res)
+}
+
+fn offset<O: Copy + Into<i64>>(offsets: &[O], idx: usize) -> Result<usize> {
+    Ok(usize::try_from(value(offsets, idx)?.into())?)
+}
+
+fn bytes<'a, O: Copy + Into<i64>>(view: &BytesView<'a, O>, idx: usize) -> Result<&'a [u8]> {
+    let start = offset(view.offsets, idx)?;
+    let end = offset(view.offsets, idx + 1)?;
+    if end < start {
+        fail!(ErrorKind::Unsupported, "Offsets must not decrease");
+    }
+    slice(view.data, start, end - start)
+}
+
+fn bytes_view<'a>(view: &BytesViewView<'a>, idx: usize) -> Result<&'a [u8]> {
+    let Some(item) = view.data.get(idx) else {
+        fail!(ErrorKind::Unsupported, "Index {idx} is out of bounds");
+    };
+    let len = *item as u32 as usize;
+    if len <= 12 {
+        return slice(bytemuck::bytes_of(item), 4, len);
+    }
+    let buffer = (*item >> 64) as u32 as usize;
+    let offset = (*item >> 96) as u32 as usize;
+    let Some(buffer) = view.buffers.get(buffer) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Invalid byte view: buffer {buffer} does not exist"
+        );
+    };
+    slice(buffer, offset, len)
+}
+
+fn str(bytes: &[u8]) -> Result<&str> {
+    Ok(std::str::from_utf8(bytes)?)
+}
+
+fn dictionary_key(keys: &View<'_>, idx: usize) -> Result<usize> {
+    let key = match keys {
+        View::Int8(view) => i64::from(value(view.values, idx)?),
+        View::Int16(view) => i64::from(value(view.values, idx)?),
+        View::Int32(view) => i64::from(value(view.values, idx)?),
+        View::Int64(view) => value(view.values, idx)?,
+        View::UInt8(view) => i64::from(value(view.values, idx)?),
+        View::UInt16(view) => i64::from(value(view.values, idx)?),
+        View::UInt32(view) => i64::from(value(view.values, idx)?),
+        View::UInt64(view) => i64::try_from(value(view.values, idx)?)?,
+        _ => fail!(ErrorKind::Unsupported, "Dictionary keys must be integers"),
+    };
+    Ok(usize::try_from(key)?)
+}
+
+/// The index of the run containing the element `idx`
+fn run_index(run_ends: &View<'_>, idx: usize) -> Result<usize> {
+    fn find<T: Copy + Into<i64>>(run_ends: &[T], idx: usize) -> Result<usize> {
+        let idx = i64::try_from(idx)?;
+        let run = run_ends.partition_point(|&end| end.into() <= idx);
+        if run >= run_ends.len() {
+            fail!(
+                ErrorKind::Unsupported,
+                "Index {idx} is out of bounds of the run end encoded view"
+            );
+        }
+        Ok(run)
+    }
+
+    match run_ends {
+        View::Int16(view) => find(view.values, idx),
+        View::Int32(view) => find(view.values, idx),
+        View::Int64(view) => find(view.values, idx),
+        _ => fail!(
+            ErrorKind::Unsupported,
+            "Run ends must be Int16, Int32 or Int64"
+        ),
+    }
+}
+
+/// The field, the child view and the index into the child of a union element
+fn union_variant<'v, 'a>(
+    view: &'v View<'a>,
+    idx: usize,
+) -> Result<(&'v FieldMeta, &'v View<'a>, usize)> {
+    let View::Union(view) = view else {
+        fail!(ErrorKind::Unsupported, "Expected a union view");
+    };
+    let type_id = value(view.types, idx)?;
+    let child_idx = match view.offsets {
+        Some(offsets) => usize::try_from(value(offsets, idx)?)?,
+        None => idx,
+    };
+    let Some((_, meta, child)) = view.fields.iter().find(|(id, _, _)| *id == type_id) else {
+        fail!(ErrorKind::Unsupported, "Unknown union type id {type_id}");
+    };
+    Ok((meta, child, child_idx))
+}
@@ -0,0 +1,1079 @@
+//! Serialize rows into arrays
+use std::collections::HashMap;
+
+use half::f16;
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+use crate::{
+    array::{
+        Array, BooleanArray, BytesArray, DecimalArray, DictionaryArray, FixedSizeBinaryArray,
+        FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray, StructArray, TimeArray,
+        TimestampArray, UnionArray,
+    },
+    bits::Bitmap,
+    datatypes::{meta_from_field, DataType, Field, FieldMeta, IntervalUnit, TimeUnit, UnionMode},
+    decimal::parse_decimal,
+    error::{fail, ErrorKind, MarrowError, Result},
+    interval::parse_year_month_interval,
+    temporal::{parse_date, parse_timestamp},
+    types::{DayTimeInterval, MonthDayNanoInterval, Rounding},
+};
+
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// Collect the values of a single field
+pub(super) struct Builder {
+    name: String,
+    data_type: DataType,
+    nullable: bool,
+    /// The validity of all values pushed so far, its length is the length of the array
+    validity: Bitmap,
+    values: Values,
+}
+
+enum Values {
+    Null,
+    Boolean(Bitmap),
+    Int8(Vec<i8>),
+    Int16(Vec<i16>),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    UInt8(Vec<u8>),
+    UInt16(Vec<u16>),
+    UInt32(Vec<u32>),
+    UInt64(Vec<u64>),
+    Float16(Vec<f16>),
+    Float32(Vec<f32>),
+    Float64(Vec<f64>),
+    Date32(Vec<i32>),
+    Date64(Vec<i64>),
+    Time32(TimeUnit, Vec<i32>),
+    Time64(TimeUnit, Vec<i64>),
+    Timestamp(TimeUnit, Option<String>, Vec<i64>),
+    Duration(TimeUnit, Vec<i64>),
+    YearMonthInterval(Vec<i32>),
+    DayTimeInterval(Vec<DayTimeInterval>),
+    MonthDayNanoInterval(Vec<MonthDayNanoInterval>),
+    Decimal128(u8, i8, Vec<i128>),
+    /// `Utf8`, `LargeUtf8`, `Binary` and `LargeBinary`, the offsets are narrowed when finishing
+    Bytes(Vec<i64>, Vec<u8>),
+    FixedSizeBinary(i32, Vec<u8>),
+    Struct(Vec<(FieldMeta, Builder)>),
+    List(Vec<i32>, FieldMeta, Box<Builder>),
+    LargeList(Vec<i64>, FieldMeta, Box<Builder>),
+    FixedSizeList(i32, FieldMeta, Box<Builder>),
+    /// The offsets, the name of the entries, whether the keys are sorted and the entries
+    Map(Vec<i32>, String, bool, Box<Builder>),
+    /// The key type, the keys, the index of the values and the values
+    Dictionary(DataType, Vec<i64>, HashMap<String, i64>, Box<Builder>),
+    Union(Vec<i8>, Option<Vec<i32>>, Vec<(i8, FieldMeta, Builder)>),
+}
+
+impl Builder {
+    pub fn new(name: &str, data_type: &DataType, nullable: bool) -> Result<Self> {
+        use {DataType as T, Values as V};
+
+        let child =
+            |field: &Field| -> Result<Box<Builder>> { Ok(Box::new(Builder::for_field(field)?)) };
+
+        let values = match data_type {
+            T::Null => V::Null,
+            T::Boolean => V::Boolean(Bitmap::new()),
+            T::Int8 => V::Int8(Vec::new()),
+            T::Int16 => V::Int16(Vec::new()),
+            T::Int32 => V::Int32(Vec::new()),
+            T::Int64 => V::Int64(Vec::new()),
+            T::UInt8 => V::UInt8(Vec::new()),
+            T::UInt16 => V::UInt16(Vec::new()),
+            T::UInt32 => V::UInt32(Vec::new()),
+            T::UInt64 => V::UInt64(Vec::new()),
+            T::Float16 => V::Float16(Vec::new()),
+            T::Float32 => V::Float32(Vec::new()),
+            T::Float64 => V::Float64(Vec::new()),
+            T::Date32 => V::Date32(Vec::new()),
+            T::Date64 => V::Date64(Vec::new()),
+            T::Time32(unit) => V::Time32(*unit, Vec::new()),
+            T::Time64(unit) => V::Time64(*unit, Vec::new()),
+            T::Timestamp(unit, timezone) => V::Timestamp(*unit, timezone.clone(), Vec::new()),
+            T::Duration(unit) => V::Duration(*unit, Vec::new()),
+            T::Interval(IntervalUnit::YearMonth) => V::YearMonthInterval(Vec::new()),
+            T::Interval(IntervalUnit::DayTime) => V::DayTimeInterval(Vec::new()),
+            T::Interval(IntervalUnit::MonthDayNano) => V::MonthDayNanoInterval(Vec::new()),
+            T::Decimal128(precision, scale) => V::Decimal128(*precision, *scale, Vec::new()),
+            T::Utf8 | T::LargeUtf8 | T::Binary | T::LargeBinary => V::Bytes(vec![0], Vec::new()),
+            T::FixedSizeBinary(n) => V::FixedSizeBinary(*n, Vec::new()),
+            T::Struct(fields) => {
+                let mut children = Vec::with_capacity(fields.len());
+                for field in fields {
+                    children.push((meta_from_field(field.clone()), Builder::for_field(field)?));
+                }
+                V::Struct(children)
+            }
+            T::List(item) => V::List(
+                vec![0],
+                meta_from_field(item.as_ref().clone()),
+                child(item)?,
+            ),
+            T::LargeList(item) => V::LargeList(
+                vec![0],
+                meta_from_field(item.as_ref().clone()),
+                child(item)?,
+            ),
+            T::FixedSizeList(item, n) => {
+                V::FixedSizeList(*n, meta_from_field(item.as_ref().clone()), child(item)?)
+            }
+            T::Map(entries, sorted) => {
+                if !matches!(&entries.data_type, T::Struct(fields) if fields.len() == 2) {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "Map fields require struct entries with two fields"
+                    );
+                }
+                V::Map(vec![0], entries.name.clone(), *sorted, child(entries)?)
+            }
+            T::Dictionary(keys, values) => {
+                if !keys.is_integer() {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "Dictionary fields require integer keys"
+                    );
+                }
+                V::Dictionary(
+                    keys.as_ref().clone(),
+                    Vec::new(),
+                    HashMap::new(),
+                    Box::new(Builder::new("values", values, false)?),
+                )
+            }
+            T::Union(fields, mode) => {
+                let mut children = Vec::with_capacity(fields.len());
+                for (type_id, field) in fields {
+                    children.push((
+                        *type_id,
+                        meta_from_field(field.clone()),
+                        Builder::for_field(field)?,
+                    ));
+                }
+                let offsets = match mode {
+                    UnionMode::Dense => Some(Vec::new()),
+                    UnionMode::Sparse => None,
+                };
+                V::Union(Vec::new(), offsets, children)
+            }
+            T::Utf8View | T::BinaryView | T::RunEndEncoded(_, _) => fail!(
+                ErrorKind::Unsupported,
+                "Serializing rows into {data_type} fields is not supported"
+            ),
+        };
+        Ok(Self {
+            name: name.to_owned(),
+            data_type: data_type.clone(),
+            nullable: nullable || matches!(data_type, T::Null),
+            validity: Bitmap::new(),
+            values,
+        })
+    }
+
+    fn for_field(field: &Field) -> Result<Self> {
+        Self::new(&field.name, &field.data_type, field.nullable)
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// Push a placeholder value that is marked as missing, even for non-nullable fields
+    ///
+    /// Used for the children of missing structs, lists and unused union children.
+    fn push_default(&mut self) -> Result<()> {
+        use Values as V;
+        self.validity.push(false);
+        match &mut self.values {
+            V::Null => {}
+            V::Boolean(values) => values.push(false),
+            V::Int8(values) => values.push(0),
+            V::Int16(values) => values.push(0),
+            V::Int32(values) | V::Date32(values) | V::Time32(_, values) => values.push(0),
+            V::YearMonthInterval(values) => values.push(0),
+            V::Int64(values) | V::Date64(values) | V::Time64(_, values) => values.push(0),
+            V::Timestamp(_, _, values) | V::Duration(_, values) => values.push(0),
+            V::UInt8(values) => values.push(0),
+            V::UInt16(values) => values.push(0),
+            V::UInt32(values) => values.push(0),
+            V::UInt64(values) => values.push(0),
+            V::Float16(values) => values.push(f16::ZERO),
+            V::Float32(values) => values.push(0.0),
+            V::Float64(values) => values.push(0.0),
+            V::DayTimeInterval(values) => values.push(DayTimeInterval::default()),
+            V::MonthDayNanoInterval(values) => values.push(MonthDayNanoInterval::default()),
+            V::Decimal128(_, _, values) => values.push(0),
+            V::Bytes(offsets, data) => offsets.push(i64::try_from(data.len())?),
+            V::FixedSizeBinary(n, data) => data.resize(data.len() + usize::try_from(*n)?, 0),
+            V::Struct(fields) => {
+                for (_, child) in fields {
+                    child.push_default()?;
+                }
+            }
+            V::List(offsets, _, child) => offsets.push(i32::try_from(child.len())?),
+            V::LargeList(offsets, _, child) => offsets.push(i64::try_from(child.len())?),
+            V::FixedSizeList(n, _, child) => {
+                for _ in 0..*n {
+                    child.push_default()?;
+                }
+            }
+            V::Map(offsets, _, _, entries) => offsets.push(i32::try_from(entries.len())?),
+            V::Dictionary(_, keys, _, _) => keys.push(0),
+            V::Union(types, offsets, fields) => {
+                let Some((type_id, _, _)) = fields.first() else {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "Cannot push into a union without fields"
+                    );
+                };
+                types.push(*type_id);
+                match offsets {
+                    Some(offsets) => {
+                        offsets.push(i32::try_from(fields[0].2.len())?);
+                        fields[0].2.push_default()?;
+                    }
+                    None => {
+                        for (_, _, child) in fields {
+                            child.push_default()?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_null(&mut self) -> Result<()> {
+        if !self.nullable {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot push a missing value into the non-nullable field {name:?}",
+                name = self.name,
+            );
+        }
+        self.push_default()
+    }
+
+    fn push_bool(&mut self, value: bool) -> Result<()> {
+        match &mut self.values {
+            Values::Boolean(values) => values.push(value),
+            _ => return self.unsupported("booleans"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn push_i64(&mut self, value: i64) -> Result<()> {
+        use Values as V;
+        match &mut self.values {
+            V::Int8(values) => values.push(i8::try_from(value)?),
+            V::Int16(values) => values.push(i16::try_from(value)?),
+            V::Int32(values) | V::Date32(values) | V::Time32(_, values) => {
+                values.push(i32::try_from(value)?)
+            }
+            V::YearMonthInterval(values) => values.push(i32::try_from(value)?),
+            V::Int64(values) | V::Date64(values) | V::Time64(_, values) => values.push(value),
+            V::Timestamp(_, _, values) | V::Duration(_, values) => values.push(value),
+            V::UInt8(values) => values.push(u8::try_from(value)?),
+            V::UInt16(values) => values.push(u16::try_from(value)?),
+            V::UInt32(values) => values.push(u32::try_from(value)?),
+            V::UInt64(values) => values.push(u64::try_from(value)?),
+            V::Float16(values) => values.push(f16::from_f64(value as f64)),
+            V::Float32(values) => values.push(value as f32),
+            V::Float64(values) => values.push(value as f64),
+            V::Decimal128(precision, scale, values) => values.push(parse_decimal(
+                &value.to_string(),
+                *precision,
+                *scale,
+                Rounding::Exact,
+            )?),
+            _ => return self.unsupported("integers"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn push_u64(&mut self, value: u64) -> Result<()> {
+        if let Ok(value) = i64::try_from(value) {
+            return self.push_i64(value);
+        }
+        match &mut self.values {
+            Values::UInt64(values) => values.push(value),
+            Values::Float32(values) => values.push(value as f32),
+            Values::Float64(values) => values.push(value as f64),
+            Values::Decimal128(precision, scale, values) => values.push(parse_decimal(
+                &value.to_string(),
+                *precision,
+                *scale,
+                Rounding::Exact,
+            )?),
+            _ => fail!(
+                ErrorKind::Unsupported,
+                "Cannot serialize {value} into the field {name:?} with data type {data_type}",
+                name = self.name,
+                data_type = self.data_type,
+            ),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn push_f64(&mut self, value: f64) -> Result<()> {
+        match &mut self.values {
+            Values::Float16(values) => values.push(f16::from_f64(value)),
+            Values::Float32(values) => values.push(value as f32),
+            Values::Float64(values) => values.push(value),
+            Values::Decimal128(precision, scale, values) => values.push(parse_decimal(
+                &value.to_string(),
+                *precision,
+                *scale,
+                Rounding::HalfEven,
+            )?),
+            _ => return self.unsupported("floats"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn push_str(&mut self, value: &str) -> Result<()> {
+        use {DataType as T, Values as V};
+        match (&self.data_type, &mut self.values) {
+            (T::Utf8 | T::LargeUtf8 | T::Binary | T::LargeBinary, V::Bytes(offsets, data)) => {
+                data.extend_from_slice(value.as_bytes());
+                offsets.push(i64::try_from(data.len())?);
+            }
+            (_, V::Dictionary(_, keys, index, values)) => {
+                let key = match index.get(value) {
+                    Some(key) => *key,
+                    None => {
+                        let key = i64::try_from(index.len())?;
+                        values.push_str(value)?;
+                        index.insert(value.to_owned(), key);
+                        key
+                    }
+                };
+                keys.push(key);
+            }
+            (_, V::Date32(values)) => values.push(i32::try_from(parse_date(value)?)?),
+            (_, V::Date64(values)) => values.push(parse_date(value)? * MILLISECONDS_PER_DAY),
+            (_, V::Timestamp(unit, _, values)) => values.push(parse_timestamp(value, *unit)?),
+            (_, V::YearMonthInterval(values)) => values.push(parse_year_month_interval(value)?),
+            (_, V::DayTimeInterval(values)) => values.push(value.parse()?),
+            (_, V::MonthDayNanoInterval(values)) => values.push(value.parse()?),
+            (_, V::Decimal128(precision, scale, values)) => {
+                values.push(parse_decimal(value, *precision, *scale, Rounding::Exact)?)
+            }
+            _ => return self.unsupported("strings"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn push_bytes(&mut self, value: &[u8]) -> Result<()> {
+        use {DataType as T, Values as V};
+        match (&self.data_type, &mut self.values) {
+            (T::Utf8 | T::LargeUtf8, V::Bytes(_, _)) => {
+                return self.push_str(std::str::from_utf8(value)?);
+            }
+            (_, V::Bytes(offsets, data)) => {
+                data.extend_from_slice(value);
+                offsets.push(i64::try_from(data.len())?);
+            }
+            (_, V::FixedSizeBinary(n, data)) => {
+                if value.len() != usize::try_from(*n)? {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "Cannot serialize {len} bytes into the field {name:?} with data type {data_type}",
+                        len = value.len(),
+                        name = self.name,
+                        data_type = self.data_type,
+                    );
+                }
+                data.extend_from_slice(value);
+            }
+            _ => return self.unsupported("bytes"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn struct_fields(&mut self) -> Result<&mut Vec<(FieldMeta, Builder)>> {
+        match &mut self.values {
+            Values::Struct(fields) => Ok(fields),
+            _ => unsupported(&self.name, &self.data_type, "structs"),
+        }
+    }
+
+    /// The builder of the elements of lists
+    fn elements(&mut self) -> Result<&mut Builder> {
+        match &mut self.values {
+            Values::List(_, _, child)
+            | Values::LargeList(_, _, child)
+            | Values::FixedSizeList(_, _, child) => Ok(child),
+            _ => unsupported(&self.name, &self.data_type, "sequences"),
+        }
+    }
+
+    /// The builders of the keys and values of maps
+    fn entries(&mut self) -> Result<&mut Builder> {
+        match &mut self.values {
+            Values::Map(_, _, _, entries) => Ok(entries),
+            _ => unsupported(&self.name, &self.data_type, "maps"),
+        }
+    }
+
+    /// Mark the end of a list or map after its elements have been pushed
+    fn end_nested(&mut self) -> Result<()> {
+        match &mut self.values {
+            Values::List(offsets, _, child) => offsets.push(i32::try_from(child.len())?),
+            Values::LargeList(offsets, _, child) => offsets.push(i64::try_from(child.len())?),
+            Values::FixedSizeList(n, _, child) => {
+                let expected = (self.validity.len() + 1) * usize::try_from(*n)?;
+                if child.len() != expected {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "The field {name:?} requires lists with {n} elements",
+                        name = self.name,
+                    );
+                }
+            }
+            Values::Map(offsets, _, _, entries) => offsets.push(i32::try_from(entries.len())?),
+            _ => return self.unsupported("sequences"),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    /// Select the union field with the given name and return its builder
+    fn variant(&mut self, variant: &str) -> Result<&mut Builder> {
+        let Values::Union(types, offsets, fields) = &mut self.values else {
+            return unsupported(&self.name, &self.data_type, "enum variants with data");
+        };
+        let Some(pos) = fields.iter().position(|(_, meta, _)| meta.name == variant) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "The union field {name:?} has no field for the variant {variant:?}",
+                name = self.name,
+            );
+        };
+        types.push(fields[pos].0);
+        match offsets {
+            Some(offsets) => offsets.push(i32::try_from(fields[pos].2.len())?),
+            None => {
+                for (idx, (_, _, child)) in fields.iter_mut().enumerate() {
+                    if idx != pos {
+                        child.push_default()?;
+                    }
+                }
+            }
+        }
+        self.validity.push(true);
+        Ok(&mut fields[pos].2)
+    }
+
+    fn unsupported<T>(&self, what: &str) -> Result<T> {
+        unsupported(&self.name, &self.data_type, what)
+    }
+
+    pub fn finish(self) -> Result<Array> {
+        use {Array as A, DataType as T, Values as V};
+
+        let len = self.validity.len();
+        let validity = if self.nullable {
+            Some(self.validity.into_vec())
+        } else {
+            None
+        };
+        let narrow = |offsets: Vec<i64>| -> Result<Vec<i32>> {
+            Ok(offsets
+                .into_iter()
+                .map(i32::try_from)
+                .collect::<Result<_, _>>()?)
+        };
+        let array = match self.values {
+            V::Null => A::Null(NullArray { len }),
+            V::Boolean(values) => A::Boolean(BooleanArray {
+                len,
+                validity,
+                values: values.into_vec(),
+            }),
+            V::Int8(values) => A::Int8(PrimitiveArray { validity, values }),
+            V::Int16(values) => A::Int16(PrimitiveArray { validity, values }),
+            V::Int32(values) => A::Int32(PrimitiveArray { validity, values }),
+            V::Int64(values) => A::Int64(PrimitiveArray { validity, values }),
+            V::UInt8(values) => A::UInt8(PrimitiveArray { validity, values }),
+            V::UInt16(values) => A::UInt16(PrimitiveArray { validity, values }),
+            V::UInt32(values) => A::UInt32(PrimitiveArray { validity, values }),
+            V::UInt64(values) => A::UInt64(PrimitiveArray { validity, values }),
+            V::Float16(values) => A::Float16(PrimitiveArray { validity, values }),
+            V::Float32(values) => A::Float32(PrimitiveArray { validity, values }),
+            V::Float64(values) => A::Float64(PrimitiveArray { validity, values }),
+            V::Date32(values) => A::Date32(PrimitiveArray { validity, values }),
+            V::Date64(values) => A::Date64(PrimitiveArray { validity, values }),
+            V::Time32(unit, values) => A::Time32(TimeArray {
+                unit,
+                validity,
+                values,
+            }),
+            V::Time64(unit, values) => A::Time64(TimeArray {
+                unit,
+                validity,
+                values,
+            }),
+            V::Timestamp(unit, timezone, values) => A::Timestamp(TimestampArray {
+                unit,
+                timezone,
+                validity,
+                values,
+            }),
+            V::Duration(unit, values) => A::Duration(TimeArray {
+                unit,
+                validity,
+                values,
+            }),
+            V::YearMonthInterval(values) => {
+                A::YearMonthInterval(PrimitiveArray { validity, values })
+            }
+            V::DayTimeInterval(values) => A::DayTimeInterval(PrimitiveArray { validity, values }),
+            V::MonthDayNanoInterval(values) => {
+                A::MonthDayNanoInterval(PrimitiveArray { validity, values })
+            }
+            V::Decimal128(precision, scale, values) => A::Decimal128(DecimalArray {
+                precision,
+                scale,
+                validity,
+                values,
+            }),
+            V::Bytes(offsets, data) => match self.data_type {
+                T::Utf8 => A::Utf8(BytesArray {
+                    validity,
+                    offsets: narrow(offsets)?,
+                    data,
+                }),
+                T::Binary => A::Binary(BytesArray {
+                    validity,
+                    offsets: narrow(offsets)?,
+                    data,
+                }),
+                T::LargeUtf8 => A::LargeUtf8(BytesArray {
+                    validity,
+                    offsets,
+                    data,
+                }),
+                _ => A::LargeBinary(BytesArray {
+                    validity,
+                    offsets,
+                    data,
+                }),
+            },
+            V::FixedSizeBinary(n, data) => {
+                A::FixedSizeBinary(FixedSizeBinaryArray { n, validity, data })
+            }
+            V::Struct(fields) => {
+                let mut arrays = Vec::with_capacity(fields.len());
+                for (meta, child) in fields {
+                    arrays.push((meta, child.finish()?));
+                }
+                A::Struct(StructArray {
+                    len,
+                    validity,
+                    fields: arrays,
+                })
+            }
+            V::List(offsets, meta, child) => A::List(ListArray {
+                validity,
+                offsets,
+                meta,
+                elements: Box::new(child.finish()?),
+            }),
+            V::LargeList(offsets, meta, child) => A::LargeList(ListArray {
+                validity,
+                offsets,
+                meta,
+                elements: Box::new(child.finish()?),
+            }),
+            V::FixedSizeList(n, meta, child) => A::FixedSizeList(FixedSizeListArray {
+                len,
+                n,
+                validity,
+                meta,
+                elements: Box::new(child.finish()?),
+            }),
+            V::Map(offsets, entries_name, sorted, entries) => A::Map(MapArray::from_logical_array(
+                entries.finish()?,
+                entries_name,
+                sorted,
+                validity,
+                offsets,
+            )?),
+            V::Dictionary(key_type, keys, _, values) => A::Dictionary(DictionaryArray {
+                keys: Box::new(dictionary_keys(&key_type, validity, keys)?),
+                values: Box::new(values.finish()?),
+            }),
+            V::Union(types, offsets, fields) => {
+                let mut arrays = Vec::with_capacity(fields.len());
+                for (type_id, meta, child) in fields {
+                    arrays.push((type_id, meta, child.finish()?));
+                }
+                A::Union(UnionArray {
+                    types,
+                    offsets,
+                    fields: arrays,
+                })
+            }
+        };
+        Ok(array)
+    }
+}
+
+fn unsupported<T>(name: &str, data_type: &DataType, what: &str) -> Result<T> {
+    fail!(
+        ErrorKind::Unsupported,
+        "Cannot serialize {what} into the field {name:?} with data type {data_type}",
+    );
+}
+
+fn dictionary_keys(
+    key_type: &DataType,
+    validity: Option<Vec<u8>>,
+    keys: Vec<i64>,
+) -> Result<Array> {
+    macro_rules! keys {
+        ($variant:ident, $ty:ty) => {
+            Array::$variant(PrimitiveArray {
+                validity,
+                values: keys
+                    .into_iter()
+                    .map(<$ty>::try_from)
+                    .collect::<Result<_, _>>()?,
+            })
+        };
+    }
+    Ok(match key_type {
+        DataType::Int8 => keys!(Int8, i8),
+        DataType::Int16 => keys!(Int16, i16),
+        DataType::Int32 => keys!(Int32, i32),
+        DataType::Int64 => Array::Int64(PrimitiveArray {
+            validity,
+            values: keys,
+        }),
+        DataType::UInt8 => keys!(UInt8, u8),
+        DataType::UInt16 => keys!(UInt16, u16),
+        DataType::UInt32 => keys!(UInt32, u32),
+        DataType::UInt64 => keys!(UInt64, u64),
+        _ => fail!(
+            ErrorKind::Unsupported,
+            "Dictionary fields require integer keys"
+        ),
+    })
+}
+
+/// Serialize a map key, that names a struct field, into a string
+fn field_name<K: ?Sized + Serialize>(key: &K) -> Result<String> {
+    let mut builder = Builder::new("key", &DataType::Utf8, false)?;
+    key.serialize(&mut builder)?;
+    let Values::Bytes(_, data) = builder.values else {
+        unreachable!();
+    };
+    Ok(String::from_utf8(data).map_err(|err| err.utf8_error())?)
+}
+
+impl<'b> Serializer for &'b mut Builder {
+    type Ok = ();
+    type Error = MarrowError;
+
+    type SerializeSeq = Sequence<'b>;
+    type SerializeTuple = Sequence<'b>;
+    type SerializeTupleStruct = Sequence<'b>;
+    type SerializeTupleVariant = Sequence<'b>;
+    type SerializeMap = Mapping<'b>;
+    type SerializeStruct = Struct<'b>;
+    type SerializeStructVariant = Struct<'b>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.push_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.push_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.push_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.push_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.push_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        match &mut self.values {
+            Values::Decimal128(precision, scale, values) => {
+                values.push(parse_decimal(
+                    &v.to_string(),
+                    *precision,
+                    *scale,
+                    Rounding::Exact,
+                )?);
+                self.validity.push(true);
+                Ok(())
+            }
+            _ => self.push_i64(i64::try_from(v)?),
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.push_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.push_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.push_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.push_u64(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.push_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.push_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.push_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.push_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.push_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.push_null()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.push_null()
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        self.push_null()
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<()> {
+        if matches!(self.values, Values::Union(_, _, _)) {
+            self.variant(variant)?.push_null()
+        } else {
+            self.push_str(variant)
+        }
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self.variant(variant)?)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Sequence<'b>> {
+        match &self.values {
+            Values::List(_, _, _) | Values::LargeList(_, _, _) | Values::FixedSizeList(_, _, _) => {
+                Ok(Sequence::List(self))
+            }
+            Values::Bytes(_, _) | Values::FixedSizeBinary(_, _) => {
+                let bytes = Box::new(Builder::new(&self.name, &DataType::UInt8, false)?);
+                Ok(Sequence::Bytes(self, bytes))
+            }
+            Values::Struct(_) => Ok(Sequence::Tuple(self, 0)),
+            _ => self.unsupported("sequences"),
+        }
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Sequence<'b>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<Sequence<'b>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Sequence<'b>> {
+        self.variant(variant)?.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Mapping<'b>> {
+        match &self.values {
+            Values::Map(_, _, _, _) => Ok(Mapping::Map(self)),
+            Values::Struct(fields) => {
+                let seen = vec![false; fields.len()];
+                Ok(Mapping::Struct(Struct {
+                    builder: self,
+                    seen,
+                    next: None,
+                }))
+            }
+            _ => self.unsupported("maps"),
+        }
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Struct<'b>> {
+        let seen = vec![false; self.struct_fields()?.len()];
+        Ok(Struct {
+            builder: self,
+            seen,
+            next: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Struct<'b>> {
+        self.variant(variant)?.serialize_struct(name, len)
+    }
+}
+
+pub(super) enum Sequence<'b> {
+    /// The elements of a list
+    List(&'b mut Builder),
+    /// A sequence of bytes, collected in a `UInt8` builder
+    Bytes(&'b mut Builder, Box<Builder>),
+    /// The fields of a struct by position
+    Tuple(&'b mut Builder, usize),
+}
+
+impl Sequence<'_> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        match self {
+            Self::List(builder) => value.serialize(builder.elements()?),
+            Self::Bytes(_, bytes) => value.serialize(bytes.as_mut()),
+            Self::Tuple(builder, next) => {
+                let len = builder.struct_fields()?.len();
+                if *next >= len {
+                    fail!(
+                        ErrorKind::Unsupported,
+                        "The struct field {name:?} has only {len} fields",
+                        name = builder.name,
+                    );
+                }
+                let child = &mut builder.struct_fields()?[*next].1;
+                *next += 1;
+                value.serialize(child)
+            }
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            Self::List(builder) => builder.end_nested(),
+            Self::Bytes(builder, bytes) => {
+                let Values::UInt8(bytes) = bytes.values else {
+                    unreachable!();
+                };
+                builder.push_bytes(&bytes)
+            }
+            Self::Tuple(builder, next) => {
+                for (_, child) in &mut builder.struct_fields()?[next..] {
+                    child.push_null()?;
+                }
+                builder.validity.push(true);
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_sequence {
+    ($($trait:ident::$method:ident),* $(,)?) => {
+        $(
+            impl $trait for Sequence<'_> {
+                type Ok = ();
+                type Error = MarrowError;
+
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+                    self.element(value)
+                }
+
+                fn end(self) -> Result<()> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_sequence!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+);
+
+/// The fields of a struct, unknown fields are ignored
+pub(super) struct Struct<'b> {
+    builder: &'b mut Builder,
+    seen: Vec<bool>,
+    /// The position of the field of the last map key, if it exists
+    next: Option<usize>,
+}
+
+impl Struct<'_> {
+    fn select(&mut self, name: &str) -> Result<Option<usize>> {
+        let fields = self.builder.struct_fields()?;
+        let Some(pos) = fields.iter().position(|(meta, _)| meta.name == name) else {
+            return Ok(None);
+        };
+        if self.seen[pos] {
+            fail!(
+                ErrorKind::Unsupported,
+                "Duplicate field {name:?} in the struct field {parent:?}",
+                parent = self.builder.name,
+            );
+        }
+        self.seen[pos] = true;
+        Ok(Some(pos))
+    }
+
+    fn field<T: ?Sized + Serialize>(&mut self, pos: Option<usize>, value: &T) -> Result<()> {
+        match pos {
+            Some(pos) => value.serialize(&mut self.builder.struct_fields()?[pos].1),
+            None => Ok(()),
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        let fields = self.builder.struct_fields()?;
+        for ((_, child), seen) in fields.iter_mut().zip(self.seen) {
+            if !seen {
+                child.push_null()?;
+            }
+        }
+        self.builder.validity.push(true);
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Struct<'_> {
+    type Ok = ();
+    type Error = MarrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let pos = self.select(key)?;
+        self.field(pos, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for Struct<'_> {
+    type Ok = ();
+    type Error = MarrowError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let pos = self.select(key)?;
+        self.field(pos, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+pub(super) enum Mapping<'b> {
+    /// The entries of a map field
+    Map(&'b mut Builder),
+    /// The fields of a struct, given as a map with string keys
+    Struct(Struct<'b>),
+}
+
+impl SerializeMap for Mapping<'_> {
+    type Ok = ();
+    type Error = MarrowError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        match self {
+            Self::Map(builder) => key.serialize(&mut builder.entries()?.struct_fields()?[0].1),
+            Self::Struct(fields) => {
+                fields.next = fields.select(&field_name(key)?)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        match self {
+            Self::Map(builder) => {
+                let entries = builder.entries()?;
+                value.serialize(&mut entries.struct_fields()?[1].1)?;
+                entries.validity.push(true);
+                Ok(())
+            }
+            Self::Struct(fields) => {
+                let pos = fields.next.take();
+                fields.field(pos, value)
+            }
+        }
+    }
+
+    fn end(self) -> Result<()> {
+        match self {
+            Self::Map(builder) => builder.end_nested(),
+            Self::Struct(fields) => fields.finish(),
+        }
+    }
+}
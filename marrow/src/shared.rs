@@ -0,0 +1,416 @@
+//! Reference counted arrays that can be cloned and sliced cheaply
+//!
+//! [`Array`] owns its buffers as `Vec`s, so cloning an array copies all of its data.
+//! [`SharedArray`] wraps an array in an [`Arc`]: clones share the same buffers and slices only
+//! adjust the referenced range. To modify the data, a shared array is converted back into an
+//! [`Array`] in a copy-on-write fashion: the buffers are moved if the array is neither shared nor
+//! sliced and copied otherwise.
+//!
+//! ```rust
+//! # fn main() -> marrow::error::Result<()> {
+//! use marrow::{array::{Array, PrimitiveArray}, shared::SharedArray};
+//!
+//! let array = SharedArray::try_from(Array::Int32(PrimitiveArray {
+//!     validity: None,
+//!     values: vec![1, 2, 3, 4],
+//! }))?;
+//!
+//! // cheap copies, e.g., to hand them to different threads
+//! let sliced = array.slice(1, 2)?;
+//! let copy = array.clone();
+//!
+//! assert_eq!(
+//!     Array::try_from(sliced)?,
+//!     Array::Int32(PrimitiveArray {
+//!         validity: None,
+//!         values: vec![2, 3],
+//!     }),
+//! );
+//!
+//! // the buffers are moved, as `copy` is the only remaining reference
+//! drop(array);
+//! let Array::Int32(array) = Array::try_from(copy)? else { panic!() };
+//! assert_eq!(array.values, vec![1, 2, 3, 4]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Shared arrays can be converted into `arrow` and `arrow2` arrays via `TryFrom` without copying.
+//! The buffers of arrays that are neither shared nor sliced are moved. The buffers of all other
+//! arrays reference the shared data, which is kept alive until the last referencing buffer is
+//! dropped. See [`bridge`][crate::bridge] for the few data types, whose layouts differ and which
+//! are copied.
+//!
+//! Slices of run end encoded arrays, also when nested in other arrays, are rejected as their views
+//! cannot express an offset.
+use std::sync::Arc;
+
+#[cfg(any(
+    // arrow-version:insert:     feature = "arrow-{version}",
+    feature = "arrow-58",
+    feature = "arrow-57",
+    feature = "arrow-56",
+    feature = "arrow-55",
+    feature = "arrow-54",
+    feature = "arrow-53",
+    feature = "arrow-52",
+    feature = "arrow-51",
+    feature = "arrow-50",
+    feature = "arrow-49",
+    feature = "arrow-48",
+    feature = "arrow-47",
+    feature = "arrow-46",
+    feature = "arrow-45",
+    feature = "arrow-44",
+    feature = "arrow-43",
+    feature = "arrow-42",
+    feature = "arrow-41",
+    feature = "arrow-40",
+    feature = "arrow-39",
+    feature = "arrow-38",
+    feature = "arrow-37",
+    feature = "arrow2-0-17",
+    feature = "arrow2-0-16",
+))]
+use crate::interop::{ForeignView, Owner};
+use crate::{
+    array::{view_len, Array},
+    datatypes::DataType,
+    error::{fail, ErrorKind, MarrowError, Result},
+    view::{slice_view, View},
+};
+
+// assert that the `SharedArray` implements the expected traits
+#[allow(unused)]
+const _: () = {
+    trait AssertExpectedTraits: Clone + std::fmt::Debug + Send + Sync {}
+    impl AssertExpectedTraits for SharedArray {}
+};
+
+/// A reference counted array that can be cloned and sliced without copying its data
+///
+/// See the [module docs][self] for details.
+#[derive(Clone, Debug)]
+pub struct SharedArray {
+    array: Arc<Array>,
+    offset: usize,
+    len: usize,
+}
+
+impl SharedArray {
+    /// The number of elements of this array
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the array does not contain any elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the data type of this array
+    pub fn data_type(&self) -> DataType {
+        self.array.data_type()
+    }
+
+    /// Whether other shared arrays reference the same data
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.array) > 1
+    }
+
+    /// Get the elements `offset..offset + len` of this array without copying
+    ///
+    /// Slices that cannot be viewed, e.g., of run end encoded arrays, are rejected.
+    pub fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        if offset.checked_add(len).map_or(true, |end| end > self.len) {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot slice {len} elements starting at {offset} from an array with {total} elements",
+                total = self.len,
+            );
+        }
+        let sliced = Self {
+            array: Arc::clone(&self.array),
+            offset: self.offset + offset,
+            len,
+        };
+        sliced.as_view()?;
+        Ok(sliced)
+    }
+
+    /// Get a view of the referenced elements
+    pub fn as_view(&self) -> Result<View<'_>> {
+        let view = self.array.as_view();
+        if self.offset == 0 && self.len == view_len(&view)? {
+            Ok(view)
+        } else {
+            slice_view(&view, self.offset, self.len)
+        }
+    }
+
+    fn is_sliced(&self) -> Result<bool> {
+        Ok(self.offset != 0 || self.len != view_len(&self.array.as_view())?)
+    }
+}
+
+#[cfg(any(
+    // arrow-version:insert:     feature = "arrow-{version}",
+    feature = "arrow-58",
+    feature = "arrow-57",
+    feature = "arrow-56",
+    feature = "arrow-55",
+    feature = "arrow-54",
+    feature = "arrow-53",
+    feature = "arrow-52",
+    feature = "arrow-51",
+    feature = "arrow-50",
+    feature = "arrow-49",
+    feature = "arrow-48",
+    feature = "arrow-47",
+    feature = "arrow-46",
+    feature = "arrow-45",
+    feature = "arrow-44",
+    feature = "arrow-43",
+    feature = "arrow-42",
+    feature = "arrow-41",
+    feature = "arrow-40",
+    feature = "arrow-39",
+    feature = "arrow-38",
+    feature = "arrow-37",
+    feature = "arrow2-0-17",
+    feature = "arrow2-0-16",
+))]
+impl SharedArray {
+    /// Convert into an array of another implementation without copying
+    ///
+    /// The buffers are moved, if the array is neither shared nor sliced. Otherwise, the buffers
+    /// of the result reference the shared data.
+    pub(crate) fn hand_over<T>(self) -> Result<T>
+    where
+        T: TryFrom<Array, Error = MarrowError>,
+        for<'a> T: TryFrom<ForeignView<'a>, Error = MarrowError>,
+    {
+        let array = if self.is_sliced()? {
+            self.array
+        } else {
+            match Arc::try_unwrap(self.array) {
+                Ok(array) => return T::try_from(array),
+                Err(array) => array,
+            }
+        };
+        let shared = Self { array, ..self };
+
+        let view = shared.as_view()?;
+        // SAFETY: the shared array is never modified, the owner keeps it alive
+        let view = unsafe { ForeignView::new(view, Owner::new(Arc::clone(&shared.array))) };
+        T::try_from(view)
+    }
+}
+
+/// Wrap an array without copying its data
+impl TryFrom<Array> for SharedArray {
+    type Error = MarrowError;
+
+    fn try_from(array: Array) -> Result<Self> {
+        let len = view_len(&array.as_view())?;
+        Ok(Self {
+            array: Arc::new(array),
+            offset: 0,
+            len,
+        })
+    }
+}
+
+/// Copy-on-write conversion into an owned array
+///
+/// The buffers are moved, if the array is neither shared nor sliced. Otherwise, the referenced
+/// data is copied.
+impl TryFrom<SharedArray> for Array {
+    type Error = MarrowError;
+
+    fn try_from(value: SharedArray) -> Result<Self> {
+        if value.is_sliced()? {
+            return Array::try_from(value.as_view()?);
+        }
+        match Arc::try_unwrap(value.array) {
+            Ok(array) => Ok(array),
+            Err(array) => Ok(array.as_ref().clone()),
+        }
+    }
+}
+
+#[test]
+fn slices_share_the_data() {
+    use crate::array::{BytesArray, PrimitiveArray, StructArray};
+    use crate::datatypes::FieldMeta;
+
+    let array = SharedArray::try_from(Array::Struct(StructArray {
+        len: 4,
+        validity: Some(vec![0b_1011]),
+        fields: vec![
+            (
+                FieldMeta {
+                    name: String::from("a"),
+                    ..FieldMeta::default()
+                },
+                Array::Int32(PrimitiveArray {
+                    validity: None,
+                    values: vec![1, 2, 3, 4],
+                }),
+            ),
+            (
+                FieldMeta {
+                    name: String::from("b"),
+                    nullable: true,
+                    ..FieldMeta::default()
+                },
+                Array::Utf8(BytesArray {
+                    validity: Some(vec![0b_1101]),
+                    offsets: vec![0, 1, 1, 3, 6],
+                    data: b"abbccc".to_vec(),
+                }),
+            ),
+        ],
+    }))
+    .unwrap();
+
+    let sliced = array.slice(1, 2).unwrap().slice(1, 1).unwrap();
+    assert!(array.is_shared());
+    assert_eq!(sliced.len(), 1);
+    assert_eq!(sliced.data_type(), array.data_type());
+    assert!(array.slice(3, 2).is_err());
+
+    assert_eq!(
+        Array::try_from(sliced).unwrap(),
+        Array::Struct(StructArray {
+            len: 1,
+            validity: Some(vec![0b_0]),
+            fields: vec![
+                (
+                    FieldMeta {
+                        name: String::from("a"),
+                        ..FieldMeta::default()
+                    },
+                    Array::Int32(PrimitiveArray {
+                        validity: None,
+                        values: vec![3],
+                    }),
+                ),
+                (
+                    FieldMeta {
+                        name: String::from("b"),
+                        nullable: true,
+                        ..FieldMeta::default()
+                    },
+                    Array::Utf8(BytesArray {
+                        validity: Some(vec![0b_1]),
+                        offsets: vec![0, 2],
+                        data: b"bb".to_vec(),
+                    }),
+                ),
+            ],
+        })
+    );
+}
+
+#[test]
+fn unique_arrays_are_moved() {
+    use crate::array::PrimitiveArray;
+
+    let values = vec![1_u64, 2, 3];
+    let ptr = values.as_ptr();
+
+    let array = SharedArray::try_from(Array::UInt64(PrimitiveArray {
+        validity: None,
+        values,
+    }))
+    .unwrap();
+    let copy = array.clone();
+    assert!(copy.is_shared());
+
+    let Array::UInt64(copied) = Array::try_from(copy).unwrap() else {
+        panic!();
+    };
+    assert_ne!(copied.values.as_ptr(), ptr);
+    assert!(!array.is_shared());
+
+    let Array::UInt64(moved) = Array::try_from(array).unwrap() else {
+        panic!();
+    };
+    assert_eq!(moved.values, vec![1, 2, 3]);
+    assert_eq!(moved.values.as_ptr(), ptr);
+}
+
+#[test]
+fn slices_of_run_end_encoded_arrays_are_rejected() {
+    use crate::array::{PrimitiveArray, RunEndEncodedArray};
+    use crate::datatypes::RunEndEncodedMeta;
+
+    let array = SharedArray::try_from(Array::RunEndEncoded(RunEndEncodedArray {
+        meta: RunEndEncodedMeta::default(),
+        run_ends: Box::new(Array::Int32(PrimitiveArray {
+            validity: None,
+            values: vec![2, 3],
+        })),
+        values: Box::new(Array::Int64(PrimitiveArray {
+            validity: None,
+            values: vec![1, 2],
+        })),
+    }))
+    .unwrap();
+
+    assert_eq!(array.len(), 3);
+    assert!(array.slice(0, 3).is_ok());
+    assert!(array.slice(1, 2).is_err());
+}
+
+#[cfg(feature = "arrow-58")]
+#[test]
+fn shared_arrays_are_handed_to_arrow_without_copying() {
+    use crate::array::PrimitiveArray;
+    use arrow_array_58::{Array as _, ArrayRef, Int64Array};
+
+    let values = vec![1_i64, 2, 3, 4];
+    let ptr = values.as_ptr();
+    let array = SharedArray::try_from(Array::Int64(PrimitiveArray {
+        validity: None,
+        values,
+    }))
+    .unwrap();
+
+    let sliced = ArrayRef::try_from(array.slice(1, 2).unwrap()).unwrap();
+    let shared = ArrayRef::try_from(array.clone()).unwrap();
+    drop(array);
+
+    let sliced = sliced.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(sliced.values().as_ref(), &[2, 3]);
+    assert_eq!(sliced.values().as_ptr(), ptr.wrapping_add(1));
+
+    let shared = shared.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(shared.values().as_ref(), &[1, 2, 3, 4]);
+    assert_eq!(shared.values().as_ptr(), ptr);
+}
+
+#[cfg(feature = "arrow2-0-17")]
+#[test]
+fn shared_arrays_are_handed_to_arrow2_without_copying() {
+    use crate::array::BytesArray;
+    use arrow2_0_17::array::Utf8Array;
+
+    let data = b"foobarbaz".to_vec();
+    let ptr = data.as_ptr();
+    let array = SharedArray::try_from(Array::Utf8(BytesArray {
+        validity: Some(vec![0b_101]),
+        offsets: vec![0, 3, 6, 9],
+        data,
+    }))
+    .unwrap();
+
+    let sliced =
+        Box::<dyn arrow2_0_17::array::Array>::try_from(array.slice(1, 2).unwrap()).unwrap();
+    drop(array);
+
+    let sliced = sliced.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(sliced.iter().collect::<Vec<_>>(), vec![None, Some("baz")]);
+    assert_eq!(sliced.values().as_ptr(), ptr);
+}
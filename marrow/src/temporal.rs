@@ -0,0 +1,387 @@
+//! Helpers for temporal arrays: unit and timezone conversions and the date and time types of other
+//! crates
+//!
+//! Timestamps can be converted into other units with [`TimestampArray::cast_unit`], their
+//! timezone can be normalized with [`canonicalize_timezone`] and changed with
+//! [`TimestampArray::with_timezone`] or [`TimestampArray::reinterpret_timezone`]. Similar
+//! conversions exist for times (e.g., [`TimeArray::to_time64`]) and dates
+//! (e.g., [`PrimitiveArray::to_date64`]).
+//!
+//! `marrow` stores dates, times, durations and timestamps as plain integers. The traits of this
+//! module describe date and time types that can be converted from and to these integers:
+//!
+//! - [`DateValue`]: dates, as stored in `Date32` and `Date64` arrays
+//! - [`TimeValue`]: times of the day, as stored in `Time32` and `Time64` arrays
+//! - [`DurationValue`]: durations, as stored in `Duration` arrays
+//! - [`TimestampValue`]: points in time, as stored in `Timestamp` arrays
+//!
+//! The views offer iterators that yield converted values (e.g.,
+//! [`TimestampView::iter_timestamps`]) and the arrays offer constructors that convert values into
+//! the given unit (e.g., [`TimestampArray::from_timestamps`]). The traits are implemented for the
+//! types of the following crates, if the corresponding feature is enabled:
+//!
+//! | Feature  | Dates                | Times                | Durations                | Timestamps |
+//! |----------|----------------------|----------------------|--------------------------|------------|
+//! | `chrono` | `NaiveDate`          | `NaiveTime`          | `TimeDelta`              | `NaiveDateTime`, `DateTime<Utc>`, `DateTime<FixedOffset>`, `DateTime<chrono_tz::Tz>` |
+//! | `time`   | `Date`               | `Time`               | `Duration`               | `PrimitiveDateTime`, `OffsetDateTime` |
+//! | `jiff`   | `civil::Date`        | `civil::Time`        | `SignedDuration`         | `civil::DateTime`, `Timestamp`, `Zoned` |
+//!
+//! Timestamps are stored relative to the Unix epoch in UTC. Naive date times (e.g.,
+//! `chrono::NaiveDateTime`) correspond to the wall clock time in UTC and ignore the timezone of the
+//! array. Zone aware types resolve the timezone of the array, either a fixed offset (e.g.,
+//! `"+01:00"`) or an IANA name (e.g., `"Europe/Berlin"`), once per view (see [`TimestampZone`]).
+//! Timestamps without timezone and the timezones `"UTC"` and `"Z"` in any capitalization are
+//! interpreted as UTC. IANA names are resolved with the timezone database of `jiff` or
+//! `chrono-tz`, also for `time`, which does not include one.
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # fn main() -> marrow::error::Result<()> {
+//! use chrono::{DateTime, FixedOffset};
+//! use marrow::{array::TimestampArray, datatypes::TimeUnit, types::Overflow};
+//!
+//! let value = DateTime::parse_from_rfc3339("2024-03-01T12:30:00+01:00").unwrap();
+//! let array = TimestampArray::from_timestamps(
+//!     [Some(value), None],
+//!     TimeUnit::Second,
+//!     Some(String::from("Europe/Berlin")),
+//!     Overflow::Error,
+//! )?;
+//! assert_eq!(array.values, vec![1709292600, 0]);
+//!
+//! let values = array
+//!     .as_view()
+//!     .iter_timestamps::<DateTime<FixedOffset>>()
+//!     .collect::<marrow::error::Result<Vec<_>>>()?;
+//! assert_eq!(values, vec![Some(value), None]);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "chrono"))]
+//! # fn main() {}
+//! ```
+use crate::{
+    array::{PrimitiveArray, TimeArray, TimestampArray},
+    bits::Bitmap,
+    datatypes::TimeUnit,
+    error::{fail, ErrorKind, Result},
+    types::Overflow,
+    view::{BitsWithOffset, PrimitiveView, TimeView, TimestampView},
+};
+
+mod cast;
+#[cfg(feature = "serde")]
+mod text;
+mod timezone;
+
+#[cfg(feature = "chrono")]
+mod impl_chrono;
+#[cfg(feature = "jiff")]
+mod impl_jiff;
+#[cfg(feature = "time")]
+mod impl_time;
+
+pub use cast::convert_unit;
+#[cfg(feature = "serde")]
+pub(crate) use text::{format_date, format_timestamp, parse_date, parse_timestamp};
+pub(crate) use timezone::ResolvedTimeZone;
+pub use timezone::{canonicalize_timezone, TimestampZone};
+
+pub(crate) const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// A date that can be stored as the number of days since the Unix epoch
+pub trait DateValue: Sized {
+    /// Construct the date from the number of days since 1970-01-01
+    fn from_days_since_epoch(days: i64) -> Result<Self>;
+
+    /// The number of days since 1970-01-01
+    fn to_days_since_epoch(&self) -> Result<i64>;
+}
+
+/// A time of the day that can be stored as the number of nanoseconds since midnight
+pub trait TimeValue: Sized {
+    /// Construct the time from the number of nanoseconds since midnight
+    fn from_nanoseconds_since_midnight(nanoseconds: i64) -> Result<Self>;
+
+    /// The number of nanoseconds since midnight
+    fn to_nanoseconds_since_midnight(&self) -> Result<i64>;
+}
+
+/// A signed duration that can be stored as a number of nanoseconds
+pub trait DurationValue: Sized {
+    /// Construct the duration from a number of nanoseconds
+    fn from_nanoseconds(nanoseconds: i128) -> Result<Self>;
+
+    /// The number of nanoseconds of this duration
+    fn to_nanoseconds(&self) -> Result<i128>;
+}
+
+/// A point in time that can be stored as the number of nanoseconds since the Unix epoch
+pub trait TimestampValue: Sized {
+    /// Construct the value from the nanoseconds since 1970-01-01T00:00:00Z and the timezone of the
+    /// array
+    fn from_timestamp(nanoseconds: i128, timezone: &TimestampZone<'_>) -> Result<Self>;
+
+    /// The number of nanoseconds since 1970-01-01T00:00:00Z
+    fn to_timestamp(&self) -> Result<i128>;
+}
+
+/// The number of nanoseconds in a single unit
+pub(crate) fn nanoseconds_per_unit(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Split nanoseconds into seconds and the non-negative subsecond nanoseconds
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn split_nanoseconds(nanoseconds: i128) -> Result<(i64, u32)> {
+    let seconds = i64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_SECOND))?;
+    let subsec = u32::try_from(nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND))?;
+    Ok((seconds, subsec))
+}
+
+/// Parse fixed offsets of the form `+HH:MM`, `+HHMM` or `+HH` into seconds east of UTC
+pub(crate) fn parse_fixed_offset(timezone: &str) -> Option<i32> {
+    let (sign, rest) = match timezone.as_bytes().first()? {
+        b'+' => (1, &timezone[1..]),
+        b'-' => (-1, &timezone[1..]),
+        _ => return None,
+    };
+    if !rest.is_ascii() {
+        return None;
+    }
+    let (hours, minutes) = match rest.len() {
+        2 => (rest, "00"),
+        4 => (&rest[..2], &rest[2..]),
+        5 if &rest[2..3] == ":" => (&rest[..2], &rest[3..]),
+        _ => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours = hours.parse::<i32>().ok()?;
+    let minutes = minutes.parse::<i32>().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn iter_values<'a, T: Copy, V>(
+    validity: Option<BitsWithOffset<'a>>,
+    values: &'a [T],
+    mut convert: impl FnMut(T) -> Result<V> + 'a,
+) -> impl Iterator<Item = Result<Option<V>>> + 'a {
+    values.iter().enumerate().map(move |(idx, value)| {
+        if validity.is_some_and(|validity| !validity.get(idx)) {
+            return Ok(None);
+        }
+        Ok(Some(convert(*value)?))
+    })
+}
+
+fn build_values<V, T: Default>(
+    values: impl IntoIterator<Item = Option<V>>,
+    mut convert: impl FnMut(V) -> Result<Option<T>>,
+) -> Result<(Option<Vec<u8>>, Vec<T>)> {
+    let mut validity = Bitmap::new();
+    let mut result = Vec::new();
+    for value in values {
+        let value = match value {
+            Some(value) => convert(value)?,
+            None => None,
+        };
+        validity.push(value.is_some());
+        result.push(value.unwrap_or_default());
+    }
+    let validity = if validity.count_set_bits() == validity.len() {
+        None
+    } else {
+        Some(validity.into_vec())
+    };
+    Ok((validity, result))
+}
+
+impl<'a> PrimitiveView<'a, i32> {
+    /// Iterate over the elements of a `Date32` view as dates
+    pub fn iter_dates<D: DateValue>(&self) -> impl Iterator<Item = Result<Option<D>>> + 'a {
+        iter_values(self.validity, self.values, |days| {
+            D::from_days_since_epoch(i64::from(days))
+        })
+    }
+}
+
+impl<'a> PrimitiveView<'a, i64> {
+    /// Iterate over the elements of a `Date64` view as dates
+    ///
+    /// Milliseconds that do not fall on the start of a day are truncated.
+    pub fn iter_dates<D: DateValue>(&self) -> impl Iterator<Item = Result<Option<D>>> + 'a {
+        iter_values(self.validity, self.values, |milliseconds| {
+            D::from_days_since_epoch(milliseconds.div_euclid(MILLISECONDS_PER_DAY))
+        })
+    }
+}
+
+impl PrimitiveArray<i32> {
+    /// Build a `Date32` array from dates
+    pub fn from_dates<D: DateValue>(
+        values: impl IntoIterator<Item = Option<D>>,
+        overflow: Overflow,
+    ) -> Result<Self> {
+        let (validity, values) = build_values(values, |value| {
+            overflow.apply(i128::from(value.to_days_since_epoch()?))
+        })?;
+        Ok(Self { validity, values })
+    }
+}
+
+impl PrimitiveArray<i64> {
+    /// Build a `Date64` array from dates
+    pub fn from_dates<D: DateValue>(
+        values: impl IntoIterator<Item = Option<D>>,
+        overflow: Overflow,
+    ) -> Result<Self> {
+        let (validity, values) = build_values(values, |value| {
+            let days = i128::from(value.to_days_since_epoch()?);
+            overflow.apply(days * i128::from(MILLISECONDS_PER_DAY))
+        })?;
+        Ok(Self { validity, values })
+    }
+}
+
+impl<'a, T: Copy + Into<i64>> TimeView<'a, T> {
+    /// Iterate over the elements of a `Time32` or `Time64` view as times of the day
+    pub fn iter_times<V: TimeValue>(&self) -> impl Iterator<Item = Result<Option<V>>> + 'a {
+        let factor = i128::from(nanoseconds_per_unit(self.unit));
+        iter_values(self.validity, self.values, move |value| {
+            let nanoseconds = i128::from(value.into()) * factor;
+            let Ok(nanoseconds) = i64::try_from(nanoseconds) else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Time {nanoseconds}ns is outside of a single day"
+                );
+            };
+            V::from_nanoseconds_since_midnight(nanoseconds)
+        })
+    }
+}
+
+impl<'a> TimeView<'a, i64> {
+    /// Iterate over the elements of a `Duration` view as durations
+    pub fn iter_durations<D: DurationValue>(&self) -> impl Iterator<Item = Result<Option<D>>> + 'a {
+        let factor = i128::from(nanoseconds_per_unit(self.unit));
+        iter_values(self.validity, self.values, move |value| {
+            D::from_nanoseconds(i128::from(value) * factor)
+        })
+    }
+}
+
+macro_rules! impl_time_array_from_times {
+    ($($ty:ty),*) => {
+        $(
+            impl TimeArray<$ty> {
+                /// Build a `Time32` or `Time64` array from times of the day
+                ///
+                /// Fractions of the unit are truncated.
+                pub fn from_times<V: TimeValue>(
+                    values: impl IntoIterator<Item = Option<V>>,
+                    unit: TimeUnit,
+                    overflow: Overflow,
+                ) -> Result<Self> {
+                    let factor = nanoseconds_per_unit(unit);
+                    let (validity, values) = build_values(values, |value| {
+                        let nanoseconds = value.to_nanoseconds_since_midnight()?;
+                        overflow.apply(i128::from(nanoseconds.div_euclid(factor)))
+                    })?;
+                    Ok(Self {
+                        unit,
+                        validity,
+                        values,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_time_array_from_times!(i32, i64);
+
+impl TimeArray<i64> {
+    /// Build a `Duration` array from durations
+    ///
+    /// Fractions of the unit are truncated towards negative infinity.
+    pub fn from_durations<D: DurationValue>(
+        values: impl IntoIterator<Item = Option<D>>,
+        unit: TimeUnit,
+        overflow: Overflow,
+    ) -> Result<Self> {
+        let factor = i128::from(nanoseconds_per_unit(unit));
+        let (validity, values) = build_values(values, |value| {
+            overflow.apply(value.to_nanoseconds()?.div_euclid(factor))
+        })?;
+        Ok(Self {
+            unit,
+            validity,
+            values,
+        })
+    }
+}
+
+impl<'a> TimestampView<'a> {
+    /// Iterate over the elements as timestamps
+    ///
+    /// Zone aware types are converted into the timezone of the view. The timezone is resolved once
+    /// for all values, see [`TimestampZone`].
+    pub fn iter_timestamps<T: TimestampValue>(
+        &self,
+    ) -> impl Iterator<Item = Result<Option<T>>> + '_ {
+        let factor = i128::from(nanoseconds_per_unit(self.unit));
+        let timezone = TimestampZone::new(self.timezone.as_deref());
+        iter_values(self.validity, self.values, move |value| {
+            T::from_timestamp(i128::from(value) * factor, &timezone)
+        })
+    }
+}
+
+impl TimestampArray {
+    /// Build a `Timestamp` array from timestamps
+    ///
+    /// The `timezone` is only stored as metadata and does not change the values. Fractions of the
+    /// unit are truncated towards negative infinity.
+    pub fn from_timestamps<T: TimestampValue>(
+        values: impl IntoIterator<Item = Option<T>>,
+        unit: TimeUnit,
+        timezone: Option<String>,
+        overflow: Overflow,
+    ) -> Result<Self> {
+        let factor = i128::from(nanoseconds_per_unit(unit));
+        let (validity, values) = build_values(values, |value| {
+            overflow.apply(value.to_timestamp()?.div_euclid(factor))
+        })?;
+        Ok(Self {
+            unit,
+            timezone,
+            validity,
+            values,
+        })
+    }
+}
+
+#[test]
+fn fixed_offsets() {
+    assert_eq!(parse_fixed_offset("+01:00"), Some(3600));
+    assert_eq!(parse_fixed_offset("-0530"), Some(-19800));
+    assert_eq!(parse_fixed_offset("+02"), Some(7200));
+    assert_eq!(parse_fixed_offset("+1:00"), None);
+    assert_eq!(parse_fixed_offset("+24:00"), None);
+    assert_eq!(parse_fixed_offset("UTC"), None);
+    assert_eq!(parse_fixed_offset("+1é:0"), None);
+}
@@ -0,0 +1,312 @@
+//! Conversions between time units and between date representations
+use crate::{
+    array::{PrimitiveArray, TimeArray, TimestampArray},
+    datatypes::TimeUnit,
+    error::{fail, ErrorKind, Result},
+    view::{BitsWithOffset, PrimitiveView, TimeView, TimestampView},
+};
+
+use super::{build_values, nanoseconds_per_unit, MILLISECONDS_PER_DAY};
+use crate::types::{Overflow, Rounding};
+
+/// Convert a value from one time unit into another
+///
+/// Returns `None`, if the value overflows and `overflow` is [`Overflow::Null`].
+///
+/// ```rust
+/// # use marrow::{datatypes::TimeUnit, temporal::convert_unit, types::{Overflow, Rounding}};
+/// let convert = |value, rounding| {
+///     convert_unit(value, TimeUnit::Millisecond, TimeUnit::Second, rounding, Overflow::Error)
+/// };
+/// assert_eq!(convert(-1_500, Rounding::Floor).unwrap(), Some(-2));
+/// assert_eq!(convert(-1_500, Rounding::TowardZero).unwrap(), Some(-1));
+/// assert!(convert(-1_500, Rounding::Exact).is_err());
+/// ```
+pub fn convert_unit(
+    value: i64,
+    from: TimeUnit,
+    to: TimeUnit,
+    rounding: Rounding,
+    overflow: Overflow,
+) -> Result<Option<i64>> {
+    overflow.apply(rescale(i128::from(value), from, to, rounding)?)
+}
+
+fn rescale(value: i128, from: TimeUnit, to: TimeUnit, rounding: Rounding) -> Result<i128> {
+    let from = i128::from(nanoseconds_per_unit(from));
+    let to = i128::from(nanoseconds_per_unit(to));
+    if from >= to {
+        Ok(value * (from / to))
+    } else {
+        rounding.divide(value, to / from)
+    }
+}
+
+fn optional_values<'a, T: Copy>(
+    validity: Option<BitsWithOffset<'a>>,
+    values: &'a [T],
+) -> impl Iterator<Item = Option<T>> + 'a {
+    values.iter().enumerate().map(move |(idx, value)| {
+        let is_valid = validity.map_or(true, |validity| validity.get(idx));
+        is_valid.then_some(*value)
+    })
+}
+
+fn check_time_unit(unit: TimeUnit, expected: [TimeUnit; 2], data_type: &str) -> Result<()> {
+    if !expected.contains(&unit) {
+        fail!(
+            ErrorKind::Unsupported,
+            "{data_type} only supports the units {} and {}, not {unit}",
+            expected[0],
+            expected[1],
+        );
+    }
+    Ok(())
+}
+
+impl<'a> TimestampView<'a> {
+    /// Convert the values into another unit
+    ///
+    /// The timezone is retained.
+    pub fn cast_unit(
+        &self,
+        unit: TimeUnit,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<TimestampArray> {
+        let (validity, values) =
+            build_values(optional_values(self.validity, self.values), |value| {
+                overflow.apply(rescale(i128::from(value), self.unit, unit, rounding)?)
+            })?;
+        Ok(TimestampArray {
+            unit,
+            timezone: self.timezone.clone(),
+            validity,
+            values,
+        })
+    }
+}
+
+impl TimestampArray {
+    /// Convert the values into another unit, see [`TimestampView::cast_unit`]
+    pub fn cast_unit(
+        &self,
+        unit: TimeUnit,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<TimestampArray> {
+        self.as_view().cast_unit(unit, rounding, overflow)
+    }
+}
+
+macro_rules! impl_time_cast_unit {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> TimeView<'a, $ty> {
+                /// Convert the values into another unit
+                ///
+                /// The unit is not checked against the data type, as the same view is used for
+                /// times and durations.
+                pub fn cast_unit(
+                    &self,
+                    unit: TimeUnit,
+                    rounding: Rounding,
+                    overflow: Overflow,
+                ) -> Result<TimeArray<$ty>> {
+                    let (validity, values) =
+                        build_values(optional_values(self.validity, self.values), |value| {
+                            overflow.apply(rescale(i128::from(value), self.unit, unit, rounding)?)
+                        })?;
+                    Ok(TimeArray {
+                        unit,
+                        validity,
+                        values,
+                    })
+                }
+            }
+
+            impl TimeArray<$ty> {
+                /// Convert the values into another unit, see [`TimeView::cast_unit`]
+                pub fn cast_unit(
+                    &self,
+                    unit: TimeUnit,
+                    rounding: Rounding,
+                    overflow: Overflow,
+                ) -> Result<TimeArray<$ty>> {
+                    self.as_view().cast_unit(unit, rounding, overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_time_cast_unit!(i32, i64);
+
+impl<'a> TimeView<'a, i32> {
+    /// Convert a `Time32` view into a `Time64` array with the unit `Microsecond` or `Nanosecond`
+    pub fn to_time64(&self, unit: TimeUnit) -> Result<TimeArray<i64>> {
+        check_time_unit(
+            unit,
+            [TimeUnit::Microsecond, TimeUnit::Nanosecond],
+            "Time64",
+        )?;
+        let (validity, values) =
+            build_values(optional_values(self.validity, self.values), |value| {
+                Overflow::Error.apply(rescale(
+                    i128::from(value),
+                    self.unit,
+                    unit,
+                    Rounding::Exact,
+                )?)
+            })?;
+        Ok(TimeArray {
+            unit,
+            validity,
+            values,
+        })
+    }
+}
+
+impl<'a> TimeView<'a, i64> {
+    /// Convert a `Time64` view into a `Time32` array with the unit `Second` or `Millisecond`
+    pub fn to_time32(
+        &self,
+        unit: TimeUnit,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<TimeArray<i32>> {
+        check_time_unit(unit, [TimeUnit::Second, TimeUnit::Millisecond], "Time32")?;
+        let (validity, values) =
+            build_values(optional_values(self.validity, self.values), |value| {
+                overflow.apply(rescale(i128::from(value), self.unit, unit, rounding)?)
+            })?;
+        Ok(TimeArray {
+            unit,
+            validity,
+            values,
+        })
+    }
+}
+
+impl TimeArray<i32> {
+    /// Convert a `Time32` array into a `Time64` array, see [`TimeView::to_time64`]
+    pub fn to_time64(&self, unit: TimeUnit) -> Result<TimeArray<i64>> {
+        self.as_view().to_time64(unit)
+    }
+}
+
+impl TimeArray<i64> {
+    /// Convert a `Time64` array into a `Time32` array, see [`TimeView::to_time32`]
+    pub fn to_time32(
+        &self,
+        unit: TimeUnit,
+        rounding: Rounding,
+        overflow: Overflow,
+    ) -> Result<TimeArray<i32>> {
+        self.as_view().to_time32(unit, rounding, overflow)
+    }
+}
+
+impl<'a> PrimitiveView<'a, i32> {
+    /// Convert a `Date32` view into a `Date64` array
+    pub fn to_date64(&self) -> PrimitiveArray<i64> {
+        PrimitiveArray {
+            validity: self
+                .validity
+                .map(|validity| validity.to_bitmap(self.values.len()).into_vec()),
+            values: self
+                .values
+                .iter()
+                .map(|days| i64::from(*days) * MILLISECONDS_PER_DAY)
+                .collect(),
+        }
+    }
+}
+
+impl<'a> PrimitiveView<'a, i64> {
+    /// Convert a `Date64` view into a `Date32` array
+    ///
+    /// `rounding` determines how milliseconds that do not fall on the start of a day are handled.
+    pub fn to_date32(&self, rounding: Rounding, overflow: Overflow) -> Result<PrimitiveArray<i32>> {
+        let (validity, values) =
+            build_values(optional_values(self.validity, self.values), |value| {
+                let days = rounding.divide(i128::from(value), i128::from(MILLISECONDS_PER_DAY))?;
+                overflow.apply(days)
+            })?;
+        Ok(PrimitiveArray { validity, values })
+    }
+}
+
+impl PrimitiveArray<i32> {
+    /// Convert a `Date32` array into a `Date64` array
+    pub fn to_date64(&self) -> PrimitiveArray<i64> {
+        self.as_view().to_date64()
+    }
+}
+
+impl PrimitiveArray<i64> {
+    /// Convert a `Date64` array into a `Date32` array, see [`PrimitiveView::to_date32`]
+    pub fn to_date32(&self, rounding: Rounding, overflow: Overflow) -> Result<PrimitiveArray<i32>> {
+        self.as_view().to_date32(rounding, overflow)
+    }
+}
+
+#[test]
+fn unit_conversions() {
+    let array = TimestampArray {
+        unit: TimeUnit::Millisecond,
+        timezone: Some(String::from("UTC")),
+        validity: Some(vec![0b_0111]),
+        values: vec![1_500, -1_500, i64::MAX, 7],
+    };
+
+    let actual = array
+        .cast_unit(TimeUnit::Second, Rounding::HalfEven, Overflow::Error)
+        .unwrap();
+    assert_eq!(actual.values, vec![2, -2, i64::MAX / 1000 + 1, 0]);
+    assert_eq!(actual.validity, Some(vec![0b_0111]));
+    assert_eq!(actual.timezone, array.timezone);
+
+    assert!(array
+        .cast_unit(TimeUnit::Nanosecond, Rounding::Exact, Overflow::Error)
+        .is_err());
+    let actual = array
+        .cast_unit(TimeUnit::Nanosecond, Rounding::Exact, Overflow::Saturate)
+        .unwrap();
+    assert_eq!(
+        actual.values,
+        vec![1_500_000_000, -1_500_000_000, i64::MAX, 0]
+    );
+    let actual = array
+        .cast_unit(TimeUnit::Nanosecond, Rounding::Exact, Overflow::Null)
+        .unwrap();
+    assert_eq!(actual.validity, Some(vec![0b_0011]));
+
+    let time = TimeArray {
+        unit: TimeUnit::Second,
+        validity: None,
+        values: vec![1_i32, 86_399],
+    };
+    let time64 = time.to_time64(TimeUnit::Microsecond).unwrap();
+    assert_eq!(time64.values, vec![1_000_000, 86_399_000_000]);
+    assert!(time.to_time64(TimeUnit::Second).is_err());
+    let time32 = time64
+        .to_time32(TimeUnit::Millisecond, Rounding::Exact, Overflow::Error)
+        .unwrap();
+    assert_eq!(time32.values, vec![1_000, 86_399_000]);
+
+    let dates = PrimitiveArray {
+        validity: Some(vec![0b_01]),
+        values: vec![-1_i32, 0],
+    };
+    let dates64 = dates.to_date64();
+    assert_eq!(dates64.values, vec![-86_400_000, 0]);
+    assert_eq!(dates64.validity, Some(vec![0b_01]));
+    assert_eq!(
+        dates64.to_date32(Rounding::Exact, Overflow::Error).unwrap(),
+        PrimitiveArray {
+            validity: Some(vec![0b_01]),
+            values: vec![-1, 0],
+        },
+    );
+}
@@ -0,0 +1,235 @@
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc,
+};
+use chrono_tz::Tz;
+
+use crate::error::{fail, ErrorKind, Result};
+
+use super::{
+    split_nanoseconds, DateValue, DurationValue, TimeValue, TimestampValue, TimestampZone,
+    NANOSECONDS_PER_SECOND,
+};
+
+/// The number of days between 0001-01-01 and 1970-01-01
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+impl DateValue for NaiveDate {
+    fn from_days_since_epoch(days: i64) -> Result<Self> {
+        let date = days
+            .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+            .and_then(|days| i32::try_from(days).ok())
+            .and_then(NaiveDate::from_num_days_from_ce_opt);
+        let Some(date) = date else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {days} days since the epoch as a chrono::NaiveDate"
+            );
+        };
+        Ok(date)
+    }
+
+    fn to_days_since_epoch(&self) -> Result<i64> {
+        Ok(i64::from(self.num_days_from_ce()) - UNIX_EPOCH_DAYS_FROM_CE)
+    }
+}
+
+impl TimeValue for NaiveTime {
+    fn from_nanoseconds_since_midnight(nanoseconds: i64) -> Result<Self> {
+        let (seconds, subsec) = split_nanoseconds(i128::from(nanoseconds))?;
+        let time = u32::try_from(seconds)
+            .ok()
+            .and_then(|seconds| NaiveTime::from_num_seconds_from_midnight_opt(seconds, subsec));
+        let Some(time) = time else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {nanoseconds}ns since midnight as a chrono::NaiveTime"
+            );
+        };
+        Ok(time)
+    }
+
+    fn to_nanoseconds_since_midnight(&self) -> Result<i64> {
+        Ok(i64::from(self.num_seconds_from_midnight()) * 1_000_000_000
+            + i64::from(self.nanosecond()))
+    }
+}
+
+impl DurationValue for TimeDelta {
+    fn from_nanoseconds(nanoseconds: i128) -> Result<Self> {
+        let (seconds, subsec) = split_nanoseconds(nanoseconds)?;
+        let Some(duration) = TimeDelta::new(seconds, subsec) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {nanoseconds}ns as a chrono::TimeDelta"
+            );
+        };
+        Ok(duration)
+    }
+
+    fn to_nanoseconds(&self) -> Result<i128> {
+        Ok(i128::from(self.num_seconds()) * NANOSECONDS_PER_SECOND
+            + i128::from(self.subsec_nanos()))
+    }
+}
+
+fn utc_from_timestamp(nanoseconds: i128) -> Result<DateTime<Utc>> {
+    let (seconds, subsec) = split_nanoseconds(nanoseconds)?;
+    let Some(value) = DateTime::from_timestamp(seconds, subsec) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot represent the timestamp {nanoseconds}ns as a chrono::DateTime"
+        );
+    };
+    Ok(value)
+}
+
+fn utc_to_timestamp(value: &DateTime<Utc>) -> i128 {
+    i128::from(value.timestamp()) * NANOSECONDS_PER_SECOND
+        + i128::from(value.timestamp_subsec_nanos())
+}
+
+impl TimestampValue for NaiveDateTime {
+    fn from_timestamp(nanoseconds: i128, _timezone: &TimestampZone<'_>) -> Result<Self> {
+        Ok(utc_from_timestamp(nanoseconds)?.naive_utc())
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(utc_to_timestamp(&self.and_utc()))
+    }
+}
+
+impl TimestampValue for DateTime<Utc> {
+    fn from_timestamp(nanoseconds: i128, _timezone: &TimestampZone<'_>) -> Result<Self> {
+        utc_from_timestamp(nanoseconds)
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(utc_to_timestamp(self))
+    }
+}
+
+impl TimestampValue for DateTime<FixedOffset> {
+    fn from_timestamp(nanoseconds: i128, timezone: &TimestampZone<'_>) -> Result<Self> {
+        let value = utc_from_timestamp(nanoseconds)?;
+        let offset = timezone.offset_from_utc(value.timestamp())?;
+        let Some(offset) = FixedOffset::east_opt(offset) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the offset {offset}s as a chrono::FixedOffset"
+            );
+        };
+        Ok(value.with_timezone(&offset))
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(utc_to_timestamp(&self.to_utc()))
+    }
+}
+
+/// Only UTC and IANA timezones are supported, other fixed offsets result in an error
+impl TimestampValue for DateTime<Tz> {
+    fn from_timestamp(nanoseconds: i128, timezone: &TimestampZone<'_>) -> Result<Self> {
+        let tz = timezone.chrono_timezone()?;
+        Ok(utc_from_timestamp(nanoseconds)?.with_timezone(&tz))
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(utc_to_timestamp(&self.to_utc()))
+    }
+}
+
+#[test]
+fn chrono_roundtrips() {
+    use crate::{
+        array::{PrimitiveArray, TimeArray, TimestampArray},
+        datatypes::TimeUnit,
+        types::Overflow,
+    };
+
+    let dates = [
+        NaiveDate::from_ymd_opt(1969, 12, 31),
+        None,
+        NaiveDate::from_ymd_opt(2024, 2, 29),
+    ];
+    let array = PrimitiveArray::<i32>::from_dates(dates, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-1, 0, 19782]);
+    let actual = array
+        .as_view()
+        .iter_dates()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, dates);
+
+    let array = PrimitiveArray::<i64>::from_dates(dates, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-86_400_000, 0, 1_709_164_800_000]);
+    let actual = array
+        .as_view()
+        .iter_dates()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, dates);
+
+    let times = [NaiveTime::from_hms_milli_opt(13, 14, 15, 160), None];
+    let array =
+        TimeArray::<i32>::from_times(times, TimeUnit::Millisecond, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![47_655_160, 0]);
+    let actual = array
+        .as_view()
+        .iter_times()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, times);
+    assert!(TimeArray::<i32>::from_times(times, TimeUnit::Nanosecond, Overflow::Error).is_err());
+
+    let durations = [Some(TimeDelta::milliseconds(-1500)), None];
+    let array =
+        TimeArray::from_durations(durations, TimeUnit::Microsecond, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-1_500_000, 0]);
+    let actual = array
+        .as_view()
+        .iter_durations()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, durations);
+
+    let timestamps = [DateTime::parse_from_rfc3339("2024-07-01T12:00:00+02:00").ok()];
+    let array = TimestampArray::from_timestamps(
+        timestamps,
+        TimeUnit::Millisecond,
+        Some(String::from("Europe/Berlin")),
+        Overflow::Error,
+    )
+    .unwrap();
+    assert_eq!(array.values, vec![1_719_828_000_000]);
+    let view = array.as_view();
+    let actual = view.iter_timestamps().collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(actual, timestamps);
+    let actual = view
+        .iter_timestamps::<DateTime<Tz>>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual[0].unwrap().timezone(), Tz::Europe__Berlin);
+    let actual = view
+        .iter_timestamps::<NaiveDateTime>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual[0].unwrap().to_string(), "2024-07-01 10:00:00");
+
+    for timezone in ["Z", "utc"] {
+        let array = TimestampArray {
+            timezone: Some(String::from(timezone)),
+            ..array.clone()
+        };
+        let view = array.as_view();
+        let actual = view
+            .iter_timestamps::<DateTime<FixedOffset>>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(actual[0].unwrap().to_rfc3339(), "2024-07-01T10:00:00+00:00");
+        let actual = view
+            .iter_timestamps::<DateTime<Tz>>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(actual[0].unwrap().timezone(), Tz::UTC);
+    }
+}
@@ -0,0 +1,191 @@
+use jiff::{
+    civil::{self, Date, DateTime, Time},
+    tz::TimeZone,
+    SignedDuration, Timestamp, Zoned,
+};
+
+use crate::error::{fail, ErrorKind, MarrowError, Result};
+
+use super::{
+    DateValue, DurationValue, TimeValue, TimestampValue, TimestampZone, NANOSECONDS_PER_SECOND,
+};
+
+const UNIX_EPOCH: Date = civil::date(1970, 1, 1);
+
+impl From<jiff::Error> for MarrowError {
+    fn from(err: jiff::Error) -> Self {
+        MarrowError::with_cause(ErrorKind::Unsupported, format!("jiff::Error: {err}"), err)
+    }
+}
+
+impl DateValue for Date {
+    fn from_days_since_epoch(days: i64) -> Result<Self> {
+        let Some(seconds) = days.checked_mul(86_400) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {days} days since the epoch as a jiff::civil::Date"
+            );
+        };
+        Ok(UNIX_EPOCH.checked_add(SignedDuration::from_secs(seconds))?)
+    }
+
+    fn to_days_since_epoch(&self) -> Result<i64> {
+        Ok(self.duration_since(UNIX_EPOCH).as_secs() / 86_400)
+    }
+}
+
+impl TimeValue for Time {
+    fn from_nanoseconds_since_midnight(nanoseconds: i64) -> Result<Self> {
+        if nanoseconds < 0 {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {nanoseconds}ns since midnight as a jiff::civil::Time"
+            );
+        }
+        Ok(Time::midnight().checked_add(SignedDuration::from_nanos(nanoseconds))?)
+    }
+
+    fn to_nanoseconds_since_midnight(&self) -> Result<i64> {
+        Ok(i64::try_from(
+            self.duration_since(Time::midnight()).as_nanos(),
+        )?)
+    }
+}
+
+impl DurationValue for SignedDuration {
+    fn from_nanoseconds(nanoseconds: i128) -> Result<Self> {
+        // truncate towards zero, so that seconds and nanoseconds have the same sign
+        let seconds = i64::try_from(nanoseconds / NANOSECONDS_PER_SECOND)?;
+        let subsec = i32::try_from(nanoseconds % NANOSECONDS_PER_SECOND)?;
+        Ok(SignedDuration::new(seconds, subsec))
+    }
+
+    fn to_nanoseconds(&self) -> Result<i128> {
+        Ok(self.as_nanos())
+    }
+}
+
+impl TimestampValue for Timestamp {
+    fn from_timestamp(nanoseconds: i128, _timezone: &TimestampZone<'_>) -> Result<Self> {
+        Ok(Timestamp::from_nanosecond(nanoseconds)?)
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(self.as_nanosecond())
+    }
+}
+
+impl TimestampValue for DateTime {
+    fn from_timestamp(nanoseconds: i128, _timezone: &TimestampZone<'_>) -> Result<Self> {
+        let value = Timestamp::from_nanosecond(nanoseconds)?;
+        Ok(TimeZone::UTC.to_datetime(value))
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(self.to_zoned(TimeZone::UTC)?.timestamp().as_nanosecond())
+    }
+}
+
+impl TimestampValue for Zoned {
+    fn from_timestamp(nanoseconds: i128, timezone: &TimestampZone<'_>) -> Result<Self> {
+        let value = Timestamp::from_nanosecond(nanoseconds)?;
+        Ok(value.to_zoned(timezone.jiff_timezone()?))
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(self.timestamp().as_nanosecond())
+    }
+}
+
+#[test]
+fn jiff_roundtrips() {
+    use crate::{
+        array::{PrimitiveArray, TimeArray, TimestampArray},
+        datatypes::TimeUnit,
+        types::Overflow,
+    };
+
+    let dates = [
+        Some(civil::date(1969, 12, 31)),
+        None,
+        Some(civil::date(2024, 2, 29)),
+    ];
+    let array = PrimitiveArray::<i32>::from_dates(dates, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-1, 0, 19782]);
+    let actual = array
+        .as_view()
+        .iter_dates()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, dates);
+
+    let times = [Some(civil::time(13, 14, 15, 160_000_000))];
+    let array =
+        TimeArray::<i32>::from_times(times, TimeUnit::Millisecond, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![47_655_160]);
+    let actual = array
+        .as_view()
+        .iter_times()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, times);
+
+    let durations = [Some(SignedDuration::from_millis(-1500))];
+    let array =
+        TimeArray::from_durations(durations, TimeUnit::Millisecond, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-1500]);
+    let actual = array
+        .as_view()
+        .iter_durations()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, durations);
+
+    let array = TimestampArray {
+        unit: TimeUnit::Second,
+        timezone: Some(String::from("America/New_York")),
+        validity: None,
+        values: vec![1_719_835_200],
+    };
+    let actual = array
+        .as_view()
+        .iter_timestamps::<Zoned>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    let value = actual[0].clone().unwrap();
+    assert_eq!(value.datetime(), civil::datetime(2024, 7, 1, 8, 0, 0, 0));
+    assert_eq!(value.time_zone().iana_name(), Some("America/New_York"));
+
+    let roundtrip = TimestampArray::from_timestamps(
+        actual,
+        TimeUnit::Second,
+        array.timezone.clone(),
+        Overflow::Error,
+    )
+    .unwrap();
+    assert_eq!(roundtrip, array);
+
+    for timezone in ["Z", "utc"] {
+        let array = TimestampArray {
+            timezone: Some(String::from(timezone)),
+            ..array.clone()
+        };
+        let actual = array
+            .as_view()
+            .iter_timestamps::<Zoned>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let value = actual[0].clone().unwrap();
+        assert_eq!(value.datetime(), civil::datetime(2024, 7, 1, 12, 0, 0, 0));
+        assert_eq!(value.offset().seconds(), 0);
+    }
+
+    let array = TimestampArray {
+        timezone: Some(String::from("Not/A_Timezone")),
+        ..array
+    };
+    assert!(array
+        .as_view()
+        .iter_timestamps::<Zoned>()
+        .all(|value| value.is_err()));
+}
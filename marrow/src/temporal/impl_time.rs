@@ -0,0 +1,192 @@
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::error::{fail, ErrorKind, MarrowError, Result};
+
+use super::{
+    split_nanoseconds, DateValue, DurationValue, TimeValue, TimestampValue, TimestampZone,
+    NANOSECONDS_PER_SECOND,
+};
+
+/// The Julian day of 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+impl From<time::error::ComponentRange> for MarrowError {
+    fn from(err: time::error::ComponentRange) -> Self {
+        MarrowError::with_cause(
+            ErrorKind::Unsupported,
+            format!("time::error::ComponentRange: {err}"),
+            err,
+        )
+    }
+}
+
+impl DateValue for Date {
+    fn from_days_since_epoch(days: i64) -> Result<Self> {
+        let Some(julian_day) = days
+            .checked_add(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(|day| i32::try_from(day).ok())
+        else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {days} days since the epoch as a time::Date"
+            );
+        };
+        Ok(Date::from_julian_day(julian_day)?)
+    }
+
+    fn to_days_since_epoch(&self) -> Result<i64> {
+        Ok(i64::from(self.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY)
+    }
+}
+
+impl TimeValue for Time {
+    fn from_nanoseconds_since_midnight(nanoseconds: i64) -> Result<Self> {
+        let (seconds, subsec) = split_nanoseconds(i128::from(nanoseconds))?;
+        if !(0..86_400).contains(&seconds) {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent {nanoseconds}ns since midnight as a time::Time"
+            );
+        }
+        let hours = u8::try_from(seconds / 3600)?;
+        let minutes = u8::try_from(seconds / 60 % 60)?;
+        let seconds = u8::try_from(seconds % 60)?;
+        Ok(Time::from_hms_nano(hours, minutes, seconds, subsec)?)
+    }
+
+    fn to_nanoseconds_since_midnight(&self) -> Result<i64> {
+        let (hours, minutes, seconds, subsec) = self.as_hms_nano();
+        let seconds = i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds);
+        Ok(seconds * 1_000_000_000 + i64::from(subsec))
+    }
+}
+
+impl DurationValue for Duration {
+    fn from_nanoseconds(nanoseconds: i128) -> Result<Self> {
+        // truncate towards zero, so that seconds and nanoseconds have the same sign
+        let seconds = i64::try_from(nanoseconds / NANOSECONDS_PER_SECOND)?;
+        let subsec = i32::try_from(nanoseconds % NANOSECONDS_PER_SECOND)?;
+        Ok(Duration::new(seconds, subsec))
+    }
+
+    fn to_nanoseconds(&self) -> Result<i128> {
+        Ok(self.whole_nanoseconds())
+    }
+}
+
+impl TimestampValue for PrimitiveDateTime {
+    fn from_timestamp(nanoseconds: i128, _timezone: &TimestampZone<'_>) -> Result<Self> {
+        let value = OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)?;
+        Ok(PrimitiveDateTime::new(value.date(), value.time()))
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(self.assume_utc().unix_timestamp_nanos())
+    }
+}
+
+/// As `time` does not include a timezone database, IANA timezones require the `chrono` or `jiff`
+/// feature
+impl TimestampValue for OffsetDateTime {
+    fn from_timestamp(nanoseconds: i128, timezone: &TimestampZone<'_>) -> Result<Self> {
+        let value = OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)?;
+        let offset = timezone.offset_from_utc(value.unix_timestamp())?;
+        let offset = UtcOffset::from_whole_seconds(offset)?;
+        let Some(value) = value.checked_to_offset(offset) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the timestamp {nanoseconds}ns with offset {offset}"
+            );
+        };
+        Ok(value)
+    }
+
+    fn to_timestamp(&self) -> Result<i128> {
+        Ok(self.unix_timestamp_nanos())
+    }
+}
+
+#[test]
+fn time_roundtrips() {
+    use crate::{
+        array::{PrimitiveArray, TimeArray, TimestampArray},
+        datatypes::TimeUnit,
+        types::Overflow,
+    };
+    use time::Month;
+
+    let dates = [
+        Date::from_calendar_date(1969, Month::December, 31).ok(),
+        None,
+    ];
+    let array = PrimitiveArray::<i32>::from_dates(dates, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![-1, 0]);
+    let actual = array
+        .as_view()
+        .iter_dates()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, dates);
+
+    let times = [Time::from_hms_micro(23, 59, 59, 999_999).ok()];
+    let array =
+        TimeArray::<i64>::from_times(times, TimeUnit::Microsecond, Overflow::Error).unwrap();
+    assert_eq!(array.values, vec![86_399_999_999]);
+    let actual = array
+        .as_view()
+        .iter_times()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, times);
+
+    let durations = [Some(Duration::nanoseconds(-1_500_000_001))];
+    let array =
+        TimeArray::from_durations(durations, TimeUnit::Nanosecond, Overflow::Error).unwrap();
+    let actual = array
+        .as_view()
+        .iter_durations()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual, durations);
+
+    let array = TimestampArray {
+        unit: TimeUnit::Second,
+        timezone: Some(String::from("-05:30")),
+        validity: None,
+        values: vec![0],
+    };
+    let actual = array
+        .as_view()
+        .iter_timestamps::<OffsetDateTime>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(actual[0].unwrap().offset().whole_minutes(), -330);
+    assert_eq!(actual[0].unwrap().unix_timestamp(), 0);
+
+    for timezone in ["Z", "utc"] {
+        let array = TimestampArray {
+            timezone: Some(String::from(timezone)),
+            ..array.clone()
+        };
+        let actual = array
+            .as_view()
+            .iter_timestamps::<OffsetDateTime>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(actual, vec![Some(OffsetDateTime::UNIX_EPOCH)]);
+    }
+
+    let array = TimestampArray {
+        timezone: Some(String::from("Europe/Berlin")),
+        ..array
+    };
+    // IANA timezones are resolved with the timezone database of chrono or jiff
+    assert!(array
+        .as_view()
+        .iter_timestamps::<OffsetDateTime>()
+        .all(|value| value.is_err() != cfg!(any(feature = "chrono", feature = "jiff"))));
+    assert!(array
+        .as_view()
+        .iter_timestamps::<PrimitiveDateTime>()
+        .all(|value| value.is_ok()));
+}
@@ -0,0 +1,163 @@
+//! Textual representations of dates and timestamps following RFC 3339
+use crate::{
+    datatypes::TimeUnit,
+    error::{fail, ErrorKind, Result},
+    interval::{civil_from_days, days_from_civil, days_in_month},
+};
+
+use super::{nanoseconds_per_unit, parse_fixed_offset, NANOSECONDS_PER_SECOND};
+
+const NANOSECONDS_PER_DAY: i128 = 86_400 * NANOSECONDS_PER_SECOND;
+
+/// Format the number of days since the Unix epoch as `YYYY-MM-DD`
+pub(crate) fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Parse a date of the form `YYYY-MM-DD` into the number of days since the Unix epoch
+pub(crate) fn parse_date(s: &str) -> Result<i64> {
+    let Some(days) = try_parse_date(s) else {
+        fail!(ErrorKind::ParseError, "Invalid date {s:?}");
+    };
+    Ok(days)
+}
+
+/// Format a timestamp as `YYYY-MM-DDTHH:MM:SS` with the fractional digits of the unit
+///
+/// If `utc` is `true`, the suffix `Z` is appended.
+pub(crate) fn format_timestamp(value: i64, unit: TimeUnit, utc: bool) -> String {
+    let nanoseconds = i128::from(value) * i128::from(nanoseconds_per_unit(unit));
+    let days = nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64;
+    let time = nanoseconds.rem_euclid(NANOSECONDS_PER_DAY);
+
+    let seconds = time / NANOSECONDS_PER_SECOND;
+    let fraction = time % NANOSECONDS_PER_SECOND;
+    let mut res = format!(
+        "{date}T{hours:02}:{minutes:02}:{seconds:02}",
+        date = format_date(days),
+        hours = seconds / 3600,
+        minutes = seconds / 60 % 60,
+        seconds = seconds % 60,
+    );
+    match unit {
+        TimeUnit::Second => {}
+        TimeUnit::Millisecond => res.push_str(&format!(".{:03}", fraction / 1_000_000)),
+        TimeUnit::Microsecond => res.push_str(&format!(".{:06}", fraction / 1_000)),
+        TimeUnit::Nanosecond => res.push_str(&format!(".{fraction:09}")),
+    }
+    if utc {
+        res.push('Z');
+    }
+    res
+}
+
+/// Parse a timestamp of the form `YYYY-MM-DDTHH:MM:SS[.fraction][Z|±HH:MM]` into the given unit
+///
+/// Timestamps without offset are interpreted as UTC. A space may be used instead of `T`. Fractions
+/// of the unit are truncated towards negative infinity.
+pub(crate) fn parse_timestamp(s: &str, unit: TimeUnit) -> Result<i64> {
+    let Some(nanoseconds) = try_parse_timestamp(s) else {
+        fail!(ErrorKind::ParseError, "Invalid timestamp {s:?}");
+    };
+    let value = nanoseconds.div_euclid(i128::from(nanoseconds_per_unit(unit)));
+    Ok(i64::try_from(value)?)
+}
+
+fn try_parse_date(s: &str) -> Option<i64> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = parse_digits(&s[0..4])?;
+    let month = parse_digits(&s[5..7])? as u32;
+    let day = parse_digits(&s[8..10])? as u32;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+fn try_parse_timestamp(s: &str) -> Option<i128> {
+    if !s.is_ascii() || s.len() < 19 || !matches!(s.as_bytes()[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let days = try_parse_date(&s[..10])?;
+
+    let time = &s[11..19];
+    if time.as_bytes()[2] != b':' || time.as_bytes()[5] != b':' {
+        return None;
+    }
+    let hours = parse_digits(&time[0..2])?;
+    let minutes = parse_digits(&time[3..5])?;
+    let seconds = parse_digits(&time[6..8])?;
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut fraction = 0;
+    if let Some(digits) = rest.strip_prefix('.') {
+        let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        let (digits, tail) = digits.split_at(len);
+        let (digits, truncated) = digits.split_at(len.min(9));
+        if !truncated.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        fraction = i128::from(parse_digits(digits)?) * 10_i128.pow(9 - digits.len() as u32);
+        rest = tail;
+    }
+
+    let offset = match rest {
+        "" | "Z" | "z" => 0,
+        offset => parse_fixed_offset(offset)?,
+    };
+    let seconds = hours * 3600 + minutes * 60 + seconds - i64::from(offset);
+    Some(
+        i128::from(days) * NANOSECONDS_PER_DAY
+            + i128::from(seconds) * NANOSECONDS_PER_SECOND
+            + fraction,
+    )
+}
+
+fn parse_digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[test]
+fn dates_and_timestamps() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(-1), "1969-12-31");
+    assert_eq!(parse_date("2024-02-29").unwrap(), 19_782);
+    assert!(parse_date("2023-02-29").is_err());
+    assert!(parse_date("2023-1-01").is_err());
+
+    assert_eq!(
+        format_timestamp(1_700_000_000_123, TimeUnit::Millisecond, true),
+        "2023-11-14T22:13:20.123Z"
+    );
+    assert_eq!(
+        format_timestamp(-1, TimeUnit::Second, false),
+        "1969-12-31T23:59:59"
+    );
+    assert_eq!(
+        parse_timestamp("2023-11-14T22:13:20.123Z", TimeUnit::Millisecond).unwrap(),
+        1_700_000_000_123
+    );
+    assert_eq!(
+        parse_timestamp("2023-11-14 23:13:20.1239+01:00", TimeUnit::Millisecond).unwrap(),
+        1_700_000_000_123
+    );
+    assert_eq!(
+        parse_timestamp("1969-12-31T23:59:59.5", TimeUnit::Second).unwrap(),
+        -1
+    );
+    assert!(parse_timestamp("2023-11-14T24:00:00", TimeUnit::Second).is_err());
+    assert!(parse_timestamp("2023-11-14T22:13:20.", TimeUnit::Second).is_err());
+}
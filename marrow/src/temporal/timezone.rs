@@ -0,0 +1,427 @@
+//! Validation of timezones and conversions between them
+//!
+//! Fixed offsets are supported directly. IANA timezones are resolved with the timezone database
+//! of `jiff` or `chrono-tz`, if the `jiff` or `chrono` feature is enabled.
+use std::cell::OnceCell;
+
+use crate::{
+    array::TimestampArray,
+    error::{fail, ErrorKind, Result},
+    view::BitsWithOffset,
+};
+
+use super::{nanoseconds_per_unit, parse_fixed_offset, NANOSECONDS_PER_SECOND};
+
+/// Validate a timezone and bring it into its canonical form
+///
+/// - fixed offsets are formatted as `+HH:MM`, e.g., `"+0100"` becomes `"+01:00"`
+/// - `"Z"` and `"UTC"` in any capitalization become `"UTC"`
+/// - IANA names are checked against the timezone database and normalized to their canonical
+///   capitalization, if the `jiff` or `chrono` feature is enabled. Otherwise, only their syntax is
+///   checked.
+///
+/// ```rust
+/// # use marrow::temporal::canonicalize_timezone;
+/// assert_eq!(canonicalize_timezone("-0530").unwrap(), "-05:30");
+/// assert_eq!(canonicalize_timezone("z").unwrap(), "UTC");
+/// assert_eq!(canonicalize_timezone("America/New_York").unwrap(), "America/New_York");
+/// assert!(canonicalize_timezone("+25:00").is_err());
+/// assert!(canonicalize_timezone("America//New_York").is_err());
+/// ```
+pub fn canonicalize_timezone(timezone: &str) -> Result<String> {
+    if is_utc(timezone) {
+        return Ok(String::from("UTC"));
+    }
+    if let Some(offset) = parse_fixed_offset(timezone) {
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        return Ok(format!(
+            "{sign}{hours:02}:{minutes:02}",
+            hours = offset / 3600,
+            minutes = offset % 3600 / 60
+        ));
+    }
+    if !is_valid_name(timezone) {
+        fail!(ErrorKind::ParseError, "Invalid timezone {timezone:?}");
+    }
+    NamedTimeZone::canonical_name(timezone)
+}
+
+fn is_utc(timezone: &str) -> bool {
+    timezone.eq_ignore_ascii_case("utc") || timezone.eq_ignore_ascii_case("z")
+}
+
+/// Check the syntax of IANA names, e.g., `"America/Port-au-Prince"` or `"Etc/GMT+1"`
+fn is_valid_name(timezone: &str) -> bool {
+    timezone.split('/').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic())
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    })
+}
+
+/// A timezone resolved to compute offsets from UTC
+pub(crate) enum ResolvedTimeZone {
+    Fixed(i32),
+    Named(NamedTimeZone),
+}
+
+impl ResolvedTimeZone {
+    /// Resolve the timezone of an array, timestamps without timezone are interpreted as UTC
+    pub fn new(timezone: Option<&str>) -> Result<Self> {
+        let Some(timezone) = timezone else {
+            return Ok(Self::Fixed(0));
+        };
+        if is_utc(timezone) {
+            return Ok(Self::Fixed(0));
+        }
+        if let Some(offset) = parse_fixed_offset(timezone) {
+            return Ok(Self::Fixed(offset));
+        }
+        Ok(Self::Named(NamedTimeZone::get(timezone)?))
+    }
+
+    /// The offset in seconds at the given UTC timestamp in seconds
+    pub fn offset_from_utc(&self, seconds: i64) -> Result<i32> {
+        match self {
+            Self::Fixed(offset) => Ok(*offset),
+            Self::Named(tz) => tz.offset_from_utc(seconds),
+        }
+    }
+
+    /// The offset in seconds for the given local time in seconds
+    ///
+    /// Ambiguous local times resolve to the earlier time. Local times that are skipped by a
+    /// transition use the offset before the transition.
+    pub fn offset_from_local(&self, seconds: i64) -> Result<i32> {
+        match self {
+            Self::Fixed(offset) => Ok(*offset),
+            Self::Named(tz) => tz.offset_from_local(seconds),
+        }
+    }
+}
+
+/// The timezone of a timestamp view, resolved once for all of its values
+///
+/// Views without timezone and `"UTC"` or `"Z"` in any capitalization are interpreted as UTC and
+/// fixed offsets (e.g., `"+01:00"`) are recognized directly. IANA names are looked up on first use
+/// and reused for the following values.
+///
+/// ```rust
+/// # use marrow::temporal::TimestampZone;
+/// assert_eq!(TimestampZone::new(None).fixed_offset(), Some(0));
+/// assert_eq!(TimestampZone::new(Some("z")).fixed_offset(), Some(0));
+/// assert_eq!(TimestampZone::new(Some("+01:00")).fixed_offset(), Some(3600));
+/// assert_eq!(TimestampZone::new(Some("Europe/Berlin")).fixed_offset(), None);
+/// ```
+pub struct TimestampZone<'a> {
+    name: Option<&'a str>,
+    fixed_offset: Option<i32>,
+    resolved: OnceCell<ResolvedTimeZone>,
+    #[cfg(feature = "chrono")]
+    chrono: OnceCell<chrono_tz::Tz>,
+}
+
+impl<'a> TimestampZone<'a> {
+    /// Classify the timezone of a view without resolving IANA names
+    pub fn new(timezone: Option<&'a str>) -> Self {
+        let fixed_offset = match timezone {
+            None => Some(0),
+            Some(timezone) if is_utc(timezone) => Some(0),
+            Some(timezone) => parse_fixed_offset(timezone),
+        };
+        Self {
+            name: timezone,
+            fixed_offset,
+            resolved: OnceCell::new(),
+            #[cfg(feature = "chrono")]
+            chrono: OnceCell::new(),
+        }
+    }
+
+    /// The timezone as stored in the view
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// The offset in seconds east of UTC for fixed offsets and UTC, `None` for IANA names
+    pub fn fixed_offset(&self) -> Option<i32> {
+        self.fixed_offset
+    }
+
+    /// The offset in seconds at the given UTC timestamp in seconds
+    ///
+    /// IANA names require the `chrono` or `jiff` feature.
+    pub fn offset_from_utc(&self, seconds: i64) -> Result<i32> {
+        if let Some(offset) = self.fixed_offset {
+            return Ok(offset);
+        }
+        self.resolve()?.offset_from_utc(seconds)
+    }
+
+    fn resolve(&self) -> Result<&ResolvedTimeZone> {
+        if let Some(resolved) = self.resolved.get() {
+            return Ok(resolved);
+        }
+        let resolved = ResolvedTimeZone::new(self.name)?;
+        Ok(self.resolved.get_or_init(|| resolved))
+    }
+
+    /// The `jiff` timezone of the view
+    #[cfg(feature = "jiff")]
+    pub(crate) fn jiff_timezone(&self) -> Result<jiff::tz::TimeZone> {
+        match self.resolve()? {
+            ResolvedTimeZone::Fixed(0) => Ok(jiff::tz::TimeZone::UTC),
+            ResolvedTimeZone::Fixed(offset) => Ok(jiff::tz::TimeZone::fixed(
+                jiff::tz::Offset::from_seconds(*offset)?,
+            )),
+            ResolvedTimeZone::Named(tz) => Ok(tz.0.clone()),
+        }
+    }
+
+    /// The `chrono-tz` timezone, UTC maps to `Tz::UTC` and other fixed offsets are not supported
+    #[cfg(feature = "chrono")]
+    pub(crate) fn chrono_timezone(&self) -> Result<chrono_tz::Tz> {
+        match (self.fixed_offset, self.name) {
+            (Some(0), _) => return Ok(chrono_tz::Tz::UTC),
+            (Some(_), Some(timezone)) => fail!(
+                ErrorKind::Unsupported,
+                "Cannot represent the fixed offset {timezone:?} as a chrono_tz::Tz"
+            ),
+            _ => {}
+        }
+        if let Some(tz) = self.chrono.get() {
+            return Ok(*tz);
+        }
+        let timezone = self.name.unwrap_or_default();
+        let Ok(tz) = timezone.parse::<chrono_tz::Tz>() else {
+            fail!(ErrorKind::ParseError, "Unknown timezone {timezone:?}");
+        };
+        Ok(*self.chrono.get_or_init(|| tz))
+    }
+}
+
+#[cfg(feature = "jiff")]
+pub(crate) struct NamedTimeZone(jiff::tz::TimeZone);
+
+#[cfg(feature = "jiff")]
+impl NamedTimeZone {
+    fn get(timezone: &str) -> Result<Self> {
+        Ok(Self(jiff::tz::TimeZone::get(timezone)?))
+    }
+
+    fn canonical_name(timezone: &str) -> Result<String> {
+        let tz = Self::get(timezone)?;
+        Ok(String::from(tz.0.iana_name().unwrap_or(timezone)))
+    }
+
+    fn offset_from_utc(&self, seconds: i64) -> Result<i32> {
+        let timestamp = jiff::Timestamp::from_second(seconds)?;
+        Ok(self.0.to_offset(timestamp).seconds())
+    }
+
+    fn offset_from_local(&self, seconds: i64) -> Result<i32> {
+        let local = jiff::tz::TimeZone::UTC.to_datetime(jiff::Timestamp::from_second(seconds)?);
+        let timestamp = self.0.to_ambiguous_timestamp(local).compatible()?;
+        Ok(i32::try_from(seconds - timestamp.as_second())?)
+    }
+}
+
+#[cfg(all(feature = "chrono", not(feature = "jiff")))]
+pub(crate) struct NamedTimeZone(chrono_tz::Tz);
+
+#[cfg(all(feature = "chrono", not(feature = "jiff")))]
+impl NamedTimeZone {
+    fn get(timezone: &str) -> Result<Self> {
+        match timezone.parse::<chrono_tz::Tz>() {
+            Ok(tz) => Ok(Self(tz)),
+            Err(_) => fail!(ErrorKind::ParseError, "Unknown timezone {timezone:?}"),
+        }
+    }
+
+    fn canonical_name(timezone: &str) -> Result<String> {
+        Ok(String::from(Self::get(timezone)?.0.name()))
+    }
+
+    fn naive_datetime(seconds: i64) -> Result<chrono::NaiveDateTime> {
+        match chrono::DateTime::from_timestamp(seconds, 0) {
+            Some(value) => Ok(value.naive_utc()),
+            None => fail!(
+                ErrorKind::Unsupported,
+                "Timestamp {seconds}s is out of range"
+            ),
+        }
+    }
+
+    fn offset_from_utc(&self, seconds: i64) -> Result<i32> {
+        use chrono::{Offset, TimeZone};
+
+        let value = Self::naive_datetime(seconds)?;
+        Ok(self
+            .0
+            .offset_from_utc_datetime(&value)
+            .fix()
+            .local_minus_utc())
+    }
+
+    fn offset_from_local(&self, seconds: i64) -> Result<i32> {
+        use chrono::{LocalResult, Offset, TimeZone};
+
+        let value = Self::naive_datetime(seconds)?;
+        match self.0.offset_from_local_datetime(&value) {
+            LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => {
+                Ok(offset.fix().local_minus_utc())
+            }
+            // transitions are far apart, the offset a day earlier is the one before the gap
+            LocalResult::None => self.offset_from_utc(seconds - 86_400),
+        }
+    }
+}
+
+#[cfg(not(any(feature = "chrono", feature = "jiff")))]
+pub(crate) enum NamedTimeZone {}
+
+#[cfg(not(any(feature = "chrono", feature = "jiff")))]
+impl NamedTimeZone {
+    fn get(timezone: &str) -> Result<Self> {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot resolve timezone {timezone:?}: IANA timezones require the chrono or jiff feature"
+        )
+    }
+
+    fn canonical_name(timezone: &str) -> Result<String> {
+        Ok(String::from(timezone))
+    }
+
+    fn offset_from_utc(&self, _seconds: i64) -> Result<i32> {
+        match *self {}
+    }
+
+    fn offset_from_local(&self, _seconds: i64) -> Result<i32> {
+        match *self {}
+    }
+}
+
+impl TimestampArray {
+    /// Change the timezone without changing the represented points in time
+    ///
+    /// As the values are stored in UTC, they are not modified. The timezone is canonicalized with
+    /// [`canonicalize_timezone`].
+    pub fn with_timezone(mut self, timezone: Option<String>) -> Result<Self> {
+        self.timezone = timezone.as_deref().map(canonicalize_timezone).transpose()?;
+        Ok(self)
+    }
+
+    /// Change the timezone while keeping the local wall clock times
+    ///
+    /// For example, `2024-01-01T12:00` in `UTC` becomes `2024-01-01T12:00` in `+01:00`, i.e.,
+    /// `2024-01-01T11:00Z`. Arrays without timezone are interpreted as UTC. Ambiguous local times
+    /// resolve to the earlier point in time, local times skipped by a transition are interpreted
+    /// with the offset before the transition.
+    ///
+    /// ```rust
+    /// # use marrow::{array::TimestampArray, datatypes::TimeUnit};
+    /// let array = TimestampArray {
+    ///     unit: TimeUnit::Second,
+    ///     timezone: None,
+    ///     validity: None,
+    ///     values: vec![43_200],
+    /// };
+    /// let array = array.reinterpret_timezone(Some(String::from("+0100"))).unwrap();
+    /// assert_eq!(array.timezone.as_deref(), Some("+01:00"));
+    /// assert_eq!(array.values, vec![39_600]);
+    /// ```
+    pub fn reinterpret_timezone(mut self, timezone: Option<String>) -> Result<Self> {
+        let timezone = timezone.as_deref().map(canonicalize_timezone).transpose()?;
+        let source = ResolvedTimeZone::new(self.timezone.as_deref())?;
+        let target = ResolvedTimeZone::new(timezone.as_deref())?;
+
+        let units_per_second =
+            i64::try_from(NANOSECONDS_PER_SECOND / i128::from(nanoseconds_per_unit(self.unit)))?;
+        for (idx, value) in self.values.iter_mut().enumerate() {
+            let validity = self.validity.as_deref();
+            if validity.is_some_and(|data| !BitsWithOffset { offset: 0, data }.get(idx)) {
+                continue;
+            }
+            let seconds = value.div_euclid(units_per_second);
+            let source_offset = source.offset_from_utc(seconds)?;
+            let target_offset = target.offset_from_local(seconds + i64::from(source_offset))?;
+
+            let shift = i64::from(source_offset - target_offset) * units_per_second;
+            let Some(shifted) = value.checked_add(shift) else {
+                fail!(
+                    ErrorKind::Unsupported,
+                    "Overflow when moving the timestamp {value} into timezone {timezone:?}"
+                );
+            };
+            *value = shifted;
+        }
+        self.timezone = timezone;
+        Ok(self)
+    }
+}
+
+#[test]
+fn timezones() {
+    assert_eq!(canonicalize_timezone("+01").unwrap(), "+01:00");
+    assert_eq!(canonicalize_timezone("-00:00").unwrap(), "+00:00");
+    assert_eq!(canonicalize_timezone("UTC").unwrap(), "UTC");
+    assert_eq!(canonicalize_timezone("Etc/GMT+1").unwrap(), "Etc/GMT+1");
+    assert!(canonicalize_timezone("").is_err());
+    assert!(canonicalize_timezone("Europe/").is_err());
+    assert!(canonicalize_timezone("+01:00:00").is_err());
+
+    let array = TimestampArray {
+        unit: crate::datatypes::TimeUnit::Millisecond,
+        timezone: Some(String::from("+02:00")),
+        validity: Some(vec![0b_01]),
+        values: vec![0, i64::MAX],
+    };
+    let relabeled = array
+        .clone()
+        .with_timezone(Some(String::from("z")))
+        .unwrap();
+    assert_eq!(relabeled.timezone.as_deref(), Some("UTC"));
+    assert_eq!(relabeled.values, array.values);
+
+    let reinterpreted = array.reinterpret_timezone(None).unwrap();
+    assert_eq!(reinterpreted.timezone, None);
+    assert_eq!(reinterpreted.values, vec![7_200_000, i64::MAX]);
+}
+
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+#[test]
+fn named_timezones() {
+    use crate::datatypes::TimeUnit;
+
+    let array = TimestampArray {
+        unit: TimeUnit::Second,
+        timezone: Some(String::from("UTC")),
+        validity: None,
+        // 2024-01-15T12:00Z, 2024-07-15T12:00Z, 2024-03-31T02:30Z
+        values: vec![1_705_320_000, 1_721_044_800, 1_711_852_200],
+    };
+    let actual = array
+        .reinterpret_timezone(Some(String::from("Europe/Berlin")))
+        .unwrap();
+    assert_eq!(actual.timezone.as_deref(), Some("Europe/Berlin"));
+    // 02:30 local time does not exist on 2024-03-31, the offset before the gap (+01:00) is used
+    assert_eq!(
+        actual.values,
+        vec![
+            1_705_320_000 - 3_600,
+            1_721_044_800 - 7_200,
+            1_711_852_200 - 3_600
+        ],
+    );
+
+    let actual = actual.reinterpret_timezone(None).unwrap();
+    assert_eq!(
+        actual.values,
+        vec![1_705_320_000, 1_721_044_800, 1_711_852_200 + 3_600]
+    );
+
+    assert!(canonicalize_timezone("Not/A_Timezone").is_err());
+}
@@ -1,7 +1,18 @@
-//! Specialized element types of arrays
+//! Specialized element types of arrays and options of conversions
+use std::cmp::Ordering;
+
+use half::f16;
+
+use crate::{
+    array::{Array, DecimalArray, PrimitiveArray},
+    datatypes::{DataType, IntervalUnit},
+    error::{fail, ErrorKind, Result},
+    view::{PrimitiveView, View},
+};
 
 /// Represent a calendar interval as days and milliseconds
-#[derive(Debug, PartialEq, Clone, Copy, bytemuck::AnyBitPattern, bytemuck::NoUninit)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, bytemuck::AnyBitPattern, bytemuck::NoUninit)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct DayTimeInterval {
     /// The number of days in the interval
@@ -11,7 +22,8 @@ pub struct DayTimeInterval {
 }
 
 /// Represent a calendar interval as months, days and nanoseconds
-#[derive(Debug, PartialEq, Clone, Copy, bytemuck::AnyBitPattern, bytemuck::NoUninit)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, bytemuck::AnyBitPattern, bytemuck::NoUninit)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct MonthDayNanoInterval {
     /// The number of months in the interval
@@ -22,6 +34,288 @@ pub struct MonthDayNanoInterval {
     pub nanoseconds: i64,
 }
 
+/// How to handle values that cannot be represented in the target type
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Return an error
+    #[default]
+    Error,
+    /// Clamp the value to the smallest or largest representable value
+    Saturate,
+    /// Store the value as missing
+    Null,
+}
+
+/// How to round values that cannot be represented exactly in the target unit
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Return an error, if the value cannot be represented exactly
+    #[default]
+    Exact,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round towards zero
+    TowardZero,
+    /// Round to the nearest value, ties are rounded away from zero
+    HalfAwayFromZero,
+    /// Round to the nearest value, ties are rounded to the nearest even value
+    HalfEven,
+}
+
+impl Rounding {
+    /// Whether a truncated value with a non-zero remainder is rounded away from zero
+    ///
+    /// `odd` is whether the truncated value is odd and `half` compares the remainder to half of
+    /// the unit. Returns `None` for [`Rounding::Exact`].
+    pub(crate) fn rounds_away_from_zero(
+        self,
+        negative: bool,
+        odd: bool,
+        half: Ordering,
+    ) -> Option<bool> {
+        match self {
+            Rounding::Exact => None,
+            Rounding::Floor => Some(negative),
+            Rounding::Ceil => Some(!negative),
+            Rounding::TowardZero => Some(false),
+            Rounding::HalfAwayFromZero => Some(half != Ordering::Less),
+            Rounding::HalfEven => Some(match half {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => odd,
+            }),
+        }
+    }
+
+    /// Divide `value` by the positive `divisor`
+    pub(crate) fn divide(self, value: i128, divisor: i128) -> Result<i128> {
+        let quotient = value / divisor;
+        let remainder = (value % divisor).unsigned_abs();
+        if remainder == 0 {
+            return Ok(quotient);
+        }
+
+        // the remainder is compared to the distance to the next multiple to avoid overflows
+        let half = remainder.cmp(&(divisor.unsigned_abs() - remainder));
+        let Some(away_from_zero) = self.rounds_away_from_zero(value < 0, quotient % 2 != 0, half)
+        else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot divide {value} by {divisor} without loss of precision"
+            );
+        };
+        Ok(match (away_from_zero, value < 0) {
+            (false, _) => quotient,
+            (true, false) => quotient + 1,
+            (true, true) => quotient - 1,
+        })
+    }
+}
+
+impl Overflow {
+    pub(crate) fn apply<T: Integer>(self, value: i128) -> Result<Option<T>> {
+        if let Ok(value) = T::try_from(value) {
+            return Ok(Some(value));
+        }
+        match self {
+            Overflow::Error => fail!(
+                ErrorKind::Unsupported,
+                "Value {value} is not in the range of {ty}",
+                ty = std::any::type_name::<T>(),
+            ),
+            Overflow::Saturate if value < 0 => Ok(Some(T::MIN)),
+            Overflow::Saturate => Ok(Some(T::MAX)),
+            Overflow::Null => Ok(None),
+        }
+    }
+}
+
+pub(crate) trait Integer: TryFrom<i128> {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+impl Integer for i32 {
+    const MIN: Self = i32::MIN;
+    const MAX: Self = i32::MAX;
+}
+
+impl Integer for i64 {
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+}
+
+/// Element types of primitive arrays
+///
+/// The trait allows to write code that is generic over the element type of primitive arrays, e.g.,
+/// using [`View::as_primitive`] or [`Array::as_primitive`]. It is implemented for all types that
+/// are stored in primitive arrays and cannot be implemented outside of `marrow`.
+///
+/// `i128` is an exception: it can only be used to construct arrays, e.g., with
+/// [`Array::from_iter_typed`], which results in `Decimal128(38, 0)` arrays. As decimal arrays carry
+/// additional metadata, `as_primitive::<i128>()` fails for all arrays and views. The values of
+/// decimal arrays are accessible via their `values` field.
+///
+/// ```rust
+/// # use marrow::{types::MarrowPrimitive, view::View};
+/// # fn main() -> marrow::error::Result<()> {
+/// fn count_valid<T: MarrowPrimitive>(view: &View<'_>) -> marrow::error::Result<usize> {
+///     Ok(view.as_primitive::<T>()?.iter().flatten().count())
+/// }
+///
+/// let array = marrow::array::Array::Int64(marrow::array::PrimitiveArray {
+///     validity: Some(marrow::bit_vec![true, false, true]),
+///     values: vec![1, 2, 3],
+/// });
+/// assert_eq!(count_valid::<i64>(&array.as_view())?, 2);
+/// assert!(count_valid::<i32>(&array.as_view()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub trait MarrowPrimitive: private::Sealed + Copy + Default + Send + Sync + 'static {
+    /// The data type of arrays with this element type
+    ///
+    /// For `i128` the data type is `Decimal128(38, 0)`, see the trait documentation for the
+    /// limitations of `i128`.
+    const DATA_TYPE: DataType;
+}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed: Sized {
+        fn from_view<'a, 'v>(view: &'v View<'a>) -> Option<&'v PrimitiveView<'a, Self>>;
+        fn from_array(array: &Array) -> Option<&PrimitiveArray<Self>>;
+        fn from_array_mut(array: &mut Array) -> Option<&mut PrimitiveArray<Self>>;
+        fn into_array(array: PrimitiveArray<Self>) -> Array;
+    }
+}
+
+macro_rules! impl_marrow_primitive {
+    (
+        $ty:ty,
+        $data_type:expr,
+        into_array: $into_array:expr,
+        primitive: [$($primitive:ident),*] $(,)?
+    ) => {
+        impl private::Sealed for $ty {
+            #[allow(clippy::match_single_binding)]
+            fn from_view<'a, 'v>(view: &'v View<'a>) -> Option<&'v PrimitiveView<'a, Self>> {
+                match view {
+                    $(View::$primitive(view) => Some(view),)*
+                    _ => None,
+                }
+            }
+
+            #[allow(clippy::match_single_binding)]
+            fn from_array(array: &Array) -> Option<&PrimitiveArray<Self>> {
+                match array {
+                    $(Array::$primitive(array) => Some(array),)*
+                    _ => None,
+                }
+            }
+
+            #[allow(clippy::match_single_binding)]
+            fn from_array_mut(array: &mut Array) -> Option<&mut PrimitiveArray<Self>> {
+                match array {
+                    $(Array::$primitive(array) => Some(array),)*
+                    _ => None,
+                }
+            }
+
+            fn into_array(array: PrimitiveArray<Self>) -> Array {
+                ($into_array)(array)
+            }
+        }
+
+        impl MarrowPrimitive for $ty {
+            const DATA_TYPE: DataType = $data_type;
+        }
+    };
+}
+
+impl_marrow_primitive!(i8, DataType::Int8, into_array: Array::Int8, primitive: [Int8]);
+impl_marrow_primitive!(
+    i16,
+    DataType::Int16,
+    into_array: Array::Int16,
+    primitive: [Int16],
+);
+impl_marrow_primitive!(
+    i32,
+    DataType::Int32,
+    into_array: Array::Int32,
+    primitive: [Int32, Date32, YearMonthInterval],
+);
+impl_marrow_primitive!(
+    i64,
+    DataType::Int64,
+    into_array: Array::Int64,
+    primitive: [Int64, Date64],
+);
+impl_marrow_primitive!(u8, DataType::UInt8, into_array: Array::UInt8, primitive: [UInt8]);
+impl_marrow_primitive!(
+    u16,
+    DataType::UInt16,
+    into_array: Array::UInt16,
+    primitive: [UInt16],
+);
+impl_marrow_primitive!(
+    u32,
+    DataType::UInt32,
+    into_array: Array::UInt32,
+    primitive: [UInt32],
+);
+impl_marrow_primitive!(
+    u64,
+    DataType::UInt64,
+    into_array: Array::UInt64,
+    primitive: [UInt64],
+);
+impl_marrow_primitive!(
+    f16,
+    DataType::Float16,
+    into_array: Array::Float16,
+    primitive: [Float16],
+);
+impl_marrow_primitive!(
+    f32,
+    DataType::Float32,
+    into_array: Array::Float32,
+    primitive: [Float32],
+);
+impl_marrow_primitive!(
+    f64,
+    DataType::Float64,
+    into_array: Array::Float64,
+    primitive: [Float64],
+);
+impl_marrow_primitive!(
+    i128,
+    DataType::Decimal128(38, 0),
+    into_array: |array: PrimitiveArray<i128>| Array::Decimal128(DecimalArray {
+        precision: 38,
+        scale: 0,
+        validity: array.validity,
+        values: array.values,
+    }),
+    primitive: [],
+);
+impl_marrow_primitive!(
+    DayTimeInterval,
+    DataType::Interval(IntervalUnit::DayTime),
+    into_array: Array::DayTimeInterval,
+    primitive: [DayTimeInterval],
+);
+impl_marrow_primitive!(
+    MonthDayNanoInterval,
+    DataType::Interval(IntervalUnit::MonthDayNano),
+    into_array: Array::MonthDayNanoInterval,
+    primitive: [MonthDayNanoInterval],
+);
+
 #[test]
 fn interval_sizes() {
     assert_eq!(
@@ -33,3 +327,81 @@ fn interval_sizes() {
         std::mem::size_of::<i128>()
     );
 }
+
+#[test]
+fn primitive_accessors() {
+    use crate::array::{DecimalArray, TimestampArray};
+    use crate::datatypes::TimeUnit;
+
+    let mut dates = Array::Date64(PrimitiveArray {
+        validity: Some(vec![0b_01]),
+        values: vec![1, 2],
+    });
+    let view = dates.as_view();
+    let view = view.as_primitive::<i64>().unwrap();
+    assert_eq!(view.iter().collect::<Vec<_>>(), vec![Some(1), None]);
+    assert!(dates.as_primitive_mut::<i64>().is_ok());
+
+    // views and arrays with additional metadata are rejected consistently
+    let timestamps = Array::Timestamp(TimestampArray {
+        unit: TimeUnit::Second,
+        timezone: None,
+        validity: None,
+        values: vec![1, 2],
+    });
+    assert!(timestamps.as_view().as_primitive::<i64>().is_err());
+    assert!(timestamps.as_primitive::<i64>().is_err());
+
+    let decimals = Array::Decimal128(DecimalArray {
+        precision: 5,
+        scale: 2,
+        validity: Some(vec![0b_01]),
+        values: vec![100, 200],
+    });
+    assert!(decimals.as_view().as_primitive::<i128>().is_err());
+    assert!(decimals.as_primitive::<i128>().is_err());
+
+    // i128 can only be used to construct arrays
+    let mut decimals = Array::from_iter_typed([Some(1_i128), None]);
+    assert_eq!(decimals.data_type(), DataType::Decimal128(38, 0));
+    assert!(decimals.as_view().as_primitive::<i128>().is_err());
+    assert!(decimals.as_primitive::<i128>().is_err());
+    assert!(decimals.as_primitive_mut::<i128>().is_err());
+
+    let err = decimals.as_view().as_primitive::<u8>().unwrap_err();
+    assert!(err.to_string().contains("as a primitive view of u8"));
+    let err = decimals.as_struct().unwrap_err();
+    assert!(err.to_string().contains("Cannot access an array of type"));
+    let err = decimals.as_view().as_struct().unwrap_err();
+    assert!(err.to_string().contains("Cannot access a view of type"));
+}
+
+#[test]
+fn rounding_modes() {
+    let divide = |rounding: Rounding, value: i128| rounding.divide(value, 10).ok();
+
+    assert_eq!(divide(Rounding::Exact, -20), Some(-2));
+    assert_eq!(divide(Rounding::Exact, -25), None);
+    assert_eq!(divide(Rounding::Floor, -25), Some(-3));
+    assert_eq!(divide(Rounding::Ceil, -25), Some(-2));
+    assert_eq!(divide(Rounding::TowardZero, -25), Some(-2));
+    assert_eq!(divide(Rounding::TowardZero, 25), Some(2));
+    assert_eq!(divide(Rounding::HalfAwayFromZero, -25), Some(-3));
+    assert_eq!(divide(Rounding::HalfAwayFromZero, 25), Some(3));
+    assert_eq!(divide(Rounding::HalfAwayFromZero, 24), Some(2));
+    assert_eq!(divide(Rounding::HalfEven, 25), Some(2));
+    assert_eq!(divide(Rounding::HalfEven, -25), Some(-2));
+    assert_eq!(divide(Rounding::HalfEven, 35), Some(4));
+    assert_eq!(divide(Rounding::HalfEven, -36), Some(-4));
+}
+
+#[test]
+fn overflow_modes() {
+    assert_eq!(Overflow::Error.apply::<i32>(1 << 40).ok(), None);
+    assert_eq!(
+        Overflow::Saturate.apply::<i32>(-(1 << 40)).unwrap(),
+        Some(i32::MIN)
+    );
+    assert_eq!(Overflow::Null.apply::<i32>(1 << 40).unwrap(), None);
+    assert_eq!(Overflow::Null.apply::<i64>(1 << 40).unwrap(), Some(1 << 40));
+}
@@ -4,14 +4,24 @@
 use half::f16;
 
 use crate::{
+    array::define_accessors,
     datatypes::{
         field_from_meta, DataType, Field, FieldMeta, IntervalUnit, MapMeta, RunEndEncodedMeta,
         TimeUnit, UnionMode,
     },
     error::{fail, ErrorKind, Result},
-    types::{DayTimeInterval, MonthDayNanoInterval},
+    types::{DayTimeInterval, MarrowPrimitive, MonthDayNanoInterval},
 };
 
+mod iter;
+mod memory;
+#[cfg(feature = "serde")]
+mod serialize;
+mod slice;
+
+pub use memory::BufferSize;
+pub(crate) use slice::slice_view;
+
 // assert that the `Array` implements the expected traits
 #[allow(unused)]
 const _: () = {
@@ -189,6 +199,7 @@ impl View<'_> {
                     data_type: arr.run_ends.data_type(),
                     nullable: false,
                     metadata: Default::default(),
+                    dictionary: Default::default(),
                 }),
                 Box::new(field_from_meta(
                     arr.values.data_type(),
@@ -199,9 +210,44 @@ impl View<'_> {
     }
 }
 
+impl<'a> View<'a> {
+    /// Access the view as a primitive view with elements of type `T`
+    ///
+    /// The same views are accepted as by
+    /// [`Array::as_primitive`][crate::array::Array::as_primitive], e.g., `Date32` and
+    /// `YearMonthInterval` views for `i32`. Views with additional metadata, e.g., `Time32`,
+    /// `Timestamp` or `Decimal128` views, are not accepted. Their values are accessible via the
+    /// `values` field of the corresponding view struct.
+    pub fn as_primitive<T: MarrowPrimitive>(&self) -> Result<&PrimitiveView<'a, T>> {
+        match T::from_view(self) {
+            Some(view) => Ok(view),
+            None => fail!(
+                ErrorKind::Unsupported,
+                "Cannot access a view of type {dt} as a primitive view of {ty}",
+                dt = self.data_type(),
+                ty = std::any::type_name::<T>(),
+            ),
+        }
+    }
+}
+
+define_accessors!(View, [
+    /// Access the view as a struct view
+    (as_struct, StructView<'a>, "a struct view", [Struct]),
+    /// Access the view as a list view with `i32` offsets
+    (as_list, ListView<'a, i32>, "a list view", [List]),
+    /// Access the view as a list view with `i64` offsets
+    (as_large_list, ListView<'a, i64>, "a large list view", [LargeList]),
+    /// Access `Utf8` or `Binary` views as a bytes view with `i32` offsets
+    (as_bytes, BytesView<'a, i32>, "a bytes view", [Utf8, Binary]),
+    /// Access `LargeUtf8` or `LargeBinary` views as a bytes view with `i64` offsets
+    (as_large_bytes, BytesView<'a, i64>, "a large bytes view", [LargeUtf8, LargeBinary]),
+]);
+
 /// A bitmap with an optional offset
 ///
-/// The `i`-th element is stored at bit `offset + i`.
+/// The `i`-th element is stored at bit `offset + i`. See [`bits`][crate::bits] for operations on
+/// bitmaps.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BitsWithOffset<'a> {
     /// The offset of the bits
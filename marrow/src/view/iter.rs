@@ -0,0 +1,309 @@
+//! Iterators over the elements of views
+//!
+//! Missing elements, as marked by the validity, are returned as `None`.
+use crate::{
+    error::{fail, ErrorKind, Result},
+    view::{
+        slice_view, BitsWithOffset, BooleanView, BytesView, BytesViewView, FixedSizeBinaryView,
+        ListView, PrimitiveView, View,
+    },
+};
+
+impl<'a, T: Copy> PrimitiveView<'a, T> {
+    /// Iterate over the elements
+    ///
+    /// ```rust
+    /// # use marrow::view::{BitsWithOffset, PrimitiveView};
+    /// let view = PrimitiveView {
+    ///     validity: Some(BitsWithOffset { offset: 1, data: &[0b_1010] }),
+    ///     values: &[1, 2, 3],
+    /// };
+    /// assert_eq!(view.iter().collect::<Vec<_>>(), vec![Some(1), None, Some(3)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Option<T>> + 'a {
+        let validity = self.validity;
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(idx, value)| is_valid(validity, idx).then_some(*value))
+    }
+}
+
+impl<'a> BooleanView<'a> {
+    /// Iterate over the elements
+    ///
+    /// ```rust
+    /// # use marrow::view::{BitsWithOffset, BooleanView};
+    /// let view = BooleanView {
+    ///     len: 3,
+    ///     validity: Some(BitsWithOffset { offset: 0, data: &[0b_011] }),
+    ///     values: BitsWithOffset { offset: 4, data: &[0b_0001_0000] },
+    /// };
+    /// assert_eq!(view.iter().collect::<Vec<_>>(), vec![Some(true), Some(false), None]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Option<bool>> + 'a {
+        let validity = self.validity;
+        let values = self.values;
+        (0..self.len).map(move |idx| is_valid(validity, idx).then(|| values.get(idx)))
+    }
+}
+
+impl<'a, O: Copy> BytesView<'a, O>
+where
+    usize: TryFrom<O>,
+{
+    /// Iterate over the elements as byte slices
+    ///
+    /// # Panics
+    ///
+    /// If the offsets are negative or reference data outside of `data`.
+    ///
+    /// ```rust
+    /// # use marrow::view::{BitsWithOffset, BytesView};
+    /// let view = BytesView {
+    ///     validity: Some(BitsWithOffset { offset: 0, data: &[0b_101] }),
+    ///     offsets: &[0_i32, 3, 3, 6],
+    ///     data: b"foobar",
+    /// };
+    /// assert_eq!(
+    ///     view.iter().collect::<Vec<_>>(),
+    ///     vec![Some(b"foo".as_slice()), None, Some(b"bar".as_slice())],
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a [u8]>> + 'a {
+        let validity = self.validity;
+        let data = self.data;
+        self.offsets
+            .windows(2)
+            .enumerate()
+            .map(move |(idx, window)| {
+                is_valid(validity, idx).then(|| &data[to_index(window[0])..to_index(window[1])])
+            })
+    }
+
+    /// Iterate over the elements as strings, checking that they are valid UTF-8
+    ///
+    /// ```rust
+    /// # use marrow::view::BytesView;
+    /// # fn main() -> marrow::error::Result<()> {
+    /// let view = BytesView {
+    ///     validity: None,
+    ///     offsets: &[0_i64, 3, 6],
+    ///     data: b"foo\xffar",
+    /// };
+    /// let mut iter = view.iter_str();
+    /// assert_eq!(iter.next().transpose()?, Some(Some("foo")));
+    /// assert!(iter.next().transpose().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_str(&self) -> impl Iterator<Item = Result<Option<&'a str>>> + 'a {
+        self.iter().map(to_str)
+    }
+}
+
+impl<'a> BytesViewView<'a> {
+    /// Iterate over the elements as byte slices, resolving inline and buffer views
+    ///
+    /// # Panics
+    ///
+    /// If a view references data outside of the buffers.
+    ///
+    /// ```rust
+    /// # use marrow::view::BytesViewView;
+    /// let long = b"hello world, hello marrow";
+    /// let view = BytesViewView {
+    ///     validity: None,
+    ///     data: &[
+    ///         // inline: length 3, data "foo"
+    ///         u128::from_le_bytes(*b"\x03\0\0\0foo\0\0\0\0\0\0\0\0\0"),
+    ///         // buffer: length 25, prefix "hell", buffer 0, offset 0
+    ///         u128::from_le_bytes(*b"\x19\0\0\0hell\0\0\0\0\0\0\0\0"),
+    ///     ],
+    ///     buffers: vec![long],
+    /// };
+    /// assert_eq!(
+    ///     view.iter().collect::<Vec<_>>(),
+    ///     vec![Some(b"foo".as_slice()), Some(long.as_slice())],
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a [u8]>> + '_ {
+        let validity = self.validity;
+        let inline: &'a [u8] = bytemuck::cast_slice(self.data);
+        self.data.iter().enumerate().map(move |(idx, &item)| {
+            if !is_valid(validity, idx) {
+                return None;
+            }
+            let len = item as u32 as usize;
+            if len <= 12 {
+                let start = 16 * idx + 4;
+                Some(&inline[start..start + len])
+            } else {
+                let buffer_index = (item >> 64) as u32 as usize;
+                let offset = (item >> 96) as u32 as usize;
+                Some(&self.buffers[buffer_index][offset..offset + len])
+            }
+        })
+    }
+
+    /// Iterate over the elements as strings, checking that they are valid UTF-8
+    pub fn iter_str(&self) -> impl Iterator<Item = Result<Option<&'a str>>> + '_ {
+        self.iter().map(to_str)
+    }
+}
+
+impl<'a> FixedSizeBinaryView<'a> {
+    /// Iterate over the elements as byte slices of length `n`
+    ///
+    /// For `n = 0` the number of elements cannot be determined from the view and no elements are
+    /// returned.
+    ///
+    /// ```rust
+    /// # use marrow::view::FixedSizeBinaryView;
+    /// let view = FixedSizeBinaryView { n: 2, validity: None, data: b"abcd" };
+    /// assert_eq!(
+    ///     view.iter().collect::<Vec<_>>(),
+    ///     vec![Some(b"ab".as_slice()), Some(b"cd".as_slice())],
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a [u8]>> + 'a {
+        let validity = self.validity;
+        let n = usize::try_from(self.n).unwrap_or_default();
+        let data = if n == 0 { &[] } else { self.data };
+        data.chunks_exact(n.max(1))
+            .enumerate()
+            .map(move |(idx, item)| is_valid(validity, idx).then_some(item))
+    }
+}
+
+impl<'a, O: Copy> ListView<'a, O>
+where
+    usize: TryFrom<O>,
+{
+    /// Iterate over the elements as slices of the child view
+    ///
+    /// The child views are sliced without copying the underlying data or allocating for flat
+    /// children. Slicing fails for run end encoded children. Decreasing offsets are reported as
+    /// errors.
+    ///
+    /// # Panics
+    ///
+    /// If the offsets are negative.
+    ///
+    /// ```rust
+    /// # use marrow::{datatypes::FieldMeta, view::{ListView, PrimitiveView, View}};
+    /// # fn main() -> marrow::error::Result<()> {
+    /// let view = ListView {
+    ///     validity: None,
+    ///     offsets: &[0_i32, 2, 3],
+    ///     meta: FieldMeta::default(),
+    ///     elements: Box::new(View::Int8(PrimitiveView { validity: None, values: &[1, 2, 3] })),
+    /// };
+    /// let items = view.iter().collect::<marrow::error::Result<Vec<_>>>()?;
+    /// assert_eq!(
+    ///     items,
+    ///     vec![
+    ///         Some(View::Int8(PrimitiveView { validity: None, values: &[1, 2] })),
+    ///         Some(View::Int8(PrimitiveView { validity: None, values: &[3] })),
+    ///     ],
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Result<Option<View<'a>>>> + 'a {
+        let validity = self.validity;
+        // cloning the view does not copy the viewed data, only nested metadata
+        let elements = self.elements.as_ref().clone();
+        self.offsets
+            .windows(2)
+            .enumerate()
+            .map(move |(idx, window)| {
+                if !is_valid(validity, idx) {
+                    return Ok(None);
+                }
+                let start = to_index(window[0]);
+                let end = to_index(window[1]);
+                if end < start {
+                    fail!(ErrorKind::Unsupported, "Offsets must not decrease");
+                }
+                Ok(Some(slice_view(&elements, start, end - start)?))
+            })
+    }
+}
+
+fn is_valid(validity: Option<BitsWithOffset<'_>>, idx: usize) -> bool {
+    validity.map_or(true, |bits| bits.get(idx))
+}
+
+fn to_index<O>(offset: O) -> usize
+where
+    usize: TryFrom<O>,
+{
+    match usize::try_from(offset) {
+        Ok(offset) => offset,
+        Err(_) => panic!("Invalid negative offset"),
+    }
+}
+
+fn to_str(item: Option<&[u8]>) -> Result<Option<&str>> {
+    match item {
+        Some(item) => Ok(Some(std::str::from_utf8(item)?)),
+        None => Ok(None),
+    }
+}
+
+#[test]
+fn list_iterators_outlive_the_view() {
+    use crate::{datatypes::FieldMeta, error::Result};
+
+    let offsets = [0_i64, 1, 1, 3];
+    let values = [1_u8, 2, 3];
+    let iter = {
+        let view = ListView {
+            validity: Some(BitsWithOffset {
+                offset: 0,
+                data: &[0b_101],
+            }),
+            offsets: &offsets,
+            meta: FieldMeta::default(),
+            elements: Box::new(View::UInt8(PrimitiveView {
+                validity: None,
+                values: &values,
+            })),
+        };
+        view.iter()
+    };
+
+    assert_eq!(
+        iter.collect::<Result<Vec<_>>>().unwrap(),
+        vec![
+            Some(View::UInt8(PrimitiveView {
+                validity: None,
+                values: &[1],
+            })),
+            None,
+            Some(View::UInt8(PrimitiveView {
+                validity: None,
+                values: &[2, 3],
+            })),
+        ],
+    );
+}
+
+#[test]
+fn list_iterators_reject_decreasing_offsets() {
+    use crate::datatypes::FieldMeta;
+
+    let view = ListView {
+        validity: None,
+        offsets: &[0_i32, 2, 1],
+        meta: FieldMeta::default(),
+        elements: Box::new(View::UInt8(PrimitiveView {
+            validity: None,
+            values: &[1, 2],
+        })),
+    };
+    let mut iter = view.iter();
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_err());
+}
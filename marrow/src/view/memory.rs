@@ -0,0 +1,305 @@
+//! The number of bytes referenced by views
+use std::collections::BTreeSet;
+
+use crate::{
+    array::view_len,
+    datatypes::BufferKind,
+    error::{fail, ErrorKind, Result},
+    view::{slice_view, BitsWithOffset, BytesViewView, View},
+};
+
+/// The number of bytes of a single buffer reachable from a view, see [`View::buffer_sizes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferSize {
+    /// The dotted path of the child owning the buffer, empty for the view itself
+    pub path: String,
+    /// The kind of the buffer
+    pub kind: BufferKind,
+    /// The number of bytes reachable from the view
+    pub bytes: usize,
+}
+
+impl View<'_> {
+    /// The bytes reachable from the view for each buffer, including the buffers of children
+    ///
+    /// Only the referenced ranges of the buffers are counted: offsets of bitmaps, slices and the
+    /// offsets of byte arrays, lists and maps are taken into account. The data buffers of
+    /// `Utf8View` and `BinaryView` views are counted once in full, if any view references them.
+    /// Children are identified by the dotted path of their names, the values of dictionaries are
+    /// called `values`. The keys of dictionaries are reported with the path of the dictionary. Run
+    /// end encoded children of nested views are counted in full.
+    ///
+    /// ```rust
+    /// # use marrow::{datatypes::BufferKind, view::{BufferSize, BytesView, View}};
+    /// # fn main() -> marrow::error::Result<()> {
+    /// let view = View::Utf8(BytesView {
+    ///     validity: None,
+    ///     offsets: &[3, 5, 8],
+    ///     data: b"foobarbaz",
+    /// });
+    /// assert_eq!(
+    ///     view.buffer_sizes()?,
+    ///     vec![
+    ///         BufferSize { path: String::new(), kind: BufferKind::Offsets(4), bytes: 12 },
+    ///         BufferSize { path: String::new(), kind: BufferKind::Data, bytes: 5 },
+    ///     ],
+    /// );
+    /// assert_eq!(view.referenced_bytes()?, 17);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn buffer_sizes(&self) -> Result<Vec<BufferSize>> {
+        let mut sizes = Vec::new();
+        collect_buffer_sizes(self, "", &mut sizes)?;
+        Ok(sizes)
+    }
+
+    /// The total number of bytes reachable from the view, see [`View::buffer_sizes`]
+    pub fn referenced_bytes(&self) -> Result<usize> {
+        Ok(self.buffer_sizes()?.iter().map(|size| size.bytes).sum())
+    }
+}
+
+fn collect_buffer_sizes(view: &View<'_>, path: &str, sizes: &mut Vec<BufferSize>) -> Result<()> {
+    use {BufferKind as B, View as V};
+
+    macro_rules! push {
+        ($kind:expr, $bytes:expr) => {
+            sizes.push(BufferSize {
+                path: String::from(path),
+                kind: $kind,
+                bytes: $bytes,
+            })
+        };
+    }
+    macro_rules! push_validity {
+        ($validity:expr, $len:expr) => {
+            if let Some(validity) = $validity {
+                push!(B::Validity, bitmap_bytes(validity, $len));
+            }
+        };
+    }
+    macro_rules! primitive {
+        ($view:expr) => {{
+            push_validity!($view.validity, $view.values.len());
+            push!(
+                B::FixedWidth(element_width($view.values)),
+                std::mem::size_of_val($view.values)
+            );
+        }};
+    }
+
+    match view {
+        V::Null(_) => {}
+        V::Boolean(view) => {
+            push_validity!(view.validity, view.len);
+            push!(B::Bitmap, bitmap_bytes(view.values, view.len));
+        }
+        V::Int8(view) => primitive!(view),
+        V::Int16(view) => primitive!(view),
+        V::Int32(view) => primitive!(view),
+        V::Int64(view) => primitive!(view),
+        V::UInt8(view) => primitive!(view),
+        V::UInt16(view) => primitive!(view),
+        V::UInt32(view) => primitive!(view),
+        V::UInt64(view) => primitive!(view),
+        V::Float16(view) => primitive!(view),
+        V::Float32(view) => primitive!(view),
+        V::Float64(view) => primitive!(view),
+        V::Date32(view) => primitive!(view),
+        V::Date64(view) => primitive!(view),
+        V::Time32(view) => primitive!(view),
+        V::Time64(view) => primitive!(view),
+        V::Timestamp(view) => primitive!(view),
+        V::Duration(view) => primitive!(view),
+        V::YearMonthInterval(view) => primitive!(view),
+        V::DayTimeInterval(view) => primitive!(view),
+        V::MonthDayNanoInterval(view) => primitive!(view),
+        V::Decimal128(view) => primitive!(view),
+        V::Utf8(view) | V::Binary(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            push_validity!(view.validity, view.offsets.len().saturating_sub(1));
+            push!(B::Offsets(4), std::mem::size_of_val(view.offsets));
+            push!(B::Data, len.min(view.data.len().saturating_sub(start)));
+        }
+        V::LargeUtf8(view) | V::LargeBinary(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            push_validity!(view.validity, view.offsets.len().saturating_sub(1));
+            push!(B::Offsets(8), std::mem::size_of_val(view.offsets));
+            push!(B::Data, len.min(view.data.len().saturating_sub(start)));
+        }
+        V::Utf8View(view) | V::BinaryView(view) => {
+            push_validity!(view.validity, view.data.len());
+            push!(B::Views, std::mem::size_of_val(view.data));
+            push!(B::VariadicData, referenced_buffer_bytes(view)?);
+        }
+        V::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            push_validity!(
+                view.validity,
+                view.data.len().checked_div(n).unwrap_or_default()
+            );
+            push!(B::FixedWidth(n), view.data.len());
+        }
+        V::Struct(view) => {
+            push_validity!(view.validity, view.len);
+            for (meta, child) in &view.fields {
+                let child = referenced_child(child, 0, view.len)?;
+                collect_buffer_sizes(&child, &child_path(path, &meta.name), sizes)?;
+            }
+        }
+        V::List(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            push_validity!(view.validity, view.offsets.len().saturating_sub(1));
+            push!(B::Offsets(4), std::mem::size_of_val(view.offsets));
+            let child = referenced_child(&view.elements, start, len)?;
+            collect_buffer_sizes(&child, &child_path(path, &view.meta.name), sizes)?;
+        }
+        V::LargeList(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            push_validity!(view.validity, view.offsets.len().saturating_sub(1));
+            push!(B::Offsets(8), std::mem::size_of_val(view.offsets));
+            let child = referenced_child(&view.elements, start, len)?;
+            collect_buffer_sizes(&child, &child_path(path, &view.meta.name), sizes)?;
+        }
+        V::FixedSizeList(view) => {
+            push_validity!(view.validity, view.len);
+            let n = usize::try_from(view.n)?;
+            let child = referenced_child(&view.elements, 0, view.len * n)?;
+            collect_buffer_sizes(&child, &child_path(path, &view.meta.name), sizes)?;
+        }
+        V::Map(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            push_validity!(view.validity, view.offsets.len().saturating_sub(1));
+            push!(B::Offsets(4), std::mem::size_of_val(view.offsets));
+            let entries = child_path(path, &view.meta.entries_name);
+            let keys = referenced_child(&view.keys, start, len)?;
+            collect_buffer_sizes(&keys, &child_path(&entries, &view.meta.keys.name), sizes)?;
+            let values = referenced_child(&view.values, start, len)?;
+            collect_buffer_sizes(
+                &values,
+                &child_path(&entries, &view.meta.values.name),
+                sizes,
+            )?;
+        }
+        V::Dictionary(view) => {
+            collect_buffer_sizes(&view.keys, path, sizes)?;
+            collect_buffer_sizes(&view.values, &child_path(path, "values"), sizes)?;
+        }
+        V::RunEndEncoded(view) => {
+            let run_ends = child_path(path, &view.meta.run_ends_name);
+            collect_buffer_sizes(&view.run_ends, &run_ends, sizes)?;
+            let values = child_path(path, &view.meta.values.name);
+            collect_buffer_sizes(&view.values, &values, sizes)?;
+        }
+        V::Union(view) => {
+            push!(B::TypeIds, std::mem::size_of_val(view.types));
+            if let Some(offsets) = view.offsets {
+                push!(B::Offsets(4), std::mem::size_of_val(offsets));
+            }
+            for (type_id, meta, child) in &view.fields {
+                let (start, len) = match view.offsets {
+                    Some(offsets) => dense_union_range(view.types, offsets, *type_id)?,
+                    None => (0, view.types.len()),
+                };
+                let child = referenced_child(child, start, len)?;
+                collect_buffer_sizes(&child, &child_path(path, &meta.name), sizes)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn element_width<T>(_: &[T]) -> usize {
+    std::mem::size_of::<T>()
+}
+
+/// The bytes spanned by `len` bits starting at the offset of the bitmap
+fn bitmap_bytes(bits: BitsWithOffset<'_>, len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    let end = (bits.offset + len + 7) / 8;
+    end.min(bits.data.len()).saturating_sub(bits.offset / 8)
+}
+
+/// The start and the length of the range referenced by offsets
+pub(super) fn offsets_range<O: Copy + Into<i64>>(offsets: &[O]) -> Result<(usize, usize)> {
+    let (Some(first), Some(last)) = (offsets.first(), offsets.last()) else {
+        return Ok((0, 0));
+    };
+    let start = usize::try_from((*first).into())?;
+    let end = usize::try_from((*last).into())?;
+    if end < start {
+        fail!(ErrorKind::Unsupported, "Offsets must not decrease");
+    }
+    Ok((start, end - start))
+}
+
+/// The range of child elements referenced by a dense union for the given type id
+fn dense_union_range(types: &[i8], offsets: &[i32], type_id: i8) -> Result<(usize, usize)> {
+    let mut range: Option<(i32, i32)> = None;
+    for (&ty, &offset) in types.iter().zip(offsets) {
+        if ty != type_id {
+            continue;
+        }
+        range = Some(match range {
+            Some((min, max)) => (min.min(offset), max.max(offset)),
+            None => (offset, offset),
+        });
+    }
+    match range {
+        Some((min, max)) => Ok((usize::try_from(min)?, usize::try_from(max - min + 1)?)),
+        None => Ok((0, 0)),
+    }
+}
+
+/// The full length of all data buffers referenced by at least one non-inlined view
+fn referenced_buffer_bytes(view: &BytesViewView<'_>) -> Result<usize> {
+    let mut indices = BTreeSet::new();
+    for &item in view.data {
+        if item as u32 as usize > 12 {
+            indices.insert((item >> 64) as u32 as usize);
+        }
+    }
+    let mut bytes = 0;
+    for index in indices {
+        let Some(buffer) = view.buffers.get(index) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Invalid byte view: buffer {index} does not exist"
+            );
+        };
+        bytes += buffer.len();
+    }
+    Ok(bytes)
+}
+
+/// Restrict the child to the given range, if it is not already restricted to it
+pub(super) fn slice_child<'a>(child: &View<'a>, start: usize, len: usize) -> Result<View<'a>> {
+    if start == 0 && view_len(child)? == len {
+        Ok(child.clone())
+    } else {
+        slice_view(child, start, len)
+    }
+}
+
+/// The part of the child referenced by its parent, run end encoded children are counted in full
+///
+/// Slicing a run end encoded view requires copying its run ends, instead all of its runs are
+/// counted.
+fn referenced_child<'a>(child: &View<'a>, start: usize, len: usize) -> Result<View<'a>> {
+    if matches!(child, View::RunEndEncoded(_)) {
+        Ok(child.clone())
+    } else {
+        slice_child(child, start, len)
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        String::from(name)
+    } else {
+        format!("{path}.{name}")
+    }
+}
@@ -0,0 +1,717 @@
+//! Serialize views in the format of the corresponding arrays
+//!
+//! The private structs mirror the array structs field by field. Slices are normalized as in the
+//! conversion to arrays: bitmaps are realigned, offsets are rebased and children are restricted
+//! to the referenced elements. Run end encoded children of lists and maps are written in full
+//! with the original offsets.
+use std::borrow::Cow;
+
+use half::f16;
+use serde::{ser::Error, Serialize, Serializer};
+
+use crate::{
+    array::copy_bits,
+    datatypes::{FieldMeta, MapMeta, RunEndEncodedMeta, TimeUnit},
+    error::{fail, ErrorKind, Result},
+    types::{DayTimeInterval, MonthDayNanoInterval},
+    view::{
+        memory::{offsets_range, slice_child},
+        BitsWithOffset, View,
+    },
+};
+
+/// Serialize the view with the encoding of the corresponding [`Array`][crate::array::Array]
+///
+/// Borrowed data is written without converting the view into an array first. The result can be
+/// deserialized as an array.
+///
+/// ```rust
+/// # use marrow::{array::Array, view::{BytesView, View}};
+/// let view = View::Utf8(BytesView {
+///     validity: None,
+///     offsets: &[3, 5, 8],
+///     data: b"foobarbaz",
+/// });
+/// let array: Array = serde_json::from_str(&serde_json::to_string(&view)?)?;
+/// assert_eq!(array, Array::try_from(view)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl Serialize for View<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_array_ref(self)
+            .map_err(|err| S::Error::custom(err.message()))?
+            .serialize(serializer)
+    }
+}
+
+// the variants are only constructed to be serialized immediately
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize)]
+#[serde(rename = "Array")]
+enum ArrayRef<'a> {
+    Null(NullArray),
+    Boolean(BooleanArray<'a>),
+    Int8(PrimitiveArray<'a, i8>),
+    Int16(PrimitiveArray<'a, i16>),
+    Int32(PrimitiveArray<'a, i32>),
+    Int64(PrimitiveArray<'a, i64>),
+    UInt8(PrimitiveArray<'a, u8>),
+    UInt16(PrimitiveArray<'a, u16>),
+    UInt32(PrimitiveArray<'a, u32>),
+    UInt64(PrimitiveArray<'a, u64>),
+    Float16(PrimitiveArray<'a, f16>),
+    Float32(PrimitiveArray<'a, f32>),
+    Float64(PrimitiveArray<'a, f64>),
+    Date32(PrimitiveArray<'a, i32>),
+    Date64(PrimitiveArray<'a, i64>),
+    Time32(TimeArray<'a, i32>),
+    Time64(TimeArray<'a, i64>),
+    Timestamp(TimestampArray<'a>),
+    Duration(TimeArray<'a, i64>),
+    YearMonthInterval(PrimitiveArray<'a, i32>),
+    DayTimeInterval(PrimitiveArray<'a, DayTimeInterval>),
+    MonthDayNanoInterval(PrimitiveArray<'a, MonthDayNanoInterval>),
+    Utf8(BytesArray<'a, i32>),
+    LargeUtf8(BytesArray<'a, i64>),
+    Utf8View(BytesViewArray<'a>),
+    Binary(BytesArray<'a, i32>),
+    LargeBinary(BytesArray<'a, i64>),
+    FixedSizeBinary(FixedSizeBinaryArray<'a>),
+    BinaryView(BytesViewArray<'a>),
+    Decimal128(DecimalArray<'a, i128>),
+    Struct(StructArray<'a>),
+    List(ListArray<'a, i32>),
+    LargeList(ListArray<'a, i64>),
+    FixedSizeList(FixedSizeListArray<'a>),
+    Dictionary(DictionaryArray<'a>),
+    RunEndEncoded(RunEndEncodedArray<'a>),
+    Map(MapArray<'a>),
+    Union(UnionArray<'a>),
+}
+
+#[derive(Serialize)]
+struct NullArray {
+    len: usize,
+}
+
+#[derive(Serialize)]
+struct BooleanArray<'a> {
+    len: usize,
+    validity: Option<Cow<'a, [u8]>>,
+    values: Cow<'a, [u8]>,
+}
+
+#[derive(Serialize)]
+struct PrimitiveArray<'a, T> {
+    validity: Option<Cow<'a, [u8]>>,
+    values: &'a [T],
+}
+
+#[derive(Serialize)]
+struct TimeArray<'a, T> {
+    unit: TimeUnit,
+    validity: Option<Cow<'a, [u8]>>,
+    values: &'a [T],
+}
+
+#[derive(Serialize)]
+struct TimestampArray<'a> {
+    unit: TimeUnit,
+    timezone: &'a Option<String>,
+    validity: Option<Cow<'a, [u8]>>,
+    values: &'a [i64],
+}
+
+#[derive(Serialize)]
+struct StructArray<'a> {
+    len: usize,
+    validity: Option<Cow<'a, [u8]>>,
+    fields: Vec<(&'a FieldMeta, View<'a>)>,
+}
+
+#[derive(Serialize)]
+struct MapArray<'a> {
+    validity: Option<Cow<'a, [u8]>>,
+    offsets: Offsets<'a, i32>,
+    meta: &'a MapMeta,
+    keys: View<'a>,
+    values: View<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(bound(serialize = "O: Copy + Serialize + std::ops::Sub<Output = O>"))]
+struct ListArray<'a, O> {
+    validity: Option<Cow<'a, [u8]>>,
+    offsets: Offsets<'a, O>,
+    meta: &'a FieldMeta,
+    elements: View<'a>,
+}
+
+#[derive(Serialize)]
+struct FixedSizeListArray<'a> {
+    len: usize,
+    n: i32,
+    validity: Option<Cow<'a, [u8]>>,
+    meta: &'a FieldMeta,
+    elements: View<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(bound(serialize = "O: Copy + Serialize + std::ops::Sub<Output = O>"))]
+struct BytesArray<'a, O> {
+    validity: Option<Cow<'a, [u8]>>,
+    offsets: Offsets<'a, O>,
+    data: &'a [u8],
+}
+
+#[derive(Serialize)]
+struct BytesViewArray<'a> {
+    validity: Option<Cow<'a, [u8]>>,
+    data: &'a [u128],
+    buffers: &'a [&'a [u8]],
+}
+
+#[derive(Serialize)]
+struct FixedSizeBinaryArray<'a> {
+    n: i32,
+    validity: Option<Cow<'a, [u8]>>,
+    data: &'a [u8],
+}
+
+#[derive(Serialize)]
+struct DecimalArray<'a, T> {
+    precision: u8,
+    scale: i8,
+    validity: Option<Cow<'a, [u8]>>,
+    values: &'a [T],
+}
+
+#[derive(Serialize)]
+struct DictionaryArray<'a> {
+    keys: &'a View<'a>,
+    values: &'a View<'a>,
+}
+
+#[derive(Serialize)]
+struct UnionArray<'a> {
+    types: &'a [i8],
+    offsets: Option<&'a [i32]>,
+    fields: Vec<(i8, &'a FieldMeta, View<'a>)>,
+}
+
+#[derive(Serialize)]
+struct RunEndEncodedArray<'a> {
+    meta: &'a RunEndEncodedMeta,
+    run_ends: &'a View<'a>,
+    values: &'a View<'a>,
+}
+
+/// Offsets, rebased to start at zero if the referenced children are sliced
+struct Offsets<'a, O> {
+    offsets: &'a [O],
+    rebase: bool,
+}
+
+impl<O> Serialize for Offsets<'_, O>
+where
+    O: Copy + Serialize + std::ops::Sub<Output = O>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (true, Some(&first)) = (self.rebase, self.offsets.first()) else {
+            return serializer.collect_seq(self.offsets);
+        };
+        serializer.collect_seq(self.offsets.iter().map(|&offset| offset - first))
+    }
+}
+
+fn to_array_ref<'a>(view: &'a View<'a>) -> Result<ArrayRef<'a>> {
+    use {ArrayRef as A, View as V};
+
+    macro_rules! primitive {
+        ($variant:ident, $view:expr) => {
+            A::$variant(PrimitiveArray {
+                validity: validity($view.validity, $view.values.len())?,
+                values: $view.values,
+            })
+        };
+    }
+    macro_rules! time {
+        ($variant:ident, $view:expr) => {
+            A::$variant(TimeArray {
+                unit: $view.unit,
+                validity: validity($view.validity, $view.values.len())?,
+                values: $view.values,
+            })
+        };
+    }
+    macro_rules! bytes {
+        ($variant:ident, $view:expr) => {{
+            let (start, len) = offsets_range($view.offsets)?;
+            A::$variant(BytesArray {
+                validity: validity($view.validity, $view.offsets.len().saturating_sub(1))?,
+                offsets: Offsets {
+                    offsets: $view.offsets,
+                    rebase: true,
+                },
+                data: slice($view.data, start, len)?,
+            })
+        }};
+    }
+    macro_rules! bytes_view {
+        ($variant:ident, $view:expr) => {
+            A::$variant(BytesViewArray {
+                validity: validity($view.validity, $view.data.len())?,
+                data: $view.data,
+                buffers: &$view.buffers,
+            })
+        };
+    }
+    macro_rules! list {
+        ($variant:ident, $view:expr) => {{
+            let (start, len) = offsets_range($view.offsets)?;
+            let rebase = !is_run_end_encoded(&$view.elements);
+            A::$variant(ListArray {
+                validity: validity($view.validity, $view.offsets.len().saturating_sub(1))?,
+                offsets: Offsets {
+                    offsets: $view.offsets,
+                    rebase,
+                },
+                meta: &$view.meta,
+                elements: referenced_child(&$view.elements, rebase, start, len)?,
+            })
+        }};
+    }
+
+    Ok(match view {
+        V::Null(view) => A::Null(NullArray { len: view.len }),
+        V::Boolean(view) => A::Boolean(BooleanArray {
+            len: view.len,
+            validity: validity(view.validity, view.len)?,
+            values: bits(view.values, view.len)?,
+        }),
+        V::Int8(view) => primitive!(Int8, view),
+        V::Int16(view) => primitive!(Int16, view),
+        V::Int32(view) => primitive!(Int32, view),
+        V::Int64(view) => primitive!(Int64, view),
+        V::UInt8(view) => primitive!(UInt8, view),
+        V::UInt16(view) => primitive!(UInt16, view),
+        V::UInt32(view) => primitive!(UInt32, view),
+        V::UInt64(view) => primitive!(UInt64, view),
+        V::Float16(view) => primitive!(Float16, view),
+        V::Float32(view) => primitive!(Float32, view),
+        V::Float64(view) => primitive!(Float64, view),
+        V::Date32(view) => primitive!(Date32, view),
+        V::Date64(view) => primitive!(Date64, view),
+        V::Time32(view) => time!(Time32, view),
+        V::Time64(view) => time!(Time64, view),
+        V::Timestamp(view) => A::Timestamp(TimestampArray {
+            unit: view.unit,
+            timezone: &view.timezone,
+            validity: validity(view.validity, view.values.len())?,
+            values: view.values,
+        }),
+        V::Duration(view) => time!(Duration, view),
+        V::YearMonthInterval(view) => primitive!(YearMonthInterval, view),
+        V::DayTimeInterval(view) => primitive!(DayTimeInterval, view),
+        V::MonthDayNanoInterval(view) => primitive!(MonthDayNanoInterval, view),
+        V::Utf8(view) => bytes!(Utf8, view),
+        V::LargeUtf8(view) => bytes!(LargeUtf8, view),
+        V::Utf8View(view) => bytes_view!(Utf8View, view),
+        V::Binary(view) => bytes!(Binary, view),
+        V::LargeBinary(view) => bytes!(LargeBinary, view),
+        V::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            A::FixedSizeBinary(FixedSizeBinaryArray {
+                n: view.n,
+                validity: validity(
+                    view.validity,
+                    view.data.len().checked_div(n).unwrap_or_default(),
+                )?,
+                data: view.data,
+            })
+        }
+        V::BinaryView(view) => bytes_view!(BinaryView, view),
+        V::Decimal128(view) => A::Decimal128(DecimalArray {
+            precision: view.precision,
+            scale: view.scale,
+            validity: validity(view.validity, view.values.len())?,
+            values: view.values,
+        }),
+        V::Struct(view) => {
+            let mut fields = Vec::with_capacity(view.fields.len());
+            for (meta, child) in &view.fields {
+                fields.push((meta, slice_child(child, 0, view.len)?));
+            }
+            A::Struct(StructArray {
+                len: view.len,
+                validity: validity(view.validity, view.len)?,
+                fields,
+            })
+        }
+        V::List(view) => list!(List, view),
+        V::LargeList(view) => list!(LargeList, view),
+        V::FixedSizeList(view) => {
+            let n = usize::try_from(view.n)?;
+            A::FixedSizeList(FixedSizeListArray {
+                len: view.len,
+                n: view.n,
+                validity: validity(view.validity, view.len)?,
+                meta: &view.meta,
+                elements: slice_child(&view.elements, 0, view.len * n)?,
+            })
+        }
+        V::Dictionary(view) => A::Dictionary(DictionaryArray {
+            keys: &view.keys,
+            values: &view.values,
+        }),
+        V::RunEndEncoded(view) => A::RunEndEncoded(RunEndEncodedArray {
+            meta: &view.meta,
+            run_ends: &view.run_ends,
+            values: &view.values,
+        }),
+        V::Map(view) => {
+            let (start, len) = offsets_range(view.offsets)?;
+            let rebase = !is_run_end_encoded(&view.keys) && !is_run_end_encoded(&view.values);
+            A::Map(MapArray {
+                validity: validity(view.validity, view.offsets.len().saturating_sub(1))?,
+                offsets: Offsets {
+                    offsets: view.offsets,
+                    rebase,
+                },
+                meta: &view.meta,
+                keys: referenced_child(&view.keys, rebase, start, len)?,
+                values: referenced_child(&view.values, rebase, start, len)?,
+            })
+        }
+        V::Union(view) => {
+            // dense unions reference their children by offsets, they are written in full
+            let mut fields = Vec::with_capacity(view.fields.len());
+            for (type_id, meta, child) in &view.fields {
+                let child = match view.offsets {
+                    Some(_) => child.clone(),
+                    None => slice_child(child, 0, view.types.len())?,
+                };
+                fields.push((*type_id, meta, child));
+            }
+            A::Union(UnionArray {
+                types: view.types,
+                offsets: view.offsets,
+                fields,
+            })
+        }
+    })
+}
+
+/// Slicing run end encoded children requires copying their run ends, instead they are written in
+/// full and the offsets of their parents are kept
+fn is_run_end_encoded(view: &View<'_>) -> bool {
+    matches!(view, View::RunEndEncoded(_))
+}
+
+/// The child restricted to the elements referenced by rebased offsets, the full child otherwise
+fn referenced_child<'a>(
+    child: &View<'a>,
+    rebase: bool,
+    start: usize,
+    len: usize,
+) -> Result<View<'a>> {
+    if rebase {
+        slice_child(child, start, len)
+    } else {
+        Ok(child.clone())
+    }
+}
+
+/// The bytes of the bitmap realigned to start at bit 0, borrowed if possible
+fn bits(bits: BitsWithOffset<'_>, len: usize) -> Result<Cow<'_, [u8]>> {
+    if bits.offset % 8 != 0 {
+        return Ok(Cow::Owned(copy_bits(bits, 0, len)?));
+    }
+    let num_bytes = len / 8 + usize::from(len % 8 != 0);
+    Ok(Cow::Borrowed(slice(bits.data, bits.offset / 8, num_bytes)?))
+}
+
+fn validity(validity: Option<BitsWithOffset<'_>>, len: usize) -> Result<Option<Cow<'_, [u8]>>> {
+    validity.map(|validity| bits(validity, len)).transpose()
+}
+
+fn slice<T>(data: &[T], start: usize, len: usize) -> Result<&[T]> {
+    let Some(res) = data.get(start..start + len) else {
+        fail!(
+            ErrorKind::Unsupported,
+            "Cannot access {len} elements starting at {start} of {total} elements",
+            total = data.len(),
+        );
+    };
+    Ok(res)
+}
+
+#[cfg(test)]
+fn example_arrays() -> Vec<crate::array::Array> {
+    use half::f16;
+
+    use crate::{
+        array::{
+            Array, BooleanArray, BytesArray, BytesViewArray, DecimalArray, DictionaryArray,
+            FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray,
+            PrimitiveArray, RunEndEncodedArray, StructArray, TimeArray, TimestampArray, UnionArray,
+        },
+        datatypes::{meta, MapMeta, RunEndEncodedMeta, TimeUnit},
+        types::{DayTimeInterval, MonthDayNanoInterval},
+    };
+
+    let int32 = |values: Vec<i32>| {
+        Array::Int32(PrimitiveArray {
+            validity: None,
+            values,
+        })
+    };
+    let utf8 = |offsets: Vec<i32>, data: &[u8]| {
+        Array::Utf8(BytesArray {
+            validity: None,
+            offsets,
+            data: data.to_vec(),
+        })
+    };
+
+    vec![
+        Array::Null(NullArray { len: 3 }),
+        Array::Boolean(BooleanArray {
+            len: 10,
+            validity: Some(vec![0b_1111_1011, 0b_10]),
+            values: vec![0b_0110_0101, 0b_01],
+        }),
+        Array::Int8(PrimitiveArray {
+            validity: Some(vec![0b_101]),
+            values: vec![-1, 0, 1],
+        }),
+        Array::UInt64(PrimitiveArray {
+            validity: None,
+            values: vec![0, u64::MAX],
+        }),
+        Array::Float16(PrimitiveArray {
+            validity: None,
+            values: vec![f16::from_f32(1.5), f16::NEG_INFINITY],
+        }),
+        Array::Float64(PrimitiveArray {
+            validity: None,
+            values: vec![0.5, -2.0],
+        }),
+        Array::Date64(PrimitiveArray {
+            validity: None,
+            values: vec![86_400_000],
+        }),
+        Array::Time32(TimeArray {
+            unit: TimeUnit::Millisecond,
+            validity: None,
+            values: vec![1_000, 2_000],
+        }),
+        Array::Timestamp(TimestampArray {
+            unit: TimeUnit::Second,
+            timezone: Some(String::from("UTC")),
+            validity: Some(vec![0b_10]),
+            values: vec![0, 1_700_000_000],
+        }),
+        Array::Duration(TimeArray {
+            unit: TimeUnit::Nanosecond,
+            validity: None,
+            values: vec![-5],
+        }),
+        Array::YearMonthInterval(PrimitiveArray {
+            validity: None,
+            values: vec![13],
+        }),
+        Array::DayTimeInterval(PrimitiveArray {
+            validity: None,
+            values: vec![DayTimeInterval {
+                days: 1,
+                milliseconds: 2,
+            }],
+        }),
+        Array::MonthDayNanoInterval(PrimitiveArray {
+            validity: None,
+            values: vec![MonthDayNanoInterval {
+                months: 1,
+                days: -2,
+                nanoseconds: 3,
+            }],
+        }),
+        utf8(vec![0, 3, 3, 6], b"foobar"),
+        Array::LargeBinary(BytesArray {
+            validity: Some(vec![0b_01]),
+            offsets: vec![0, 2, 2],
+            data: vec![1, 2],
+        }),
+        Array::Utf8View(BytesViewArray {
+            validity: None,
+            data: vec![
+                u128::from_le_bytes(*b"\x03\0\0\0foo\0\0\0\0\0\0\0\0\0"),
+                20 | (1 << 96),
+            ],
+            buffers: vec![b"_abcdefghijklmnopqrstuvwxyz".to_vec()],
+        }),
+        Array::FixedSizeBinary(FixedSizeBinaryArray {
+            n: 2,
+            validity: Some(vec![0b_110]),
+            data: vec![1, 2, 3, 4, 5, 6],
+        }),
+        Array::Decimal128(DecimalArray {
+            precision: 5,
+            scale: 2,
+            validity: None,
+            values: vec![12_345, i128::MIN],
+        }),
+        Array::Struct(StructArray {
+            len: 3,
+            validity: Some(vec![0b_011]),
+            fields: vec![
+                (meta("a", false), int32(vec![1, 2, 3])),
+                (meta("b", false), utf8(vec![0, 1, 2, 3], b"xyz")),
+            ],
+        }),
+        Array::List(ListArray {
+            validity: Some(vec![0b_1101]),
+            offsets: vec![0, 2, 2, 3, 5],
+            meta: meta("item", false),
+            elements: Box::new(int32(vec![1, 2, 3, 4, 5])),
+        }),
+        Array::LargeList(ListArray {
+            validity: None,
+            offsets: vec![0, 1, 3],
+            meta: meta("element", false),
+            elements: Box::new(utf8(vec![0, 1, 3, 6], b"abbccc")),
+        }),
+        Array::FixedSizeList(FixedSizeListArray {
+            len: 3,
+            n: 2,
+            validity: Some(vec![0b_101]),
+            meta: meta("item", false),
+            elements: Box::new(int32(vec![1, 2, 3, 4, 5, 6])),
+        }),
+        Array::Dictionary(DictionaryArray {
+            keys: Box::new(Array::UInt8(PrimitiveArray {
+                validity: None,
+                values: vec![1, 0, 1],
+            })),
+            values: Box::new(utf8(vec![0, 1, 2], b"ab")),
+        }),
+        Array::RunEndEncoded(RunEndEncodedArray {
+            meta: RunEndEncodedMeta {
+                run_ends_name: String::from("run_ends"),
+                values: meta("values", false),
+            },
+            run_ends: Box::new(int32(vec![2, 5])),
+            values: Box::new(utf8(vec![0, 1, 2], b"ab")),
+        }),
+        Array::Map(MapArray {
+            validity: None,
+            offsets: vec![0, 1, 3],
+            meta: MapMeta {
+                entries_name: String::from("entries"),
+                sorted: false,
+                keys: meta("keys", false),
+                values: meta("values", false),
+            },
+            keys: Box::new(utf8(vec![0, 1, 2, 3], b"abc")),
+            values: Box::new(int32(vec![1, 2, 3])),
+        }),
+        Array::Union(UnionArray {
+            types: vec![0, 1, 0],
+            offsets: Some(vec![0, 0, 1]),
+            fields: vec![
+                (0, meta("a", false), int32(vec![1, 2])),
+                (1, meta("b", false), utf8(vec![0, 1], b"x")),
+            ],
+        }),
+        Array::Union(UnionArray {
+            types: vec![1, 0],
+            offsets: None,
+            fields: vec![
+                (0, meta("a", false), int32(vec![1, 2])),
+                (1, meta("b", false), utf8(vec![0, 1, 2], b"xy")),
+            ],
+        }),
+    ]
+}
+
+#[test]
+fn views_serialize_like_arrays() {
+    use crate::array::Array;
+
+    for array in example_arrays() {
+        let expected = bincode::serialize(&array).unwrap();
+        let actual = bincode::serialize(&array.as_view()).unwrap();
+        assert_eq!(actual, expected, "{array:?}");
+
+        let roundtripped: Array = bincode::deserialize(&actual).unwrap();
+        assert_eq!(roundtripped, array);
+
+        let json = serde_json::to_string(&array.as_view()).unwrap();
+        assert_eq!(json, serde_json::to_string(&array).unwrap());
+        assert_eq!(serde_json::from_str::<Array>(&json).unwrap(), array);
+    }
+}
+
+#[test]
+fn sliced_views_serialize_like_copied_arrays() {
+    use crate::{
+        array::{view_len, Array},
+        view::slice_view,
+    };
+
+    for array in example_arrays() {
+        if matches!(
+            array,
+            Array::Utf8View(_) | Array::RunEndEncoded(_) | Array::Union(_)
+        ) {
+            // views and dense unions keep unreferenced data, run end encoded arrays cannot
+            // be sliced
+            continue;
+        }
+        let len = view_len(&array.as_view()).unwrap();
+        let view = slice_view(&array.as_view(), 1, len - 1).unwrap();
+        let bytes = bincode::serialize(&view).unwrap();
+        let actual: Array = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(actual, Array::try_from(view).unwrap(), "{array:?}");
+    }
+}
+
+#[test]
+fn run_end_encoded_children_are_written_in_full() {
+    use crate::{
+        array::{Array, ListArray, PrimitiveArray, RunEndEncodedArray},
+        datatypes::RunEndEncodedMeta,
+        view::slice_view,
+    };
+
+    let elements = Array::RunEndEncoded(RunEndEncodedArray {
+        meta: RunEndEncodedMeta::default(),
+        run_ends: Box::new(Array::Int32(PrimitiveArray {
+            validity: None,
+            values: vec![2, 5],
+        })),
+        values: Box::new(Array::Int64(PrimitiveArray {
+            validity: None,
+            values: vec![1, 2],
+        })),
+    });
+    let array = Array::List(ListArray {
+        validity: None,
+        offsets: vec![0_i32, 2, 3, 5],
+        meta: FieldMeta::default(),
+        elements: Box::new(elements.clone()),
+    });
+    let view = slice_view(&array.as_view(), 1, 2).unwrap();
+
+    let bytes = bincode::serialize(&view).unwrap();
+    let actual: Array = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(
+        actual,
+        Array::List(ListArray {
+            validity: None,
+            offsets: vec![2, 3, 5],
+            meta: FieldMeta::default(),
+            elements: Box::new(elements),
+        }),
+    );
+}
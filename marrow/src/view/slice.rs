@@ -0,0 +1,195 @@
+//! Slicing of views without copying the underlying data
+use crate::{
+    error::{fail, ErrorKind, Result},
+    view::{
+        BitsWithOffset, BooleanView, BytesView, BytesViewView, DecimalView, DictionaryView,
+        FixedSizeBinaryView, FixedSizeListView, ListView, MapView, NullView, PrimitiveView,
+        StructView, TimeView, TimestampView, UnionView, View,
+    },
+};
+
+/// Slice the view without copying the underlying data
+///
+/// Run end encoded views cannot be sliced, as they have no offset.
+pub(crate) fn slice_view<'a>(view: &View<'a>, start: usize, len: usize) -> Result<View<'a>> {
+    fn slice<T>(data: &[T], start: usize, len: usize) -> Result<&[T]> {
+        let Some(res) = data.get(start..start + len) else {
+            fail!(
+                ErrorKind::Unsupported,
+                "Cannot slice {len} elements starting at {start} from {total} elements",
+                total = data.len(),
+            );
+        };
+        Ok(res)
+    }
+    fn slice_bits(bits: BitsWithOffset<'_>, start: usize) -> BitsWithOffset<'_> {
+        BitsWithOffset {
+            offset: bits.offset + start,
+            data: bits.data,
+        }
+    }
+    fn slice_validity(
+        validity: Option<BitsWithOffset<'_>>,
+        start: usize,
+    ) -> Option<BitsWithOffset<'_>> {
+        validity.map(|bits| slice_bits(bits, start))
+    }
+
+    macro_rules! slice_primitive {
+        ($variant:ident, $view:expr) => {
+            View::$variant(PrimitiveView {
+                validity: slice_validity($view.validity, start),
+                values: slice($view.values, start, len)?,
+            })
+        };
+    }
+    macro_rules! slice_time {
+        ($variant:ident, $view:expr) => {
+            View::$variant(TimeView {
+                unit: $view.unit,
+                validity: slice_validity($view.validity, start),
+                values: slice($view.values, start, len)?,
+            })
+        };
+    }
+    macro_rules! slice_bytes {
+        ($variant:ident, $view:expr) => {
+            View::$variant(BytesView {
+                validity: slice_validity($view.validity, start),
+                offsets: slice($view.offsets, start, len + 1)?,
+                data: $view.data,
+            })
+        };
+    }
+    macro_rules! slice_bytes_view {
+        ($variant:ident, $view:expr) => {
+            View::$variant(BytesViewView {
+                validity: slice_validity($view.validity, start),
+                data: slice($view.data, start, len)?,
+                buffers: $view.buffers.clone(),
+            })
+        };
+    }
+    macro_rules! slice_list {
+        ($variant:ident, $view:expr) => {
+            View::$variant(ListView {
+                validity: slice_validity($view.validity, start),
+                offsets: slice($view.offsets, start, len + 1)?,
+                meta: $view.meta.clone(),
+                elements: $view.elements.clone(),
+            })
+        };
+    }
+
+    let view = match view {
+        View::Null(_) => View::Null(NullView { len }),
+        View::Boolean(view) => View::Boolean(BooleanView {
+            len,
+            validity: slice_validity(view.validity, start),
+            values: slice_bits(view.values, start),
+        }),
+        View::Int8(view) => slice_primitive!(Int8, view),
+        View::Int16(view) => slice_primitive!(Int16, view),
+        View::Int32(view) => slice_primitive!(Int32, view),
+        View::Int64(view) => slice_primitive!(Int64, view),
+        View::UInt8(view) => slice_primitive!(UInt8, view),
+        View::UInt16(view) => slice_primitive!(UInt16, view),
+        View::UInt32(view) => slice_primitive!(UInt32, view),
+        View::UInt64(view) => slice_primitive!(UInt64, view),
+        View::Float16(view) => slice_primitive!(Float16, view),
+        View::Float32(view) => slice_primitive!(Float32, view),
+        View::Float64(view) => slice_primitive!(Float64, view),
+        View::Date32(view) => slice_primitive!(Date32, view),
+        View::Date64(view) => slice_primitive!(Date64, view),
+        View::YearMonthInterval(view) => slice_primitive!(YearMonthInterval, view),
+        View::DayTimeInterval(view) => slice_primitive!(DayTimeInterval, view),
+        View::MonthDayNanoInterval(view) => slice_primitive!(MonthDayNanoInterval, view),
+        View::Time32(view) => slice_time!(Time32, view),
+        View::Time64(view) => slice_time!(Time64, view),
+        View::Duration(view) => slice_time!(Duration, view),
+        View::Timestamp(view) => View::Timestamp(TimestampView {
+            unit: view.unit,
+            timezone: view.timezone.clone(),
+            validity: slice_validity(view.validity, start),
+            values: slice(view.values, start, len)?,
+        }),
+        View::Decimal128(view) => View::Decimal128(DecimalView {
+            precision: view.precision,
+            scale: view.scale,
+            validity: slice_validity(view.validity, start),
+            values: slice(view.values, start, len)?,
+        }),
+        View::Utf8(view) => slice_bytes!(Utf8, view),
+        View::LargeUtf8(view) => slice_bytes!(LargeUtf8, view),
+        View::Binary(view) => slice_bytes!(Binary, view),
+        View::LargeBinary(view) => slice_bytes!(LargeBinary, view),
+        View::Utf8View(view) => slice_bytes_view!(Utf8View, view),
+        View::BinaryView(view) => slice_bytes_view!(BinaryView, view),
+        View::FixedSizeBinary(view) => {
+            let n = usize::try_from(view.n)?;
+            View::FixedSizeBinary(FixedSizeBinaryView {
+                n: view.n,
+                validity: slice_validity(view.validity, start),
+                data: slice(view.data, start * n, len * n)?,
+            })
+        }
+        View::Struct(view) => {
+            let mut fields = Vec::new();
+            for (meta, field) in &view.fields {
+                fields.push((meta.clone(), slice_view(field, start, len)?));
+            }
+            View::Struct(StructView {
+                len,
+                validity: slice_validity(view.validity, start),
+                fields,
+            })
+        }
+        View::List(view) => slice_list!(List, view),
+        View::LargeList(view) => slice_list!(LargeList, view),
+        View::FixedSizeList(view) => {
+            let n = usize::try_from(view.n)?;
+            View::FixedSizeList(FixedSizeListView {
+                len,
+                n: view.n,
+                validity: slice_validity(view.validity, start),
+                meta: view.meta.clone(),
+                elements: Box::new(slice_view(&view.elements, start * n, len * n)?),
+            })
+        }
+        View::Map(view) => View::Map(MapView {
+            validity: slice_validity(view.validity, start),
+            offsets: slice(view.offsets, start, len + 1)?,
+            meta: view.meta.clone(),
+            keys: view.keys.clone(),
+            values: view.values.clone(),
+        }),
+        View::Dictionary(view) => View::Dictionary(DictionaryView {
+            keys: Box::new(slice_view(&view.keys, start, len)?),
+            values: view.values.clone(),
+        }),
+        View::Union(view) => {
+            let mut fields = Vec::new();
+            for (type_id, meta, field) in &view.fields {
+                // dense unions may reference any child element, sparse unions are aligned
+                let field = match view.offsets {
+                    Some(_) => field.clone(),
+                    None => slice_view(field, start, len)?,
+                };
+                fields.push((*type_id, meta.clone(), field));
+            }
+            View::Union(UnionView {
+                types: slice(view.types, start, len)?,
+                offsets: match view.offsets {
+                    Some(offsets) => Some(slice(offsets, start, len)?),
+                    None => None,
+                },
+                fields,
+            })
+        }
+        View::RunEndEncoded(_) => fail!(
+            ErrorKind::Unsupported,
+            "Cannot view slices of RunEndEncoded arrays"
+        ),
+    };
+    Ok(view)
+}
@@ -1,13 +1,15 @@
 #![cfg_attr(any(), rustfmt::skip)]
 
 macro_rules! define_test_module {
-    ($feature:literal, $mod:ident, $array_mod:ident, $schema_mod:ident, $($test_mod:ident),* $(,)?) => {
+    ($feature:literal, $version:literal, $mod:ident, $array_mod:ident, $schema_mod:ident, $to_arrow:ident, $($test_mod:ident),* $(,)?) => {
         #[cfg(all(test, feature = $feature))]
         mod $mod {
             $(
                 mod $test_mod {
                     #[allow(unused)]
-                    use { $array_mod as arrow_array, $schema_mod as arrow_schema };
+                    use { $array_mod as arrow_array, $schema_mod as arrow_schema, marrow::bridge::$mod::$to_arrow as bridge_to_arrow };
+                    #[allow(unused)]
+                    const ARROW_VERSION: u32 = $version;
                     
                     include!(concat!("tests/", stringify!($test_mod), ".rs"));
                 }
@@ -16,25 +18,25 @@ macro_rules! define_test_module {
     };
 }
 
-// arrow-version:insert: define_test_module!("arrow-{version}", arrow_{version}, arrow_array_{version}, arrow_schema_{version}, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-58", arrow_58, arrow_array_58, arrow_schema_58, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-56", arrow_56, arrow_array_56, arrow_schema_56, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-55", arrow_55, arrow_array_55, arrow_schema_55, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-54", arrow_54, arrow_array_54, arrow_schema_54, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-53", arrow_53, arrow_array_53, arrow_schema_53, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views);
-define_test_module!("arrow-52", arrow_52, arrow_array_52, arrow_schema_52, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays);
-define_test_module!("arrow-51", arrow_51, arrow_array_51, arrow_schema_51, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays);
-define_test_module!("arrow-50", arrow_50, arrow_array_50, arrow_schema_50, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays);
-define_test_module!("arrow-49", arrow_49, arrow_array_49, arrow_schema_49, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays);
-define_test_module!("arrow-48", arrow_48, arrow_array_48, arrow_schema_48, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays);
-define_test_module!("arrow-47", arrow_47, arrow_array_47, arrow_schema_47, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays);
-define_test_module!("arrow-46", arrow_46, arrow_array_46, arrow_schema_46, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-45", arrow_45, arrow_array_45, arrow_schema_45, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-44", arrow_44, arrow_array_44, arrow_schema_44, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-43", arrow_43, arrow_array_43, arrow_schema_43, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-42", arrow_42, arrow_array_42, arrow_schema_42, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-41", arrow_41, arrow_array_41, arrow_schema_41, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-40", arrow_40, arrow_array_40, arrow_schema_40, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-39", arrow_39, arrow_array_39, arrow_schema_39, utils, arrays, data_types,struct_arrays);
-define_test_module!("arrow-38", arrow_38, arrow_array_38, arrow_schema_38, utils, arrays, data_types);
-define_test_module!("arrow-37", arrow_37, arrow_array_37, arrow_schema_37, utils, arrays, data_types);
\ No newline at end of file
+// arrow-version:insert: define_test_module!("arrow-{version}", {version}, arrow_{version}, arrow_array_{version}, arrow_schema_{version}, to_arrow_{version}, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-58", 58, arrow_58, arrow_array_58, arrow_schema_58, to_arrow_58, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-56", 56, arrow_56, arrow_array_56, arrow_schema_56, to_arrow_56, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-55", 55, arrow_55, arrow_array_55, arrow_schema_55, to_arrow_55, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-54", 54, arrow_54, arrow_array_54, arrow_schema_54, to_arrow_54, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-53", 53, arrow_53, arrow_array_53, arrow_schema_53, to_arrow_53, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, views, sliced_arrays, owned_arrays, generated);
+define_test_module!("arrow-52", 52, arrow_52, arrow_array_52, arrow_schema_52, to_arrow_52, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, intervals, union_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-51", 51, arrow_51, arrow_array_51, arrow_schema_51, to_arrow_51, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-50", 50, arrow_50, arrow_array_50, arrow_schema_50, to_arrow_50, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-49", 49, arrow_49, arrow_array_49, arrow_schema_49, to_arrow_49, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-48", 48, arrow_48, arrow_array_48, arrow_schema_48, to_arrow_48, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-47", 47, arrow_47, arrow_array_47, arrow_schema_47, to_arrow_47, utils, arrays, data_types,struct_arrays, fixed_size_binary_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-46", 46, arrow_46, arrow_array_46, arrow_schema_46, to_arrow_46, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-45", 45, arrow_45, arrow_array_45, arrow_schema_45, to_arrow_45, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-44", 44, arrow_44, arrow_array_44, arrow_schema_44, to_arrow_44, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-43", 43, arrow_43, arrow_array_43, arrow_schema_43, to_arrow_43, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-42", 42, arrow_42, arrow_array_42, arrow_schema_42, to_arrow_42, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-41", 41, arrow_41, arrow_array_41, arrow_schema_41, to_arrow_41, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-40", 40, arrow_40, arrow_array_40, arrow_schema_40, to_arrow_40, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-39", 39, arrow_39, arrow_array_39, arrow_schema_39, to_arrow_39, utils, arrays, data_types,struct_arrays, sliced_arrays, owned_arrays);
+define_test_module!("arrow-38", 38, arrow_38, arrow_array_38, arrow_schema_38, to_arrow_38, utils, arrays, data_types, sliced_arrays, owned_arrays);
+define_test_module!("arrow-37", 37, arrow_37, arrow_array_37, arrow_schema_37, to_arrow_37, utils, arrays, data_types, sliced_arrays, owned_arrays);
\ No newline at end of file
@@ -1256,7 +1256,7 @@ mod list {
                 meta: FieldMeta {
                     name: String::from("item"),
                     nullable: true,
-                    metadata: Default::default(),
+                    ..FieldMeta::default()
                 },
                 elements: Box::new(Array::Int32(PrimitiveArray {
                     validity: Some(marrow::bit_vec![true, true, true, false]),
@@ -1321,7 +1321,7 @@ mod large_list {
                 meta: FieldMeta {
                     name: String::from("item"),
                     nullable: true,
-                    metadata: Default::default(),
+                    ..FieldMeta::default()
                 },
                 elements: Box::new(Array::Int32(PrimitiveArray {
                     validity: Some(marrow::bit_vec!(true, true, true, false)),
@@ -1400,7 +1400,7 @@ mod fixed_size_list {
                 meta: FieldMeta {
                     name: String::from("item"),
                     nullable: true,
-                    metadata: Default::default(),
+                    ..FieldMeta::default()
                 },
                 elements: Box::new(Array::Int32(PrimitiveArray {
                     validity: Some(marrow::bit_vec![
@@ -7,6 +7,7 @@ fn assert_symmetric_conversion(arrow: AD, marrow: DataType) -> PanicOnError<()>
     // conversion via try-from
     assert_eq!(DataType::try_from(&arrow)?, marrow);
     assert_eq!(AD::try_from(&marrow)?, arrow);
+    marrow.validate()?;
 
     // conversion via serde
     assert_eq!(
@@ -112,3 +113,78 @@ fn binary_like() -> PanicOnError<()> {
     assert_symmetric_conversion(AD::LargeUtf8, DataType::LargeUtf8)?;
     Ok(())
 }
+
+#[test]
+fn validated_deserialization() -> PanicOnError<()> {
+    use marrow::datatypes::deserialize_validated;
+
+    let valid = serde_json::to_value(AD::Time32(AU::Millisecond))?;
+    assert_eq!(
+        deserialize_validated::<_, DataType>(valid)?,
+        DataType::Time32(TimeUnit::Millisecond),
+    );
+
+    let invalid = serde_json::to_value(AD::Time32(AU::Nanosecond))?;
+    assert_eq!(
+        serde_json::from_value::<DataType>(invalid.clone())?,
+        DataType::Time32(TimeUnit::Nanosecond),
+    );
+    let err = deserialize_validated::<_, DataType>(invalid).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid data type: Time32 does not support the unit Nanosecond",
+    );
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn dictionary_fields() -> PanicOnError<()> {
+    use marrow::{
+        array::{Array, DictionaryArray, PrimitiveArray, StructArray},
+        datatypes::{DictionaryMeta, Field, FieldMeta},
+    };
+
+    let data_type = AD::Dictionary(Box::new(AD::Int8), Box::new(AD::Utf8));
+    let arrow = arrow_schema::Field::new_dict("category", data_type, true, 3, true);
+    let marrow = Field::try_from(&arrow)?;
+    assert_eq!(
+        marrow.dictionary,
+        DictionaryMeta {
+            ordered: true,
+            id: Some(3),
+        }
+    );
+    assert_eq!(arrow_schema::Field::try_from(&marrow)?, arrow);
+
+    // serde uses the same format for the dictionary metadata
+    let value = serde_json::to_value(&marrow)?;
+    assert_eq!(value, serde_json::to_value(&arrow)?);
+    assert_eq!(serde_json::from_value::<Field>(value.clone())?, marrow);
+    assert_eq!(serde_json::from_value::<arrow_schema::Field>(value)?, arrow);
+
+    // the metadata of struct children is kept when converting arrays
+    let array = Array::Struct(StructArray {
+        len: 3,
+        validity: None,
+        fields: vec![(
+            FieldMeta {
+                name: String::from("category"),
+                nullable: true,
+                dictionary: marrow.dictionary.clone(),
+                ..FieldMeta::default()
+            },
+            Array::Dictionary(DictionaryArray {
+                keys: Box::new(Array::Int8(PrimitiveArray::from(vec![0, 1, 0]))),
+                values: Box::new(Array::Utf8(vec![Some("a"), Some("b")].into_iter().collect())),
+            }),
+        )],
+    });
+    let arrow_array = arrow_array::ArrayRef::try_from(array.clone())?;
+    let AD::Struct(fields) = arrow_array.data_type() else {
+        panic!("Unexpected data type");
+    };
+    assert_eq!(fields[0].as_ref(), &arrow);
+    assert_eq!(Array::try_from(arrow_array)?, array);
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use arrow_array::ArrayRef;
+use marrow::{
+    arbitrary::Options,
+    array::Array,
+    datatypes::{DataType, Field},
+    diff::diff_views,
+    proptest::field_and_array,
+    view::View,
+};
+use proptest::{
+    prop_assert_eq,
+    test_runner::{Config, TestCaseError, TestRunner},
+};
+
+fn fail<E: std::fmt::Display>(err: E) -> TestCaseError {
+    TestCaseError::fail(err.to_string())
+}
+
+/// Convert random fields and arrays into arrow and back again
+#[test]
+fn generated_arrays_round_trip() {
+    let mut runner = TestRunner::new(Config {
+        cases: 256,
+        failure_persistence: None,
+        ..Config::default()
+    });
+    let options = Options {
+        // arrow before 57 cannot compare run end encoded arrays at an offset, e.g., in lists
+        run_end_encoded: ARROW_VERSION >= 57,
+        ..Options::default()
+    };
+    let result = runner.run(&field_and_array(options), |(field, array)| {
+        let arrow_field = arrow_schema::Field::try_from(&field).map_err(fail)?;
+        prop_assert_eq!(&Field::try_from(&arrow_field).map_err(fail)?, &field, "field");
+
+        let arrow_array = ArrayRef::try_from(array.clone()).map_err(fail)?;
+        prop_assert_eq!(arrow_array.data_type(), arrow_field.data_type(), "data type");
+        prop_assert_eq!(
+            DataType::try_from(arrow_array.data_type()).map_err(fail)?,
+            array.data_type(),
+            "marrow data type"
+        );
+
+        let arrow_view = ArrayRef::try_from(array.as_view()).map_err(fail)?;
+        prop_assert_eq!(&arrow_view, &arrow_array, "array via marrow view");
+
+        let view = View::try_from(&*arrow_array).map_err(fail)?;
+        let arrow_via_view = ArrayRef::try_from(view).map_err(fail)?;
+        prop_assert_eq!(&arrow_via_view, &arrow_array, "array via arrow view");
+
+        let owned = Array::try_from(arrow_array.clone()).map_err(fail)?;
+        if let Some(diff) = diff_views(&array.as_view(), &owned.as_view()) {
+            return Err(TestCaseError::fail(format!("owned array differs:\n{diff}")));
+        }
+        let arrow_via_owned = ArrayRef::try_from(owned).map_err(fail)?;
+        prop_assert_eq!(&arrow_via_owned, &arrow_array, "array via owned array");
+
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use arrow_array::{types::Int32Type, ArrayRef};
+use marrow::{
+    array::{Array, BytesArray, PrimitiveArray},
+    datatypes::FieldMeta,
+};
+
+use super::utils::PanicOnError;
+
+/// Convert owned (sliced) arrays into marrow arrays and back again into arrow arrays
+fn assert_owned_roundtrip(array: ArrayRef) -> PanicOnError<()> {
+    for (offset, len) in [(0, array.len()), (1, array.len() - 1), (3, 2), (2, 0)] {
+        let sliced = array.slice(offset, len);
+        let owned = Array::try_from(sliced.clone())?;
+        let actual = ArrayRef::try_from(owned)?;
+
+        assert_eq!(actual.len(), len);
+        assert_eq!(&actual, &sliced, "slice {offset}..{}", offset + len);
+    }
+    Ok(())
+}
+
+#[test]
+fn moves_primitive_buffers() -> PanicOnError<()> {
+    let values = vec![1_i64, 2, 3, 4];
+    let ptr = values.as_ptr();
+
+    let array = ArrayRef::try_from(Array::Int64(PrimitiveArray {
+        validity: Some(vec![0b_1101]),
+        values,
+    }))?;
+    let Array::Int64(array) = Array::try_from(array)? else {
+        panic!("Unexpected array type");
+    };
+
+    assert_eq!(array.values, vec![1, 2, 3, 4]);
+    assert_eq!(array.validity, Some(vec![0b_1101]));
+    assert_eq!(array.values.as_ptr(), ptr);
+    Ok(())
+}
+
+#[test]
+fn moves_bitmaps() -> PanicOnError<()> {
+    let values = vec![0b_0101_u8];
+    let validity = vec![0b_1101_u8];
+    let (values_ptr, validity_ptr) = (values.as_ptr(), validity.as_ptr());
+
+    let array = ArrayRef::try_from(Array::Boolean(marrow::array::BooleanArray {
+        len: 4,
+        validity: Some(validity),
+        values,
+    }))?;
+    let Array::Boolean(array) = Array::try_from(array)? else {
+        panic!("Unexpected array type");
+    };
+
+    assert_eq!(array.values, vec![0b_0101]);
+    assert_eq!(array.validity, Some(vec![0b_1101]));
+    assert_eq!(array.values.as_ptr(), values_ptr);
+    assert_eq!(array.validity.as_ref().map(|v| v.as_ptr()), Some(validity_ptr));
+    Ok(())
+}
+
+#[test]
+fn moves_nested_buffers() -> PanicOnError<()> {
+    let data = b"foobarbaz".to_vec();
+    let ptr = data.as_ptr();
+
+    let array = ArrayRef::try_from(Array::List(marrow::array::ListArray {
+        validity: None,
+        offsets: vec![0, 2, 3],
+        meta: FieldMeta {
+            name: String::from("element"),
+            ..FieldMeta::default()
+        },
+        elements: Box::new(Array::Utf8(BytesArray {
+            validity: None,
+            offsets: vec![0, 3, 6, 9],
+            data,
+        })),
+    }))?;
+    let Array::List(array) = Array::try_from(array)? else {
+        panic!("Unexpected array type");
+    };
+    let Array::Utf8(elements) = *array.elements else {
+        panic!("Unexpected elements type");
+    };
+
+    assert_eq!(array.offsets, vec![0, 2, 3]);
+    assert_eq!(elements.data, b"foobarbaz");
+    assert_eq!(elements.data.as_ptr(), ptr);
+    Ok(())
+}
+
+#[test]
+fn copies_shared_buffers() -> PanicOnError<()> {
+    let array: ArrayRef = Arc::new(arrow_array::Int32Array::from(vec![1, 2, 3]));
+    let Array::Int32(owned) = Array::try_from(array.clone())? else {
+        panic!("Unexpected array type");
+    };
+
+    assert_eq!(owned.values, vec![1, 2, 3]);
+    assert_eq!(array.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn primitive() -> PanicOnError<()> {
+    assert_owned_roundtrip(Arc::new(arrow_array::Int32Array::from(vec![
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(6),
+        Some(7),
+        None,
+        Some(9),
+        Some(10),
+    ])))
+}
+
+#[test]
+fn boolean() -> PanicOnError<()> {
+    assert_owned_roundtrip(Arc::new(arrow_array::BooleanArray::from(vec![
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        Some(true),
+        None,
+        Some(false),
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+    ])))
+}
+
+#[test]
+fn large_utf8() -> PanicOnError<()> {
+    assert_owned_roundtrip(Arc::new(arrow_array::LargeStringArray::from(vec![
+        Some("foo"),
+        None,
+        Some("bar"),
+        Some(""),
+        Some("hello world"),
+        None,
+        Some("baz"),
+    ])))
+}
+
+#[test]
+fn list() -> PanicOnError<()> {
+    assert_owned_roundtrip(Arc::new(
+        arrow_array::ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5), None, Some(7)]),
+            Some(vec![Some(8)]),
+            None,
+            Some(vec![None, Some(10)]),
+        ]),
+    ))
+}
+
+#[test]
+fn dictionary() -> PanicOnError<()> {
+    assert_owned_roundtrip(Arc::new(
+        vec![Some("a"), None, Some("b"), Some("a"), Some("c"), None]
+            .into_iter()
+            .collect::<arrow_array::DictionaryArray<arrow_array::types::Int16Type>>(),
+    ))
+}
+
+#[test]
+fn run_end_encoded() -> PanicOnError<()> {
+    let array: ArrayRef = Arc::new(
+        vec![Some("a"), Some("a"), None, Some("b"), Some("b"), Some("b")]
+            .into_iter()
+            .collect::<arrow_array::RunArray<Int32Type>>(),
+    );
+    // NOTE: older arrow versions do not support comparing sliced run end encoded arrays
+    let Array::RunEndEncoded(sliced) = Array::try_from(array.slice(1, 3))? else {
+        panic!("Unexpected array type");
+    };
+    assert_eq!(
+        *sliced.run_ends,
+        Array::Int32(PrimitiveArray {
+            validity: None,
+            values: vec![1, 2, 3],
+        })
+    );
+    assert_eq!(
+        *sliced.values,
+        Array::Utf8(BytesArray {
+            validity: Some(vec![0b_101]),
+            offsets: vec![0, 1, 1, 2],
+            data: b"ab".to_vec(),
+        })
+    );
+    assert!(marrow::view::View::try_from(array.slice(1, 3).as_ref()).is_err());
+    Ok(())
+}
+
+#[test]
+fn struct_() -> PanicOnError<()> {
+    assert_owned_roundtrip(ArrayRef::try_from(Array::Struct(
+        marrow::array::StructArray {
+            len: 5,
+            validity: Some(vec![0b_10111]),
+            fields: vec![
+                (
+                    FieldMeta {
+                        name: String::from("a"),
+                        ..FieldMeta::default()
+                    },
+                    Array::Int32(PrimitiveArray {
+                        validity: None,
+                        values: vec![1, 2, 3, 4, 5],
+                    }),
+                ),
+                (
+                    FieldMeta {
+                        name: String::from("b"),
+                        nullable: true,
+                        ..FieldMeta::default()
+                    },
+                    Array::Utf8(BytesArray {
+                        validity: Some(vec![0b_11011]),
+                        offsets: vec![0, 1, 3, 3, 6, 10],
+                        data: b"abbcccdddd".to_vec(),
+                    }),
+                ),
+            ],
+        },
+    ))?)
+}
+
+#[test]
+fn shared_arrays() -> PanicOnError<()> {
+    let values = vec![1_i64, 2, 3, 4];
+    let ptr = values.as_ptr();
+
+    let array = marrow::shared::SharedArray::try_from(Array::Int64(PrimitiveArray {
+        validity: None,
+        values,
+    }))?;
+    let sliced = ArrayRef::try_from(array.slice(1, 2)?)?;
+    let copied = ArrayRef::try_from(array.clone())?;
+    let moved = ArrayRef::try_from(array)?;
+
+    let expected: ArrayRef = Arc::new(arrow_array::Int64Array::from(vec![1, 2, 3, 4]));
+    assert_eq!(&sliced, &expected.slice(1, 2));
+    assert_eq!(&copied, &expected);
+    assert_eq!(&moved, &expected);
+
+    let as_ptr = |array: &ArrayRef| {
+        array
+            .as_any()
+            .downcast_ref::<arrow_array::Int64Array>()
+            .unwrap()
+            .values()
+            .as_ptr()
+    };
+    // all arrays reference the shared buffer without copying
+    assert_eq!(as_ptr(&sliced), ptr.wrapping_add(1));
+    assert_eq!(as_ptr(&copied), ptr);
+    assert_eq!(as_ptr(&moved), ptr);
+    Ok(())
+}
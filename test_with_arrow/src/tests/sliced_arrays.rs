@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use arrow_array::{types::Int32Type, ArrayRef};
+use marrow::view::View;
+
+use super::utils::PanicOnError;
+
+/// Convert a sliced array into a view and back again into an arrow array
+fn assert_sliced_roundtrip(array: ArrayRef) -> PanicOnError<()> {
+    for (offset, len) in [(0, array.len()), (1, array.len() - 1), (3, 2), (2, 0)] {
+        let sliced = array.slice(offset, len);
+        let view = View::try_from(sliced.as_ref())?;
+        let actual = ArrayRef::try_from(view)?;
+
+        assert_eq!(actual.len(), len);
+        assert_eq!(actual.offset(), 0);
+        assert_eq!(&actual, &sliced, "slice {offset}..{}", offset + len);
+
+        let bridged = bridge_to_arrow(sliced.as_ref())?;
+        assert_eq!(&bridged, &sliced, "bridged slice {offset}..{}", offset + len);
+    }
+    Ok(())
+}
+
+#[test]
+fn primitive() -> PanicOnError<()> {
+    assert_sliced_roundtrip(Arc::new(arrow_array::Int32Array::from(vec![
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(6),
+        Some(7),
+        None,
+        Some(9),
+        Some(10),
+    ])))
+}
+
+#[test]
+fn boolean() -> PanicOnError<()> {
+    assert_sliced_roundtrip(Arc::new(arrow_array::BooleanArray::from(vec![
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        Some(true),
+        None,
+        Some(false),
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+    ])))
+}
+
+#[test]
+fn utf8() -> PanicOnError<()> {
+    assert_sliced_roundtrip(Arc::new(arrow_array::StringArray::from(vec![
+        Some("foo"),
+        None,
+        Some("bar"),
+        Some(""),
+        Some("hello world"),
+        None,
+        Some("baz"),
+    ])))
+}
+
+#[test]
+fn large_binary() -> PanicOnError<()> {
+    assert_sliced_roundtrip(Arc::new(arrow_array::LargeBinaryArray::from(vec![
+        Some(b"foo".as_slice()),
+        None,
+        Some(b"bar".as_slice()),
+        Some(b"".as_slice()),
+        Some(b"hello world".as_slice()),
+    ])))
+}
+
+#[test]
+fn list() -> PanicOnError<()> {
+    assert_sliced_roundtrip(Arc::new(
+        arrow_array::ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5), None, Some(7)]),
+            Some(vec![Some(8)]),
+            None,
+            Some(vec![None, Some(10)]),
+        ]),
+    ))
+}
+
+#[test]
+fn nested_slices() -> PanicOnError<()> {
+    let array: ArrayRef = Arc::new(
+        arrow_array::LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(4), Some(5), None, Some(7)]),
+            Some(vec![Some(8)]),
+            Some(vec![None, Some(10)]),
+            Some(vec![Some(11), Some(12)]),
+        ]),
+    );
+    assert_sliced_roundtrip(array.slice(1, 5))
+}
@@ -66,6 +66,12 @@ pub fn assert_arrays_eq(
         "array: arrow (left) != marrow (right)"
     );
 
+    let array_via_bridge = bridge_to_arrow(array_via_marrow.as_ref())?;
+    assert_eq!(
+        &array_via_arrow, &array_via_bridge,
+        "array: arrow (left) != bridge (right)"
+    );
+
     let view_via_arrow = View::try_from(&*array_via_arrow)?;
     let view_via_marrow = marrow_array.as_view();
 
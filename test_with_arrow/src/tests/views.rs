@@ -173,3 +173,18 @@ mod binary_view {
         )
     }
 }
+
+#[test]
+fn iterate_utf8_view() -> PanicOnError<()> {
+    let items = [Some("foo"), None, Some("a string longer than twelve bytes"), Some("")];
+    let array = arrow_array::StringViewArray::from(items.to_vec());
+
+    let marrow::view::View::Utf8View(view) =
+        marrow::view::View::try_from(&array as &dyn arrow_array::Array)?
+    else {
+        panic!("Unexpected view");
+    };
+    let actual = view.iter_str().collect::<marrow::error::Result<Vec<_>>>()?;
+    assert_eq!(actual, items);
+    Ok(())
+}
@@ -36,6 +36,11 @@ all_arrow_features = [
     "arrow-37",
 ]
 all_arrow2_features = ["arrow2-0-17", "arrow2-0-16"]
+all_polars_arrow_features = [
+    "polars-arrow-0-51",
+    "polars-arrow-0-50",
+    "polars-arrow-0-46",
+]
 default_features = f"serde,{all_arrow2_features[0]},{all_arrow_features[0]}"
 
 workflow_test_template = lambda: {
@@ -144,7 +149,15 @@ def _workflow_check_steps():
                 "name": f"Check {feature}",
                 "run": f"cargo check -p marrow --features {feature}",
             }
-            for feature in ("serde", *all_arrow2_features, *all_arrow_features)
+            for feature in (
+                "serde",
+                "chrono",
+                "time",
+                "jiff",
+                *all_arrow2_features,
+                *all_polars_arrow_features,
+                *all_arrow_features,
+            )
         ),
         {"name": "Check", "run": "cargo check --all-features"},
         {
@@ -167,6 +180,7 @@ def format():
     impl_files = [
         *self_path.joinpath("marrow", "src", "impl_arrow").glob("impl*.rs"),
         *self_path.joinpath("marrow", "src", "impl_arrow2").glob("impl*.rs"),
+        *self_path.joinpath("marrow", "src", "impl_polars_arrow").glob("impl*.rs"),
         *self_path.joinpath("test_with_arrow", "src", "tests").glob("*.rs"),
     ]
 
@@ -181,7 +195,15 @@ def check(all=False):
     _sh(f"cargo clippy --all-features")
 
     if all:
-        for features in ("serde", *all_arrow2_features, *all_arrow_features):
+        for features in (
+            "serde",
+            "chrono",
+            "time",
+            "jiff",
+            *all_arrow2_features,
+            *all_polars_arrow_features,
+            *all_arrow_features,
+        ):
             _sh(f"cargo check -p marrow --features {features}")
 
 